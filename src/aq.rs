@@ -0,0 +1,140 @@
+// Copyright (c) 2024-2025, The tinyavif contributors. All rights reserved
+//
+// This source code is subject to the terms of the BSD 2 Clause License and
+// the Alliance for Open Media Patent License 1.0. If the BSD 2 Clause License
+// was not distributed with this source code in the LICENSE file, you can
+// obtain it at www.aomedia.org/license/software. If the Alliance for Open
+// Media Patent License 1.0 was not distributed with this source code in the
+// PATENTS file, you can obtain it at www.aomedia.org/license/patent.
+
+// Adaptive quantization
+//
+// With a single qindex for the whole image, errors are spread fairly evenly.
+// But the eye is much more sensitive to errors in smooth areas (eg, banding in
+// skies) than in textured areas, where the texture itself masks them.
+// So we pick a qindex per superblock, lowering it in smooth areas and raising
+// it in textured ones. These are then signalled using the delta-q syntax.
+//
+// Alternatively, the same analysis can be done per 8x8 block, with the results
+// signalled using segmentation. This allows finer-grained adjustments, but is
+// limited to 8 distinct qindex values and costs extra bits per block.
+
+use crate::array2d::Array2D;
+use crate::consts::*;
+use crate::frame::Frame;
+use crate::util::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AQMode {
+  // Use the same qindex everywhere
+  None,
+  // Adjust qindex per superblock, based on the variance of the luma
+  Variance,
+  // Adjust qindex per 8x8 block, based on the variance of the luma,
+  // using segmentation
+  Segment,
+}
+
+// Largest change in quantizer step size allowed, in units of log2(step).
+// ie, superblocks can use anywhere from half to double the base step size
+const MAX_LOG2_STEP_ADJUSTMENT: f64 = 1.0;
+
+// Find the qindex whose AC quantizer is closest to the given step size
+// This is only ever used for lossy coding, so we never return qindex 0
+fn qindex_for_ac_q(step: f64) -> u8 {
+  let mut best_qindex = 1;
+  let mut best_error = f64::MAX;
+  for qindex in 1..=255 {
+    let error = (qindex_to_ac_q[qindex] as f64 - step).abs();
+    if error < best_error {
+      best_qindex = qindex;
+      best_error = error;
+    }
+  }
+  return best_qindex as u8;
+}
+
+// Pick a qindex for each superblock, based on how textured it is
+//
+// For each superblock, we measure the average of log2(1 + variance) over its
+// 8x8 luma blocks. Each unit this is above or below the frame average
+// then scales the quantizer step size by 2^(strength / 6). This is similar to
+// the variance-based AQ used by x264, where each unit corresponds to one QP step.
+pub fn variance_aq(source: &Frame, base_qindex: u8, strength: f64, sb_size: usize) -> Array2D<u8> {
+  let pixels = source.y().pixels();
+  let height = source.y().height();
+  let width = source.y().width();
+  let sb_rows = height.div_ceil(sb_size);
+  let sb_cols = width.div_ceil(sb_size);
+
+  let mut energy = Array2D::zeroed(sb_rows, sb_cols);
+  let mut total_energy = 0.0;
+  let mut total_blocks = 0;
+  for sb_row in 0..sb_rows {
+    for sb_col in 0..sb_cols {
+      let mut sum = 0.0;
+      let mut num_blocks = 0;
+      for y0 in (sb_row * sb_size .. min((sb_row + 1) * sb_size, height)).step_by(8) {
+        for x0 in (sb_col * sb_size .. min((sb_col + 1) * sb_size, width)).step_by(8) {
+          sum += (1.0 + block_variance(pixels, y0, x0, 8, 8)).log2();
+          num_blocks += 1;
+        }
+      }
+      energy[sb_row][sb_col] = sum / (num_blocks as f64);
+      total_energy += sum;
+      total_blocks += num_blocks;
+    }
+  }
+  let mean_energy = total_energy / (total_blocks as f64);
+
+  let base_step = qindex_to_ac_q[base_qindex as usize] as f64;
+  Array2D::new_with(sb_rows, sb_cols, |sb_row, sb_col| {
+    let log2_adjustment = (strength * (energy[sb_row][sb_col] - mean_energy) / 6.0)
+      .clamp(-MAX_LOG2_STEP_ADJUSTMENT, MAX_LOG2_STEP_ADJUSTMENT);
+    qindex_for_ac_q(base_step * log2_adjustment.exp2())
+  })
+}
+
+// Pick a qindex for each 8x8 block, based on how textured it is
+//
+// This uses the same measure as variance_aq(), but per block rather than per superblock.
+// The result is then turned into segments by Segmentation::from_block_qindex()
+pub fn block_aq(source: &Frame, base_qindex: u8, strength: f64) -> Array2D<u8> {
+  let pixels = source.y().pixels();
+  let block_rows = source.y().height() / 8;
+  let block_cols = source.y().width() / 8;
+
+  let energy = Array2D::new_with(block_rows, block_cols, |row, col| {
+    (1.0 + block_variance(pixels, row * 8, col * 8, 8, 8)).log2()
+  });
+  let mut total_energy = 0.0;
+  for row in 0..block_rows {
+    for col in 0..block_cols {
+      total_energy += energy[row][col];
+    }
+  }
+  let mean_energy = total_energy / ((block_rows * block_cols) as f64);
+
+  let base_step = qindex_to_ac_q[base_qindex as usize] as f64;
+  Array2D::new_with(block_rows, block_cols, |row, col| {
+    let log2_adjustment = (strength * (energy[row][col] - mean_energy) / 6.0)
+      .clamp(-MAX_LOG2_STEP_ADJUSTMENT, MAX_LOG2_STEP_ADJUSTMENT);
+    qindex_for_ac_q(base_step * log2_adjustment.exp2())
+  })
+}
+
+// Variance of a block of pixels
+fn block_variance(pixels: &Array2D<u8>, y0: usize, x0: usize, h: usize, w: usize) -> f64 {
+  let mut sum = 0u64;
+  let mut sum_sq = 0u64;
+  for i in 0..h {
+    for j in 0..w {
+      let px = pixels[y0 + i][x0 + j] as u64;
+      sum += px;
+      sum_sq += px * px;
+    }
+  }
+  let n = (h * w) as f64;
+  let mean = (sum as f64) / n;
+  return (sum_sq as f64) / n - mean * mean;
+}
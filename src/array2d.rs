@@ -85,6 +85,21 @@ impl<T: Zeroable> Array2D<T> {
   }
 }
 
+impl<T: Zeroable + Clone> Array2D<T> {
+  // Copy out a rectangular region into a new array
+  pub fn copy_region(&self, row_start: usize, col_start: usize, rows: usize, cols: usize) -> Self {
+    Array2D::new_with(rows, cols, |i, j| self[row_start + i][col_start + j].clone())
+  }
+
+  // Copy the contents of `src` into the region starting at (row_start, col_start)
+  // This is the inverse of copy_region()
+  pub fn paste_region(&mut self, row_start: usize, col_start: usize, src: &Self) {
+    for i in 0 .. src.rows {
+      self[row_start + i][col_start .. col_start + src.cols].clone_from_slice(&src[i]);
+    }
+  }
+}
+
 impl<T: Zeroable + Copy> Array2D<T> {
   pub fn transpose_into(&self, dst: &mut Self) {
     assert!(self.rows == dst.cols);
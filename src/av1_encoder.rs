@@ -10,31 +10,251 @@
 use bytemuck::Zeroable;
 use std::io;
 use std::fs::File;
+use std::cmp::Reverse;
 
+use crate::aq::*;
 use crate::array2d::Array2D;
 use crate::bitcode::BitWriter;
 use crate::cdf::*;
 use crate::consts::*;
-use crate::entropycode::EntropyWriter;
+use crate::entropycode::*;
 use crate::enums::*;
+use crate::film_grain::FilmGrainParams;
 use crate::frame::Frame;
+use crate::inter::*;
+use crate::intrabc::*;
+use crate::level::*;
+use crate::palette::*;
+use crate::rate::*;
+use crate::rdo::*;
 use crate::recon::*;
+use crate::segmentation::*;
+use crate::sequence_header::*;
+use crate::superres::*;
 use crate::util::*;
 use crate::y4m::*;
 
+// Frame-level quantizer deltas
+// These are added to the base qindex to get the qindex for each plane and
+// coefficient type. There is no luma AC delta, as the base qindex is used for that.
+// Valid range is -64 to +63
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub struct DeltaQ {
+  pub y_dc: i8,
+  pub u_dc: i8,
+  pub u_ac: i8,
+  pub v_dc: i8,
+  pub v_ac: i8,
+}
+
+impl DeltaQ {
+  pub fn dc(&self, plane: usize) -> i8 {
+    [self.y_dc, self.u_dc, self.v_dc][plane]
+  }
+
+  pub fn ac(&self, plane: usize) -> i8 {
+    [0, self.u_ac, self.v_ac][plane]
+  }
+
+  // Pick chroma deltas based on the content of the image
+  //
+  // Highly saturated images tend to show chroma artifacts (blurring and colour
+  // bleeding around edges) much more than the default chroma quantizers would
+  // suggest. So lower the chroma qindex in proportion to how far the average
+  // pixel is from neutral grey in each chroma plane.
+  pub fn auto_chroma(source: &Frame) -> Self {
+    let mut delta = [0i8; 3];
+    for plane in 1..3 {
+      let p = source.plane(plane);
+      let pixels = p.pixels();
+      let mut sum = 0u64;
+      for i in 0..p.crop_height() {
+        for j in 0..p.crop_width() {
+          sum += (pixels[i][j] as i32 - 128).unsigned_abs() as u64;
+        }
+      }
+      let saturation = sum / (p.crop_height() * p.crop_width()) as u64;
+
+      // Images with an average chroma deviation below 16 are left alone,
+      // beyond that we reduce qindex by 1 per 2 units, up to a maximum of 24
+      delta[plane] = -(min(saturation.saturating_sub(16) / 2, 24) as i8);
+    }
+
+    Self {
+      y_dc: 0,
+      u_dc: delta[1],
+      u_ac: delta[1],
+      v_dc: delta[2],
+      v_ac: delta[2],
+    }
+  }
+}
+
+// User-configurable encoder settings
+#[derive(Clone)]
+pub struct EncoderConfig {
+  // Multiplier applied to the rate-distortion lambda, for tuning experiments
+  pub lambda_scale: f64,
+
+  // Whether to use rate-distortion optimized quantization
+  pub trellis: bool,
+
+  // How to round coefficients during the initial quantization step
+  pub quantizer_mode: QuantizerMode,
+
+  // Quantizer matrix level for each plane, or None to disable quantizer matrices
+  pub qm_levels: Option<[u8; 3]>,
+
+  // Frame-level quantizer deltas
+  pub delta_q: DeltaQ,
+
+  // Adaptive quantization mode and strength, see aq.rs
+  pub aq_mode: AQMode,
+  pub aq_strength: f64,
+
+  // Segment map and per-segment features, or None to disable segmentation
+  pub segmentation: Option<Segmentation>,
+
+  // Whether to enable screen content tools, which allows palette mode to be used
+  pub screen_content_tools: bool,
+
+  // Whether to allow intra block copy. This requires screen content tools to be enabled
+  pub intrabc: bool,
+
+  // Superblock size in luma pixels, either 64 or 128
+  pub sb_size: usize,
+
+  // Superres denominator: the image is coded at (SUPERRES_NUM / superres_denom) times
+  // its real width, then upscaled. SUPERRES_NUM disables superres
+  pub superres_denom: u8,
+
+  // Film grain parameters, if the decoder should add synthetic grain to the image
+  pub film_grain: Option<FilmGrainParams>,
+
+  // Whether film grain is enabled in the sequence header. This must be set if any frame
+  // in the sequence has grain, and frames without grain then signal that explicitly
+  pub film_grain_params_present: bool,
+
+  // Whether to code a key frame, or an inter frame which is predicted from the
+  // previous frame. Inter frames require a full sequence header, and can't use superres
+  pub frame_type: FrameType,
+
+  // Position of this frame within the sequence, used for the order hint and frame ID
+  pub frame_index: usize,
+
+  // Whether to code only the luma plane. This is used for alpha planes, which are
+  // stored as separate monochrome images
+  pub monochrome: bool,
+}
+
+impl Default for EncoderConfig {
+  fn default() -> Self {
+    Self {
+      lambda_scale: 1.0,
+      trellis: true,
+      quantizer_mode: QuantizerMode::Nearest,
+      qm_levels: None,
+      delta_q: DeltaQ::default(),
+      aq_mode: AQMode::None,
+      aq_strength: 1.0,
+      segmentation: None,
+      screen_content_tools: false,
+      intrabc: false,
+      sb_size: 64,
+      superres_denom: SUPERRES_NUM,
+      film_grain: None,
+      film_grain_params_present: false,
+      frame_type: FrameType::KEY_FRAME,
+      frame_index: 0,
+      monochrome: false,
+    }
+  }
+}
+
+// Pick a superblock size for an image of the given size.
+// Large images benefit from 128x128 superblocks, as these reduce the per-superblock
+// signalling overhead (partitions at the top level, and delta-q values with variance AQ).
+// Smaller images are better served by 64x64 superblocks, which fit them more closely
+pub fn choose_sb_size(y_crop_width: usize, y_crop_height: usize) -> usize {
+  if min(y_crop_width, y_crop_height) > 480 { 128 } else { 64 }
+}
+
+// Superres is only considered at qindex values at least this high. Below this, there
+// are enough bits available that coding at full resolution is always better
+pub const SUPERRES_AUTO_MIN_QINDEX: u8 = 200;
+
+// Superres denominators to try in automatic mode
+const SUPERRES_AUTO_DENOMS: [u8; 5] = [8, 10, 12, 14, 16];
+
+// Pick a superres denominator for the given image, by encoding it at each candidate
+// width and comparing the rate-distortion cost of the upscaled result.
+// `config.superres_denom` is ignored.
+//
+// This costs a full encode per candidate, so up to five extra encodes, on top of the
+// real one. For sequences, it's only run on the first frame, and the chosen
+// denominator is then used for every frame, even if later frames would be better
+// served by a different one
+pub fn choose_superres_denom(source: &Frame, base_qindex: u8, config: &EncoderConfig) -> u8 {
+  if base_qindex < SUPERRES_AUTO_MIN_QINDEX {
+    return SUPERRES_NUM;
+  }
+
+  let crop_width = source.y().crop_width();
+  let crop_height = source.y().crop_height();
+  let lambda = qindex_to_lambda(base_qindex, config.lambda_scale);
+
+  // The frame header is only used to count bits, and the sequence header settings
+  // change its size by the same amount for every denominator
+  let seq = SequenceHeaderConfig::still_image(true, SEQ_LEVEL_MAX, Tier::Main);
+
+  let mut best_denom = SUPERRES_NUM;
+  let mut best_cost = f64::MAX;
+  for denom in SUPERRES_AUTO_DENOMS {
+    if denom != SUPERRES_NUM && superres_downscaled_width(crop_width, denom) < SUPERRES_MIN_WIDTH {
+      continue;
+    }
+
+    let mut trial_config = config.clone();
+    trial_config.superres_denom = denom;
+    let encoder = AV1Encoder::new(crop_width, crop_height, trial_config);
+    let frame_header = encoder.generate_frame_header(&seq, base_qindex, false);
+    let (tile_data, recon) = encoder.encode_image_with_recon(source, None, base_qindex);
+
+    let mut dist = 0;
+    for plane in 0..encoder.num_planes() {
+      let src = source.plane(plane);
+      dist += sse(src.pixels(), recon.plane(plane).pixels(), 0, 0, src.crop_height(), src.crop_width());
+    }
+    let bits = 8 * (frame_header.len() + tile_data.len());
+    let cost = dist as f64 + lambda * bits as f64;
+
+    if cost < best_cost {
+      best_denom = denom;
+      best_cost = cost;
+    }
+  }
+
+  return best_denom;
+}
+
 // Top-level encoder state
 pub struct AV1Encoder {
+  config: EncoderConfig,
+
   // Size used for encoding - always padded to a multiple of 8x8 luma pixels
   y_width: usize,
   y_height: usize,
   uv_width: usize,
   uv_height: usize,
 
-  // Original image size
+  // Original image size, or the downscaled size if using superres
   y_crop_width: usize,
   y_crop_height: usize,
   uv_crop_width: usize,
   uv_crop_height: usize,
+
+  // Width of the image after any superres upscaling, ie. the real width
+  upscaled_width: usize,
 }
 
 // "Mode info" unit - a struct representing the state of a single 4x4 luma pixel unit.
@@ -42,11 +262,11 @@ pub struct AV1Encoder {
 #[derive(Zeroable, Clone)]
 pub struct ModeInfo {
   // "Level context" for each plane
-  // This is the sum of absolute values of the coefficients in each block,
-  // capped at 63, and is used as part of the context for coefficient sizes
+  // This is the sum of absolute values of the coefficients in the transform block
+  // covering this unit, capped at 63, and is used as part of the context for coefficient sizes.
   //
-  // Note: As we don't use transform partitioning, this is never actually
-  // used for luma in this encoder. But it is required for chroma.
+  // Unlike the other fields, which are the same across each block, this and dc_sign
+  // are tracked per transform block, so can differ between units of the same block
   level_ctx: [u8; 3],
 
   // Sign of the DC coefficient for each plane
@@ -55,6 +275,177 @@ pub struct ModeInfo {
   // This way, we can compare the number of nearby +ve and -ve DC coefficients by
   // simply summing this value over nearby blocks.
   dc_sign: [i8; 3],
+
+  // Segment that this block belongs to, used to predict the segment IDs of later blocks
+  segment_id: u8,
+
+  // Luma prediction mode, used as context for the y modes of later blocks.
+  // IntraBC blocks count as DC_PRED, which is what this is zero-initialized to.
+  // For inter blocks, this holds the inter mode (NEARESTMV, NEARMV, GLOBALMV, or NEWMV)
+  y_mode: u8,
+
+  // Whether this block was coded with no residual, used as context for later skip flags
+  skip: bool,
+
+  // Width (and height) of the luma transform blocks within this block, in pixels.
+  // This is used as context for the transform sizes of later blocks
+  tx_size: u8,
+
+  // Width (and height) of this block, in luma pixels.
+  // This is used as context for the partitions of later blocks
+  bsize: u8,
+
+  // Palette size and colours for luma (index 0) and chroma (index 1), used to
+  // build the palette cache for later blocks. For chroma, only the U colours
+  // are needed. A size of 0 means that the block doesn't use palette mode.
+  palette_size: [u8; 2],
+  palette_colors: [[u8; PALETTE_MAX_SIZE]; 2],
+
+  // Whether this block uses intra block copy, and if so, its displacement vector
+  // in luma pixels, as (row, col). These are used to predict the DVs of later blocks
+  use_intrabc: bool,
+  dv: [i16; 2],
+
+  // Whether this block is predicted from the previous frame, and if so, its motion
+  // vector in units of 1/8 luma pixel, as (row, col). These are used as context for
+  // the modes of later blocks, and to predict their MVs
+  is_inter: bool,
+  mv: [i16; 2],
+}
+
+// Luma intra prediction mode, other than palette mode
+#[derive(Clone, Copy, PartialEq)]
+struct LumaMode {
+  y_mode: PredictionMode,
+  // Offset from the base angle of a directional mode, in units of ANGLE_STEP degrees
+  angle_delta: i8,
+  // Filter intra mode, which replaces DC_PRED if set
+  filter_intra_mode: Option<FilterIntraMode>,
+}
+
+const DC_MODE: LumaMode = LumaMode { y_mode: PredictionMode::DC_PRED, angle_delta: 0, filter_intra_mode: None };
+
+impl LumaMode {
+  // Prediction direction used as context for the transform type. Filter intra modes
+  // map to the directional mode which they most resemble
+  fn intra_dir(&self) -> PredictionMode {
+    match self.filter_intra_mode {
+      Some(filter_intra_mode) => Filter_Intra_Mode_To_Intra_Dir[filter_intra_mode as usize],
+      None => self.y_mode,
+    }
+  }
+}
+
+// Number of luma modes which are fully encoded when choosing between them,
+// after ranking all of the modes by their prediction error
+const LUMA_MODE_CANDIDATES: usize = 3;
+
+// Palettes used by a block, if any
+#[derive(Clone, Copy)]
+struct BlockPalettes {
+  y: Option<Palette>,
+  // U and V palettes, which share a single colour map
+  uv: Option<[Palette; 2]>,
+}
+
+// How a block is predicted
+#[derive(Clone, Copy)]
+enum BlockMode {
+  // Intra prediction, with DC_PRED for chroma, or palette prediction for any
+  // planes which have a palette
+  Intra(LumaMode, BlockPalettes),
+  // Intra block copy, using the given displacement vector (see intrabc.rs)
+  IntraBC([i16; 2]),
+  // Inter prediction from the previous frame (see inter.rs)
+  Inter(InterMode),
+}
+
+// Inter prediction settings for a block
+#[derive(Clone, Copy)]
+struct InterMode {
+  // One of NEARESTMV, NEARMV, GLOBALMV, or NEWMV
+  y_mode: PredictionMode,
+  // Which entry of the reference MV stack is used, for NEARMV and NEWMV.
+  // This corresponds to RefMvIdx in the spec
+  ref_mv_idx: usize,
+  // The resulting motion vector, in units of 1/8 luma pixel
+  mv: [i16; 2],
+}
+
+// List of candidate motion vectors (or displacement vectors) for predicting the
+// vector of the current block, gathered from nearby blocks, along with the other
+// information the spec's find_mv_stack() process produces
+struct MvStack {
+  // Candidate vectors and their weights, sorted by weight within the nearest
+  // and outer groups of neighbours, then clamped. This is RefStackMv in the spec
+  entries: Vec<([i16; 2], u32)>,
+  // Contexts for coding the inter mode
+  new_mv_ctx: usize,
+  ref_mv_ctx: usize,
+}
+
+impl MvStack {
+  // Predicted vector at the given position in the stack. Missing entries
+  // are filled in with the global motion vector, which is always zero for us
+  fn ref_mv(&self, idx: usize) -> [i16; 2] {
+    self.entries.get(idx).map_or([0, 0], |&(mv, _)| mv)
+  }
+
+  // Context for coding whether to use entry `idx + 1` rather than entry `idx`
+  fn drl_ctx(&self, idx: usize) -> usize {
+    let w0 = self.entries[idx].1;
+    let w1 = self.entries[idx + 1].1;
+    if w0 >= REF_CAT_LEVEL {
+      if w1 < REF_CAT_LEVEL { 1 } else { 0 }
+    } else {
+      2
+    }
+  }
+}
+
+// Which set of transform types a transform block chooses from. Inter blocks, including
+// IntraBC blocks, use one set, while intra blocks use another, where the CDF can depend
+// on the prediction direction
+#[derive(Clone, Copy)]
+enum TxSet {
+  Intra(PredictionMode),
+  Inter,
+}
+
+// Contexts for coefficient coding which depend on the neighbouring transform blocks
+struct CoeffContext {
+  all_zero_ctx: usize,
+  dc_sign_ctx: usize,
+}
+
+// Quantized coefficients for one transform block, along with the contexts
+// needed to code them
+struct TxBlock {
+  plane: usize,
+  ctx: CoeffContext,
+  coeffs: Array2D<i32>,
+}
+
+// Saved state of a TileEncoder, covering the bitstream plus one block's worth
+// of mode info and reconstructed pixels. This allows the encoder to try out a
+// coding choice for that block, then undo it.
+pub struct TileCheckpoint {
+  bitstream: EntropyWriterCheckpoint,
+
+  mi_row: usize,
+  mi_col: usize,
+  mode_info: Array2D<ModeInfo>,
+
+  // Saved reconstruction for each plane, along with its position
+  recon: [(usize, usize, Array2D<u8>); 3],
+
+  // Saved delta-q state
+  current_qindex: u8,
+  read_deltas: bool,
+  lambda: f64,
+
+  // Saved quantizer statistics, which are updated as each block is coded
+  adaptive_rounding: AdaptiveRounding,
 }
 
 // Mutable state used while encoding a single tile
@@ -64,18 +455,75 @@ pub struct TileEncoder<'a> {
 
   base_qindex: u8,
 
+  // Target qindex for each superblock when using adaptive quantization,
+  // or None if superblock-level delta-q is disabled
+  sb_qindex: Option<Array2D<u8>>,
+
+  // qindex used for the current block, including any superblock-level delta.
+  // This corresponds to CurrentQIndex in the spec
+  current_qindex: u8,
+
+  // Whether we still need to code the delta-q for the current superblock.
+  // This corresponds to ReadDeltas in the spec
+  read_deltas: bool,
+
+  // Rate-distortion tradeoff parameter, see rdo.rs
+  // This is updated for each block, to match the qindex it is coded with
+  lambda: f64,
+
+  // Coefficient statistics for QuantizerMode::Adaptive
+  adaptive_rounding: AdaptiveRounding,
+
   // Mode info per 4x4 luma pixel unit
   mode_info: Array2D<ModeInfo>,
 
+  // Hash table of source blocks, used to find candidates for intra block copy,
+  // or None if intra block copy is disabled
+  intrabc_hash: Option<BlockHashTable>,
+
   // Source frame
   // This is the image we are trying to reproduce
   // This must be pre-padded to match encoder.y_{width/height}, not the crop size
   source: &'a Frame,
 
+  // Reconstruction of the previous frame, for inter frames
+  reference: Option<&'a Frame>,
+
+  // Starting motion vector for each 8x8 block, from a frame-level motion search
+  motion_field: Option<Array2D<[i16; 2]>>,
+
   // Reconstructed frame
   recon: Frame,
 }
 
+// Superblock-level delta-q values are coded in units of (1 << DELTA_Q_RES).
+// Our adaptive quantization picks arbitrary qindex values, so use the finest resolution
+const DELTA_Q_RES: u32 = 0;
+
+// Write a single frame-level delta-q value
+fn write_delta_q(w: &mut BitWriter, delta_q: i8) {
+  if delta_q == 0 {
+    w.write_bit(0);
+  } else {
+    w.write_bit(1);
+    w.write_signed_bits(delta_q as i64, 7);
+  }
+}
+
+// Largest block size which we code as a single block. Larger partitions are always split
+const MAX_BLOCK_SIZE: usize = 32;
+
+// Index of a block size in the CDF tables which have one entry per supported block size
+fn bsize_idx(bsize: usize) -> usize {
+  (floor_log2(bsize) - 3) as usize
+}
+
+// Select which set of default coefficient CDFs to use
+//
+// Note: This always depends on the frame's base_q_idx, even when some planes
+// use a different qindex due to the delta-q values. The spec loads the coefficient
+// CDFs once per frame based on base_q_idx, so using a per-plane qindex here would
+// desynchronize us from the decoder.
 fn get_qctx(base_qindex: u8) -> usize {
   if base_qindex <= 20 {
     0
@@ -89,11 +537,22 @@ fn get_qctx(base_qindex: u8) -> usize {
 }
 
 impl AV1Encoder {
-  pub fn new(y_crop_width: usize, y_crop_height: usize) -> Self {
+  pub fn new(y_crop_width: usize, y_crop_height: usize, mut config: EncoderConfig) -> Self {
     // Check limits imposed by AV1
     assert!(0 < y_crop_width && y_crop_width <= 65536);
     assert!(0 < y_crop_height && y_crop_height <= 65536);
 
+    // With superres, everything is coded at the downscaled width
+    let upscaled_width = y_crop_width;
+    let y_crop_width = if config.superres_denom != SUPERRES_NUM {
+      assert!(SUPERRES_DENOM_MIN <= config.superres_denom && config.superres_denom <= 16);
+      let width = superres_downscaled_width(upscaled_width, config.superres_denom);
+      assert!(width >= SUPERRES_MIN_WIDTH);
+      width
+    } else {
+      upscaled_width
+    };
+
     let y_width = y_crop_width.next_multiple_of(8);
     let y_height = y_crop_height.next_multiple_of(8);
 
@@ -103,7 +562,26 @@ impl AV1Encoder {
     let uv_width = y_width / 2;
     let uv_height = y_height / 2;
 
+    // Superres would require the reference frame to be scaled during prediction,
+    // which we don't support
+    assert!(config.frame_type == FrameType::KEY_FRAME || config.superres_denom == SUPERRES_NUM);
+
+    // The segment map is given at the original size, so needs to be downscaled
+    // along with the image. Keep each 8x8 block's units together, as they must
+    // share a segment ID
+    if let Some(segmentation) = &mut config.segmentation {
+      let src_block_cols = segmentation.map.cols() / 2;
+      let dst_block_cols = y_width / 8;
+      if src_block_cols != dst_block_cols {
+        let map = &segmentation.map;
+        segmentation.map = Array2D::new_with(map.rows(), dst_block_cols * 2, |mi_row, mi_col| {
+          map[mi_row][(mi_col / 2) * src_block_cols / dst_block_cols * 2]
+        });
+      }
+    }
+
     Self {
+      config: config,
       y_width: y_width,
       y_height: y_height,
       uv_width: uv_width,
@@ -112,112 +590,379 @@ impl AV1Encoder {
       y_crop_height: y_crop_height,
       uv_crop_width: uv_crop_width,
       uv_crop_height: uv_crop_height,
+      upscaled_width: upscaled_width,
     }
   }
 
-  pub fn generate_sequence_header(&self) -> Box<[u8]> {
+  // The U and V planes can only use different quantizer settings if this flag
+  // is set in the sequence header. As it costs extra bits in the frame header,
+  // only set it if needed
+  fn separate_uv_delta_q(&self) -> bool {
+    if self.config.monochrome {
+      return false;
+    }
+    let delta_q = &self.config.delta_q;
+    let separate_qm = match self.config.qm_levels {
+      Some([_, qm_u, qm_v]) => qm_u != qm_v,
+      None => false,
+    };
+    delta_q.u_dc != delta_q.v_dc || delta_q.u_ac != delta_q.v_ac || separate_qm
+  }
+
+  // Whether the qindex can vary per superblock
+  fn delta_q_present(&self) -> bool {
+    self.config.aq_mode == AQMode::Variance
+  }
+
+  fn use_superres(&self) -> bool {
+    self.config.superres_denom != SUPERRES_NUM
+  }
+
+  fn is_inter_frame(&self) -> bool {
+    self.config.frame_type == FrameType::INTER_FRAME
+  }
+
+  // Number of planes which are actually coded
+  pub fn num_planes(&self) -> usize {
+    if self.config.monochrome { 1 } else { 3 }
+  }
+
+  // Whether intra block copy is enabled for the frame. This can't be combined with superres,
+  // and is only allowed in intra frames
+  fn allow_intrabc(&self) -> bool {
+    self.config.screen_content_tools && self.config.intrabc && !self.use_superres() && !self.is_inter_frame()
+  }
+
+  // The sequence header settings are kept separate from the encoder config, as the level
+  // has to be chosen after encoding: it depends on the compressed size
+  pub fn generate_sequence_header(&self, seq: &SequenceHeaderConfig) -> Box<[u8]> {
+    assert!(seq.validate().is_ok());
     let mut w = BitWriter::new();
     
     w.write_bits(0, 3); // "Main" profile: 8 or 10 bits, YUV 4:2:0 or monochrome
-    w.write_bit(1); // Still picture
-    w.write_bit(1); // with simplified headers
-  
-    w.write_bits(31, 5); // Level = 31, a special value meaning no level-based constraints apply
+    w.write_bool(seq.still_picture);
+    w.write_bool(seq.reduced_still_picture_header); // Whether to use simplified headers
+
+    if seq.reduced_still_picture_header {
+      // Only the level is coded, and the tier is implicitly Main
+      w.write_bits(seq.operating_points[0].seq_level_idx as u64, 5);
+    } else {
+      seq.write_operating_points(&mut w);
+    }
   
     // Width and height - we first code how many bits to use for each value (here just use 16,
     // for simplicity), then one less than the actual width and height
     w.write_bits(15, 4);
     w.write_bits(15, 4);
-    w.write_bits((self.y_crop_width-1) as u64, 16);
+    w.write_bits((self.upscaled_width-1) as u64, 16);
     w.write_bits((self.y_crop_height-1) as u64, 16);
+
+    if !seq.reduced_still_picture_header {
+      w.write_bool(seq.frame_id_numbers.is_some());
+      if let Some(frame_ids) = &seq.frame_id_numbers {
+        w.write_bits((frame_ids.delta_frame_id_length - 2) as u64, 4);
+        w.write_bits((frame_ids.additional_frame_id_length - 1) as u64, 3);
+      }
+    }
   
+    w.write_bool(self.config.sb_size == 128); // Superblock size: 64x64 or 128x128
+    w.write_bit(1); // Enable filter intra
+    w.write_bit(1); // Enable intra edge filter
+
+    if !seq.reduced_still_picture_header {
+      seq.write_inter_tools(&mut w);
+    }
+
+    w.write_bool(self.use_superres()); // Enable superres
+
     // Now to disable a bunch of features we aren't going to use
-    // 6 zero bits means:
-    // * 64x64 superblocks
-    // * Disable filter-intra and intra-edge-filter
-    // * Disable superres, CDEF, and loop restoration
-    w.write_bits(0, 6);
+    // 2 zero bits means: disable CDEF and loop restoration
+    w.write_bits(0, 2);
   
     // Colour configuration
     w.write_bit(0); // 8 bits per pixel
-    w.write_bit(0); // Not monochrome, ie. we have chroma
+    w.write_bool(self.config.monochrome);
     w.write_bit(0); // No colour info for now - we can put it in the AVIF headers later
-    w.write_bit(0); // "TV" colour range
-    w.write_bits(0, 2); // Unknown chroma sample position
-    w.write_bit(0); // UV channels have shared delta-q values
+    if self.config.monochrome {
+      // Monochrome images are used for alpha planes, which AVIF requires to be full range.
+      // Nothing else is signalled in this case
+      w.write_bit(1); // "PC" colour range
+    } else {
+      w.write_bit(0); // "TV" colour range
+      w.write_bits(0, 2); // Unknown chroma sample position
+      w.write_bool(self.separate_uv_delta_q()); // Whether U and V can have different delta-q values
+    }
   
-    w.write_bit(0); // No film grain
+    w.write_bool(self.config.film_grain_params_present); // Film grain params present
   
     // Sequence headers always appear in their own OBU, so always add a trailing 1 bit
     return w.finalize(true);
   }
   
-  pub fn generate_frame_header(&self, base_qindex: u8, add_trailing_one_bit: bool) -> Box<[u8]> {
+  pub fn generate_frame_header(&self, seq: &SequenceHeaderConfig, base_qindex: u8, add_trailing_one_bit: bool) -> Box<[u8]> {
     let mut w = BitWriter::new();
+
+    // Inter frames can only be signalled with the full sequence header. We also don't
+    // signal the per-block inter-intra flag, so that tool must be disabled
+    let is_inter_frame = self.is_inter_frame();
+    assert!(!is_inter_frame || !seq.reduced_still_picture_header);
+    assert!(!is_inter_frame || !seq.enable_interintra_compound);
+
+    if !seq.reduced_still_picture_header {
+      w.write_bit(0); // Not showing an existing frame
+      w.write_bits(self.config.frame_type as u64, 2);
+      w.write_bit(1); // Show frame
+      if let (Some(info), Some(timing_info)) = (&seq.decoder_model_info, &seq.timing_info) {
+        if timing_info.num_ticks_per_picture.is_none() {
+          w.write_bits(0, info.frame_presentation_time_length); // frame_presentation_time
+        }
+      }
+      // Shown key frames are implicitly error resilient, but inter frames have to say
+      // that they aren't, so that they can use information from the reference frame
+      if is_inter_frame {
+        w.write_bit(0); // error_resilient_mode
+      }
+    }
     
     w.write_bit(1); // Disable CDF updates
-    w.write_bit(0); // Disable screen content tools
+    w.write_bool(self.config.screen_content_tools);
+    if self.config.screen_content_tools {
+      // force_integer_mv - this is implied to be 1 for intra frames, but still has to be signalled.
+      // Inter frames use quarter-pixel motion vectors
+      w.write_bool(!is_inter_frame);
+    }
+
+    if !seq.reduced_still_picture_header {
+      if let Some(frame_ids) = &seq.frame_id_numbers {
+        let frame_id = self.config.frame_index % (1 << frame_ids.frame_id_length());
+        w.write_bits(frame_id as u64, frame_ids.frame_id_length()); // current_frame_id
+      }
+      w.write_bit(0); // No frame size override: use the size from the sequence header
+      if let Some(bits) = seq.order_hint_bits {
+        let order_hint = self.config.frame_index % (1 << bits);
+        w.write_bits(order_hint as u64, bits);
+      }
+      // primary_ref_frame is implicitly PRIMARY_REF_NONE for intra frames. Inter frames
+      // also use PRIMARY_REF_NONE, so that they start from the default CDFs and other
+      // settings, like key frames do
+      if is_inter_frame {
+        w.write_bits(PRIMARY_REF_NONE as u64, 3);
+      }
+      if seq.decoder_model_info.is_some() {
+        w.write_bit(0); // No buffer removal times
+      }
+      // refresh_frame_flags is implicitly 0xFF for shown key frames. Inter frames
+      // only need to replace slot 0, as that is the only slot we reference
+      if is_inter_frame {
+        w.write_bits(0x01, 8);
+        if seq.order_hint_bits.is_some() {
+          w.write_bit(0); // frame_refs_short_signaling
+        }
+        // Point every reference at slot 0, which always holds the previous frame
+        for _ in 0..REFS_PER_FRAME {
+          w.write_bits(0, 3); // ref_frame_idx
+          if let Some(frame_ids) = &seq.frame_id_numbers {
+            // The previous frame's ID is one less than ours, which is coded as 0
+            w.write_bits(0, frame_ids.delta_frame_id_length); // delta_frame_id_minus_1
+          }
+        }
+      }
+    }
+    if self.use_superres() {
+      // superres_params() - only present if enabled in the sequence header
+      w.write_bit(1); // use_superres
+      w.write_bits((self.config.superres_denom - SUPERRES_DENOM_MIN) as u64, SUPERRES_DENOM_BITS);
+    }
     w.write_bit(0); // Render size = frame size
+    if is_inter_frame {
+      // allow_high_precision_mv is only signalled if force_integer_mv is 0, which it
+      // always is for inter frames. We only use quarter-pixel MVs
+      w.write_bit(0);
+      w.write_bit(0); // is_filter_switchable
+      w.write_bits(0, 2); // Interpolation filter = EIGHTTAP (the "regular" filter)
+      w.write_bit(0); // is_motion_mode_switchable
+      if seq.enable_ref_frame_mvs {
+        w.write_bit(0); // use_ref_frame_mvs
+      }
+    } else if self.config.screen_content_tools && !self.use_superres() {
+      w.write_bool(self.allow_intrabc()); // Whether intra block copy is enabled
+    }
   
     // Tile info
     // We need to code a tiling mode, then two zero bits to select 1x1 tiling.
-    // However, if the width or height is less than one superblock (ie, 64 pixels), the
-    // corresponding flag is implicitly set to 0 and doesn't need to be signalled.
+    // However, if the width or height is at most one superblock, the corresponding
+    // flag is implicitly set to 0 and doesn't need to be signalled.
     // So we need to add these conditionally
     w.write_bit(1); // Uniform tile mode - allows the cheapest signaling of 1x1 tile layout
-    if self.y_width > 64 {
+    if self.y_width > self.config.sb_size {
       w.write_bit(0); // 1 tile column
     }
-    if self.y_height > 64 {
+    if self.y_height > self.config.sb_size {
       w.write_bit(0); // 1 tile row
     }
   
     w.write_bits(base_qindex as u64, 8);
   
-    w.write_bits(0, 3); // No frame-level delta-qs (three bits: Y DC, UV DC, UV AC)
-    w.write_bit(0); // Don't use quantizer matrices
-    w.write_bit(0); // No segmentation
-    w.write_bit(0); // No superblock-level delta-q (=> no superblock-level delta-lf)
+    // Frame-level delta-qs
+    let delta_q = &self.config.delta_q;
+    let separate_uv_delta_q = self.separate_uv_delta_q();
+    write_delta_q(&mut w, delta_q.y_dc);
+    if self.num_planes() > 1 {
+      if separate_uv_delta_q {
+        w.write_bit(1); // diff_uv_delta: U and V deltas are coded separately
+      }
+      write_delta_q(&mut w, delta_q.u_dc);
+      write_delta_q(&mut w, delta_q.u_ac);
+      if separate_uv_delta_q {
+        write_delta_q(&mut w, delta_q.v_dc);
+        write_delta_q(&mut w, delta_q.v_ac);
+      }
+    }
+
+    if let Some([qm_y, qm_u, qm_v]) = self.config.qm_levels {
+      w.write_bit(1); // Use quantizer matrices
+      w.write_bits(qm_y as u64, 4);
+      w.write_bits(qm_u as u64, 4);
+      // qm_v is only signalled separately if separate_uv_delta_q is set.
+      // Otherwise it is implicitly equal to qm_u
+      if separate_uv_delta_q {
+        w.write_bits(qm_v as u64, 4);
+      }
+    } else {
+      w.write_bit(0); // Don't use quantizer matrices
+    }
+    match &self.config.segmentation {
+      Some(segmentation) => segmentation.write_params(&mut w),
+      None => w.write_bit(0), // No segmentation
+    }
+    if self.delta_q_present() {
+      w.write_bit(1); // Superblock-level delta-q present
+      w.write_bits(DELTA_Q_RES as u64, 2);
+      // Superblock-level delta-lf can't be used with intra block copy, so isn't signalled then
+      if !self.allow_intrabc() {
+        w.write_bit(0); // No superblock-level delta-lf
+      }
+    } else {
+      w.write_bit(0); // No superblock-level delta-q (=> no superblock-level delta-lf)
+    }
   
     // Deblocking params
-    w.write_bits(0, 6); // Strength 0 = 0
-    w.write_bits(0, 6); // Strength 1 = 0
-    w.write_bits(0, 3); // Sharpness = 0
-    w.write_bit(0); // No per-ref delta-lf (present even though it's irrelevant for still images)
+    // Intra block copy implicitly disables deblocking, so these are only coded without it
+    if !self.allow_intrabc() {
+      w.write_bits(0, 6); // Strength 0 = 0
+      w.write_bits(0, 6); // Strength 1 = 0
+      w.write_bits(0, 3); // Sharpness = 0
+      w.write_bit(0); // No per-ref delta-lf (present even though it's irrelevant for still images)
+    }
   
     // Transforms
-    w.write_bit(0); // Always use largest possible TX size for each block
+    w.write_bit(1); // TX_MODE_SELECT: Signal the transform size for each block
+    if is_inter_frame {
+      w.write_bit(0); // reference_select: Only use single-reference prediction
+      // Skip mode requires reference_select, so isn't signalled
+      if seq.enable_warped_motion {
+        w.write_bit(0); // allow_warped_motion
+      }
+    }
     w.write_bit(1); // Use reduced TX type selection
+
+    if is_inter_frame {
+      // Global motion params: No global motion for any reference frame
+      for _ in 0..REFS_PER_FRAME {
+        w.write_bit(0); // is_global
+      }
+    }
+
+    // Film grain params are only present if enabled in the sequence header
+    assert!(self.config.film_grain.is_none() || self.config.film_grain_params_present);
+    if self.config.film_grain_params_present {
+      match &self.config.film_grain {
+        Some(film_grain) => film_grain.write(&mut w, self.config.frame_type),
+        None => w.write_bit(0), // apply_grain
+      }
+    }
   
     // Frame header needs a trailing 1 bit if it's in a standalone FRAME_HEADER OBU, but *not*
     // if it's in an OBU_FRAME
     return w.finalize(add_trailing_one_bit);
   }
 
-  pub fn encode_image(&self, source: &Frame, base_qindex: u8) -> Box<[u8]> {
+  // Encode an image, and also return the frame which the decoder will output
+  // (before any film grain is added). Inter frames are predicted from `reference`,
+  // which must be the output of encoding the previous frame
+  pub fn encode_image_with_recon(&self, source: &Frame, reference: Option<&Frame>,
+                                 base_qindex: u8) -> (Box<[u8]>, Frame) {
+    assert!(source.y().crop_width() == self.upscaled_width);
+    assert!(source.y().crop_height() == self.y_crop_height);
+    assert!(reference.is_some() == self.is_inter_frame());
+
+    // With superres, we encode a downscaled copy of the source
+    let downscaled_source;
+    let source = if self.use_superres() {
+      downscaled_source = downscale_frame(source, self.y_crop_width);
+      &downscaled_source
+    } else {
+      source
+    };
+
     // Encode a single tile for now
     assert!(source.y().width() == self.y_width);
     assert!(source.y().height() == self.y_height);
 
-    // We don't currently support lossless mode
+    // We don't currently support lossless mode, either for the whole frame or
+    // for individual segments
     assert!(base_qindex != 0);
+    if let Some(segmentation) = &self.config.segmentation {
+      assert!(segmentation.map.rows() == self.y_height / 4);
+      assert!(segmentation.map.cols() == self.y_width / 4);
+      for segment_id in 0..=segmentation.last_active_seg_id() {
+        assert!(segmentation.get_qindex(segment_id as u8, base_qindex) != 0);
+      }
+    }
 
     // Allocate MI array
     let mi_rows = self.y_height / 4;
     let mi_cols = self.y_width / 4;
 
+    let sb_qindex = match self.config.aq_mode {
+      AQMode::None | AQMode::Segment => None,
+      AQMode::Variance => Some(variance_aq(source, base_qindex, self.config.aq_strength, self.config.sb_size)),
+    };
+
+    // For inter frames, find a rough MV for every block up front. These are refined
+    // per block during encoding
+    let motion_field = reference.map(|reference| {
+      assert!(reference.y().crop_width() == self.y_crop_width);
+      assert!(reference.y().crop_height() == self.y_crop_height);
+      estimate_motion(source.y(), reference.y())
+    });
+
     let mut tile = TileEncoder {
-      encoder: &self,
+      encoder: self,
       bitstream: EntropyWriter::new(),
       base_qindex: base_qindex,
+      sb_qindex: sb_qindex,
+      current_qindex: base_qindex,
+      read_deltas: false,
+      lambda: qindex_to_lambda(base_qindex, self.config.lambda_scale),
+      adaptive_rounding: AdaptiveRounding::new(),
       mode_info: Array2D::zeroed(mi_rows, mi_cols),
+      intrabc_hash: if self.allow_intrabc() { Some(BlockHashTable::new(source.y())) } else { None },
       source: source,
-      recon: Frame::new(self.y_height, self.y_width),
+      reference: reference,
+      motion_field: motion_field,
+      recon: Frame::new(self.y_crop_height, self.y_crop_width),
     };
 
     tile.encode();
     //tile.dump_recon("recon.y4m").unwrap();
-    return tile.bitstream.finalize();
+
+    let recon = if self.use_superres() {
+      upscale_frame(&tile.recon, self.upscaled_width)
+    } else {
+      tile.recon
+    };
+    return (tile.bitstream.finalize(), recon);
   }
 }
 
@@ -225,8 +970,9 @@ impl<'a> TileEncoder<'a> {
   pub fn encode(&mut self) {
     let mi_rows = self.mode_info.rows();
     let mi_cols = self.mode_info.cols();
-    let sb_rows = mi_rows.div_ceil(16);
-    let sb_cols = mi_cols.div_ceil(16);
+    let sb_size4 = self.sb_size4();
+    let sb_rows = mi_rows.div_ceil(sb_size4);
+    let sb_cols = mi_cols.div_ceil(sb_size4);
 
     for sb_row in 0..sb_rows {
       for sb_col in 0..sb_cols {
@@ -235,29 +981,29 @@ impl<'a> TileEncoder<'a> {
     }
   }
 
+  // Superblock size in units of 4x4 luma pixels
+  fn sb_size4(&self) -> usize {
+    self.encoder.config.sb_size / 4
+  }
+
   fn encode_superblock(&mut self, sb_row: usize, sb_col: usize) {
-    let mi_row = sb_row * 16;
-    let mi_col = sb_col * 16;
-    self.encode_partition(mi_row, mi_col, 64);
+    let sb_size4 = self.sb_size4();
+    let mi_row = sb_row * sb_size4;
+    let mi_col = sb_col * sb_size4;
+    self.read_deltas = self.encoder.delta_q_present();
+    self.encode_partition(mi_row, mi_col, self.encoder.config.sb_size);
   }
 
   fn encode_partition(&mut self, mi_row: usize, mi_col: usize, bsize: usize) {
     //println!("Encoding {:2}x{:2} partition at mi_row={:3}, mi_col={:3}", bsize, bsize, mi_row, mi_col);
-    // Always split down to 8x8 blocks
+    // We code 8x8, 16x16, and 32x32 blocks, so partitions are always either
+    // PARTITION_NONE or PARTITION_SPLIT (or one of the forced splits at the frame edges).
+    //
     // For each partition symbol, the context depends on whether the above and/or left
-    // blocks are partitioned to a size smaller than what we're currently considering.
+    // blocks are smaller than the size we're currently considering.
     // For blocks at one of the frame edges, the missing neighbour is assumed to be
-    // the maximum possible size.
-    //
-    // Because we always split down to the same size, this ends up implying that the
-    // context is:
-    //
-    // Current partition is 8x8: context = 0
-    // Otherwise:
-    //   Top-left corner: context = 0
-    //   Left edge: context = 1
-    //   Top edge: context = 2
-    //   Everywhere else: context = 3
+    // the maximum possible size. As we never code blocks smaller than 8x8, the
+    // context for 8x8 partitions is always 0.
     if bsize == 8 {
       self.bitstream.write_symbol(0, &partition_8x8_cdf); // PARTITION_NONE
       self.encode_block(mi_row, mi_col, bsize);
@@ -268,21 +1014,28 @@ impl<'a> TileEncoder<'a> {
       let sub_rows = if (mi_row + bsize/8) < mi_rows { 2 } else { 1 };
       let sub_cols = if (mi_col + bsize/8) < mi_cols { 2 } else { 1 };
 
-      let above_ctx = if mi_row > 0 { 1 } else { 0 };
-      let left_ctx = if mi_col > 0 { 1 } else { 0 };
-      let ctx = 2 * left_ctx + above_ctx;
+      let above_ctx = mi_row > 0 && (self.mode_info[mi_row - 1][mi_col].bsize as usize) < bsize;
+      let left_ctx = mi_col > 0 && (self.mode_info[mi_row][mi_col - 1].bsize as usize) < bsize;
+      let ctx = 2 * (left_ctx as usize) + (above_ctx as usize);
 
-      let cdf = match bsize {
+      let cdf: &[u16] = match bsize {
         16 => &partition_16x16_cdf[ctx],
         32 => &partition_32x32_cdf[ctx],
         64 => &partition_64x64_cdf[ctx],
+        128 => &partition_128x128_cdf[ctx],
         _ => panic!("Reached an unexpected partition size")
       };
+      // 128x128 partitions can't use PARTITION_HORZ_4 or PARTITION_VERT_4,
+      // so those don't contribute to the probabilities below
+      let get_prob_4 = |partition: Partition| if bsize == 128 { 0 } else { get_prob(partition as usize, cdf) };
 
       if sub_rows > 1 && sub_cols > 1 {
         // Normal case, all partitions are available
-        // Always choose PARTITION_SPLIT
-        self.bitstream.write_symbol(3, cdf);
+        if self.allow_partition_none(mi_row, mi_col, bsize) {
+          self.encode_partition_none_or_split(mi_row, mi_col, bsize, cdf);
+          return;
+        }
+        self.bitstream.write_symbol(Partition::SPLIT as usize, cdf);
       } else if sub_cols > 1 {
         // The bottom edge of the frame falls in the top half of this partition, so
         // we must split horizontally. The only useful choice is whether to split the
@@ -296,7 +1049,7 @@ impl<'a> TileEncoder<'a> {
                       get_prob(Partition::HORZ_A as usize, cdf) +
                       get_prob(Partition::VERT_A as usize, cdf) +
                       get_prob(Partition::VERT_B as usize, cdf) +
-                      get_prob(Partition::VERT_4 as usize, cdf);
+                      get_prob_4(Partition::VERT_4);
         self.bitstream.write_bit(1, 32768 - p_split);
       } else if sub_rows > 1 {
         // The right edge of the frame falls in the left half of this partition, so
@@ -311,7 +1064,7 @@ impl<'a> TileEncoder<'a> {
                       get_prob(Partition::HORZ_A as usize, cdf) +
                       get_prob(Partition::HORZ_B as usize, cdf) +
                       get_prob(Partition::VERT_A as usize, cdf) +
-                      get_prob(Partition::HORZ_4 as usize, cdf);
+                      get_prob_4(Partition::HORZ_4);
         self.bitstream.write_bit(1, 32768 - p_split);
       } else {
         // The bottom-right corner of the frame falls in the top-left quadrant of this partition,
@@ -327,326 +1080,1190 @@ impl<'a> TileEncoder<'a> {
     }
   }
 
-  fn encode_block(&mut self, mi_row: usize, mi_col: usize, bsize: usize) {
-    assert!(bsize == 8);
-
-    //println!("Encoding 8x8 block at mi_row={:3}, mi_col={:3}", mi_row, mi_col);
-
-    // Allocate a ModeInfo struct to hold information about the current block
-    let mut this_mi = ModeInfo::zeroed();
-
-    // For skip, the context depends on the above and left skip flags,
-    // defaulting to false if those aren't present
-    // As we always set skip = false, this context is always 0
-    // skip = false
-    self.bitstream.write_symbol(0, &skip_cdf);
-  
-    // For intra_frame_y_mode, the context depends on the above and left Y modes,
-    // defaulting to DC_PRED if those aren't present
-    // As we always choose DC_PRED, this context is always 0
-    // intra_frame_y_mode(context=0,0) = DC_PRED
-    self.bitstream.write_symbol(0, &y_mode_cdf);
-
-    // For uv_mode, the context is simply y_mode combined with whether CFL is allowed
-    // Here the y mode is always DC_PRED and CFL is always allowed for 8x8 blocks,
-    // so we always end up with the same context
-    // uv_mode(context=0, CFL allowed) = DC_PRED
-    self.bitstream.write_symbol(0, &uv_mode_cdf);
-
-    // Encode residuals
-    for plane in 0..3 {
-      let subsampling = if plane > 0 { 1 } else { 0 };
-      let y0 = (mi_row * 4) >> subsampling;
-      let x0 = (mi_col * 4) >> subsampling;
-      let h = bsize >> subsampling;
-      let w = bsize >> subsampling;
-
-      dc_predict(self.recon.plane_mut(plane).pixels_mut(), y0, x0, h, w);
-      let mut residual = compute_residual(self.source.plane(plane).pixels(),
-                                          self.recon.plane(plane).pixels(),
-                                          y0, x0, h, w);
-      quantize(&mut residual, self.base_qindex);
-
-      // Encode the quantized coefficients while we have them,
-      // before we consume them to finalize the reconstructed image
-      self.encode_coeffs(plane, mi_row, mi_col, bsize, &mut this_mi, &residual);
-
-      dequantize(&mut residual, self.base_qindex);
-      apply_residual(self.recon.plane_mut(plane).pixels_mut(), residual, y0, x0, h, w);
+  // Whether we consider coding a whole partition as a single block, rather than splitting it.
+  // We support 16x16 and 32x32 blocks, as long as they lie entirely inside the frame.
+  // Larger blocks also have to be within a single segment, as the segment ID is
+  // coded per block
+  fn allow_partition_none(&self, mi_row: usize, mi_col: usize, bsize: usize) -> bool {
+    let size4 = bsize / 4;
+    if bsize > MAX_BLOCK_SIZE || mi_row + size4 > self.mode_info.rows() || mi_col + size4 > self.mode_info.cols() {
+      return false;
+    }
+    match &self.encoder.config.segmentation {
+      Some(segmentation) => {
+        let segment_id = segmentation.map[mi_row][mi_col];
+        (mi_row..mi_row + size4).all(|row| (mi_col..mi_col + size4).all(|col| segmentation.map[row][col] == segment_id))
+      },
+      None => true,
     }
-
-    // Save mode info
-    self.mode_info.fill_region(mi_row, mi_col, bsize/4, bsize/4, &this_mi);
   }
 
-  fn encode_coeffs(&mut self, plane: usize, mi_row: usize, mi_col: usize, bsize: usize, this_mi: &mut ModeInfo,
-                   coeffs: &Array2D<i32>) {
-    if bsize != 8 {
-      todo!();
+  // Code a 16x16 or 32x32 partition, with partition symbol CDF `cdf`, as either a single block or four quarter-size
+  // partitions, whichever has the lower rate-distortion cost.
+  // The split is evaluated last and kept if it wins, so that we only need to re-encode
+  // the partition if the single block turns out to be better.
+  fn encode_partition_none_or_split(&mut self, mi_row: usize, mi_col: usize, bsize: usize, cdf: &[u16]) {
+    let encode_none = |this: &mut Self| {
+      this.bitstream.write_symbol(Partition::NONE as usize, cdf);
+      this.encode_block(mi_row, mi_col, bsize);
+    };
+    let none_cost = self.rd_evaluate(mi_row, mi_col, bsize, encode_none);
+
+    let checkpoint = self.checkpoint(mi_row, mi_col, bsize);
+    let start_bits = self.bitstream.tell_frac();
+    self.bitstream.write_symbol(Partition::SPLIT as usize, cdf);
+    let offset = bsize / 8;
+    for i in 0..2 {
+      for j in 0..2 {
+        self.encode_partition(mi_row + i*offset, mi_col + j*offset, bsize/2);
+      }
     }
+    let rate = (self.bitstream.tell_frac() - start_bits) << (RATE_SHIFT - 3);
+    let split_cost = RDCost::new(rate, self.block_distortion(mi_row, mi_col, bsize), self.lambda);
 
-    // Make sure there are the right number of coefficients
-    let txsize = if plane > 0 { bsize/2 } else { bsize };
-    let txs_ctx = if txsize == 8 { 1 } else { 0 };
-    let num_coeffs = txsize * txsize;
-    assert!(coeffs.rows() == txsize);
-    assert!(coeffs.cols() == txsize);
-
-    let scan: &[(u8, u8)] = scan_order_2d[txs_ctx];
+    if none_cost.is_better_than(&split_cost) {
+      self.restore(&checkpoint);
+      encode_none(self);
+    }
+  }
 
-    let qctx = get_qctx(self.base_qindex);
+  fn encode_block(&mut self, mi_row: usize, mi_col: usize, bsize: usize) {
+    assert!(bsize == 8 || bsize == 16 || bsize == 32);
 
-    let ptype = if plane == 0 { 0 } else { 1 };
+    //println!("Encoding {:2}x{:2} block at mi_row={:3}, mi_col={:3}", bsize, bsize, mi_row, mi_col);
 
-    // Find the "end of block" location
-    // This is one past the last nonzero coefficient, or 0 if all coeffs are zero
-    let mut eob = 0;
-    let mut culLevel = 0; // "Cumulative level", gets stored into this_mi.level_ctx
-    for c in 0..num_coeffs {
-      let (row, col) = scan[c];
-      let coeff = coeffs[row as usize][col as usize];
-      culLevel += abs(coeff);
-      if coeff != 0 {
-        eob = c + 1;
-      }
+    // Work out which qindex this block will be coded with. This has to be done before
+    // writing any symbols, as we need to quantize the residuals to decide whether to skip
+    let map_segment_id = match &self.encoder.config.segmentation {
+      Some(segmentation) => segmentation.map[mi_row][mi_col],
+      None => 0,
+    };
+    let qindex = self.get_qindex(map_segment_id, self.next_qindex(mi_row, mi_col));
+    self.lambda = qindex_to_lambda(qindex, self.encoder.config.lambda_scale);
+
+    // Pick the luma mode first, using a single transform for the whole block
+    let mut palettes = BlockPalettes { y: None, uv: None };
+    let mut luma_mode = DC_MODE;
+    let luma_candidates = self.luma_mode_candidates(mi_row, mi_col, bsize);
+    if luma_candidates.len() > 1 {
+      (luma_mode, _) = self.rd_select(mi_row, mi_col, bsize, &luma_candidates, |this, luma_mode| {
+        this.encode_block_with_mode(mi_row, mi_col, bsize, qindex, BlockMode::Intra(luma_mode, palettes), bsize);
+      });
     }
-    this_mi.level_ctx[plane] = min(culLevel, 63) as u8;
 
-    let all_zero = eob == 0;
+    // Then see whether splitting into smaller transforms helps. As each transform block
+    // is predicted separately, this also allows the prediction to follow the source more closely.
+    // 8x8 blocks can use 4x4 transforms, while larger blocks can split the transform twice
+    let tx_candidates: &[usize] = if bsize == 8 { &[8, 4] } else { &[bsize, bsize / 2, bsize / 4] };
+    let (tx_size, _) = self.rd_select(mi_row, mi_col, bsize, tx_candidates, |this, tx_size| {
+      this.encode_block_with_mode(mi_row, mi_col, bsize, qindex, BlockMode::Intra(luma_mode, palettes), tx_size);
+    });
+
+    // The remaining tools are only used for 8x8 blocks
+    if bsize > 8 {
+      self.encode_block_with_mode(mi_row, mi_col, bsize, qindex, BlockMode::Intra(luma_mode, palettes), tx_size);
+      return;
+    }
 
-    // The all_zero symbol has a complex dependency on the nearby transform coefficients.
-    // For luma, there is a special case where this is short-circuited to 0 for max-size
-    // transforms (ie, transform size == block size), so we can ignore the complex logic.
-    // But for chroma it is mandatory.
-    let all_zero_ctx = if plane == 0 {
-      0
-    } else {
-      let mut above = false;
-      let mut left = false;
-      // In theory we need to scan all blocks above and left of the current block here
-      // However, because all blocks are currently 8x8, there's always exactly one
-      // block above and one block left
-      if mi_row > 0 {
-        let above_block = &self.mode_info[mi_row - 1][mi_col];
-        above |= above_block.level_ctx[plane] != 0;
-        above |= above_block.dc_sign[plane] != 0;
-      }
-      if mi_col > 0 {
-        let left_block = &self.mode_info[mi_row][mi_col - 1];
-        left |= left_block.level_ctx[plane] != 0;
-        left |= left_block.dc_sign[plane] != 0;
+    if self.encoder.config.screen_content_tools {
+      // Then see if a luma palette does better, then pick the chroma palette given that choice.
+      // Luma palettes can only be used along with DC_PRED
+      let (y0, x0, h, w) = self.block_region(0, mi_row, mi_col, bsize);
+      let mut y_candidates = vec![None];
+      y_candidates.extend(luma_palette_candidates(self.source.y().pixels(), y0, x0, h, w).into_iter().map(Some));
+      if y_candidates.len() > 1 {
+        (palettes.y, _) = self.rd_select(mi_row, mi_col, bsize, &y_candidates, |this, y| {
+          let luma_mode = if y.is_some() { DC_MODE } else { luma_mode };
+          this.encode_block_with_mode(mi_row, mi_col, bsize, qindex,
+                                      BlockMode::Intra(luma_mode, BlockPalettes { y: y, uv: None }), tx_size);
+        });
+        if palettes.y.is_some() {
+          luma_mode = DC_MODE;
+        }
       }
-      7 + (above as usize) + (left as usize)
-    };
 
-    self.bitstream.write_symbol(all_zero as usize, &all_zero_cdf[qctx][txs_ctx][all_zero_ctx]);
-    if all_zero {
-      return;
+      let (y0, x0, h, w) = self.block_region(1, mi_row, mi_col, bsize);
+      let mut uv_candidates = vec![None];
+      if self.encoder.num_planes() > 1 {
+        uv_candidates.extend(chroma_palette_candidates(self.source.u().pixels(), self.source.v().pixels(),
+                                                       y0, x0, h, w).into_iter().map(Some));
+      }
+      if uv_candidates.len() > 1 {
+        let y = palettes.y;
+        (palettes.uv, _) = self.rd_select(mi_row, mi_col, bsize, &uv_candidates, |this, uv| {
+          this.encode_block_with_mode(mi_row, mi_col, bsize, qindex,
+                                      BlockMode::Intra(luma_mode, BlockPalettes { y: y, uv: uv }), tx_size);
+        });
+      }
     }
 
-    // Transform type - only coded for luma
-    // As we selected the reduced transform set in the frame header,
-    // we end up looking at the TX_SET_INTRA_2 set, which consists of
-    // { IDTX, DCT_DCT, ADST_ADST, ADST_DCT, DCT_ADST }, in that order.
-    // We want DCT_DCT, so we want to encode index 1.
-    if plane == 0 {
-      self.bitstream.write_symbol(1, &tx_type_cdf);
+    // Then see if copying from elsewhere in the frame, or from the previous frame,
+    // beats the best intra mode
+    let mut mode = BlockMode::Intra(luma_mode, palettes);
+    let mut candidates = vec![mode];
+    candidates.extend(self.intrabc_candidates(mi_row, mi_col).into_iter().map(BlockMode::IntraBC));
+    candidates.extend(self.inter_candidates(mi_row, mi_col, bsize).into_iter().map(BlockMode::Inter));
+    if candidates.len() > 1 {
+      (mode, _) = self.rd_select(mi_row, mi_col, bsize, &candidates, |this, mode| {
+        this.encode_block_with_mode(mi_row, mi_col, bsize, qindex, mode, tx_size);
+      });
     }
 
-    // Number of coefficients, encoded as a logarithmic class + value within that class
-    // Here, the contexts are qindex, plane type, and (for 16x16 and smaller)
-    // whether the selected transform type is 1D (last context = 1) or 2D
-    // (last context = 0). We always choose DCT_DCT, which counts as a 2D transform
-    //
-    // The EOB is split into a class plus optional extra bits. Each class has the following range:
-    // Class 0 => EOB = 1
-    // Class 1 => EOB = 2
-    // Class 2 => EOB = 3-4
-    // Class 3 => EOB = 5-8
-    // ...
-    // up to a maximum class which depends on the transform size
-    // For 4x4 the largest class is class 4 (EOB = 9-16), for 8x8 it's class 6 (EOB = 33-64)
-    let eob_class = ceil_log2(eob) as usize;
-    let eob_class_cdf: &[u16] = if plane == 0 {
-      &eob_class_64_cdf[qctx][ptype]
-    } else {
-      &eob_class_16_cdf[qctx][ptype]
+    self.encode_block_with_mode(mi_row, mi_col, bsize, qindex, mode, tx_size);
+  }
+
+  // Fully encode a block using the given qindex, prediction mode, and luma transform size.
+  // IntraBC and inter blocks always use a single transform covering the whole block
+  fn encode_block_with_mode(&mut self, mi_row: usize, mi_col: usize, bsize: usize, qindex: u8,
+                            mode: BlockMode, tx_size: usize) {
+    // Allocate a ModeInfo struct to hold information about the current block
+    let mut this_mi = ModeInfo::zeroed();
+
+    let no_palettes = BlockPalettes { y: None, uv: None };
+    let (luma_mode, palettes, dv, inter_mode, tx_size) = match mode {
+      BlockMode::Intra(luma_mode, palettes) => (luma_mode, palettes, None, None, tx_size),
+      BlockMode::IntraBC(dv) => (DC_MODE, no_palettes, Some(dv), None, bsize),
+      BlockMode::Inter(inter_mode) => (DC_MODE, no_palettes, None, Some(inter_mode), bsize),
     };
-    self.bitstream.write_symbol(eob_class, eob_class_cdf);
-
-    if eob_class > 1 {
-      let eob_class_low = (1 << (eob_class - 1)) + 1;
-      let eob_class_hi = 1 << eob_class;
-      assert!(eob_class_low <= eob && eob <= eob_class_hi);
-
-      // EOB classes 2+ require extra bits
-      // The first extra bit is coded with a special CDF, the rest are literal bits
-      // Context = (qctx, tx size, ptype, eob_class - 2)
-      // For 8x8 and luma, this gives:
-      let first_extra_bit_cdf = if plane == 0 {
-        &eob_extra_8x8_cdf[qctx][ptype][eob_class - 2]
-      } else {
-        &eob_extra_4x4_cdf[qctx][ptype][eob_class - 2]
+    // IntraBC blocks count as inter blocks for the purposes of transform coding
+    let is_inter = dv.is_some() || inter_mode.is_some();
+    let tx_set = if is_inter { TxSet::Inter } else { TxSet::Intra(luma_mode.intra_dir()) };
+
+    // Predict, quantize, and reconstruct each transform block of each plane.
+    // This has to be done one transform block at a time, as intra prediction for each
+    // luma transform block uses the reconstructed pixels of the previous ones
+    let mut tx_blocks: Vec<TxBlock> = Vec::with_capacity(6);
+    let mut color_maps: [Option<Array2D<u8>>; 2] = [None, None];
+    for plane in 0..self.encoder.num_planes() {
+      let (y0, x0, h, w) = self.block_region(plane, mi_row, mi_col, bsize);
+
+      // Palette, IntraBC, inter, and chroma predictions don't depend on the transform size,
+      // so can be generated for the whole block up front
+      let palette = match plane {
+        0 => palettes.y,
+        _ => palettes.uv.map(|uv| uv[plane - 1]),
+      };
+      let predict_per_tx_block = match palette {
+        Some(palette) => {
+          // Luma uses its own colour map, while U and V share one, based on the closest (U, V) pair
+          if plane <= 1 {
+            let color_map = match palettes.uv {
+              Some([palette_u, palette_v]) if plane == 1 => {
+                build_color_map(&[(self.source.u().pixels(), &palette_u), (self.source.v().pixels(), &palette_v)],
+                                y0, x0, h, w)
+              },
+              _ => build_color_map(&[(self.source.y().pixels(), &palette)], y0, x0, h, w),
+            };
+            color_maps[plane] = Some(color_map);
+          }
+          let color_map = color_maps[min(plane, 1)].as_ref().unwrap();
+          palette_predict(self.recon.plane_mut(plane).pixels_mut(), &palette, color_map, y0, x0);
+          false
+        },
+        None => match (dv, inter_mode) {
+          (Some(dv), _) => {
+            intrabc_predict(self.recon.plane_mut(plane).pixels_mut(), dv, y0, x0, h, w, min(plane, 1));
+            false
+          },
+          (_, Some(inter_mode)) => {
+            let reference = self.reference.unwrap().plane(plane);
+            inter_predict(reference, self.recon.plane_mut(plane).pixels_mut(), inter_mode.mv,
+                          y0, x0, h, w, min(plane, 1));
+            false
+          },
+          _ if plane == 0 => true,
+          _ => {
+            dc_predict(self.recon.plane_mut(plane).pixels_mut(), y0, x0, h, w);
+            false
+          },
+        },
       };
-      let eob_shift = eob_class - 2;
-      let extra_bit = ((eob - eob_class_low) >> eob_shift) & 1;
-      self.bitstream.write_symbol(extra_bit, first_extra_bit_cdf);
 
-      // Write any remaining bits as a literal
-      // Note: The AV1 decoder spec gives a more detailed process here,
-      // but it's just writing individual bits from high to low,
-      // which is exactly what write_literal() does
-      let remainder = eob - eob_class_low - (extra_bit << eob_shift);
-      let remainder_bits = eob_class - 2;
-      self.bitstream.write_literal(remainder as u32, remainder_bits as u32);
-    }
+      // Chroma always uses a single transform covering the whole chroma block
+      let plane_tx_size = if plane == 0 { tx_size } else { bsize / 2 };
+      let subsampling = if plane > 0 { 1 } else { 0 };
+      let quantizer = self.get_quantizer(plane, qindex);
 
-    // Write "base range" for each coefficient, in high-to-low index order
-    for c in (0..eob).rev() {
-      // Split coefficient into absolute value and sign, as these are coded separately
-      let (row, col) = scan[c];
-      let coeff = coeffs[row as usize][col as usize];
-      let abs_value = unsigned_abs(coeff) as usize;
-
-      // Code coeff_base symbol, which can indicate values 0, 1, 2, or 3+
-      if c == eob - 1 {
-        // Last nonzero coefficient, so we know this can't be zero
-        // Therefore we use a separate set of CDFs and contexts
-        let base_eob_ctx = if c == 0 {
-          0
-        } else if c <= num_coeffs/8 {
-          1
-        } else if c <= num_coeffs/4 {
-          2
-        } else {
-          3
-        };
-        assert!(abs_value >= 1);
-        let coded_value = min(abs_value - 1, 2);
-        self.bitstream.write_symbol(coded_value, &coeff_base_eob_cdf[qctx][txs_ctx][ptype][base_eob_ctx]);
-      } else {
-        // Context depends on the base values of coefficients below and to the right,
-        // which have already been encoded
-        let base_ctx = if c == 0 {
-          0
-        } else {
-          let mut mag = 0;
+      for ty in (y0..y0 + h).step_by(plane_tx_size) {
+        for tx in (x0..x0 + w).step_by(plane_tx_size) {
+          let (tx_mi_row, tx_mi_col) = ((ty << subsampling) / 4, (tx << subsampling) / 4);
+          if predict_per_tx_block {
+            self.predict_luma(mi_row, mi_col, bsize, tx_mi_row, tx_mi_col, plane_tx_size, luma_mode);
+          }
+
+          let coeffs = compute_residual(self.source.plane(plane).pixels(),
+                                        self.recon.plane(plane).pixels(),
+                                        ty, tx, plane_tx_size, plane_tx_size);
+          let smooth = is_smooth_block(self.source.plane(plane).pixels(), ty, tx, plane_tx_size, plane_tx_size);
+          let rounding = match self.encoder.config.quantizer_mode {
+            QuantizerMode::Nearest => NEAREST_ROUNDING,
+            QuantizerMode::DeadZone if smooth => DEADZONE_ROUNDING_SMOOTH,
+            QuantizerMode::DeadZone => DEADZONE_ROUNDING_TEXTURED,
+            QuantizerMode::Adaptive => self.adaptive_rounding.offsets(smooth),
+          };
+
+          let mut residual = coeffs.clone();
+          quantize(&mut residual, &quantizer, &rounding);
+          if self.encoder.config.quantizer_mode == QuantizerMode::Adaptive {
+            self.adaptive_rounding.update(smooth, &coeffs, &quantizer);
+          }
+
+          let ctx = self.get_coeff_context(plane, tx_mi_row, tx_mi_col, bsize, plane_tx_size);
+          if self.encoder.config.trellis {
+            self.optimize_coeffs(plane, tx_set, &ctx, &quantizer, &coeffs, &mut residual);
+          }
 
-          for (row_off, col_off) in Sig_Ref_Diff_Offset {
-            let ref_row = (row + row_off) as usize;
-            let ref_col = (col + col_off) as usize;
-            if ref_row < txsize && ref_col < txsize {
-              mag += min(abs(coeffs[ref_row][ref_col]), 3);
+          // Store the level and DC sign for reference by later transform blocks,
+          // both within this block and in later blocks
+          let (level_ctx, dc_sign) = level_ctx_and_dc_sign(&residual);
+          let tx_mi_size = (plane_tx_size << subsampling) / 4;
+          for row in tx_mi_row..tx_mi_row + tx_mi_size {
+            for col in tx_mi_col..tx_mi_col + tx_mi_size {
+              self.mode_info[row][col].level_ctx[plane] = level_ctx;
+              self.mode_info[row][col].dc_sign[plane] = dc_sign;
             }
           }
 
-          let mag_part = min(round2(mag, 1), 4) as usize;
-          let loc_part = Coeff_Base_Ctx_Offset_8x8[min(row, 4) as usize][min(col, 4) as usize] as usize;
-          mag_part + loc_part
-        };
+          // Finalize the reconstruction now, so that later transform blocks can predict from it.
+          // The quantized coefficients are kept for coding once the mode info has been written
+          let mut dequantized = residual.clone();
+          dequantize(&mut dequantized, &quantizer);
+          apply_residual(self.recon.plane_mut(plane).pixels_mut(), dequantized,
+                         ty, tx, plane_tx_size, plane_tx_size);
 
-        let coded_value = min(abs_value, 3);
-        self.bitstream.write_symbol(coded_value, &coeff_base_cdf[qctx][txs_ctx][ptype][base_ctx]);
+          tx_blocks.push(TxBlock { plane: plane, ctx: ctx, coeffs: residual });
+        }
       }
+    }
 
-      // If coeff_base is 3, we can encode up to 4 symbols to increment the
-      // absolute value further. This can directly encode values up to 14,
-      // or the value 15 for all larger coefficients, in which case the remainder
-      // is Golomb encoded in a separate pass
-      if abs_value > 2 {
-        // All four coeff_br symbols use the same context and CDF, so compute that first
-        let br_ctx = {
-          let mut mag = 0;
+    // If every coefficient in every transform block is zero, we can skip coding the residual entirely.
+    // In that case, the level and DC sign contexts stored above are all zero, as required
+    let skip = tx_blocks.iter().all(|tx_block| {
+      (0..tx_block.coeffs.rows()).all(|row| tx_block.coeffs[row].iter().all(|&level| level == 0))
+    });
+    this_mi.skip = skip;
+
+    // For skip, the context is the number of above and left neighbours which
+    // were also skipped, defaulting to not skipped if those aren't present
+    let above_skip = mi_row > 0 && self.mode_info[mi_row - 1][mi_col].skip;
+    let left_skip = mi_col > 0 && self.mode_info[mi_row][mi_col - 1].skip;
+    let skip_ctx = above_skip as usize + left_skip as usize;
+    self.bitstream.write_symbol(skip as usize, &skip_cdf[skip_ctx]);
+
+    // We don't use any segment features which would require the segment ID to be
+    // coded before the skip flag (ie. SegIdPreSkip is always 0), so it goes here
+    if self.encoder.config.segmentation.is_some() {
+      this_mi.segment_id = self.encode_segment_id(mi_row, mi_col, skip);
+    }
 
-          for (row_off, col_off) in Mag_Ref_Offset {
-            let ref_row = (row + row_off) as usize;
-            let ref_col = (col + col_off) as usize;
-            if ref_row < txsize && ref_col < txsize {
-              mag += min(abs(coeffs[ref_row][ref_col]), 15);
-            }
+    if self.read_deltas {
+      self.encode_delta_qindex(mi_row, mi_col);
+    }
+
+    if self.encoder.allow_intrabc() {
+      self.bitstream.write_bool(dv.is_some(), intrabc_cdf[0]);
+    }
+    if self.encoder.is_inter_frame() {
+      self.encode_is_inter(mi_row, mi_col, inter_mode.is_some());
+    }
+    if let Some(dv) = dv {
+      // IntraBC blocks code a DV in place of the intra modes, and can't use palettes
+      let ref_dv = self.get_ref_dv(mi_row, mi_col);
+      write_dv(&mut self.bitstream, dv, ref_dv);
+      this_mi.use_intrabc = true;
+      this_mi.dv = dv;
+    } else if let Some(inter_mode) = inter_mode {
+      self.encode_inter_modes(mi_row, mi_col, &inter_mode);
+      this_mi.is_inter = true;
+      this_mi.y_mode = inter_mode.y_mode as u8;
+      this_mi.mv = inter_mode.mv;
+    } else {
+      self.encode_intra_modes(mi_row, mi_col, bsize, luma_mode, &palettes, &color_maps, &mut this_mi);
+    }
+
+    self.encode_tx_size(mi_row, mi_col, bsize, tx_size, is_inter, skip);
+    this_mi.tx_size = tx_size as u8;
+    this_mi.bsize = bsize as u8;
+
+    // Encode residuals
+    // For skipped blocks, the prediction is the final reconstruction
+    if !skip {
+      for tx_block in tx_blocks {
+        self.encode_coeffs(tx_block.plane, tx_set, &tx_block.ctx, &tx_block.coeffs);
+      }
+    }
+
+    // Save mode info, keeping the per-transform-block contexts stored above
+    for row in mi_row..mi_row + bsize/4 {
+      for col in mi_col..mi_col + bsize/4 {
+        let mi = &mut self.mode_info[row][col];
+        *mi = ModeInfo { level_ctx: mi.level_ctx, dc_sign: mi.dc_sign, ..this_mi.clone() };
+      }
+    }
+  }
+
+  // Code the transform size for a block
+  fn encode_tx_size(&mut self, mi_row: usize, mi_col: usize, bsize: usize, tx_size: usize, is_inter: bool,
+                    skip: bool) {
+    let above_mi = if mi_row > 0 { Some(&self.mode_info[mi_row - 1][mi_col]) } else { None };
+    let left_mi = if mi_col > 0 { Some(&self.mode_info[mi_row][mi_col - 1]) } else { None };
+
+    if !is_inter {
+      // Intra blocks always code a tx_depth, even if they are skipped, as the transform
+      // size also determines the size of the intra prediction blocks.
+      // The context is the number of neighbours whose transforms span the full block width
+      // (for above) or height (for left)
+      let above = above_mi.is_some_and(|mi| mi.tx_size as usize >= bsize);
+      let left = left_mi.is_some_and(|mi| mi.tx_size as usize >= bsize);
+      let ctx = above as usize + left as usize;
+      let tx_depth = (floor_log2(bsize) - floor_log2(tx_size)) as usize;
+      let cdf: &[u16] = match bsize {
+        8 => &tx_8x8_depth_cdf[ctx],
+        16 => &tx_16x16_depth_cdf[ctx],
+        32 => &tx_32x32_depth_cdf[ctx],
+        _ => panic!("Reached an unexpected block size")
+      };
+      self.bitstream.write_symbol(tx_depth, cdf);
+    } else if !skip {
+      // Inter blocks, including IntraBC blocks, instead use a recursive transform split,
+      // which is only coded if the block has a residual. We never split the transform,
+      // and only use inter prediction and IntraBC for 8x8 blocks.
+      // Here the context is whether the neighbouring transforms are smaller than the
+      // unsplit transform, counting missing neighbours as arbitrarily large
+      let above = above_mi.is_some_and(|mi| (mi.tx_size as usize) < bsize);
+      let left = left_mi.is_some_and(|mi| (mi.tx_size as usize) < bsize);
+      let ctx = above as usize + left as usize;
+      self.bitstream.write_symbol(0, &txfm_split_8x8_cdf[ctx]);
+    }
+  }
+
+  // Code the intra prediction modes for a block, along with any palettes
+  #[allow(clippy::too_many_arguments)]
+  fn encode_intra_modes(&mut self, mi_row: usize, mi_col: usize, bsize: usize, luma_mode: LumaMode,
+                        palettes: &BlockPalettes, color_maps: &[Option<Array2D<u8>>; 2], this_mi: &mut ModeInfo) {
+    let y_mode = luma_mode.y_mode as usize;
+    if self.encoder.is_inter_frame() {
+      // In inter frames, the context is just the block size
+      self.bitstream.write_symbol(y_mode, &y_mode_cdf[bsize_idx(bsize)]);
+    } else {
+      // For intra_frame_y_mode, the context depends on the above and left Y modes,
+      // defaulting to DC_PRED if those aren't present
+      let above_mode = if mi_row > 0 { self.mode_info[mi_row - 1][mi_col].y_mode } else { 0 };
+      let left_mode = if mi_col > 0 { self.mode_info[mi_row][mi_col - 1].y_mode } else { 0 };
+      let above_ctx = Intra_Mode_Context[above_mode as usize];
+      let left_ctx = Intra_Mode_Context[left_mode as usize];
+      self.bitstream.write_symbol(y_mode, &kf_y_mode_cdf[above_ctx][left_ctx]);
+    }
+    this_mi.y_mode = y_mode as u8;
+
+    if luma_mode.y_mode.is_directional() {
+      let symbol = (luma_mode.angle_delta + MAX_ANGLE_DELTA) as usize;
+      self.bitstream.write_symbol(symbol, &angle_delta_cdf[y_mode - PredictionMode::V_PRED as usize]);
+    }
+
+    // For uv_mode, the context is simply y_mode combined with whether CFL is allowed,
+    // which it always is for blocks up to 32x32. We always pick DC_PRED for chroma
+    if self.encoder.num_planes() > 1 {
+      self.bitstream.write_symbol(0, &uv_mode_cdf[y_mode]);
+    }
+
+    if self.encoder.config.screen_content_tools {
+      self.encode_palette_mode_info(mi_row, mi_col, bsize, luma_mode, palettes, this_mi);
+    }
+
+    // Filter intra can only be used in place of DC_PRED, and not along with a luma palette
+    if luma_mode.y_mode == PredictionMode::DC_PRED && palettes.y.is_none() {
+      self.bitstream.write_bool(luma_mode.filter_intra_mode.is_some(), use_filter_intra_cdf[bsize_idx(bsize)][0]);
+      if let Some(filter_intra_mode) = luma_mode.filter_intra_mode {
+        self.bitstream.write_symbol(filter_intra_mode as usize, &filter_intra_mode_cdf);
+      }
+    }
+
+    // Colour index maps for any palettes
+    if let Some(palette) = palettes.y {
+      let cdfs = &palette_y_color_cdf[palette.size - PALETTE_MIN_SIZE];
+      write_color_map(&mut self.bitstream, color_maps[0].as_ref().unwrap(), palette.size, cdfs);
+    }
+    if let Some([palette_u, _]) = palettes.uv {
+      let cdfs = &palette_uv_color_cdf[palette_u.size - PALETTE_MIN_SIZE];
+      write_color_map(&mut self.bitstream, color_maps[1].as_ref().unwrap(), palette_u.size, cdfs);
+    }
+  }
+
+  // Code the change in qindex for the current superblock
+  // This happens in the first block of each superblock
+  fn encode_delta_qindex(&mut self, mi_row: usize, mi_col: usize) {
+    let sb_size4 = self.sb_size4();
+    let target_qindex = self.sb_qindex.as_ref().unwrap()[mi_row / sb_size4][mi_col / sb_size4];
+    let delta = (target_qindex as i32 - self.current_qindex as i32) >> DELTA_Q_RES;
+    let delta_q_abs = abs(delta) as u32;
+
+    if delta_q_abs < 3 {
+      self.bitstream.write_symbol(delta_q_abs as usize, &delta_q_abs_cdf);
+    } else {
+      // Larger values are coded as DELTA_Q_SMALL, followed by the number of bits needed
+      // and then the remaining bits of (delta_q_abs - 1), excluding the leading 1 bit
+      self.bitstream.write_symbol(3, &delta_q_abs_cdf);
+      let rem_bits = floor_log2(delta_q_abs - 1);
+      self.bitstream.write_literal(rem_bits - 1, 3);
+      self.bitstream.write_literal(delta_q_abs - 1 - (1 << rem_bits), rem_bits);
+    }
+    if delta_q_abs > 0 {
+      self.bitstream.write_literal((delta < 0) as u32, 1);
+    }
+
+    // Update the qindex in the same way as the decoder will
+    self.current_qindex = clamp(self.current_qindex as i32 + (delta << DELTA_Q_RES), 1, 255) as u8;
+    self.read_deltas = false;
+  }
+
+  // Signal whether the current block uses palette mode for luma and/or chroma,
+  // and if so, what the palette colours are
+  fn encode_palette_mode_info(&mut self, mi_row: usize, mi_col: usize, bsize: usize, luma_mode: LumaMode,
+                              palettes: &BlockPalettes, this_mi: &mut ModeInfo) {
+    let above_mi = if mi_row > 0 { Some(&self.mode_info[mi_row - 1][mi_col]) } else { None };
+    let left_mi = if mi_col > 0 { Some(&self.mode_info[mi_row][mi_col - 1]) } else { None };
+
+    // The palette cache only looks at the above block if it's in the same 64-pixel row,
+    // to limit how much data a hardware decoder needs to store
+    let above_cache_mi = if !mi_row.is_multiple_of(16) { above_mi } else { None };
+    let cache = |plane: usize| {
+      let above = above_cache_mi.map_or(&[][..], |mi| &mi.palette_colors[plane][..mi.palette_size[plane] as usize]);
+      let left = left_mi.map_or(&[][..], |mi| &mi.palette_colors[plane][..mi.palette_size[plane] as usize]);
+      get_palette_cache(above, left)
+    };
+    let cache_y = cache(0);
+    let cache_uv = cache(1);
+
+    // Luma palette, which is only allowed with DC_PRED
+    // The context is the block size, along with the number of above and left neighbours
+    // which use a luma palette
+    if luma_mode.y_mode == PredictionMode::DC_PRED {
+      let above_palette = above_mi.is_some_and(|mi| mi.palette_size[0] > 0);
+      let left_palette = left_mi.is_some_and(|mi| mi.palette_size[0] > 0);
+      let ctx = above_palette as usize + left_palette as usize;
+      self.bitstream.write_bool(palettes.y.is_some(), palette_y_mode_cdf[bsize_idx(bsize)][ctx][0]);
+    }
+    if let Some(palette) = palettes.y {
+      self.bitstream.write_symbol(palette.size - PALETTE_MIN_SIZE, &palette_y_size_cdf);
+      write_palette_colors_y(&mut self.bitstream, &palette, &cache_y);
+      this_mi.palette_size[0] = palette.size as u8;
+      this_mi.palette_colors[0] = palette.colors;
+    }
+
+    // Chroma palette, which is only signalled if we have chroma
+    if self.encoder.num_planes() == 1 {
+      return;
+    }
+    let ctx = palettes.y.is_some() as usize;
+    self.bitstream.write_bool(palettes.uv.is_some(), palette_uv_mode_cdf[ctx][0]);
+    if let Some([palette_u, palette_v]) = palettes.uv {
+      self.bitstream.write_symbol(palette_u.size - PALETTE_MIN_SIZE, &palette_uv_size_cdf);
+      write_palette_colors_uv(&mut self.bitstream, &palette_u, &palette_v, &cache_uv);
+      this_mi.palette_size[1] = palette_u.size as u8;
+      this_mi.palette_colors[1] = palette_u.colors;
+    }
+  }
+
+  // Generate the luma prediction for one transform block using the given intra mode.
+  // The transform block is `tx_size` pixels square, with its top-left corner at (tx_mi_row, tx_mi_col),
+  // and is part of the `bsize` x `bsize` block at (mi_row, mi_col)
+  #[allow(clippy::too_many_arguments)]
+  fn predict_luma(&mut self, mi_row: usize, mi_col: usize, bsize: usize, tx_mi_row: usize, tx_mi_col: usize,
+                  tx_size: usize, luma_mode: LumaMode) {
+    let (y0, x0, h, w) = (tx_mi_row * 4, tx_mi_col * 4, tx_size, tx_size);
+    let size4 = tx_size / 4;
+    let mi_rows = self.mode_info.rows();
+    let mi_cols = self.mode_info.cols();
+    let (row_off, col_off) = (tx_mi_row - mi_row, tx_mi_col - mi_col);
+    let avail = EdgeAvailability {
+      above: tx_mi_row > 0,
+      left: tx_mi_col > 0,
+      above_right: tx_mi_row > 0 && tx_mi_col + size4 < mi_cols &&
+                   tx_has_top_right(mi_row, mi_col, bsize / 4, row_off, col_off, size4, self.sb_size4()),
+      below_left: tx_mi_col > 0 && tx_mi_row + size4 < mi_rows &&
+                  tx_has_bottom_left(mi_row, mi_col, bsize / 4, row_off, col_off, size4, self.sb_size4()),
+    };
+
+    let pixels = self.recon.y_mut().pixels_mut();
+    if let Some(filter_intra_mode) = luma_mode.filter_intra_mode {
+      filter_intra_predict(pixels, y0, x0, h, w, avail, filter_intra_mode);
+    } else if luma_mode.y_mode.is_directional() {
+      let angle = Mode_To_Angle[luma_mode.y_mode as usize] + luma_mode.angle_delta as i32 * ANGLE_STEP;
+      directional_predict(pixels, y0, x0, h, w, avail, angle, true);
+    } else {
+      dc_predict(pixels, y0, x0, h, w);
+    }
+  }
+
+  // Find luma modes worth fully encoding. Every DC, filter intra, and directional mode
+  // (including each angle delta) is tried, and ranked by the SATD of its prediction error.
+  // The best few are returned, along with DC_PRED, which is cheap to code
+  fn luma_mode_candidates(&mut self, mi_row: usize, mi_col: usize, bsize: usize) -> Vec<LumaMode> {
+    let mut modes = vec![DC_MODE];
+    for filter_intra_mode in [FilterIntraMode::FILTER_DC_PRED, FilterIntraMode::FILTER_V_PRED,
+                              FilterIntraMode::FILTER_H_PRED, FilterIntraMode::FILTER_D157_PRED,
+                              FilterIntraMode::FILTER_PAETH_PRED] {
+      modes.push(LumaMode { filter_intra_mode: Some(filter_intra_mode), ..DC_MODE });
+    }
+    for y_mode in [PredictionMode::V_PRED, PredictionMode::H_PRED, PredictionMode::D45_PRED,
+                   PredictionMode::D135_PRED, PredictionMode::D113_PRED, PredictionMode::D157_PRED,
+                   PredictionMode::D203_PRED, PredictionMode::D67_PRED] {
+      for angle_delta in -MAX_ANGLE_DELTA ..= MAX_ANGLE_DELTA {
+        modes.push(LumaMode { y_mode: y_mode, angle_delta: angle_delta, filter_intra_mode: None });
+      }
+    }
+
+    // The predictions are written into the reconstruction, which is fine as
+    // the block will be predicted again when it is actually encoded
+    let (y0, x0, h, w) = self.block_region(0, mi_row, mi_col, bsize);
+    let mut costs: Vec<(u64, LumaMode)> = modes.into_iter().map(|luma_mode| {
+      self.predict_luma(mi_row, mi_col, bsize, mi_row, mi_col, bsize, luma_mode);
+      (satd(self.source.y().pixels(), self.recon.y().pixels(), y0, x0, h, w), luma_mode)
+    }).collect();
+    costs.sort_by_key(|&(cost, _)| cost);
+
+    let mut candidates = vec![DC_MODE];
+    candidates.extend(costs.into_iter().map(|(_, luma_mode)| luma_mode)
+                           .filter(|&luma_mode| luma_mode != DC_MODE)
+                           .take(LUMA_MODE_CANDIDATES));
+    return candidates;
+  }
+
+  // Find displacement vectors worth trying for intra block copy
+  // This consists of the predicted DV, plus the closest few exact matches
+  // for the current source block, among the positions we are allowed to copy from
+  fn intrabc_candidates(&self, mi_row: usize, mi_col: usize) -> Vec<[i16; 2]> {
+    let Some(hash_table) = &self.intrabc_hash else {
+      return Vec::new();
+    };
+
+    let mi_cols = self.mode_info.cols();
+    let crop_height = self.encoder.y_crop_height;
+    let crop_width = self.encoder.y_crop_width;
+    let sb_size = self.encoder.config.sb_size;
+    let ref_dv = self.get_ref_dv(mi_row, mi_col);
+    let is_usable = |dv: [i16; 2]| {
+      is_dv_valid(dv, mi_row, mi_col, mi_cols, crop_height, crop_width, sb_size) && can_code_dv(dv, ref_dv)
+    };
+
+    let (y0, x0) = (mi_row * 4, mi_col * 4);
+    let to_dv = |y: usize, x: usize| [(y as i32 - y0 as i32) as i16, (x as i32 - x0 as i32) as i16];
+    let mut matches: Vec<[i16; 2]> = Vec::new();
+    if y0 + 8 <= crop_height && x0 + 8 <= crop_width {
+      let is_new_usable = |y: usize, x: usize| {
+        let dv = to_dv(y, x);
+        dv != ref_dv && is_usable(dv)
+      };
+      matches = hash_table.find_matches(self.source.y().pixels(), y0, x0, is_new_usable)
+        .into_iter()
+        .map(|(y, x)| to_dv(y, x))
+        .collect();
+    }
+
+    // Prefer the matches which are cheapest to code
+    let dv_cost = |dv: &[i16; 2]| abs(dv[0] as i32 - ref_dv[0] as i32) + abs(dv[1] as i32 - ref_dv[1] as i32);
+    matches.sort_by_key(dv_cost);
+
+    let mut candidates = Vec::with_capacity(matches.len() + 1);
+    if is_usable(ref_dv) {
+      candidates.push(ref_dv);
+    }
+    candidates.extend(matches);
+    return candidates;
+  }
+
+  // Predict the DV for an IntraBC block, based on the DVs of nearby IntraBC blocks.
+  // This is the first nonzero DV out of the top two entries of the stack
+  fn get_ref_dv(&self, mi_row: usize, mi_col: usize) -> [i16; 2] {
+    let stack = self.find_mv_stack(mi_row, mi_col, true);
+    for &(dv, _) in stack.entries.iter().take(2) {
+      if dv != [0, 0] {
+        return dv;
+      }
+    }
+    return default_ref_dv(mi_row, self.encoder.config.sb_size);
+  }
+
+  // Gather candidate vectors for predicting the MV of an inter block, or the DV of an
+  // IntraBC block if `use_intrabc` is set, from nearby blocks of the same kind.
+  // This follows the spec's find_mv_stack() process, simplified using the fact that
+  // all blocks are 8x8, so each row or column scanned by the spec covers exactly one block.
+  // We also never use temporal MVs, and the spec's extra search can never add anything
+  // for us, as every inter block uses the same reference frame
+  fn find_mv_stack(&self, mi_row: usize, mi_col: usize, use_intrabc: bool) -> MvStack {
+    let mi_rows = self.mode_info.rows();
+    let mi_cols = self.mode_info.cols();
+
+    // Weighted list of candidate vectors, where repeated vectors accumulate weight.
+    // Adding a block returns None if it's the wrong kind of block, otherwise
+    // it returns whether that block used NEWMV
+    let mut entries: Vec<([i16; 2], u32)> = Vec::new();
+    let add_candidate = |entries: &mut Vec<([i16; 2], u32)>, row: usize, col: usize| {
+      let mi = &self.mode_info[row][col];
+      let mv = match use_intrabc {
+        true if mi.use_intrabc => mi.dv,
+        false if mi.is_inter => mi.mv,
+        _ => return None,
+      };
+      match entries.iter_mut().find(|(candidate, _)| *candidate == mv) {
+        Some((_, weight)) => *weight += REF_MV_WEIGHT,
+        None => entries.push((mv, REF_MV_WEIGHT)),
+      }
+      Some(mi.y_mode == PredictionMode::NEWMV as u8)
+    };
+
+    // The nearest neighbours are the above, left, and above-right blocks,
+    // and are given extra weight
+    let above = if mi_row > 0 { add_candidate(&mut entries, mi_row - 1, mi_col) } else { None };
+    let left = if mi_col > 0 { add_candidate(&mut entries, mi_row, mi_col - 1) } else { None };
+    let above_right = if mi_row > 0 && mi_col + 2 < mi_cols && has_top_right(mi_row, mi_col, 2, self.sb_size4()) {
+      add_candidate(&mut entries, mi_row - 1, mi_col + 2)
+    } else {
+      None
+    };
+    let num_nearest = entries.len();
+    for (_, weight) in entries.iter_mut() {
+      *weight += REF_CAT_LEVEL;
+    }
+
+    // Track which directions have matching neighbours, and how many of the nearest use NEWMV
+    let num_new = [above, left, above_right].iter().filter(|&&found| found == Some(true)).count();
+    let mut found_above = above.is_some() || above_right.is_some();
+    let mut found_left = left.is_some();
+    let close_matches = found_above as usize + found_left as usize;
+
+    // Then the above-left block, and the blocks two and three positions above and to the left
+    if mi_row > 0 && mi_col > 0 {
+      found_above |= add_candidate(&mut entries, mi_row - 1, mi_col - 1).is_some();
+    }
+    for offset in [3, 5] {
+      if mi_row >= offset {
+        found_above |= add_candidate(&mut entries, mi_row - offset, mi_col + 1).is_some();
+      }
+      if mi_col >= offset {
+        found_left |= add_candidate(&mut entries, mi_row + 1, mi_col - offset).is_some();
+      }
+    }
+    let total_matches = found_above as usize + found_left as usize;
+
+    // Sort each group by weight. This must be a stable sort to match the decoder
+    entries[..num_nearest].sort_by_key(|&(_, weight)| Reverse(weight));
+    entries[num_nearest..].sort_by_key(|&(_, weight)| Reverse(weight));
+
+    for (mv, _) in entries.iter_mut() {
+      *mv = if use_intrabc {
+        clamp_ref_dv(*mv, mi_row, mi_col, mi_rows, mi_cols)
+      } else {
+        clamp_ref_mv(*mv, mi_row, mi_col, mi_rows, mi_cols)
+      };
+    }
+
+    let (new_mv_ctx, ref_mv_ctx) = match close_matches {
+      0 => (min(total_matches, 1), total_matches),
+      1 => (3 - min(num_new, 1), 2 + total_matches),
+      _ => (5 - min(num_new, 1), 5),
+    };
+
+    MvStack {
+      entries: entries,
+      new_mv_ctx: new_mv_ctx,
+      ref_mv_ctx: ref_mv_ctx,
+    }
+  }
+
+  // Find inter modes worth trying for the current block. This is every way of using the
+  // MV stack, along with GLOBALMV (ie. zero motion), and NEWMV using the result of
+  // a motion search
+  fn inter_candidates(&self, mi_row: usize, mi_col: usize, bsize: usize) -> Vec<InterMode> {
+    if self.reference.is_none() {
+      return Vec::new();
+    }
+
+    let stack = self.find_mv_stack(mi_row, mi_col, false);
+    let num_found = stack.entries.len();
+
+    let mut candidates = vec![
+      InterMode { y_mode: PredictionMode::NEARESTMV, ref_mv_idx: 0, mv: stack.ref_mv(0) },
+      InterMode { y_mode: PredictionMode::GLOBALMV, ref_mv_idx: 0, mv: [0, 0] },
+    ];
+    // NEARMV starts from the second stack entry, and can use up to the fourth
+    for ref_mv_idx in 1 ..= clamp(num_found as i32 - 1, 1, 3) as usize {
+      candidates.push(InterMode { y_mode: PredictionMode::NEARMV, ref_mv_idx: ref_mv_idx, mv: stack.ref_mv(ref_mv_idx) });
+    }
+
+    // NEWMV codes the MV relative to one of the first three stack entries
+    let mv = self.motion_search(mi_row, mi_col, bsize, &stack);
+    for ref_mv_idx in 0 ..= min(num_found.saturating_sub(1), 2) {
+      if can_code_mv(mv, stack.ref_mv(ref_mv_idx)) {
+        candidates.push(InterMode { y_mode: PredictionMode::NEWMV, ref_mv_idx: ref_mv_idx, mv: mv });
+      }
+    }
+    return candidates;
+  }
+
+  // Find a good MV for the current block, to code with NEWMV. This starts from the
+  // frame-level motion field, the MVs of nearby blocks, and zero motion, then refines
+  // the best of those to quarter-pixel precision.
+  //
+  // Each MV is scored by the SATD of the luma prediction error, plus the cost of coding
+  // the MV relative to the first stack entry. As SATD scales roughly with the square root
+  // of the squared error, the rate is weighted by the square root of lambda
+  fn motion_search(&self, mi_row: usize, mi_col: usize, bsize: usize, stack: &MvStack) -> [i16; 2] {
+    let reference = self.reference.unwrap().y();
+    let (y0, x0, h, w) = self.block_region(0, mi_row, mi_col, bsize);
+    let source = self.source.y().pixels().copy_region(y0, x0, h, w);
+    let ref_mv = stack.ref_mv(0);
+    let rate_scale = self.lambda.sqrt();
+
+    let cost = |mv: [i16; 2]| {
+      if !can_code_mv(mv, ref_mv) {
+        return f64::MAX;
+      }
+      let pred = inter_predict_block(reference, mv, y0, x0, h, w, 0);
+      let mut rate = RateEstimator::new();
+      write_mv(&mut rate, mv, ref_mv);
+      satd(&source, &pred, 0, 0, h, w) as f64 + rate_scale * rate_to_bits(rate.rate())
+    };
+
+    let mut starts = vec![self.motion_field.as_ref().unwrap()[mi_row / 2][mi_col / 2], [0, 0]];
+    starts.extend(stack.entries.iter().map(|&(mv, _)| mv));
+
+    let mut best_mv = starts[0];
+    let mut best_cost = f64::MAX;
+    for mv in starts {
+      let mv_cost = cost(mv);
+      if mv_cost < best_cost {
+        best_mv = mv;
+        best_cost = mv_cost;
+      }
+    }
+
+    // Search the eight neighbouring half-pixel positions, then the eight
+    // neighbouring quarter-pixel positions around the best of those
+    for step in [4, 2] {
+      let center = best_mv;
+      for dy in [-step, 0, step] {
+        for dx in [-step, 0, step] {
+          if dy == 0 && dx == 0 {
+            continue;
+          }
+          let mv = [center[0] + dy, center[1] + dx];
+          let mv_cost = cost(mv);
+          if mv_cost < best_cost {
+            best_mv = mv;
+            best_cost = mv_cost;
           }
+        }
+      }
+    }
+    return best_mv;
+  }
 
-          let mag_part = min(round2(mag, 1), 6) as usize;
-          let loc_part = if c == 0 {
-            0
-          } else if row < 2 && col < 2 {
-            7
-          } else {
-            14
-          };
-          mag_part + loc_part
-        };
+  // Code whether the current block is an inter block. The context depends on whether
+  // the above and left neighbours are intra blocks
+  fn encode_is_inter(&mut self, mi_row: usize, mi_col: usize, is_inter: bool) {
+    let above_intra = if mi_row > 0 { Some(!self.mode_info[mi_row - 1][mi_col].is_inter) } else { None };
+    let left_intra = if mi_col > 0 { Some(!self.mode_info[mi_row][mi_col - 1].is_inter) } else { None };
+    let ctx = match (above_intra, left_intra) {
+      (Some(true), Some(true)) => 3,
+      (Some(above), Some(left)) => (above || left) as usize,
+      (Some(intra), None) | (None, Some(intra)) => 2 * intra as usize,
+      (None, None) => 0,
+    };
+    self.bitstream.write_bool(is_inter, intra_inter_cdf[ctx][0]);
+  }
+
+  // Code the reference frame and inter mode for a block, along with its MV if needed
+  fn encode_inter_modes(&mut self, mi_row: usize, mi_col: usize, inter_mode: &InterMode) {
+    // We always use LAST_FRAME, which takes three binary choices to code. The context
+    // for each compares how many neighbours use references on either side of that choice.
+    // As every inter block uses LAST_FRAME, this only depends on whether any neighbours
+    // are inter blocks
+    let above_inter = mi_row > 0 && self.mode_info[mi_row - 1][mi_col].is_inter;
+    let left_inter = mi_col > 0 && self.mode_info[mi_row][mi_col - 1].is_inter;
+    let ref_ctx = if above_inter || left_inter { 2 } else { 1 };
+    self.bitstream.write_bool(false, single_ref_p1_cdf[ref_ctx][0]);
+    self.bitstream.write_bool(false, single_ref_p3_cdf[ref_ctx][0]);
+    self.bitstream.write_bool(false, single_ref_p4_cdf[ref_ctx][0]);
+
+    let stack = self.find_mv_stack(mi_row, mi_col, false);
+    let y_mode = inter_mode.y_mode;
+    self.bitstream.write_bool(y_mode != PredictionMode::NEWMV, new_mv_cdf[stack.new_mv_ctx][0]);
+    if y_mode != PredictionMode::NEWMV {
+      // The context for zero_mv would depend on temporal MVs, which we don't use
+      self.bitstream.write_bool(y_mode != PredictionMode::GLOBALMV, zero_mv_cdf[0][0]);
+      if y_mode != PredictionMode::GLOBALMV {
+        self.bitstream.write_bool(y_mode == PredictionMode::NEARMV, ref_mv_cdf[stack.ref_mv_ctx][0]);
+      }
+    }
 
-        // Now encode the coeff_br symbols
-        let mut level = 3;
-        for _ in 0..4 {
-          let coeff_br = min(abs_value - level, 3);
-          self.bitstream.write_symbol(coeff_br as usize, &coeff_br_cdf[qctx][txs_ctx][ptype][br_ctx]);
-          level += coeff_br;
-          if coeff_br < 3 {
+    // NEWMV and NEARMV select a stack entry using a series of "use the next entry" flags,
+    // each of which is only coded if the stack is long enough for there to be a choice.
+    // NEWMV chooses between the first three entries, and NEARMV between the second to fourth
+    let num_found = stack.entries.len();
+    let first_idx = match y_mode {
+      PredictionMode::NEWMV => Some(0),
+      PredictionMode::NEARMV => Some(1),
+      _ => None,
+    };
+    if let Some(first_idx) = first_idx {
+      for idx in first_idx .. first_idx + 2 {
+        if num_found > idx + 1 {
+          let use_next = inter_mode.ref_mv_idx != idx;
+          self.bitstream.write_bool(use_next, drl_mode_cdf[stack.drl_ctx(idx)][0]);
+          if !use_next {
             break;
           }
         }
       }
     }
 
-    // Code DC sign + golomb bits
-    let dc_coeff = coeffs[0][0];
-    if dc_coeff != 0 {
-      // The DC sign context depends on whether there are more +ve signs, more -ve signs,
-      // or an equal number, among all above and left 4x4 units. Since we always use 8x8
-      // blocks, there is exactly one above and one left neighbour.
-      //
-      // Also, for the chroma planes, in theory we're only meant to look at the blocks which are "chroma references",
-      // i.e. the ones which contain an MI unit with odd mi_row and mi_col. This matters if we ever support 4x4
-      // block sizes, but as we currently don't, that's just every block.
-      //
-      // Therefore we can simplify the scan given in the spec, into just looking at the single above and single left
-      // block, if they exist.
-      //
-      // As we store the DC sign in ModeInfo::dc_sign as -1 / 0 / +1, we can do this by
-      // simply summing the DC signs of all surrounding blocks
-      let mut net_neighbour_sign = 0;
-      if mi_row > 0 {
-        net_neighbour_sign += self.mode_info[mi_row - 1][mi_col].dc_sign[plane];
-      }
-      if mi_col > 0 {
-        net_neighbour_sign += self.mode_info[mi_row][mi_col - 1].dc_sign[plane];
+    if y_mode == PredictionMode::NEWMV {
+      write_mv(&mut self.bitstream, inter_mode.mv, stack.ref_mv(inter_mode.ref_mv_idx));
+    }
+  }
+
+  // The qindex which will be in effect for the current block once any
+  // superblock-level delta has been applied, ie. CurrentQIndex after
+  // encode_delta_qindex() has been called
+  fn next_qindex(&self, mi_row: usize, mi_col: usize) -> u8 {
+    if self.read_deltas {
+      let sb_size4 = self.sb_size4();
+      let target_qindex = self.sb_qindex.as_ref().unwrap()[mi_row / sb_size4][mi_col / sb_size4];
+      let delta = (target_qindex as i32 - self.current_qindex as i32) >> DELTA_Q_RES;
+      return clamp(self.current_qindex as i32 + (delta << DELTA_Q_RES), 1, 255) as u8;
+    }
+    return self.current_qindex;
+  }
+
+  // Code the segment ID for the current block, relative to the ID predicted
+  // from the above-left, above, and left blocks
+  //
+  // For skipped blocks, nothing is coded and the block takes on the predicted
+  // segment ID. This is fine, as the segment only affects how the residual is coded.
+  // Returns the segment ID which the decoder will use for this block
+  fn encode_segment_id(&mut self, mi_row: usize, mi_col: usize, skip: bool) -> u8 {
+    let segmentation = self.encoder.config.segmentation.as_ref().unwrap();
+    let segment_id = segmentation.map[mi_row][mi_col];
+    let max_segment_id = segmentation.last_active_seg_id();
+    assert!(segment_id as usize <= max_segment_id);
+
+    let prev_ul = if mi_row > 0 && mi_col > 0 { Some(self.mode_info[mi_row - 1][mi_col - 1].segment_id) } else { None };
+    let prev_u = if mi_row > 0 { Some(self.mode_info[mi_row - 1][mi_col].segment_id) } else { None };
+    let prev_l = if mi_col > 0 { Some(self.mode_info[mi_row][mi_col - 1].segment_id) } else { None };
+    let (pred, ctx) = predict_segment_id(prev_ul, prev_u, prev_l);
+
+    if skip {
+      return pred;
+    }
+
+    let symbol = neg_interleave(segment_id as usize, pred as usize, max_segment_id + 1);
+    self.bitstream.write_symbol(symbol, &segment_id_cdf[ctx]);
+    return segment_id;
+  }
+
+  // Get the qindex for a block in the given segment, given the current
+  // superblock-level qindex. This corresponds to get_qidx(0, segment_id) in the spec
+  fn get_qindex(&self, segment_id: u8, current_qindex: u8) -> u8 {
+    match &self.encoder.config.segmentation {
+      Some(segmentation) => segmentation.get_qindex(segment_id, current_qindex),
+      None => current_qindex,
+    }
+  }
+
+  // Get the quantizer settings for a given plane and qindex
+  fn get_quantizer(&self, plane: usize, qindex: u8) -> Quantizer {
+    let qm_level = match self.encoder.config.qm_levels {
+      Some(levels) => levels[plane],
+      None => NUM_QM_LEVELS - 1,
+    };
+    let delta_q = &self.encoder.config.delta_q;
+    Quantizer::new(qindex, delta_q.dc(plane), delta_q.ac(plane), qm_level, plane)
+  }
+
+  // Rate-distortion optimized quantization
+  //
+  // Starting from the quantized levels produced by quantize(), try reducing the
+  // magnitude of each coefficient by one, and try truncating the block so that
+  // the EOB comes earlier. Keep each change which reduces the total RD cost.
+  //
+  // This is a greedy search rather than a true trellis search, but it uses the
+  // exact same contexts as encode_coeffs(), via coeff_cost(), so the rate
+  // of each option is accurately accounted for.
+  fn optimize_coeffs(&self, plane: usize, tx_set: TxSet, ctx: &CoeffContext,
+                     quantizer: &Quantizer, coeffs: &Array2D<i32>, levels: &mut Array2D<i32>) {
+    let txsize = levels.rows();
+    let scan: &[(u8, u8)] = scan_order_2d[txs_ctx(txsize)];
+
+    // Distortion of coding the coefficient at scan index `c` with the given level.
+    // 32x32 coefficients are doubled to match the scale which coeff_dist() expects; see dq_shift()
+    let dist_at = |c: usize, level: i32| {
+      let (row, col) = (scan[c].0 as usize, scan[c].1 as usize);
+      let q = quantizer.step(row, col, txsize);
+      coeff_dist(coeffs[row][col] << dq_shift(txsize), level, q)
+    };
+    let cost_of = |levels: &Array2D<i32>, dist: f64| {
+      let rate = self.coeff_cost(plane, tx_set, ctx, levels);
+      dist + self.lambda * rate_to_bits(rate)
+    };
+    let find_eob = |levels: &Array2D<i32>| {
+      (0..txsize*txsize).rev()
+        .find(|&c| levels[scan[c].0 as usize][scan[c].1 as usize] != 0)
+        .map_or(0, |c| c + 1)
+    };
+
+    let eob = find_eob(levels);
+    if eob == 0 {
+      return;
+    }
+
+    let mut dist: f64 = (0..txsize*txsize).map(|c| dist_at(c, levels[scan[c].0 as usize][scan[c].1 as usize])).sum();
+    let mut best_cost = cost_of(levels, dist);
+
+    // Pass 1: Try lowering each coefficient by one, in reverse scan order,
+    // which is the order in which the coefficient contexts are built up
+    for c in (0..eob).rev() {
+      let (row, col) = (scan[c].0 as usize, scan[c].1 as usize);
+      let level = levels[row][col];
+      if level == 0 {
+        continue;
+      }
+
+      let new_level = level - signum(level);
+      let new_dist = dist - dist_at(c, level) + dist_at(c, new_level);
+      levels[row][col] = new_level;
+      let new_cost = cost_of(levels, new_dist);
+      if new_cost < best_cost {
+        best_cost = new_cost;
+        dist = new_dist;
+      } else {
+        levels[row][col] = level;
+      }
+    }
+
+    // Pass 2: Try zeroing all coefficients from some point onwards, to reduce the EOB.
+    // This can be worthwhile even when zeroing each coefficient individually is not,
+    // because the coefficients before the EOB are more expensive to code
+    let eob = find_eob(levels);
+    let mut trial = levels.clone();
+    let mut trial_dist = dist;
+    let mut best_eob = eob;
+    for c in (0..eob).rev() {
+      let (row, col) = (scan[c].0 as usize, scan[c].1 as usize);
+      let level = trial[row][col];
+      if level == 0 {
+        continue;
       }
-  
-      // Map result to the appropriate context
-      let dc_sign_ctx = if net_neighbour_sign == 0 {
+
+      trial_dist += dist_at(c, 0) - dist_at(c, level);
+      trial[row][col] = 0;
+      let trial_cost = cost_of(&trial, trial_dist);
+      if trial_cost < best_cost {
+        best_cost = trial_cost;
+        best_eob = c;
+      }
+    }
+
+    for c in best_eob..eob {
+      levels[scan[c].0 as usize][scan[c].1 as usize] = 0;
+    }
+  }
+
+  // Calculate the contexts for coefficient coding which depend on neighbouring transform blocks.
+  // (mi_row, mi_col) is the top-left corner of the transform block, and `tx_size` is its
+  // width and height in the given plane's pixels
+  fn get_coeff_context(&self, plane: usize, mi_row: usize, mi_col: usize, bsize: usize,
+                       tx_size: usize) -> CoeffContext {
+    // The neighbouring contexts are tracked per 4x4 unit of each plane. We store these per
+    // luma 4x4 unit instead, which for chroma means that each value is repeated over
+    // a 2x2 group of units, so we only need to look at every other unit
+    let (mi_size, step) = if plane == 0 { (tx_size / 4, 1) } else { (tx_size / 2, 2) };
+    let above: Vec<&ModeInfo> = if mi_row > 0 {
+      (mi_col..mi_col + mi_size).step_by(step).map(|col| &self.mode_info[mi_row - 1][col]).collect()
+    } else {
+      Vec::new()
+    };
+    let left: Vec<&ModeInfo> = if mi_col > 0 {
+      (mi_row..mi_row + mi_size).step_by(step).map(|row| &self.mode_info[row][mi_col - 1]).collect()
+    } else {
+      Vec::new()
+    };
+
+    // The all_zero symbol has a complex dependency on the nearby transform coefficients
+    let all_zero_ctx = if plane == 0 {
+      // For luma, there is a special case where this is short-circuited to 0 for max-size
+      // transforms (ie, transform size == block size). Otherwise, the context depends on
+      // the largest level context along each edge
+      let top = above.iter().map(|mi| mi.level_ctx[plane]).max().unwrap_or(0);
+      let left = left.iter().map(|mi| mi.level_ctx[plane]).max().unwrap_or(0);
+      if tx_size == bsize {
         0
-      } else if net_neighbour_sign < 0 {
+      } else if top == 0 && left == 0 {
         1
+      } else if top == 0 || left == 0 {
+        2 + (max(top, left) > 3) as usize
+      } else if max(top, left) <= 3 {
+        4
+      } else if min(top, left) <= 3 {
+        5
       } else {
-        2
-      };
+        6
+      }
+    } else {
+      // For chroma, the context depends on whether there are any nonzero
+      // coefficients along each edge
+      let above = above.iter().any(|mi| mi.level_ctx[plane] != 0 || mi.dc_sign[plane] != 0);
+      let left = left.iter().any(|mi| mi.level_ctx[plane] != 0 || mi.dc_sign[plane] != 0);
+      7 + (above as usize) + (left as usize)
+    };
+
+    // The DC sign context depends on whether there are more +ve signs, more -ve signs,
+    // or an equal number, among all above and left 4x4 units.
+    //
+    // Also, for the chroma planes, in theory we're only meant to look at the blocks which are "chroma references",
+    // i.e. the ones which contain an MI unit with odd mi_row and mi_col. This matters if we ever support 4x4
+    // block sizes, but as we currently don't, that's just every block.
+    //
+    // As we store the DC sign in ModeInfo::dc_sign as -1 / 0 / +1, we can do this by
+    // simply summing the DC signs of all surrounding units
+    let net_neighbour_sign: i8 = above.iter().chain(left.iter()).map(|mi| mi.dc_sign[plane]).sum();
+
+    // Map result to the appropriate context
+    let dc_sign_ctx = if net_neighbour_sign == 0 {
+      0
+    } else if net_neighbour_sign < 0 {
+      1
+    } else {
+      2
+    };
 
-      let sign = if dc_coeff < 0 { 1 } else { 0 };
-      self.bitstream.write_symbol(sign, &dc_sign_cdf[qctx][ptype][dc_sign_ctx]);
+    CoeffContext {
+      all_zero_ctx: all_zero_ctx,
+      dc_sign_ctx: dc_sign_ctx,
     }
-    if abs(dc_coeff) >= 15 {
-      self.bitstream.write_golomb(unsigned_abs(dc_coeff) - 15);
+  }
+
+  fn encode_coeffs(&mut self, plane: usize, tx_set: TxSet, ctx: &CoeffContext, coeffs: &Array2D<i32>) {
+    let qctx = get_qctx(self.base_qindex);
+    write_coeffs(&mut self.bitstream, plane, tx_set, qctx, ctx, coeffs);
+  }
+
+  // Estimate the cost of coding the given coefficients, using exactly the same
+  // contexts as encode_coeffs() would, but without writing anything
+  fn coeff_cost(&self, plane: usize, tx_set: TxSet, ctx: &CoeffContext, coeffs: &Array2D<i32>) -> u32 {
+    let qctx = get_qctx(self.base_qindex);
+
+    let mut rate = RateEstimator::new();
+    write_coeffs(&mut rate, plane, tx_set, qctx, ctx, coeffs);
+    return rate.rate();
+  }
+
+  // Get the region of a given plane covered by a block, clamped to the frame size.
+  // Returns (y0, x0, h, w) in that plane's pixel units.
+  fn block_region(&self, plane: usize, mi_row: usize, mi_col: usize, bsize: usize) -> (usize, usize, usize, usize) {
+    // Blocks at the bottom or right of the frame may extend past the frame edge
+    let mi_rows = min(bsize / 4, self.mode_info.rows() - mi_row);
+    let mi_cols = min(bsize / 4, self.mode_info.cols() - mi_col);
+
+    let subsampling = if plane > 0 { 1 } else { 0 };
+    let y0 = (mi_row * 4) >> subsampling;
+    let x0 = (mi_col * 4) >> subsampling;
+    let h = (mi_rows * 4) >> subsampling;
+    let w = (mi_cols * 4) >> subsampling;
+    (y0, x0, h, w)
+  }
+
+  // Save the current state of the bitstream, along with the mode info and
+  // reconstructed pixels for the given block, so that we can try encoding
+  // the block and then undo it
+  pub fn checkpoint(&self, mi_row: usize, mi_col: usize, bsize: usize) -> TileCheckpoint {
+    let mi_rows = min(bsize / 4, self.mode_info.rows() - mi_row);
+    let mi_cols = min(bsize / 4, self.mode_info.cols() - mi_col);
+
+    let recon = std::array::from_fn(|plane| {
+      let (y0, x0, h, w) = self.block_region(plane, mi_row, mi_col, bsize);
+      (y0, x0, self.recon.plane(plane).pixels().copy_region(y0, x0, h, w))
+    });
+
+    TileCheckpoint {
+      bitstream: self.bitstream.checkpoint(),
+      mi_row: mi_row,
+      mi_col: mi_col,
+      mode_info: self.mode_info.copy_region(mi_row, mi_col, mi_rows, mi_cols),
+      recon: recon,
+      current_qindex: self.current_qindex,
+      read_deltas: self.read_deltas,
+      lambda: self.lambda,
+      adaptive_rounding: self.adaptive_rounding.clone(),
+    }
+  }
+
+  // Undo everything done since the given checkpoint was taken
+  // Note that only the block passed to checkpoint() is restored, so the caller must
+  // not modify anything outside of that block in the meantime
+  pub fn restore(&mut self, checkpoint: &TileCheckpoint) {
+    self.bitstream.restore(&checkpoint.bitstream);
+    self.mode_info.paste_region(checkpoint.mi_row, checkpoint.mi_col, &checkpoint.mode_info);
+    for plane in 0..3 {
+      let (y0, x0, ref pixels) = checkpoint.recon[plane];
+      self.recon.plane_mut(plane).pixels_mut().paste_region(y0, x0, pixels);
     }
+    self.current_qindex = checkpoint.current_qindex;
+    self.read_deltas = checkpoint.read_deltas;
+    self.lambda = checkpoint.lambda;
+    self.adaptive_rounding = checkpoint.adaptive_rounding.clone();
+  }
 
-    // Store DC sign for reference by later blocks
-    this_mi.dc_sign[plane] = signum(dc_coeff) as i8;
+  // Total squared error between the source and reconstruction of a block, over all planes
+  pub fn block_distortion(&self, mi_row: usize, mi_col: usize, bsize: usize) -> u64 {
+    let mut dist = 0;
+    for plane in 0..self.encoder.num_planes() {
+      let (y0, x0, h, w) = self.block_region(plane, mi_row, mi_col, bsize);
+      dist += sse(self.source.plane(plane).pixels(), self.recon.plane(plane).pixels(), y0, x0, h, w);
+    }
+    return dist;
+  }
 
-    // Code sign + golomb bits for the rest of coefficients
-    // Note that this is done in low-to-high index order, in contrast to the earlier loop
-    for c in 1..eob {
-      let (row, col) = scan[c];
-      let coeff = coeffs[row as usize][col as usize];
-      if coeff != 0 {
-        let sign = if coeff < 0 { 1 } else { 0 };
-        self.bitstream.write_literal(sign, 1);
-      }
+  // Generic hook for making rate-distortion decisions:
+  // Trial-encode a block using `encode_fn`, measure the resulting rate and distortion,
+  // then undo the encode.
+  //
+  // `encode_fn` must fully code the block at (mi_row, mi_col), including writing
+  // its symbols and updating the reconstruction and mode info, and must not touch
+  // anything outside of that block.
+  pub fn rd_evaluate<F: FnOnce(&mut Self)>(&mut self, mi_row: usize, mi_col: usize, bsize: usize,
+                                           encode_fn: F) -> RDCost {
+    let checkpoint = self.checkpoint(mi_row, mi_col, bsize);
+    let start_bits = self.bitstream.tell_frac();
+
+    encode_fn(self);
+
+    // tell_frac() counts in units of 1/8 bit, so scale up to match our rate units
+    let rate = (self.bitstream.tell_frac() - start_bits) << (RATE_SHIFT - 3);
+    let dist = self.block_distortion(mi_row, mi_col, bsize);
+    self.restore(&checkpoint);
+
+    return RDCost::new(rate, dist, self.lambda);
+  }
 
-      if abs(coeff) >= 15 {
-        self.bitstream.write_golomb(unsigned_abs(coeff) - 15);
+  // Evaluate each of a list of candidate coding choices for a block, and return
+  // the best one along with its cost. This leaves the block un-encoded, so the
+  // caller should then encode it for real using the selected choice.
+  pub fn rd_select<T: Copy, F: FnMut(&mut Self, T)>(&mut self, mi_row: usize, mi_col: usize, bsize: usize,
+                                                     candidates: &[T], mut encode_fn: F) -> (T, RDCost) {
+    assert!(!candidates.is_empty());
+
+    let mut best: Option<(T, RDCost)> = None;
+    for &candidate in candidates {
+      let cost = self.rd_evaluate(mi_row, mi_col, bsize, |this| encode_fn(this, candidate));
+      match best {
+        Some((_, best_cost)) if !cost.is_better_than(&best_cost) => {},
+        _ => { best = Some((candidate, cost)); }
       }
     }
+
+    return best.unwrap();
   }
 
   fn dump_recon(&mut self, path: &str) -> Result<(), io::Error> {
@@ -655,3 +2272,315 @@ impl<'a> TileEncoder<'a> {
     Ok(())
   }
 }
+
+// Compute the level context and DC sign for a transform block, to store for reference by
+// later transform blocks. See ModeInfo::level_ctx and ModeInfo::dc_sign
+fn level_ctx_and_dc_sign(coeffs: &Array2D<i32>) -> (u8, i8) {
+  let mut culLevel = 0; // "Cumulative level"
+  for row in 0..coeffs.rows() {
+    for &coeff in coeffs[row].iter() {
+      culLevel += abs(coeff);
+    }
+  }
+  return (min(culLevel, 63) as u8, signum(coeffs[0][0]) as i8);
+}
+
+// Write the coefficients for one transform block, using the given neighbour-dependent
+// contexts. This is generic over the output so that it can also be used for rate estimation.
+// `tx_set` selects the transform type syntax.
+fn write_coeffs<W: SymbolWriter>(w: &mut W, plane: usize, tx_set: TxSet, qctx: usize,
+                                 ctx: &CoeffContext, coeffs: &Array2D<i32>) {
+  // We support square transforms from 4x4 up to 32x32
+  let txsize = coeffs.rows();
+  assert!(txsize == 4 || txsize == 8 || txsize == 16 || txsize == 32);
+  assert!(coeffs.cols() == txsize);
+  let txs_ctx = txs_ctx(txsize);
+  let num_coeffs = txsize * txsize;
+
+  let scan: &[(u8, u8)] = scan_order_2d[txs_ctx];
+
+  let ptype = if plane == 0 { 0 } else { 1 };
+
+  // Find the "end of block" location
+  // This is one past the last nonzero coefficient, or 0 if all coeffs are zero
+  let mut eob = 0;
+  for c in 0..num_coeffs {
+    let (row, col) = scan[c];
+    let coeff = coeffs[row as usize][col as usize];
+    if coeff != 0 {
+      eob = c + 1;
+    }
+  }
+
+  let all_zero = eob == 0;
+
+  w.write_symbol(all_zero as usize, &all_zero_cdf[qctx][txs_ctx][ctx.all_zero_ctx]);
+  if all_zero {
+    return;
+  }
+
+  // Transform type - only coded for luma
+  // As we selected the reduced transform set in the frame header,
+  // we end up looking at the TX_SET_INTRA_2 set, which consists of
+  // { IDTX, DCT_DCT, ADST_ADST, ADST_DCT, DCT_ADST }, in that order.
+  // We want DCT_DCT, so we want to encode index 1.
+  // The CDF also depends on the luma mode, but the default CDFs for 4x4 and 8x8
+  // transforms are the same for every mode. 32x32 intra transforms can only
+  // use DCT_DCT, so nothing is coded for those.
+  // For inter and IntraBC blocks, the set is TX_SET_INTER_3 = { IDTX, DCT_DCT }, so
+  // DCT_DCT is again index 1.
+  if plane == 0 {
+    match tx_set {
+      TxSet::Inter => w.write_symbol(1, &inter_tx_type_cdf[txs_ctx]),
+      TxSet::Intra(_) if txsize == 32 => {},
+      TxSet::Intra(intra_dir) if txsize == 16 => w.write_symbol(1, &tx_type_16x16_cdf[intra_dir as usize]),
+      TxSet::Intra(_) => w.write_symbol(1, &tx_type_cdf),
+    }
+  }
+
+  // Number of coefficients, encoded as a logarithmic class + value within that class
+  // Here, the contexts are qindex, plane type, and (for 16x16 and smaller)
+  // whether the selected transform type is 1D (last context = 1) or 2D
+  // (last context = 0). We always choose DCT_DCT, which counts as a 2D transform
+  //
+  // The EOB is split into a class plus optional extra bits. Each class has the following range:
+  // Class 0 => EOB = 1
+  // Class 1 => EOB = 2
+  // Class 2 => EOB = 3-4
+  // Class 3 => EOB = 5-8
+  // ...
+  // up to a maximum class which depends on the transform size
+  // For 4x4 the largest class is class 4 (EOB = 9-16), for 8x8 it's class 6 (EOB = 33-64),
+  // and so on up to class 10 (EOB = 513-1024) for 32x32
+  let eob_class = ceil_log2(eob) as usize;
+  let eob_class_cdf: &[u16] = match txsize {
+    4 => &eob_class_16_cdf[qctx][ptype],
+    8 => &eob_class_64_cdf[qctx][ptype],
+    16 => &eob_class_256_cdf[qctx][ptype],
+    _ => &eob_class_1024_cdf[qctx][ptype],
+  };
+  w.write_symbol(eob_class, eob_class_cdf);
+
+  if eob_class > 1 {
+    let eob_class_low = (1 << (eob_class - 1)) + 1;
+    let eob_class_hi = 1 << eob_class;
+    assert!(eob_class_low <= eob && eob <= eob_class_hi);
+
+    // EOB classes 2+ require extra bits
+    // The first extra bit is coded with a special CDF, the rest are literal bits
+    // Context = (qctx, tx size, ptype, eob_class - 2)
+    let first_extra_bit_cdf = match txsize {
+      4 => &eob_extra_4x4_cdf[qctx][ptype][eob_class - 2],
+      8 => &eob_extra_8x8_cdf[qctx][ptype][eob_class - 2],
+      16 => &eob_extra_16x16_cdf[qctx][ptype][eob_class - 2],
+      _ => &eob_extra_32x32_cdf[qctx][ptype][eob_class - 2],
+    };
+    let eob_shift = eob_class - 2;
+    let extra_bit = ((eob - eob_class_low) >> eob_shift) & 1;
+    w.write_symbol(extra_bit, first_extra_bit_cdf);
+
+    // Write any remaining bits as a literal
+    // Note: The AV1 decoder spec gives a more detailed process here,
+    // but it's just writing individual bits from high to low,
+    // which is exactly what write_literal() does
+    let remainder = eob - eob_class_low - (extra_bit << eob_shift);
+    let remainder_bits = eob_class - 2;
+    w.write_literal(remainder as u32, remainder_bits as u32);
+  }
+
+  // Write "base range" for each coefficient, in high-to-low index order
+  for c in (0..eob).rev() {
+    // Split coefficient into absolute value and sign, as these are coded separately
+    let (row, col) = scan[c];
+    let coeff = coeffs[row as usize][col as usize];
+    let abs_value = unsigned_abs(coeff) as usize;
+
+    // Code coeff_base symbol, which can indicate values 0, 1, 2, or 3+
+    if c == eob - 1 {
+      // Last nonzero coefficient, so we know this can't be zero
+      // Therefore we use a separate set of CDFs and contexts
+      let base_eob_ctx = if c == 0 {
+        0
+      } else if c <= num_coeffs/8 {
+        1
+      } else if c <= num_coeffs/4 {
+        2
+      } else {
+        3
+      };
+      assert!(abs_value >= 1);
+      let coded_value = min(abs_value - 1, 2);
+      w.write_symbol(coded_value, &coeff_base_eob_cdf[qctx][txs_ctx][ptype][base_eob_ctx]);
+    } else {
+      // Context depends on the base values of coefficients below and to the right,
+      // which have already been encoded
+      let base_ctx = if c == 0 {
+        0
+      } else {
+        let mut mag = 0;
+
+        for (row_off, col_off) in Sig_Ref_Diff_Offset {
+          let ref_row = (row + row_off) as usize;
+          let ref_col = (col + col_off) as usize;
+          if ref_row < txsize && ref_col < txsize {
+            mag += min(abs(coeffs[ref_row][ref_col]), 3);
+          }
+        }
+
+        let mag_part = min(round2(mag, 1), 4) as usize;
+        let loc_part = Coeff_Base_Ctx_Offset_8x8[min(row, 4) as usize][min(col, 4) as usize] as usize;
+        mag_part + loc_part
+      };
+
+      let coded_value = min(abs_value, 3);
+      w.write_symbol(coded_value, &coeff_base_cdf[qctx][txs_ctx][ptype][base_ctx]);
+    }
+
+    // If coeff_base is 3, we can encode up to 4 symbols to increment the
+    // absolute value further. This can directly encode values up to 14,
+    // or the value 15 for all larger coefficients, in which case the remainder
+    // is Golomb encoded in a separate pass
+    if abs_value > 2 {
+      // All four coeff_br symbols use the same context and CDF, so compute that first
+      let br_ctx = {
+        let mut mag = 0;
+
+        for (row_off, col_off) in Mag_Ref_Offset {
+          let ref_row = (row + row_off) as usize;
+          let ref_col = (col + col_off) as usize;
+          if ref_row < txsize && ref_col < txsize {
+            mag += min(abs(coeffs[ref_row][ref_col]), 15);
+          }
+        }
+
+        let mag_part = min(round2(mag, 1), 6) as usize;
+        let loc_part = if c == 0 {
+          0
+        } else if row < 2 && col < 2 {
+          7
+        } else {
+          14
+        };
+        mag_part + loc_part
+      };
+
+      // Now encode the coeff_br symbols
+      let mut level = 3;
+      for _ in 0..4 {
+        let coeff_br = min(abs_value - level, 3);
+        w.write_symbol(coeff_br, &coeff_br_cdf[qctx][txs_ctx][ptype][br_ctx]);
+        level += coeff_br;
+        if coeff_br < 3 {
+          break;
+        }
+      }
+    }
+  }
+
+  // Code DC sign + golomb bits
+  let dc_coeff = coeffs[0][0];
+  if dc_coeff != 0 {
+    let sign = if dc_coeff < 0 { 1 } else { 0 };
+    w.write_symbol(sign, &dc_sign_cdf[qctx][ptype][ctx.dc_sign_ctx]);
+  }
+  if abs(dc_coeff) >= 15 {
+    w.write_golomb(unsigned_abs(dc_coeff) - 15);
+  }
+
+  // Code sign + golomb bits for the rest of coefficients
+  // Note that this is done in low-to-high index order, in contrast to the earlier loop
+  for c in 1..eob {
+    let (row, col) = scan[c];
+    let coeff = coeffs[row as usize][col as usize];
+    if coeff != 0 {
+      let sign = if coeff < 0 { 1 } else { 0 };
+      w.write_literal(sign, 1);
+    }
+
+    if abs(coeff) >= 15 {
+      w.write_golomb(unsigned_abs(coeff) - 15);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Set up a tile encoder for a 64x64 frame, without encoding anything, so that
+  // the mode info can be filled in by hand
+  fn test_tile<'a>(encoder: &'a AV1Encoder, source: &'a Frame) -> TileEncoder<'a> {
+    let mi_rows = encoder.y_height / 4;
+    let mi_cols = encoder.y_width / 4;
+    TileEncoder {
+      encoder: encoder,
+      bitstream: EntropyWriter::new(),
+      base_qindex: 100,
+      sb_qindex: None,
+      current_qindex: 100,
+      read_deltas: false,
+      lambda: qindex_to_lambda(100, 1.0),
+      adaptive_rounding: AdaptiveRounding::new(),
+      mode_info: Array2D::zeroed(mi_rows, mi_cols),
+      intrabc_hash: None,
+      source: source,
+      reference: None,
+      motion_field: None,
+      recon: Frame::new(encoder.y_crop_height, encoder.y_crop_width),
+    }
+  }
+
+  fn set_inter(tile: &mut TileEncoder, mi_row: usize, mi_col: usize, y_mode: PredictionMode, mv: [i16; 2]) {
+    let mi = &mut tile.mode_info[mi_row][mi_col];
+    mi.is_inter = true;
+    mi.y_mode = y_mode as u8;
+    mi.mv = mv;
+  }
+
+  #[test]
+  fn mv_stack_weights_and_contexts() {
+    let encoder = AV1Encoder::new(64, 64, EncoderConfig::default());
+    let source = Frame::new(64, 64);
+    let mut tile = test_tile(&encoder, &source);
+
+    // Neighbours of the 8x8 block at mi_row = mi_col = 4: The above and left blocks share
+    // an MV, and the above-right block has a different one. Further out, the above-left
+    // block has a third MV, and the blocks three units above and to the left share a fourth
+    set_inter(&mut tile, 3, 4, PredictionMode::NEWMV, [8, 8]);
+    set_inter(&mut tile, 4, 3, PredictionMode::NEARESTMV, [8, 8]);
+    set_inter(&mut tile, 3, 6, PredictionMode::NEARMV, [-16, 4]);
+    set_inter(&mut tile, 3, 3, PredictionMode::GLOBALMV, [0, 24]);
+    set_inter(&mut tile, 1, 5, PredictionMode::NEWMV, [2, -2]);
+    set_inter(&mut tile, 5, 1, PredictionMode::NEWMV, [2, -2]);
+
+    // The nearest neighbours get REF_CAT_LEVEL on top of their weights, and come first.
+    // The outer blocks come after, sorted by weight
+    let stack = tile.find_mv_stack(4, 4, false);
+    assert!(stack.entries == vec![
+      ([8, 8], 2 * REF_MV_WEIGHT + REF_CAT_LEVEL),
+      ([-16, 4], REF_MV_WEIGHT + REF_CAT_LEVEL),
+      ([2, -2], 2 * REF_MV_WEIGHT),
+      ([0, 24], REF_MV_WEIGHT),
+    ]);
+    // Matches both above and to the left, and one of the nearest blocks used NEWMV
+    assert_eq!(stack.new_mv_ctx, 4);
+    assert_eq!(stack.ref_mv_ctx, 5);
+    assert_eq!(stack.drl_ctx(0), 0);
+    assert_eq!(stack.drl_ctx(1), 1);
+    assert_eq!(stack.drl_ctx(2), 2);
+
+    // IntraBC blocks only use the DVs of other IntraBC blocks, here just the above block
+    tile.mode_info[7][4].use_intrabc = true;
+    tile.mode_info[7][4].dv = [-8, 0];
+    let stack = tile.find_mv_stack(8, 4, true);
+    assert!(stack.entries == vec![([-8, 0], REF_MV_WEIGHT + REF_CAT_LEVEL)]);
+    assert_eq!(stack.new_mv_ctx, 3);
+    assert_eq!(stack.ref_mv_ctx, 3);
+
+    // At the top-left corner of the frame there are no neighbours at all
+    let stack = tile.find_mv_stack(0, 0, false);
+    assert!(stack.entries.is_empty());
+    assert_eq!(stack.new_mv_ctx, 0);
+    assert_eq!(stack.ref_mv_ctx, 0);
+  }
+}
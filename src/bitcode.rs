@@ -31,7 +31,7 @@ impl BitWriter {
     assert!(bit == 0 || bit == 1);
     self.partial_byte = (self.partial_byte << 1) | bit;
     self.bitpos += 1;
-    if self.bitpos % 8 == 0 {
+    if self.bitpos.is_multiple_of(8) {
       self.data.push(self.partial_byte);
       self.partial_byte = 0u8;
     }
@@ -61,6 +61,23 @@ impl BitWriter {
     self.bitpos += nbits;
   }
 
+  // Write a signed value in two's complement form, as in the su(n) descriptor in the AV1 spec
+  pub fn write_signed_bits(&mut self, value: i64, nbits: usize) {
+    assert!((1..=56).contains(&nbits));
+    assert!(-(1i64 << (nbits - 1)) <= value && value < (1i64 << (nbits - 1)));
+    self.write_bits((value as u64) & ((1u64 << nbits) - 1), nbits);
+  }
+
+  // Write a value using the variable-length uvlc() code from the AV1 spec:
+  // N zero bits, followed by the (N+1)-bit value (value + 1), which always starts with a 1 bit
+  pub fn write_uvlc(&mut self, value: u32) {
+    assert!(value < u32::MAX);
+    let value = value as u64 + 1;
+    let leading_zeros = 63 - value.leading_zeros() as usize;
+    self.write_bits(0, leading_zeros);
+    self.write_bits(value, leading_zeros + 1);
+  }
+
   pub fn byte_align(&mut self) {
     let partial_bits = self.bitpos % 8;
     if partial_bits == 0 {
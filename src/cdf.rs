@@ -10,11 +10,15 @@
 // All of the CDFs used in the encoder currently
 
 use crate::consts::*;
+use crate::inter::*;
+use crate::palette::*;
+use crate::segmentation::MAX_SEGMENTS;
 
 // Partitions
 // For 8x8, the options are NONE, HORZ, VERT, SPLIT only;
 // for larger sizes, T-shaped and 4-way partitions are also available
-// (HORZ_A, HORZ_B, VERT_A, VERT_B, HORZ_4, VERT_4)
+// (HORZ_A, HORZ_B, VERT_A, VERT_B, HORZ_4, VERT_4),
+// except that 128x128 doesn't allow the 4-way partitions
 
 // We only ever use one context for 8x8 partitions, so don't
 // bother including the other three
@@ -41,12 +45,217 @@ pub const partition_64x64_cdf: [[u16; 9]; 4] = [
   [870, 1212, 1487, 31198, 31394, 31574, 31743, 31881, 32332]
 ];
 
+pub const partition_128x128_cdf: [[u16; 7]; 4] = [
+  [27899, 28219, 28529, 32484, 32539, 32619, 32639],
+  [6607, 6990, 8268, 32060, 32219, 32338, 32371],
+  [5429, 6676, 7122, 32027, 32227, 32531, 32582],
+  [711, 966, 1172, 32448, 32538, 32617, 32664]
+];
+
 // Block mode syntax
-// This encoder arranges things so that these only ever use one context each,
-// so just store the single relevant CDF
-pub const skip_cdf: [u16; 1] = [31671];
-pub const y_mode_cdf: [u16; 12] = [15588, 17027, 19338, 20218, 20682, 21110, 21825, 23244, 24189, 28165, 29093, 30466];
-pub const uv_mode_cdf: [u16; 13] = [10407, 11208, 12900, 13181, 13823, 14175, 14899, 15656, 15986, 20086, 20995, 22455, 24212];
+// The skip flag is coded with a context equal to the number of
+// above and left neighbours which are also skipped
+pub const skip_cdf: [[u16; 1]; SKIP_CONTEXTS] = [[31671], [16515], [4576]];
+
+// Luma intra modes. The context is derived from the y modes of the above and left
+// neighbours, see Intra_Mode_Context
+pub const kf_y_mode_cdf: [[[u16; INTRA_MODES - 1]; KF_MODE_CONTEXTS]; KF_MODE_CONTEXTS] = [
+  [
+    [15588, 17027, 19338, 20218, 20682, 21110, 21825, 23244, 24189, 28165, 29093, 30466],
+    [12016, 18066, 19516, 20303, 20719, 21444, 21888, 23032, 24434, 28658, 30172, 31409],
+    [10052, 10771, 22296, 22788, 23055, 23239, 24133, 25620, 26160, 29336, 29929, 31567],
+    [14091, 15406, 16442, 18808, 19136, 19546, 19998, 22096, 24746, 29585, 30958, 32462],
+    [12122, 13265, 15603, 16501, 18609, 20033, 22391, 25583, 26437, 30261, 31073, 32475]
+  ],
+  [
+    [10023, 19585, 20848, 21440, 21832, 22760, 23089, 24023, 25381, 29014, 30482, 31436],
+    [5983, 24099, 24560, 24886, 25066, 25795, 25913, 26423, 27610, 29905, 31276, 31794],
+    [7444, 12781, 20177, 20728, 21077, 21607, 22170, 23405, 24469, 27915, 29090, 30492],
+    [8537, 14689, 15432, 17087, 17408, 18172, 18408, 19825, 24649, 29153, 31096, 32210],
+    [7543, 14231, 15496, 16195, 17905, 20717, 21984, 24516, 26001, 29675, 30981, 31994]
+  ],
+  [
+    [12613, 13591, 21383, 22004, 22312, 22577, 23401, 25055, 25729, 29538, 30305, 32077],
+    [9687, 13470, 18506, 19230, 19604, 20147, 20695, 22062, 23219, 27743, 29211, 30907],
+    [6183, 6505, 26024, 26252, 26366, 26434, 27082, 28354, 28555, 30467, 30794, 32086],
+    [10718, 11734, 14954, 17224, 17565, 17924, 18561, 21523, 23878, 28975, 30287, 32252],
+    [9194, 9858, 16501, 17263, 18424, 19171, 21563, 25961, 26561, 30072, 30737, 32463]
+  ],
+  [
+    [12602, 14399, 15488, 18381, 18778, 19315, 19724, 21419, 25060, 29696, 30917, 32409],
+    [8203, 13821, 14524, 17105, 17439, 18131, 18404, 19468, 25225, 29485, 31158, 32342],
+    [8451, 9731, 15004, 17643, 18012, 18425, 19070, 21538, 24605, 29118, 30078, 32018],
+    [7714, 9048, 9516, 16667, 16817, 16994, 17153, 18767, 26743, 30389, 31536, 32528],
+    [8843, 10280, 11496, 15317, 16652, 17943, 19108, 22718, 25769, 29953, 30983, 32485]
+  ],
+  [
+    [12578, 13671, 15979, 16834, 19075, 20913, 22989, 25449, 26219, 30214, 31150, 32477],
+    [9563, 13626, 15080, 15892, 17756, 20863, 22207, 24236, 25380, 29653, 31143, 32277],
+    [8356, 8901, 17616, 18256, 19350, 20106, 22598, 25947, 26466, 29900, 30523, 32261],
+    [10835, 11815, 13124, 16042, 17018, 18039, 18947, 22753, 24615, 29489, 30883, 32482],
+    [7618, 8288, 9859, 10509, 15386, 18657, 22903, 28776, 29180, 31355, 31802, 32593]
+  ]
+];
+
+// Directional modes are followed by an angle delta, coded as (delta + MAX_ANGLE_DELTA)
+// with a separate CDF for each mode
+pub const angle_delta_cdf: [[u16; 2 * MAX_ANGLE_DELTA as usize]; DIRECTIONAL_MODES] = [
+  [2180, 5032, 7567, 22776, 26989, 30217],
+  [2301, 5608, 8801, 23487, 26974, 30330],
+  [3780, 11018, 13699, 19354, 23083, 31286],
+  [4581, 11226, 15147, 17138, 21834, 28397],
+  [1737, 10927, 14509, 19588, 22745, 28823],
+  [2664, 10176, 12485, 17650, 21600, 30495],
+  [2240, 11096, 15453, 20341, 22561, 28917],
+  [3605, 10428, 12459, 17676, 21244, 30655]
+];
+
+// For uv_mode, the context is the y mode combined with whether CFL is allowed.
+// CFL is always allowed for blocks up to 32x32, and we only use DC_PRED and the directional
+// modes for luma, so only store the CDFs for those
+pub const uv_mode_cdf: [[u16; UV_INTRA_MODES_CFL_ALLOWED - 1]; DIRECTIONAL_MODES + 1] = [
+  [10407, 11208, 12900, 13181, 13823, 14175, 14899, 15656, 15986, 20086, 20995, 22455, 24212],
+  [4532, 19780, 20057, 20215, 20428, 21071, 21199, 21451, 22099, 24228, 24693, 27032, 29472],
+  [5273, 5379, 20177, 20270, 20385, 20439, 20949, 21695, 21774, 23138, 24256, 24703, 26679],
+  [6740, 7167, 7662, 14152, 14536, 14785, 15034, 16741, 18371, 21520, 22206, 23389, 24182],
+  [4987, 5368, 5928, 6068, 19114, 20315, 21857, 22253, 22411, 24911, 25380, 26027, 26376],
+  [5370, 6889, 7247, 7393, 9498, 21114, 21402, 21753, 21981, 24780, 25386, 26517, 27176],
+  [4816, 4961, 7204, 7326, 8765, 8930, 20169, 20682, 20803, 23188, 23763, 24455, 24940],
+  [6608, 6740, 8529, 9049, 9257, 9356, 9735, 18827, 19059, 22336, 23204, 23964, 24793],
+  [5998, 7419, 7781, 8933, 9255, 9549, 9753, 10417, 18898, 22494, 23139, 24764, 25989]
+];
+
+// Filter intra. This is allowed for blocks up to 32x32, with a separate use_filter_intra
+// CDF for each block size, indexed by bsize_idx()
+pub const use_filter_intra_cdf: [[u16; 1]; SUPPORTED_BLOCK_SIZES] = [[7866], [12408], [22343]];
+pub const filter_intra_mode_cdf: [u16; FILTER_INTRA_MODES - 1] = [8949, 12776, 17211, 29558];
+
+// Superblock-level delta-q
+// Symbols 0-2 code small deltas directly, and symbol 3 (DELTA_Q_SMALL) means
+// that the value is continued using literal bits
+pub const delta_q_abs_cdf: [u16; 3] = [28160, 32120, 32677];
+
+// Segment IDs, coded relative to a spatial prediction (see segmentation.rs)
+// The context depends on how many of the above-left, above, and left neighbours agree
+pub const segment_id_cdf: [[u16; MAX_SEGMENTS - 1]; SEGMENT_ID_CONTEXTS] = [
+  [5622, 7893, 16093, 18233, 27809, 28373, 32533],
+  [14274, 18230, 22557, 24935, 29980, 30851, 32344],
+  [27527, 28487, 28723, 28890, 32397, 32647, 32679]
+];
+
+// Palette mode
+// We only use palettes for 8x8 blocks, but larger blocks still have to signal that they
+// don't use one, so has_palette_y needs its CDFs for every block size, indexed by bsize_idx().
+// For has_palette_y, the context is the number of above and left neighbours which
+// use a luma palette; for has_palette_uv, it's whether this block uses a luma palette
+pub const palette_y_mode_cdf: [[[u16; 1]; 3]; SUPPORTED_BLOCK_SIZES] = [
+  [[31676], [3419], [1261]],
+  [[31823], [3400], [781]],
+  [[32309], [7337], [1462]]
+];
+pub const palette_uv_mode_cdf: [[u16; 1]; 2] = [[32461], [21488]];
+pub const palette_y_size_cdf: [u16; PALETTE_SIZES - 1] = [7952, 13000, 18149, 21478, 25527, 29241];
+pub const palette_uv_size_cdf: [u16; PALETTE_SIZES - 1] = [8713, 19979, 27128, 29609, 31331, 32272];
+
+// Colour index CDFs, indexed by [palette size - 2][colour context]
+// The number of symbols depends on the palette size, so these are stored as slices
+pub const palette_y_color_cdf: [[&[u16]; PALETTE_COLOR_CONTEXTS]; PALETTE_SIZES] = [
+  // 2 colours
+  [&[28710], &[16384], &[10553], &[27036], &[31603]],
+  // 3 colours
+  [&[27877, 30490], &[11532, 25697], &[6544, 30234], &[23018, 28072], &[31915, 32385]],
+  // 4 colours
+  [&[25572, 28046, 30045], &[9478, 21590, 27256], &[7248, 26837, 29824], &[19167, 24486, 28349], &[31400, 31825, 32250]],
+  // 5 colours
+  [&[24779, 26955, 28576, 30282], &[8669, 20364, 24073, 28093], &[4255, 27565, 29377, 31067],
+   &[19864, 23674, 26716, 29530], &[31646, 31893, 32147, 32426]],
+  // 6 colours
+  [&[23132, 25407, 26970, 28435, 30073], &[7443, 17242, 20717, 24762, 27982], &[6300, 24862, 26944, 28784, 30671],
+   &[18916, 22895, 25267, 27435, 29652], &[31270, 31550, 31808, 32059, 32353]],
+  // 7 colours
+  [&[23105, 25199, 26464, 27684, 28931, 30318], &[6950, 15447, 18952, 22681, 25567, 28563],
+   &[7560, 23474, 25490, 27203, 28921, 30708], &[18544, 22373, 24457, 26195, 28119, 30045],
+   &[31198, 31451, 31670, 31882, 32123, 32391]],
+  // 8 colours
+  [&[21689, 23883, 25163, 26352, 27506, 28827, 30195], &[6892, 15385, 17840, 21606, 24287, 26753, 29204],
+   &[5651, 23182, 25042, 26518, 27982, 29392, 30900], &[19349, 22578, 24418, 25994, 27524, 29031, 30448],
+   &[31028, 31270, 31504, 31705, 31927, 32153, 32392]]
+];
+
+pub const palette_uv_color_cdf: [[&[u16]; PALETTE_COLOR_CONTEXTS]; PALETTE_SIZES] = [
+  // 2 colours
+  [&[29089], &[16384], &[8713], &[29257], &[31610]],
+  // 3 colours
+  [&[25257, 29145], &[12287, 27293], &[7033, 27960], &[20145, 25405], &[30608, 31639]],
+  // 4 colours
+  [&[24210, 27175, 29903], &[9888, 22386, 27214], &[5901, 26053, 29293], &[18318, 22152, 28333], &[30459, 31136, 31926]],
+  // 5 colours
+  [&[22980, 25479, 27781, 29986], &[8413, 21408, 24859, 28874], &[2257, 29449, 30594, 31598],
+   &[19189, 21202, 25915, 28620], &[31844, 32044, 32281, 32518]],
+  // 6 colours
+  [&[22217, 24567, 26637, 28683, 30548], &[7307, 16406, 19636, 24632, 28424], &[4441, 25064, 26879, 28942, 30919],
+   &[17210, 20528, 23319, 26750, 29582], &[30674, 30953, 31396, 31735, 32207]],
+  // 7 colours
+  [&[21239, 23168, 25044, 26962, 28705, 30506], &[6545, 15012, 18004, 21817, 25503, 28701],
+   &[3448, 26295, 27437, 28704, 30126, 31442], &[15889, 18323, 21704, 24698, 26976, 29690],
+   &[30988, 31204, 31479, 31734, 31983, 32325]],
+  // 8 colours
+  [&[21442, 23288, 24758, 26246, 27649, 28980, 30563], &[5863, 14933, 17552, 20668, 23683, 26411, 29273],
+   &[3415, 25810, 26877, 27990, 29223, 30394, 31618], &[17965, 20084, 22232, 23974, 26274, 28402, 30390],
+   &[31190, 31329, 31516, 31679, 31825, 32026, 32322]]
+];
+
+// Intra block copy
+pub const intrabc_cdf: [u16; 1] = [30531];
+
+// Motion vectors
+// Displacement vectors for IntraBC are coded using the same syntax and default CDFs
+// as motion vectors, but in a separate context. As we disable CDF updates, both
+// contexts always hold the same values, so we only store one copy. Similarly, the
+// row and column components use identical CDFs.
+// We never enable high-precision (1/8 pixel) motion vectors, so the CDFs for the
+// final fractional bit aren't needed
+pub const mv_joint_cdf: [u16; MV_JOINTS - 1] = [4096, 11264, 19328];
+pub const mv_sign_cdf: [u16; 1] = [16384];
+pub const mv_class_cdf: [u16; MV_CLASSES - 1] = [28672, 30976, 31858, 32320, 32551, 32656, 32740, 32757, 32762, 32767];
+pub const mv_class0_bit_cdf: [u16; 1] = [27648];
+pub const mv_bits_cdf: [[u16; 1]; MV_OFFSET_BITS] = [
+  [17408], [17920], [18944], [20480], [22528], [24576], [28672], [29952], [29952], [30720]
+];
+// The quarter-pixel part of each component. For class 0, this depends on the class0 bit
+pub const mv_class0_fr_cdf: [[u16; 3]; 2] = [[16384, 24576, 26624], [12288, 21248, 24128]];
+pub const mv_fr_cdf: [u16; 3] = [8192, 17408, 21248];
+
+// Inter frame block modes
+// Whether a block is inter predicted. The context depends on whether the
+// above and left neighbours are intra blocks
+pub const intra_inter_cdf: [[u16; 1]; INTRA_INTER_CONTEXTS] = [[806], [16662], [20186], [26538]];
+
+// Luma intra modes in inter frames. Unlike in intra frames, the context is the block size
+// rather than the neighbouring modes. Each of our block sizes is in a different size group,
+// so this is indexed by bsize_idx()
+pub const y_mode_cdf: [[u16; INTRA_MODES - 1]; SUPPORTED_BLOCK_SIZES] = [
+  [18673, 19845, 22631, 23318, 23950, 24649, 25527, 27364, 28152, 29701, 29984, 30852],
+  [19770, 20979, 23396, 23939, 24241, 24654, 25136, 27073, 27830, 29360, 29730, 30659],
+  [20155, 21301, 22838, 23178, 23261, 23533, 23703, 24804, 25352, 26575, 27016, 28049]
+];
+
+// The reference frame is coded as a series of binary choices. We only ever use LAST_FRAME,
+// which takes three of them: forward rather than backward references (single_ref_p1),
+// LAST or LAST2 rather than LAST3 or GOLDEN (single_ref_p3), then LAST rather than LAST2
+// (single_ref_p4). The context for each compares how often the neighbours use each side
+pub const single_ref_p1_cdf: [[u16; 1]; REF_CONTEXTS] = [[4897], [16973], [29744]];
+pub const single_ref_p3_cdf: [[u16; 1]; REF_CONTEXTS] = [[4236], [19647], [31194]];
+pub const single_ref_p4_cdf: [[u16; 1]; REF_CONTEXTS] = [[8650], [24773], [31895]];
+
+// Inter modes are coded as a series of binary choices: NEWMV or not, then GLOBALMV
+// or not, then NEARESTMV or NEARMV. The contexts come from the reference MV stack
+pub const new_mv_cdf: [[u16; 1]; NEW_MV_CONTEXTS] = [[24035], [16630], [15339], [8386], [12222], [4676]];
+pub const zero_mv_cdf: [[u16; 1]; ZERO_MV_CONTEXTS] = [[2175], [1054]];
+pub const ref_mv_cdf: [[u16; 1]; REF_MV_CONTEXTS] = [[23974], [24188], [17848], [28622], [24312], [19923]];
+
+// Which entry of the reference MV stack is used by NEWMV and NEARMV blocks
+pub const drl_mode_cdf: [[u16; 1]; DRL_MODE_CONTEXTS] = [[13104], [24560], [18945]];
 
 // Residual syntax
 // These CDFs all have complex contexts, some of which are fixed in our case
@@ -82,7 +291,35 @@ pub const all_zero_cdf: [[[[u16; 1]; TXB_SKIP_CONTEXTS]; SUPPORTED_TX_SIZES]; TO
       [30003],
       [16384],
       [16384],
-      [16384] ]
+      [16384] ],
+    // 16x16
+    [ [29957],
+      [5391],
+      [18039],
+      [23566],
+      [22431],
+      [25822],
+      [32197],
+      [3778],
+      [15336],
+      [28981],
+      [16384],
+      [16384],
+      [16384] ],
+    // 32x32
+    [ [17920],
+      [1818],
+      [7282],
+      [25273],
+      [10923],
+      [31554],
+      [32624],
+      [1366],
+      [15628],
+      [30462],
+      [146],
+      [5132],
+      [31657] ]
   ],
   // qctx = 1
   [
@@ -114,6 +351,34 @@ pub const all_zero_cdf: [[[[u16; 1]; TXB_SKIP_CONTEXTS]; SUPPORTED_TX_SIZES]; TO
       [16384],
       [16384],
       [16384] ],
+    // 16x16
+    [ [31901],
+      [10311],
+      [18047],
+      [24806],
+      [23288],
+      [27914],
+      [32296],
+      [4215],
+      [15756],
+      [28341],
+      [16384],
+      [16384],
+      [16384] ],
+    // 32x32
+    [ [26726],
+      [1045],
+      [11703],
+      [20590],
+      [18554],
+      [25970],
+      [31938],
+      [5583],
+      [21313],
+      [29390],
+      [641],
+      [22265],
+      [31452] ],
   ],
   // qctx = 2
   [
@@ -145,6 +410,34 @@ pub const all_zero_cdf: [[[[u16; 1]; TXB_SKIP_CONTEXTS]; SUPPORTED_TX_SIZES]; TO
       [16384],
       [16384],
       [16384] ],
+    // 16x16
+    [ [32363],
+      [10692],
+      [19090],
+      [24357],
+      [24442],
+      [28312],
+      [32169],
+      [3648],
+      [15690],
+      [26815],
+      [16384],
+      [16384],
+      [16384] ],
+    // 32x32
+    [ [30669],
+      [3832],
+      [11663],
+      [18889],
+      [19782],
+      [23313],
+      [31330],
+      [5124],
+      [18719],
+      [28468],
+      [3082],
+      [20982],
+      [29443] ],
   ],
   // qctx = 3
   [
@@ -176,13 +469,78 @@ pub const all_zero_cdf: [[[[u16; 1]; TXB_SKIP_CONTEXTS]; SUPPORTED_TX_SIZES]; TO
       [16384],
       [16384],
       [16384] ],
+    // 16x16
+    [ [32510],
+      [8430],
+      [17318],
+      [24154],
+      [23674],
+      [28789],
+      [32139],
+      [3440],
+      [13117],
+      [22702],
+      [16384],
+      [16384],
+      [16384] ],
+    // 32x32
+    [ [31671],
+      [2056],
+      [11746],
+      [16852],
+      [18635],
+      [24715],
+      [31484],
+      [4656],
+      [16074],
+      [24704],
+      [1806],
+      [14645],
+      [25336] ],
   ]
 ];
 
+// Transform size
+// For intra blocks, tx_depth selects how many times the transform is halved from the
+// block size. 8x8 blocks can go down to 4x4 (depth 1), while larger blocks allow up to
+// depth 2, and so have a CDF with an extra symbol. The context is the number of above and
+// left neighbours whose transforms are at least as wide / high as the block
+pub const tx_8x8_depth_cdf: [[u16; 1]; TX_SIZE_CONTEXTS] = [[19968], [19968], [24320]];
+pub const tx_16x16_depth_cdf: [[u16; 2]; TX_SIZE_CONTEXTS] = [[12272, 30172], [12272, 30172], [18677, 30848]];
+pub const tx_32x32_depth_cdf: [[u16; 2]; TX_SIZE_CONTEXTS] = [[12986, 15180], [12986, 15180], [24302, 25602]];
+
+// Inter blocks, including IntraBC blocks, signal whether to split their transform
+// via txfm_split instead. Of the full set of contexts, 8x8 blocks only use the last three,
+// which are selected by whether the above and left transforms are narrower / shorter than 8 pixels
+pub const txfm_split_8x8_cdf: [[u16; 1]; 3] = [[28165], [22401], [16088]];
+
 // The tx type encoding depends on a lot of factors:
 // inter vs. intra, tx set, luma tx size and luma mode
-// For our use case, these are always the same, so we only need one CDF
+// For our use case, the tx set only depends on inter vs. intra and the tx size.
+// The default CDFs for 4x4 and 8x8 transforms are identical and don't depend on
+// the luma mode, so we only need one CDF for those. 16x16 transforms have a CDF per
+// luma mode, and 32x32 intra transforms are always DCT_DCT, so don't code a tx type
 pub const tx_type_cdf: [u16; 4] = [6554, 13107, 19661, 26214];
+pub const tx_type_16x16_cdf: [[u16; 4]; INTRA_MODES] = [
+  [1127, 12814, 22772, 27483],
+  [145, 6761, 11980, 26667],
+  [362, 5887, 11678, 16725],
+  [385, 15213, 18587, 30693],
+  [25, 2914, 23134, 27903],
+  [60, 4470, 11749, 23991],
+  [37, 3332, 14511, 21448],
+  [157, 6320, 13036, 17439],
+  [119, 6719, 12906, 29396],
+  [47, 5537, 12576, 21499],
+  [269, 6076, 11258, 23115],
+  [83, 5615, 12001, 17228],
+  [1968, 5556, 12023, 18547]
+];
+
+// Inter blocks, including IntraBC blocks, use a different transform set,
+// TX_SET_INTER_3, which only contains { IDTX, DCT_DCT }. This has one CDF per
+// transform size, indexed by txs_ctx
+pub const inter_tx_type_cdf: [[u16; 1]; SUPPORTED_TX_SIZES] = [[16384], [4167], [1998], [748]];
 
 // For EOB, there are separate CDFs per transform size to account for the
 // different number of coefficients available.
@@ -234,6 +592,52 @@ pub const eob_class_64_cdf: [[[u16; 6]; PLANE_TYPES]; TOKEN_CDF_Q_CTXS] = [
   ]
 ];
 
+pub const eob_class_256_cdf: [[[u16; 8]; PLANE_TYPES]; TOKEN_CDF_Q_CTXS] = [
+  // qctx = 0
+  [
+    [ 310, 584, 1887, 3589, 6168, 8611, 11352, 15652 ], // Y
+    [ 2520, 3240, 5952, 8870, 12577, 17558, 19954, 24168 ] // U/V
+  ],
+  // qctx = 1
+  [
+    [ 1448, 2109, 4151, 6263, 9329, 13260, 17944, 23300 ],
+    [ 6402, 8148, 12623, 15072, 18728, 22847, 26447, 29377 ]
+  ],
+  // qctx = 2
+  [
+    [ 3089, 3920, 6038, 9460, 14266, 19881, 25766, 29176 ],
+    [ 11514, 13794, 17480, 20754, 24361, 27378, 29492, 31277 ]
+  ],
+  // qctx = 3
+  [
+    [ 5348, 7113, 11820, 15924, 22106, 26777, 30334, 31757 ],
+    [ 23110, 24597, 27140, 28894, 30167, 30927, 31392, 32094 ]
+  ]
+];
+
+pub const eob_class_1024_cdf: [[[u16; 10]; PLANE_TYPES]; TOKEN_CDF_Q_CTXS] = [
+  // qctx = 0
+  [
+    [ 393, 421, 751, 1623, 3160, 6352, 13345, 18047, 22571, 25830 ], // Y
+    [ 1865, 1988, 2930, 4242, 10533, 16538, 21354, 27255, 28546, 31784 ] // U/V
+  ],
+  // qctx = 1
+  [
+    [ 696, 948, 3145, 5702, 9706, 13217, 17851, 21856, 25692, 28034 ],
+    [ 2672, 3591, 9330, 17084, 22725, 24284, 26527, 28027, 28377, 30876 ]
+  ],
+  // qctx = 2
+  [
+    [ 2784, 3831, 7041, 10521, 14847, 18844, 23155, 26682, 29229, 31045 ],
+    [ 9577, 12466, 17739, 20750, 22061, 23215, 24601, 25483, 25843, 32056 ]
+  ],
+  // qctx = 3
+  [
+    [ 6698, 8334, 11961, 15762, 20186, 23862, 27434, 29326, 31082, 32050 ],
+    [ 20569, 22426, 25569, 26859, 28053, 28913, 29486, 29724, 29807, 32570 ]
+  ]
+];
+
 pub const eob_extra_4x4_cdf: [[[[u16; 1]; 3]; PLANE_TYPES]; TOKEN_CDF_Q_CTXS] = [
   // qctx = 0
   [
@@ -376,6 +780,212 @@ pub const eob_extra_8x8_cdf: [[[[u16; 1]; 5]; PLANE_TYPES]; TOKEN_CDF_Q_CTXS] =
   ],
 ];
 
+pub const eob_extra_16x16_cdf: [[[[u16; 1]; 7]; PLANE_TYPES]; TOKEN_CDF_Q_CTXS] = [
+  // qctx = 0
+  [
+    // Y
+    [
+      [ 23905 ],
+      [ 17194 ],
+      [ 16170 ],
+      [ 17695 ],
+      [ 13826 ],
+      [ 15810 ],
+      [ 12036 ],
+    ],
+    // U/V
+    [
+      [ 23959 ],
+      [ 20799 ],
+      [ 19021 ],
+      [ 16203 ],
+      [ 17886 ],
+      [ 14144 ],
+      [ 12010 ],
+    ]
+  ],
+  // qctx = 1
+  [
+    // Y
+    [
+      [ 22409 ],
+      [ 21012 ],
+      [ 15650 ],
+      [ 17395 ],
+      [ 15469 ],
+      [ 20205 ],
+      [ 19511 ],
+    ],
+    // U/V
+    [
+      [ 24220 ],
+      [ 22480 ],
+      [ 17737 ],
+      [ 18916 ],
+      [ 19268 ],
+      [ 18412 ],
+      [ 18844 ],
+    ]
+  ],
+  // qctx = 2
+  [
+    // Y
+    [
+      [ 19833 ],
+      [ 21502 ],
+      [ 17485 ],
+      [ 20267 ],
+      [ 18353 ],
+      [ 23329 ],
+      [ 21478 ],
+    ],
+    // U/V
+    [
+      [ 22041 ],
+      [ 23434 ],
+      [ 20001 ],
+      [ 20554 ],
+      [ 20951 ],
+      [ 20145 ],
+      [ 15562 ],
+    ]
+  ],
+  // qctx = 3
+  [
+    // Y
+    [
+      [ 19941 ],
+      [ 20527 ],
+      [ 21470 ],
+      [ 22487 ],
+      [ 19558 ],
+      [ 22354 ],
+      [ 20331 ],
+    ],
+    // U/V
+    [
+      [ 22752 ],
+      [ 25006 ],
+      [ 22075 ],
+      [ 21576 ],
+      [ 17740 ],
+      [ 21690 ],
+      [ 19211 ],
+    ]
+  ],
+];
+
+pub const eob_extra_32x32_cdf: [[[[u16; 1]; 9]; PLANE_TYPES]; TOKEN_CDF_Q_CTXS] = [
+  // qctx = 0
+  [
+    // Y
+    [
+      [ 27399 ],
+      [ 16327 ],
+      [ 18071 ],
+      [ 19584 ],
+      [ 20721 ],
+      [ 18432 ],
+      [ 19560 ],
+      [ 10150 ],
+      [ 8805 ],
+    ],
+    // U/V
+    [
+      [ 24932 ],
+      [ 20833 ],
+      [ 12027 ],
+      [ 16670 ],
+      [ 19914 ],
+      [ 15106 ],
+      [ 17662 ],
+      [ 13783 ],
+      [ 28756 ],
+    ]
+  ],
+  // qctx = 1
+  [
+    // Y
+    [
+      [ 25991 ],
+      [ 20314 ],
+      [ 17731 ],
+      [ 19678 ],
+      [ 18649 ],
+      [ 17307 ],
+      [ 21798 ],
+      [ 17549 ],
+      [ 15630 ],
+    ],
+    // U/V
+    [
+      [ 26585 ],
+      [ 21469 ],
+      [ 20432 ],
+      [ 17735 ],
+      [ 19280 ],
+      [ 15235 ],
+      [ 20297 ],
+      [ 22471 ],
+      [ 28997 ],
+    ]
+  ],
+  // qctx = 2
+  [
+    // Y
+    [
+      [ 23312 ],
+      [ 21607 ],
+      [ 16526 ],
+      [ 18957 ],
+      [ 18034 ],
+      [ 18934 ],
+      [ 24247 ],
+      [ 16921 ],
+      [ 17080 ],
+    ],
+    // U/V
+    [
+      [ 26579 ],
+      [ 24910 ],
+      [ 18637 ],
+      [ 19800 ],
+      [ 20388 ],
+      [ 9887 ],
+      [ 15642 ],
+      [ 30198 ],
+      [ 24721 ],
+    ]
+  ],
+  // qctx = 3
+  [
+    // Y
+    [
+      [ 21442 ],
+      [ 22358 ],
+      [ 18503 ],
+      [ 20291 ],
+      [ 19945 ],
+      [ 21294 ],
+      [ 21178 ],
+      [ 19400 ],
+      [ 10556 ],
+    ],
+    // U/V
+    [
+      [ 24648 ],
+      [ 24949 ],
+      [ 20708 ],
+      [ 23905 ],
+      [ 20501 ],
+      [ 9558 ],
+      [ 9423 ],
+      [ 30365 ],
+      [ 19253 ],
+    ]
+  ],
+];
+
 pub const coeff_base_eob_cdf: [[[[[u16; 2]; COEFF_BASE_EOB_CONTEXTS]; PLANE_TYPES]; SUPPORTED_TX_SIZES]; TOKEN_CDF_Q_CTXS] = [
   // qctx = 0
   [
@@ -412,6 +1022,40 @@ pub const coeff_base_eob_cdf: [[[[[u16; 2]; COEFF_BASE_EOB_CONTEXTS]; PLANE_TYPE
         [ 30809, 32335 ],
         [ 31299, 32423 ]
       ]
+    ],
+    // 16x16
+    [
+      // Y
+      [
+        [ 1786, 12612 ],
+        [ 30663, 31625 ],
+        [ 32339, 32468 ],
+        [ 31148, 31833 ]
+      ],
+      // U/V
+      [
+        [ 18857, 23865 ],
+        [ 31428, 32428 ],
+        [ 31744, 32373 ],
+        [ 31775, 32526 ]
+      ]
+    ],
+    // 32x32
+    [
+      // Y
+      [
+        [ 1787, 2532 ],
+        [ 30832, 31662 ],
+        [ 31824, 32682 ],
+        [ 32133, 32569 ]
+      ],
+      // U/V
+      [
+        [ 13751, 22235 ],
+        [ 32089, 32409 ],
+        [ 27084, 27920 ],
+        [ 29291, 32594 ]
+      ]
     ]
   ],
   // qctx = 1
@@ -449,6 +1093,40 @@ pub const coeff_base_eob_cdf: [[[[[u16; 2]; COEFF_BASE_EOB_CONTEXTS]; PLANE_TYPE
         [ 31713, 32540 ],
         [ 31929, 32594 ]
       ]
+    ],
+    // 16x16
+    [
+      // Y
+      [
+        [ 2644, 25198 ],
+        [ 32038, 32451 ],
+        [ 32639, 32695 ],
+        [ 32166, 32518 ]
+      ],
+      // U/V
+      [
+        [ 17187, 27668 ],
+        [ 31714, 32550 ],
+        [ 32283, 32678 ],
+        [ 31930, 32563 ]
+      ]
+    ],
+    // 32x32
+    [
+      // Y
+      [
+        [ 1044, 2257 ],
+        [ 30755, 31923 ],
+        [ 32208, 32693 ],
+        [ 32244, 32615 ]
+      ],
+      // U/V
+      [
+        [ 21317, 26207 ],
+        [ 29133, 30868 ],
+        [ 29311, 31231 ],
+        [ 29657, 31087 ]
+      ]
     ]
   ],
   // qctx = 2
@@ -486,6 +1164,40 @@ pub const coeff_base_eob_cdf: [[[[[u16; 2]; COEFF_BASE_EOB_CONTEXTS]; PLANE_TYPE
         [ 32088, 32576 ],
         [ 32067, 32664 ]
       ]
+    ],
+    // 16x16
+    [
+      // Y
+      [
+        [ 12854, 29093 ],
+        [ 32272, 32558 ],
+        [ 32667, 32729 ],
+        [ 32306, 32585 ]
+      ],
+      // U/V
+      [
+        [ 25476, 30366 ],
+        [ 32169, 32687 ],
+        [ 32479, 32689 ],
+        [ 31673, 32634 ]
+      ]
+    ],
+    // 32x32
+    [
+      // Y
+      [
+        [ 2809, 19301 ],
+        [ 32205, 32622 ],
+        [ 32338, 32730 ],
+        [ 31786, 32616 ]
+      ],
+      // U/V
+      [
+        [ 22737, 29105 ],
+        [ 30810, 32362 ],
+        [ 30014, 32627 ],
+        [ 30528, 32574 ]
+      ]
     ]
   ],
   // qctx = 3
@@ -523,6 +1235,40 @@ pub const coeff_base_eob_cdf: [[[[[u16; 2]; COEFF_BASE_EOB_CONTEXTS]; PLANE_TYPE
         [ 32097, 32575 ],
         [ 32242, 32719 ]
       ]
+    ],
+    // 16x16
+    [
+      // Y
+      [
+        [ 19980, 30591 ],
+        [ 32219, 32597 ],
+        [ 32581, 32706 ],
+        [ 31803, 32287 ]
+      ],
+      // U/V
+      [
+        [ 26473, 30507 ],
+        [ 32431, 32723 ],
+        [ 32196, 32611 ],
+        [ 31588, 32528 ]
+      ]
+    ],
+    // 32x32
+    [
+      // Y
+      [
+        [ 24647, 30463 ],
+        [ 32412, 32695 ],
+        [ 32468, 32720 ],
+        [ 31269, 32523 ]
+      ],
+      // U/V
+      [
+        [ 28482, 31505 ],
+        [ 32152, 32701 ],
+        [ 31732, 32598 ],
+        [ 31767, 32712 ]
+      ]
     ]
   ]
 ];
@@ -652,6 +1398,128 @@ pub const coeff_base_cdf: [[[[[u16; 3]; COEFF_BASE_CONTEXTS]; PLANE_TYPES]; SUPP
         [5364, 14059, 20467],
       ],
     ],
+    // 16x16
+    [
+      // Y
+      [
+        [5487, 10460, 13708],
+        [21597, 28303, 30674],
+        [11037, 21953, 26476],
+        [8147, 17962, 22952],
+        [5242, 13061, 18532],
+        [1889, 5208, 8182],
+        [26774, 32133, 32590],
+        [17844, 29564, 31767],
+        [11690, 24438, 29171],
+        [7542, 18215, 24459],
+        [2993, 8050, 12319],
+        [28023, 32328, 32591],
+        [18651, 30126, 31954],
+        [12164, 25146, 29589],
+        [7762, 18530, 24771],
+        [3492, 9183, 13920],
+        [27591, 32008, 32491],
+        [17149, 28853, 31510],
+        [11485, 24003, 28860],
+        [7697, 18086, 24210],
+        [3075, 7999, 12218],
+        [28268, 32482, 32654],
+        [19631, 31051, 32404],
+        [13860, 27260, 31020],
+        [9605, 21613, 27594],
+        [4876, 12162, 17908],
+      ],
+      // U/V
+      [
+        [5673, 14302, 19711],
+        [26251, 30701, 31834],
+        [12782, 23783, 27803],
+        [9127, 20657, 25808],
+        [6368, 16208, 21462],
+        [2465, 7177, 10822],
+        [29961, 32563, 32719],
+        [18318, 29891, 31949],
+        [11361, 24514, 29357],
+        [7900, 19603, 25607],
+        [4002, 10590, 15546],
+        [29637, 32310, 32595],
+        [18296, 29913, 31809],
+        [10144, 21515, 26871],
+        [5358, 14322, 20394],
+        [3067, 8362, 13346],
+        [28652, 32470, 32676],
+        [17538, 30771, 32209],
+        [13924, 26882, 30494],
+        [10496, 22837, 27869],
+        [7236, 16396, 21621],
+        [30743, 32687, 32746],
+        [23006, 31676, 32489],
+        [14494, 27828, 31120],
+        [10174, 22801, 28352],
+        [6242, 15281, 21043],
+      ],
+    ],
+    // 32x32
+    [
+      // Y
+      [
+        [5141, 7096, 8260],
+        [27186, 29022, 29789],
+        [6668, 12568, 15682],
+        [2172, 6181, 8638],
+        [1126, 3379, 4531],
+        [443, 1361, 2254],
+        [26083, 31153, 32436],
+        [13486, 24603, 28483],
+        [6508, 14840, 19910],
+        [3386, 8800, 13286],
+        [1530, 4322, 7054],
+        [29639, 32080, 32548],
+        [15897, 27552, 30290],
+        [8588, 20047, 25383],
+        [4889, 13339, 19269],
+        [2240, 6871, 10498],
+        [28165, 32197, 32517],
+        [20735, 30427, 31568],
+        [14325, 24671, 27692],
+        [5119, 12554, 17805],
+        [1810, 5441, 8261],
+        [31212, 32724, 32748],
+        [23352, 31766, 32545],
+        [14669, 27570, 31059],
+        [8492, 20894, 27272],
+        [3644, 10194, 15204],
+      ],
+      // U/V
+      [
+        [2461, 7013, 9371],
+        [24749, 29600, 30986],
+        [9466, 19037, 22417],
+        [3584, 9280, 14400],
+        [1505, 3929, 5433],
+        [677, 1500, 2736],
+        [23987, 30702, 32117],
+        [13554, 24571, 29263],
+        [6211, 14556, 21155],
+        [3135, 10972, 15625],
+        [2435, 7127, 11427],
+        [31300, 32532, 32550],
+        [14757, 30365, 31954],
+        [4405, 11612, 18553],
+        [580, 4132, 7322],
+        [1695, 10169, 14124],
+        [30008, 32282, 32591],
+        [19244, 30108, 31748],
+        [11180, 24158, 29555],
+        [5650, 14972, 19209],
+        [2114, 5109, 8456],
+        [31856, 32716, 32748],
+        [23012, 31664, 32572],
+        [13694, 26656, 30636],
+        [8142, 19508, 26093],
+        [4253, 10955, 16724],
+      ],
+    ],
   ],
   // qctx = 1
   [
@@ -777,6 +1645,128 @@ pub const coeff_base_cdf: [[[[[u16; 3]; COEFF_BASE_CONTEXTS]; PLANE_TYPES]; SUPP
         [5437, 14665, 21187],
       ],
     ],
+    // 16x16
+    [
+      // Y
+      [
+        [7555, 14942, 18501],
+        [24410, 31178, 32287],
+        [14394, 26738, 30253],
+        [8413, 19554, 25195],
+        [4766, 12924, 18785],
+        [2029, 5806, 9207],
+        [26776, 32364, 32663],
+        [18732, 29967, 31931],
+        [11005, 23786, 28852],
+        [6466, 16909, 23510],
+        [3044, 8638, 13419],
+        [29208, 32582, 32704],
+        [20068, 30857, 32208],
+        [12003, 25085, 29595],
+        [6947, 17750, 24189],
+        [3245, 9103, 14007],
+        [27359, 32465, 32669],
+        [19421, 30614, 32174],
+        [11915, 25010, 29579],
+        [6950, 17676, 24074],
+        [3007, 8473, 13096],
+        [29002, 32676, 32735],
+        [22102, 31849, 32576],
+        [14408, 28009, 31405],
+        [9027, 21679, 27931],
+        [4694, 12678, 18748],
+      ],
+      // U/V
+      [
+        [6465, 16958, 21688],
+        [25199, 31514, 32360],
+        [14774, 27149, 30607],
+        [9257, 21438, 26972],
+        [5723, 15183, 21882],
+        [3150, 8879, 13731],
+        [26989, 32262, 32682],
+        [17396, 29937, 32085],
+        [11387, 24901, 29784],
+        [7289, 18821, 25548],
+        [3734, 10577, 16086],
+        [29728, 32501, 32695],
+        [17431, 29701, 31903],
+        [9921, 22826, 28300],
+        [5896, 15434, 22068],
+        [3430, 9646, 14757],
+        [28614, 32511, 32705],
+        [19364, 30638, 32263],
+        [13129, 26254, 30402],
+        [8754, 20484, 26440],
+        [4378, 11607, 17110],
+        [30292, 32671, 32744],
+        [21780, 31603, 32501],
+        [14314, 27829, 31291],
+        [9611, 22327, 28263],
+        [4890, 13087, 19065],
+      ],
+    ],
+    // 32x32
+    [
+      // Y
+      [
+        [3078, 6839, 9890],
+        [13837, 20450, 24479],
+        [5914, 14222, 19328],
+        [3866, 10267, 14762],
+        [2612, 7208, 11042],
+        [1067, 2991, 4776],
+        [25817, 31646, 32529],
+        [13708, 26338, 30385],
+        [7328, 18585, 24870],
+        [4691, 13080, 19276],
+        [1825, 5253, 8352],
+        [29386, 32315, 32624],
+        [17160, 29001, 31360],
+        [9602, 21862, 27396],
+        [5915, 15772, 22148],
+        [2786, 7779, 12047],
+        [29246, 32450, 32663],
+        [18696, 29929, 31818],
+        [10510, 23369, 28560],
+        [6229, 16499, 23125],
+        [2608, 7448, 11705],
+        [30753, 32710, 32748],
+        [21638, 31487, 32503],
+        [12937, 26854, 30870],
+        [8182, 20596, 26970],
+        [3637, 10269, 15497],
+      ],
+      // U/V
+      [
+        [5244, 12150, 16906],
+        [20486, 26858, 29701],
+        [7756, 18317, 23735],
+        [3452, 9256, 13146],
+        [2020, 5206, 8229],
+        [1801, 4993, 7903],
+        [27051, 31858, 32531],
+        [15988, 27531, 30619],
+        [9188, 21484, 26719],
+        [6273, 17186, 23800],
+        [3108, 9355, 14764],
+        [31076, 32520, 32680],
+        [18119, 30037, 31850],
+        [10244, 22969, 27472],
+        [4692, 14077, 19273],
+        [3694, 11677, 17556],
+        [30060, 32581, 32720],
+        [21011, 30775, 32120],
+        [11931, 24820, 29289],
+        [7119, 17662, 24356],
+        [3833, 10706, 16304],
+        [31954, 32731, 32748],
+        [23913, 31724, 32489],
+        [15520, 28060, 31286],
+        [11517, 23008, 28571],
+        [6193, 14508, 20629],
+      ],
+    ],
   ],
   // qctx = 2
   [
@@ -902,6 +1892,128 @@ pub const coeff_base_cdf: [[[[[u16; 3]; COEFF_BASE_CONTEXTS]; PLANE_TYPES]; SUPP
         [6144, 15913, 22968],
       ],
     ],
+    // 16x16
+    [
+      // Y
+      [
+        [9800, 17635, 21073],
+        [26153, 31885, 32527],
+        [15038, 27852, 31006],
+        [8718, 20564, 26486],
+        [5128, 14076, 20514],
+        [2636, 7566, 11925],
+        [27551, 32504, 32701],
+        [18310, 30054, 32100],
+        [10211, 23420, 29082],
+        [6222, 16876, 23916],
+        [3462, 9954, 15498],
+        [29991, 32633, 32721],
+        [19883, 30751, 32201],
+        [11141, 24184, 29285],
+        [6420, 16940, 23774],
+        [3392, 9753, 15118],
+        [28465, 32616, 32712],
+        [19850, 30702, 32244],
+        [10983, 24024, 29223],
+        [6294, 16770, 23582],
+        [3244, 9283, 14509],
+        [30023, 32717, 32748],
+        [22940, 32032, 32626],
+        [14282, 27928, 31473],
+        [8562, 21327, 27914],
+        [4846, 13393, 19919],
+      ],
+      // U/V
+      [
+        [9185, 19694, 24688],
+        [26081, 31985, 32621],
+        [16015, 29000, 31787],
+        [10542, 23690, 29206],
+        [6732, 17945, 24677],
+        [3916, 11039, 16722],
+        [28224, 32566, 32744],
+        [19100, 31138, 32485],
+        [12528, 26620, 30879],
+        [7741, 20277, 26885],
+        [4566, 12845, 18990],
+        [29933, 32593, 32718],
+        [17670, 30333, 32155],
+        [10385, 23600, 28909],
+        [6243, 16236, 22407],
+        [3976, 10389, 16017],
+        [28377, 32561, 32738],
+        [19366, 31175, 32482],
+        [13327, 27175, 31094],
+        [8258, 20769, 27143],
+        [4703, 13198, 19527],
+        [31086, 32706, 32748],
+        [22853, 31902, 32583],
+        [14759, 28186, 31419],
+        [9284, 22382, 28348],
+        [5585, 15192, 21868],
+      ],
+    ],
+    // 32x32
+    [
+      // Y
+      [
+        [4456, 11274, 15533],
+        [21219, 29079, 31616],
+        [11173, 23774, 28567],
+        [7282, 18293, 24263],
+        [4890, 13286, 19115],
+        [1890, 5508, 8659],
+        [26651, 32136, 32647],
+        [14630, 28254, 31455],
+        [8716, 21287, 27395],
+        [5615, 15331, 22008],
+        [2675, 7700, 12150],
+        [29954, 32526, 32690],
+        [16126, 28982, 31633],
+        [9030, 21361, 27352],
+        [5411, 14793, 21271],
+        [2943, 8422, 13163],
+        [29539, 32601, 32730],
+        [18125, 30385, 32201],
+        [10422, 24090, 29468],
+        [6468, 17487, 24438],
+        [2970, 8653, 13531],
+        [30912, 32715, 32748],
+        [20666, 31373, 32497],
+        [12509, 26640, 30917],
+        [8058, 20629, 27290],
+        [4231, 12006, 18052],
+      ],
+      // U/V
+      [
+        [10202, 20633, 25484],
+        [27336, 31445, 32352],
+        [12420, 24384, 28552],
+        [7648, 18115, 23856],
+        [5662, 14341, 19902],
+        [3611, 10328, 15390],
+        [30945, 32616, 32736],
+        [18682, 30505, 32253],
+        [11513, 25336, 30203],
+        [7449, 19452, 26148],
+        [4482, 13051, 18886],
+        [32022, 32690, 32747],
+        [18578, 30501, 32146],
+        [11249, 23368, 28631],
+        [5645, 16958, 22158],
+        [5009, 11444, 16637],
+        [31357, 32710, 32748],
+        [21552, 31494, 32504],
+        [13891, 27677, 31340],
+        [9051, 22098, 28172],
+        [5190, 13377, 19486],
+        [32364, 32740, 32748],
+        [24839, 31907, 32551],
+        [17160, 28779, 31696],
+        [12452, 24137, 29602],
+        [6165, 15389, 22477],
+      ],
+    ],
   ],
   // qctx = 3
   [
@@ -1027,6 +2139,128 @@ pub const coeff_base_cdf: [[[[[u16; 3]; COEFF_BASE_CONTEXTS]; PLANE_TYPES]; SUPP
         [6922, 17616, 25010],
       ],
     ],
+    // 16x16
+    [
+      // Y
+      [
+        [7876, 16901, 21741],
+        [24001, 31898, 32625],
+        [14529, 27959, 31451],
+        [8273, 20818, 27258],
+        [5278, 14673, 21510],
+        [2983, 8843, 14039],
+        [28016, 32574, 32732],
+        [17471, 30306, 32301],
+        [10224, 24063, 29728],
+        [6602, 17954, 25052],
+        [4002, 11585, 17759],
+        [30190, 32634, 32739],
+        [17497, 30282, 32270],
+        [10229, 23729, 29538],
+        [6344, 17211, 24440],
+        [3849, 11189, 17108],
+        [28570, 32583, 32726],
+        [17521, 30161, 32238],
+        [10153, 23565, 29378],
+        [6455, 17341, 24443],
+        [3907, 11042, 17024],
+        [30689, 32715, 32748],
+        [21546, 31840, 32610],
+        [13547, 27581, 31459],
+        [8912, 21757, 28309],
+        [5548, 15080, 22046],
+      ],
+      // U/V
+      [
+        [7833, 18369, 24095],
+        [26650, 32273, 32702],
+        [16371, 29961, 32191],
+        [11055, 24082, 29629],
+        [6892, 18644, 25400],
+        [5006, 13057, 19240],
+        [29834, 32666, 32748],
+        [19577, 31335, 32570],
+        [12253, 26509, 31122],
+        [7991, 20772, 27711],
+        [5677, 15910, 23059],
+        [30109, 32532, 32720],
+        [16747, 30166, 32252],
+        [10134, 23542, 29184],
+        [5791, 16176, 23556],
+        [4362, 10414, 17284],
+        [29492, 32626, 32748],
+        [19894, 31402, 32525],
+        [12942, 27071, 30869],
+        [8346, 21216, 27405],
+        [6572, 17087, 23859],
+        [32035, 32735, 32748],
+        [22957, 31838, 32618],
+        [14724, 28572, 31772],
+        [10364, 23999, 29553],
+        [7004, 18433, 25655],
+      ],
+    ],
+    // 32x32
+    [
+      // Y
+      [
+        [5992, 14304, 19765],
+        [22612, 31238, 32456],
+        [13456, 27162, 31087],
+        [8001, 20062, 26504],
+        [5168, 14105, 20764],
+        [2632, 7771, 12385],
+        [27034, 32344, 32709],
+        [15850, 29415, 31997],
+        [9494, 22776, 28841],
+        [6151, 16830, 23969],
+        [3461, 10039, 15722],
+        [30134, 32569, 32731],
+        [15638, 29422, 31945],
+        [9150, 21865, 28218],
+        [5647, 15719, 22676],
+        [3402, 9772, 15477],
+        [28530, 32586, 32735],
+        [17139, 30298, 32292],
+        [10200, 24039, 29685],
+        [6419, 17674, 24786],
+        [3544, 10225, 15824],
+        [31333, 32726, 32748],
+        [20618, 31487, 32544],
+        [12901, 27217, 31232],
+        [8624, 21734, 28171],
+        [5104, 14191, 20748],
+      ],
+      // U/V
+      [
+        [11206, 21090, 26561],
+        [28759, 32279, 32671],
+        [14171, 27952, 31569],
+        [9743, 22907, 29141],
+        [6871, 17886, 24868],
+        [4960, 13152, 19315],
+        [31077, 32661, 32748],
+        [19400, 31195, 32515],
+        [12752, 26858, 31040],
+        [8370, 22098, 28591],
+        [5457, 15373, 22298],
+        [31697, 32706, 32748],
+        [17860, 30657, 32333],
+        [12510, 24812, 29261],
+        [6180, 19124, 24722],
+        [5041, 13548, 17959],
+        [31552, 32716, 32748],
+        [21908, 31769, 32623],
+        [14470, 28201, 31565],
+        [9493, 22982, 28608],
+        [6858, 17240, 24137],
+        [32543, 32752, 32756],
+        [24286, 32097, 32666],
+        [15958, 29217, 32024],
+        [10207, 24234, 29958],
+        [6929, 18305, 25652],
+      ],
+    ],
   ],
 ];
 
@@ -1135,6 +2369,108 @@ pub const coeff_br_cdf: [[[[[u16; 3]; COEFF_BR_CONTEXTS]; PLANE_TYPES]; SUPPORTE
         [5205, 9745, 13622],
       ],
     ],
+    // 16x16
+    [
+      // Y
+      [
+        [10563, 16233, 19763],
+        [9794, 16022, 19804],
+        [6750, 11945, 15759],
+        [4963, 9186, 12752],
+        [3845, 7435, 10627],
+        [3051, 6085, 8834],
+        [1311, 2596, 3830],
+        [11246, 16404, 19689],
+        [12315, 18911, 22731],
+        [10557, 17095, 21289],
+        [8136, 14006, 18249],
+        [6348, 11474, 15565],
+        [5196, 9655, 13400],
+        [2349, 4526, 6587],
+        [13337, 18730, 21569],
+        [19306, 26071, 28882],
+        [15952, 23540, 27254],
+        [12409, 19934, 24430],
+        [9760, 16706, 21389],
+        [8004, 14220, 18818],
+        [4138, 7794, 10961],
+      ],
+      // U/V
+      [
+        [10870, 16684, 20949],
+        [9664, 15230, 18680],
+        [6886, 12109, 15408],
+        [4825, 8900, 12305],
+        [3630, 7162, 10314],
+        [3036, 6429, 9387],
+        [1671, 3296, 4940],
+        [13819, 19159, 23026],
+        [11984, 19108, 23120],
+        [10690, 17210, 21663],
+        [7984, 14154, 18333],
+        [6868, 12294, 16124],
+        [5274, 8994, 12868],
+        [2988, 5771, 8424],
+        [19736, 26647, 29141],
+        [18933, 26070, 28984],
+        [15779, 23048, 27200],
+        [12638, 20061, 24532],
+        [10692, 17545, 22220],
+        [9217, 15251, 20054],
+        [5078, 9284, 12594],
+      ],
+    ],
+    // 32x32
+    [
+      // Y
+      [
+        [2331, 3662, 5244],
+        [2891, 4771, 6145],
+        [4598, 7623, 9729],
+        [3520, 6845, 9199],
+        [3417, 6119, 9324],
+        [2601, 5412, 7385],
+        [600, 1173, 1744],
+        [7672, 13286, 17469],
+        [4232, 7792, 10793],
+        [2915, 5317, 7397],
+        [2318, 4356, 6152],
+        [2127, 4000, 5554],
+        [1850, 3478, 5275],
+        [977, 1933, 2843],
+        [18280, 24387, 27989],
+        [15852, 22671, 26185],
+        [13845, 20951, 24789],
+        [11055, 17966, 22129],
+        [9138, 15422, 19801],
+        [7454, 13145, 17456],
+        [3370, 6393, 9013],
+      ],
+      // U/V
+      [
+        [5842, 9229, 10838],
+        [2313, 3491, 4276],
+        [2998, 6104, 7496],
+        [2420, 7447, 9868],
+        [3034, 8495, 10923],
+        [4076, 8937, 10975],
+        [1086, 2370, 3299],
+        [9714, 17254, 20444],
+        [8543, 13698, 17123],
+        [4918, 9007, 11910],
+        [4129, 7532, 10553],
+        [2364, 5533, 8058],
+        [1834, 3546, 5563],
+        [1473, 2908, 4133],
+        [15405, 21193, 25619],
+        [15691, 21952, 26561],
+        [12962, 19194, 24165],
+        [10272, 17855, 22129],
+        [8588, 15270, 20718],
+        [8682, 14669, 19500],
+        [4870, 9636, 13205],
+      ],
+    ],
   ],
   // qctx = 1
   [
@@ -1240,6 +2576,108 @@ pub const coeff_br_cdf: [[[[[u16; 3]; COEFF_BR_CONTEXTS]; PLANE_TYPES]; SUPPORTE
         [6130, 11199, 15423],
       ],
     ],
+    // 16x16
+    [
+      // Y
+      [
+        [13549, 19724, 23158],
+        [11844, 18382, 22246],
+        [7919, 13619, 17773],
+        [5486, 10143, 13946],
+        [4166, 7983, 11324],
+        [3364, 6506, 9427],
+        [1598, 3160, 4674],
+        [15281, 20979, 23781],
+        [14939, 22119, 25952],
+        [11363, 18407, 22812],
+        [8609, 14857, 19370],
+        [6737, 12184, 16480],
+        [5506, 10263, 14262],
+        [2990, 5786, 8380],
+        [20249, 25253, 27417],
+        [21070, 27518, 30001],
+        [16854, 24469, 28074],
+        [12864, 20486, 25000],
+        [9962, 16978, 21778],
+        [8074, 14338, 19048],
+        [4494, 8479, 11906],
+      ],
+      // U/V
+      [
+        [13960, 19617, 22829],
+        [11150, 17341, 21228],
+        [7150, 12964, 17190],
+        [5331, 10002, 13867],
+        [4167, 7744, 11057],
+        [3480, 6629, 9646],
+        [1883, 3784, 5686],
+        [18752, 25660, 28912],
+        [16968, 24586, 28030],
+        [13520, 21055, 25313],
+        [10453, 17626, 22280],
+        [8386, 14505, 19116],
+        [6742, 12595, 17008],
+        [4273, 8140, 11499],
+        [22120, 27827, 30233],
+        [20563, 27358, 29895],
+        [17076, 24644, 28153],
+        [13362, 20942, 25309],
+        [10794, 17965, 22695],
+        [9014, 15652, 20319],
+        [5708, 10512, 14497],
+      ],
+    ],
+    // 32x32
+    [
+      // Y
+      [
+        [5705, 10930, 15725],
+        [7946, 12765, 16115],
+        [6801, 12123, 16226],
+        [5462, 10135, 14200],
+        [4189, 8011, 11507],
+        [3191, 6229, 9408],
+        [1057, 2137, 3212],
+        [10018, 17067, 21491],
+        [7380, 12582, 16453],
+        [6068, 10845, 14339],
+        [5098, 9198, 12555],
+        [4312, 8010, 11119],
+        [3700, 6966, 9781],
+        [1693, 3326, 4887],
+        [18757, 24930, 27774],
+        [17648, 24596, 27817],
+        [14707, 22052, 26026],
+        [11720, 18852, 23292],
+        [9357, 15952, 20525],
+        [7810, 13753, 18210],
+        [3879, 7333, 10328],
+      ],
+      // U/V
+      [
+        [8278, 13242, 15922],
+        [10547, 15867, 18919],
+        [9106, 15842, 20609],
+        [6833, 13007, 17218],
+        [4811, 9712, 13923],
+        [3985, 7352, 11128],
+        [1688, 3458, 5262],
+        [12951, 21861, 26510],
+        [9788, 16044, 20276],
+        [6309, 11244, 14870],
+        [5183, 9349, 12566],
+        [4389, 8229, 11492],
+        [3633, 6945, 10620],
+        [3600, 6847, 9907],
+        [21748, 28137, 30255],
+        [19436, 26581, 29560],
+        [16359, 24201, 27953],
+        [13961, 21693, 25871],
+        [11544, 18686, 23322],
+        [9372, 16462, 20952],
+        [6138, 11210, 15390],
+      ],
+    ],
   ],
   // qctx = 2
   [
@@ -1345,6 +2783,108 @@ pub const coeff_br_cdf: [[[[[u16; 3]; COEFF_BR_CONTEXTS]; PLANE_TYPES]; SUPPORTE
         [7625, 13801, 19144],
       ],
     ],
+    // 16x16
+    [
+      // Y
+      [
+        [14438, 20798, 24089],
+        [12621, 19203, 23097],
+        [8177, 14125, 18402],
+        [5674, 10501, 14456],
+        [4236, 8239, 11733],
+        [3447, 6750, 9806],
+        [1986, 3950, 5864],
+        [16208, 22099, 24930],
+        [16537, 24025, 27585],
+        [12780, 20381, 24867],
+        [9767, 16612, 21416],
+        [7686, 13738, 18398],
+        [6333, 11614, 15964],
+        [3941, 7571, 10836],
+        [22819, 27422, 29202],
+        [22224, 28514, 30721],
+        [17660, 25433, 28913],
+        [13574, 21482, 26002],
+        [10629, 17977, 22938],
+        [8612, 15298, 20265],
+        [5607, 10491, 14596],
+      ],
+      // U/V
+      [
+        [13569, 19800, 23206],
+        [13128, 19924, 23869],
+        [8329, 14841, 19403],
+        [6130, 10976, 15057],
+        [4682, 8839, 12518],
+        [3656, 7409, 10588],
+        [2577, 5099, 7412],
+        [22427, 28684, 30585],
+        [20913, 27750, 30139],
+        [15840, 24109, 27834],
+        [12308, 20029, 24569],
+        [10216, 16785, 21458],
+        [8309, 14203, 19113],
+        [6043, 11168, 15307],
+        [23166, 28901, 30998],
+        [21899, 28405, 30751],
+        [18413, 26091, 29443],
+        [15233, 23114, 27352],
+        [12683, 20472, 25288],
+        [10702, 18259, 23409],
+        [8125, 14464, 19226],
+      ],
+    ],
+    // 32x32
+    [
+      // Y
+      [
+        [9040, 14786, 18360],
+        [9979, 15718, 19415],
+        [7913, 13918, 18311],
+        [5859, 10889, 15184],
+        [4593, 8677, 12510],
+        [3820, 7396, 10791],
+        [1730, 3471, 5192],
+        [11803, 18365, 22709],
+        [11419, 18058, 22225],
+        [9418, 15774, 20243],
+        [7539, 13325, 17657],
+        [6233, 11317, 15384],
+        [5137, 9656, 13545],
+        [2977, 5774, 8349],
+        [21207, 27246, 29640],
+        [19547, 26578, 29497],
+        [16169, 23871, 27690],
+        [12820, 20458, 25018],
+        [10224, 17332, 22214],
+        [8526, 15048, 19884],
+        [5037, 9410, 13118],
+      ],
+      // U/V
+      [
+        [12339, 17329, 20140],
+        [13505, 19895, 23225],
+        [9847, 16944, 21564],
+        [7280, 13256, 18348],
+        [4712, 10009, 14454],
+        [4361, 7914, 12477],
+        [2870, 5628, 7995],
+        [20061, 25504, 28526],
+        [15235, 22878, 26145],
+        [12985, 19958, 24155],
+        [9782, 16641, 21403],
+        [9456, 16360, 20760],
+        [6855, 12940, 18557],
+        [5661, 10564, 15002],
+        [25656, 30602, 31894],
+        [22570, 29107, 31092],
+        [18917, 26423, 29541],
+        [15940, 23649, 27754],
+        [12803, 20581, 25219],
+        [11082, 18695, 23376],
+        [7939, 14373, 19005],
+      ],
+    ],
   ],
   // qctx = 3
   [
@@ -1450,6 +2990,108 @@ pub const coeff_br_cdf: [[[[[u16; 3]; COEFF_BR_CONTEXTS]; PLANE_TYPES]; SUPPORTE
         [8301, 13544, 20535],
       ],
     ],
+    // 16x16
+    [
+      // Y
+      [
+        [17113, 23733, 27081],
+        [14139, 21406, 25452],
+        [8552, 15002, 19776],
+        [5871, 11120, 15378],
+        [4455, 8616, 12253],
+        [3469, 6910, 10386],
+        [2255, 4553, 6782],
+        [18224, 24376, 27053],
+        [19290, 26710, 29614],
+        [14936, 22991, 27184],
+        [11238, 18951, 23762],
+        [8786, 15617, 20588],
+        [7317, 13228, 18003],
+        [5101, 9512, 13493],
+        [22639, 28222, 30210],
+        [23216, 29331, 31307],
+        [19075, 26762, 29895],
+        [15014, 23113, 27457],
+        [11938, 19857, 24752],
+        [9942, 17280, 22282],
+        [7167, 13144, 17752],
+      ],
+      // U/V
+      [
+        [15820, 22738, 26488],
+        [13530, 20885, 25216],
+        [8395, 15530, 20452],
+        [6574, 12321, 16380],
+        [5353, 10419, 14568],
+        [4613, 8446, 12381],
+        [3440, 7158, 9903],
+        [24247, 29051, 31224],
+        [22118, 28058, 30369],
+        [16498, 24768, 28389],
+        [12920, 21175, 26137],
+        [10730, 18619, 25352],
+        [10187, 16279, 22791],
+        [9310, 14631, 22127],
+        [24970, 30558, 32057],
+        [24801, 29942, 31698],
+        [22432, 28453, 30855],
+        [19054, 25680, 29580],
+        [14392, 23036, 28109],
+        [12495, 20947, 26650],
+        [12442, 20326, 26214],
+      ],
+    ],
+    // 32x32
+    [
+      // Y
+      [
+        [12162, 18785, 22648],
+        [12749, 19697, 23806],
+        [8580, 15297, 20346],
+        [6169, 11749, 16543],
+        [4836, 9391, 13448],
+        [3821, 7711, 11613],
+        [2228, 4601, 7070],
+        [16319, 24725, 28280],
+        [15698, 23277, 27168],
+        [12726, 20368, 25047],
+        [9912, 17015, 21976],
+        [7888, 14220, 19179],
+        [6777, 12284, 17018],
+        [4492, 8590, 12252],
+        [23249, 28904, 30947],
+        [21050, 27908, 30512],
+        [17440, 25340, 28949],
+        [14059, 22018, 26541],
+        [11288, 18903, 23898],
+        [9411, 16342, 21428],
+        [6278, 11588, 15944],
+      ],
+      // U/V
+      [
+        [13981, 20067, 23226],
+        [16922, 23580, 26783],
+        [11005, 19039, 24487],
+        [7389, 14218, 19798],
+        [5598, 11505, 17206],
+        [6090, 11213, 15659],
+        [3820, 7371, 10119],
+        [21082, 26925, 29675],
+        [21262, 28627, 31128],
+        [18392, 26454, 30437],
+        [14870, 22910, 27096],
+        [12620, 19484, 24908],
+        [9290, 16553, 22802],
+        [6668, 14288, 20004],
+        [27704, 31055, 31949],
+        [24709, 29978, 31788],
+        [21668, 29264, 31657],
+        [18295, 26968, 30074],
+        [16399, 24422, 29313],
+        [14347, 23026, 28104],
+        [12370, 19806, 24477],
+      ],
+    ],
   ],
 ];
 
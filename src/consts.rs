@@ -7,15 +7,21 @@
 // Media Patent License 1.0 was not distributed with this source code in the
 // PATENTS file, you can obtain it at www.aomedia.org/license/patent.
 
+use crate::enums::PredictionMode;
+
 pub const PLANE_TYPES: usize = 2;
 
 // Number of transform sizes that this code supports
-// Currently only 2: 4x4 (for chroma) and 8x8 (for luma)
-pub const SUPPORTED_TX_SIZES: usize = 2;
+// Currently 4: the square sizes 4x4, 8x8, 16x16 and 32x32
+pub const SUPPORTED_TX_SIZES: usize = 4;
+
+// Number of block sizes that this code supports
+// Currently 3: the square sizes 8x8, 16x16 and 32x32
+pub const SUPPORTED_BLOCK_SIZES: usize = 3;
 
 // Maximum EOB class among the supported transforms
-// This is class 6, indicating that the EOB is in [33, 64], for an 8x8 transform
-pub const MAX_SUPPORTED_EOB_CLASS: usize = 6;
+// This is class 10, indicating that the EOB is in [513, 1024], for a 32x32 transform
+pub const MAX_SUPPORTED_EOB_CLASS: usize = 10;
 
 // Values used in CDF sizes
 pub const TOKEN_CDF_Q_CTXS: usize = 4;
@@ -27,6 +33,31 @@ pub const COEFF_BASE_EOB_CONTEXTS: usize = 4;
 pub const COEFF_BR_CONTEXTS: usize = 21;
 pub const DC_SIGN_CONTEXTS: usize = 3;
 
+pub const SKIP_CONTEXTS: usize = 3;
+
+pub const TX_SIZE_CONTEXTS: usize = 3;
+
+pub const SEGMENT_ID_CONTEXTS: usize = 3;
+
+pub const INTRA_MODES: usize = 13;
+pub const UV_INTRA_MODES_CFL_ALLOWED: usize = 14;
+pub const KF_MODE_CONTEXTS: usize = 5;
+pub const DIRECTIONAL_MODES: usize = 8;
+pub const MAX_ANGLE_DELTA: i8 = 3;
+pub const ANGLE_STEP: i32 = 3;
+pub const FILTER_INTRA_MODES: usize = 5;
+
+pub const INTRA_INTER_CONTEXTS: usize = 4;
+pub const REF_CONTEXTS: usize = 3;
+pub const NEW_MV_CONTEXTS: usize = 6;
+pub const ZERO_MV_CONTEXTS: usize = 2;
+pub const REF_MV_CONTEXTS: usize = 6;
+pub const DRL_MODE_CONTEXTS: usize = 3;
+
+// Frame header values
+pub const REFS_PER_FRAME: usize = 7;
+pub const PRIMARY_REF_NONE: usize = 7;
+
 // Scan orders for 2D (ie. not H_* or V_*) transforms
 // The input to this is an index in coefficient scan order,
 // the output is an index (row * tx_width + col) into the quantized
@@ -55,9 +86,179 @@ pub const default_scan_8x8: [(u8, u8); 64] = [
   (6, 5), (7, 4), (7, 5), (6, 6), (5, 7), (6, 7), (7, 6), (7, 7)
 ];
 
+// 16x16
+pub const default_scan_16x16: [(u8, u8); 256] = [
+  (0, 0), (0, 1), (1, 0), (2, 0), (1, 1), (0, 2), (0, 3), (1, 2),
+  (2, 1), (3, 0), (4, 0), (3, 1), (2, 2), (1, 3), (0, 4), (0, 5),
+  (1, 4), (2, 3), (3, 2), (4, 1), (5, 0), (6, 0), (5, 1), (4, 2),
+  (3, 3), (2, 4), (1, 5), (0, 6), (0, 7), (1, 6), (2, 5), (3, 4),
+  (4, 3), (5, 2), (6, 1), (7, 0), (8, 0), (7, 1), (6, 2), (5, 3),
+  (4, 4), (3, 5), (2, 6), (1, 7), (0, 8), (0, 9), (1, 8), (2, 7),
+  (3, 6), (4, 5), (5, 4), (6, 3), (7, 2), (8, 1), (9, 0), (10, 0),
+  (9, 1), (8, 2), (7, 3), (6, 4), (5, 5), (4, 6), (3, 7), (2, 8),
+  (1, 9), (0, 10), (0, 11), (1, 10), (2, 9), (3, 8), (4, 7), (5, 6),
+  (6, 5), (7, 4), (8, 3), (9, 2), (10, 1), (11, 0), (12, 0), (11, 1),
+  (10, 2), (9, 3), (8, 4), (7, 5), (6, 6), (5, 7), (4, 8), (3, 9),
+  (2, 10), (1, 11), (0, 12), (0, 13), (1, 12), (2, 11), (3, 10), (4, 9),
+  (5, 8), (6, 7), (7, 6), (8, 5), (9, 4), (10, 3), (11, 2), (12, 1),
+  (13, 0), (14, 0), (13, 1), (12, 2), (11, 3), (10, 4), (9, 5), (8, 6),
+  (7, 7), (6, 8), (5, 9), (4, 10), (3, 11), (2, 12), (1, 13), (0, 14),
+  (0, 15), (1, 14), (2, 13), (3, 12), (4, 11), (5, 10), (6, 9), (7, 8),
+  (8, 7), (9, 6), (10, 5), (11, 4), (12, 3), (13, 2), (14, 1), (15, 0),
+  (15, 1), (14, 2), (13, 3), (12, 4), (11, 5), (10, 6), (9, 7), (8, 8),
+  (7, 9), (6, 10), (5, 11), (4, 12), (3, 13), (2, 14), (1, 15), (2, 15),
+  (3, 14), (4, 13), (5, 12), (6, 11), (7, 10), (8, 9), (9, 8), (10, 7),
+  (11, 6), (12, 5), (13, 4), (14, 3), (15, 2), (15, 3), (14, 4), (13, 5),
+  (12, 6), (11, 7), (10, 8), (9, 9), (8, 10), (7, 11), (6, 12), (5, 13),
+  (4, 14), (3, 15), (4, 15), (5, 14), (6, 13), (7, 12), (8, 11), (9, 10),
+  (10, 9), (11, 8), (12, 7), (13, 6), (14, 5), (15, 4), (15, 5), (14, 6),
+  (13, 7), (12, 8), (11, 9), (10, 10), (9, 11), (8, 12), (7, 13), (6, 14),
+  (5, 15), (6, 15), (7, 14), (8, 13), (9, 12), (10, 11), (11, 10), (12, 9),
+  (13, 8), (14, 7), (15, 6), (15, 7), (14, 8), (13, 9), (12, 10), (11, 11),
+  (10, 12), (9, 13), (8, 14), (7, 15), (8, 15), (9, 14), (10, 13), (11, 12),
+  (12, 11), (13, 10), (14, 9), (15, 8), (15, 9), (14, 10), (13, 11), (12, 12),
+  (11, 13), (10, 14), (9, 15), (10, 15), (11, 14), (12, 13), (13, 12), (14, 11),
+  (15, 10), (15, 11), (14, 12), (13, 13), (12, 14), (11, 15), (12, 15), (13, 14),
+  (14, 13), (15, 12), (15, 13), (14, 14), (13, 15), (14, 15), (15, 14), (15, 15)
+];
+
+// 32x32
+pub const default_scan_32x32: [(u8, u8); 1024] = [
+  (0, 0), (0, 1), (1, 0), (2, 0), (1, 1), (0, 2), (0, 3), (1, 2),
+  (2, 1), (3, 0), (4, 0), (3, 1), (2, 2), (1, 3), (0, 4), (0, 5),
+  (1, 4), (2, 3), (3, 2), (4, 1), (5, 0), (6, 0), (5, 1), (4, 2),
+  (3, 3), (2, 4), (1, 5), (0, 6), (0, 7), (1, 6), (2, 5), (3, 4),
+  (4, 3), (5, 2), (6, 1), (7, 0), (8, 0), (7, 1), (6, 2), (5, 3),
+  (4, 4), (3, 5), (2, 6), (1, 7), (0, 8), (0, 9), (1, 8), (2, 7),
+  (3, 6), (4, 5), (5, 4), (6, 3), (7, 2), (8, 1), (9, 0), (10, 0),
+  (9, 1), (8, 2), (7, 3), (6, 4), (5, 5), (4, 6), (3, 7), (2, 8),
+  (1, 9), (0, 10), (0, 11), (1, 10), (2, 9), (3, 8), (4, 7), (5, 6),
+  (6, 5), (7, 4), (8, 3), (9, 2), (10, 1), (11, 0), (12, 0), (11, 1),
+  (10, 2), (9, 3), (8, 4), (7, 5), (6, 6), (5, 7), (4, 8), (3, 9),
+  (2, 10), (1, 11), (0, 12), (0, 13), (1, 12), (2, 11), (3, 10), (4, 9),
+  (5, 8), (6, 7), (7, 6), (8, 5), (9, 4), (10, 3), (11, 2), (12, 1),
+  (13, 0), (14, 0), (13, 1), (12, 2), (11, 3), (10, 4), (9, 5), (8, 6),
+  (7, 7), (6, 8), (5, 9), (4, 10), (3, 11), (2, 12), (1, 13), (0, 14),
+  (0, 15), (1, 14), (2, 13), (3, 12), (4, 11), (5, 10), (6, 9), (7, 8),
+  (8, 7), (9, 6), (10, 5), (11, 4), (12, 3), (13, 2), (14, 1), (15, 0),
+  (16, 0), (15, 1), (14, 2), (13, 3), (12, 4), (11, 5), (10, 6), (9, 7),
+  (8, 8), (7, 9), (6, 10), (5, 11), (4, 12), (3, 13), (2, 14), (1, 15),
+  (0, 16), (0, 17), (1, 16), (2, 15), (3, 14), (4, 13), (5, 12), (6, 11),
+  (7, 10), (8, 9), (9, 8), (10, 7), (11, 6), (12, 5), (13, 4), (14, 3),
+  (15, 2), (16, 1), (17, 0), (18, 0), (17, 1), (16, 2), (15, 3), (14, 4),
+  (13, 5), (12, 6), (11, 7), (10, 8), (9, 9), (8, 10), (7, 11), (6, 12),
+  (5, 13), (4, 14), (3, 15), (2, 16), (1, 17), (0, 18), (0, 19), (1, 18),
+  (2, 17), (3, 16), (4, 15), (5, 14), (6, 13), (7, 12), (8, 11), (9, 10),
+  (10, 9), (11, 8), (12, 7), (13, 6), (14, 5), (15, 4), (16, 3), (17, 2),
+  (18, 1), (19, 0), (20, 0), (19, 1), (18, 2), (17, 3), (16, 4), (15, 5),
+  (14, 6), (13, 7), (12, 8), (11, 9), (10, 10), (9, 11), (8, 12), (7, 13),
+  (6, 14), (5, 15), (4, 16), (3, 17), (2, 18), (1, 19), (0, 20), (0, 21),
+  (1, 20), (2, 19), (3, 18), (4, 17), (5, 16), (6, 15), (7, 14), (8, 13),
+  (9, 12), (10, 11), (11, 10), (12, 9), (13, 8), (14, 7), (15, 6), (16, 5),
+  (17, 4), (18, 3), (19, 2), (20, 1), (21, 0), (22, 0), (21, 1), (20, 2),
+  (19, 3), (18, 4), (17, 5), (16, 6), (15, 7), (14, 8), (13, 9), (12, 10),
+  (11, 11), (10, 12), (9, 13), (8, 14), (7, 15), (6, 16), (5, 17), (4, 18),
+  (3, 19), (2, 20), (1, 21), (0, 22), (0, 23), (1, 22), (2, 21), (3, 20),
+  (4, 19), (5, 18), (6, 17), (7, 16), (8, 15), (9, 14), (10, 13), (11, 12),
+  (12, 11), (13, 10), (14, 9), (15, 8), (16, 7), (17, 6), (18, 5), (19, 4),
+  (20, 3), (21, 2), (22, 1), (23, 0), (24, 0), (23, 1), (22, 2), (21, 3),
+  (20, 4), (19, 5), (18, 6), (17, 7), (16, 8), (15, 9), (14, 10), (13, 11),
+  (12, 12), (11, 13), (10, 14), (9, 15), (8, 16), (7, 17), (6, 18), (5, 19),
+  (4, 20), (3, 21), (2, 22), (1, 23), (0, 24), (0, 25), (1, 24), (2, 23),
+  (3, 22), (4, 21), (5, 20), (6, 19), (7, 18), (8, 17), (9, 16), (10, 15),
+  (11, 14), (12, 13), (13, 12), (14, 11), (15, 10), (16, 9), (17, 8), (18, 7),
+  (19, 6), (20, 5), (21, 4), (22, 3), (23, 2), (24, 1), (25, 0), (26, 0),
+  (25, 1), (24, 2), (23, 3), (22, 4), (21, 5), (20, 6), (19, 7), (18, 8),
+  (17, 9), (16, 10), (15, 11), (14, 12), (13, 13), (12, 14), (11, 15), (10, 16),
+  (9, 17), (8, 18), (7, 19), (6, 20), (5, 21), (4, 22), (3, 23), (2, 24),
+  (1, 25), (0, 26), (0, 27), (1, 26), (2, 25), (3, 24), (4, 23), (5, 22),
+  (6, 21), (7, 20), (8, 19), (9, 18), (10, 17), (11, 16), (12, 15), (13, 14),
+  (14, 13), (15, 12), (16, 11), (17, 10), (18, 9), (19, 8), (20, 7), (21, 6),
+  (22, 5), (23, 4), (24, 3), (25, 2), (26, 1), (27, 0), (28, 0), (27, 1),
+  (26, 2), (25, 3), (24, 4), (23, 5), (22, 6), (21, 7), (20, 8), (19, 9),
+  (18, 10), (17, 11), (16, 12), (15, 13), (14, 14), (13, 15), (12, 16), (11, 17),
+  (10, 18), (9, 19), (8, 20), (7, 21), (6, 22), (5, 23), (4, 24), (3, 25),
+  (2, 26), (1, 27), (0, 28), (0, 29), (1, 28), (2, 27), (3, 26), (4, 25),
+  (5, 24), (6, 23), (7, 22), (8, 21), (9, 20), (10, 19), (11, 18), (12, 17),
+  (13, 16), (14, 15), (15, 14), (16, 13), (17, 12), (18, 11), (19, 10), (20, 9),
+  (21, 8), (22, 7), (23, 6), (24, 5), (25, 4), (26, 3), (27, 2), (28, 1),
+  (29, 0), (30, 0), (29, 1), (28, 2), (27, 3), (26, 4), (25, 5), (24, 6),
+  (23, 7), (22, 8), (21, 9), (20, 10), (19, 11), (18, 12), (17, 13), (16, 14),
+  (15, 15), (14, 16), (13, 17), (12, 18), (11, 19), (10, 20), (9, 21), (8, 22),
+  (7, 23), (6, 24), (5, 25), (4, 26), (3, 27), (2, 28), (1, 29), (0, 30),
+  (0, 31), (1, 30), (2, 29), (3, 28), (4, 27), (5, 26), (6, 25), (7, 24),
+  (8, 23), (9, 22), (10, 21), (11, 20), (12, 19), (13, 18), (14, 17), (15, 16),
+  (16, 15), (17, 14), (18, 13), (19, 12), (20, 11), (21, 10), (22, 9), (23, 8),
+  (24, 7), (25, 6), (26, 5), (27, 4), (28, 3), (29, 2), (30, 1), (31, 0),
+  (31, 1), (30, 2), (29, 3), (28, 4), (27, 5), (26, 6), (25, 7), (24, 8),
+  (23, 9), (22, 10), (21, 11), (20, 12), (19, 13), (18, 14), (17, 15), (16, 16),
+  (15, 17), (14, 18), (13, 19), (12, 20), (11, 21), (10, 22), (9, 23), (8, 24),
+  (7, 25), (6, 26), (5, 27), (4, 28), (3, 29), (2, 30), (1, 31), (2, 31),
+  (3, 30), (4, 29), (5, 28), (6, 27), (7, 26), (8, 25), (9, 24), (10, 23),
+  (11, 22), (12, 21), (13, 20), (14, 19), (15, 18), (16, 17), (17, 16), (18, 15),
+  (19, 14), (20, 13), (21, 12), (22, 11), (23, 10), (24, 9), (25, 8), (26, 7),
+  (27, 6), (28, 5), (29, 4), (30, 3), (31, 2), (31, 3), (30, 4), (29, 5),
+  (28, 6), (27, 7), (26, 8), (25, 9), (24, 10), (23, 11), (22, 12), (21, 13),
+  (20, 14), (19, 15), (18, 16), (17, 17), (16, 18), (15, 19), (14, 20), (13, 21),
+  (12, 22), (11, 23), (10, 24), (9, 25), (8, 26), (7, 27), (6, 28), (5, 29),
+  (4, 30), (3, 31), (4, 31), (5, 30), (6, 29), (7, 28), (8, 27), (9, 26),
+  (10, 25), (11, 24), (12, 23), (13, 22), (14, 21), (15, 20), (16, 19), (17, 18),
+  (18, 17), (19, 16), (20, 15), (21, 14), (22, 13), (23, 12), (24, 11), (25, 10),
+  (26, 9), (27, 8), (28, 7), (29, 6), (30, 5), (31, 4), (31, 5), (30, 6),
+  (29, 7), (28, 8), (27, 9), (26, 10), (25, 11), (24, 12), (23, 13), (22, 14),
+  (21, 15), (20, 16), (19, 17), (18, 18), (17, 19), (16, 20), (15, 21), (14, 22),
+  (13, 23), (12, 24), (11, 25), (10, 26), (9, 27), (8, 28), (7, 29), (6, 30),
+  (5, 31), (6, 31), (7, 30), (8, 29), (9, 28), (10, 27), (11, 26), (12, 25),
+  (13, 24), (14, 23), (15, 22), (16, 21), (17, 20), (18, 19), (19, 18), (20, 17),
+  (21, 16), (22, 15), (23, 14), (24, 13), (25, 12), (26, 11), (27, 10), (28, 9),
+  (29, 8), (30, 7), (31, 6), (31, 7), (30, 8), (29, 9), (28, 10), (27, 11),
+  (26, 12), (25, 13), (24, 14), (23, 15), (22, 16), (21, 17), (20, 18), (19, 19),
+  (18, 20), (17, 21), (16, 22), (15, 23), (14, 24), (13, 25), (12, 26), (11, 27),
+  (10, 28), (9, 29), (8, 30), (7, 31), (8, 31), (9, 30), (10, 29), (11, 28),
+  (12, 27), (13, 26), (14, 25), (15, 24), (16, 23), (17, 22), (18, 21), (19, 20),
+  (20, 19), (21, 18), (22, 17), (23, 16), (24, 15), (25, 14), (26, 13), (27, 12),
+  (28, 11), (29, 10), (30, 9), (31, 8), (31, 9), (30, 10), (29, 11), (28, 12),
+  (27, 13), (26, 14), (25, 15), (24, 16), (23, 17), (22, 18), (21, 19), (20, 20),
+  (19, 21), (18, 22), (17, 23), (16, 24), (15, 25), (14, 26), (13, 27), (12, 28),
+  (11, 29), (10, 30), (9, 31), (10, 31), (11, 30), (12, 29), (13, 28), (14, 27),
+  (15, 26), (16, 25), (17, 24), (18, 23), (19, 22), (20, 21), (21, 20), (22, 19),
+  (23, 18), (24, 17), (25, 16), (26, 15), (27, 14), (28, 13), (29, 12), (30, 11),
+  (31, 10), (31, 11), (30, 12), (29, 13), (28, 14), (27, 15), (26, 16), (25, 17),
+  (24, 18), (23, 19), (22, 20), (21, 21), (20, 22), (19, 23), (18, 24), (17, 25),
+  (16, 26), (15, 27), (14, 28), (13, 29), (12, 30), (11, 31), (12, 31), (13, 30),
+  (14, 29), (15, 28), (16, 27), (17, 26), (18, 25), (19, 24), (20, 23), (21, 22),
+  (22, 21), (23, 20), (24, 19), (25, 18), (26, 17), (27, 16), (28, 15), (29, 14),
+  (30, 13), (31, 12), (31, 13), (30, 14), (29, 15), (28, 16), (27, 17), (26, 18),
+  (25, 19), (24, 20), (23, 21), (22, 22), (21, 23), (20, 24), (19, 25), (18, 26),
+  (17, 27), (16, 28), (15, 29), (14, 30), (13, 31), (14, 31), (15, 30), (16, 29),
+  (17, 28), (18, 27), (19, 26), (20, 25), (21, 24), (22, 23), (23, 22), (24, 21),
+  (25, 20), (26, 19), (27, 18), (28, 17), (29, 16), (30, 15), (31, 14), (31, 15),
+  (30, 16), (29, 17), (28, 18), (27, 19), (26, 20), (25, 21), (24, 22), (23, 23),
+  (22, 24), (21, 25), (20, 26), (19, 27), (18, 28), (17, 29), (16, 30), (15, 31),
+  (16, 31), (17, 30), (18, 29), (19, 28), (20, 27), (21, 26), (22, 25), (23, 24),
+  (24, 23), (25, 22), (26, 21), (27, 20), (28, 19), (29, 18), (30, 17), (31, 16),
+  (31, 17), (30, 18), (29, 19), (28, 20), (27, 21), (26, 22), (25, 23), (24, 24),
+  (23, 25), (22, 26), (21, 27), (20, 28), (19, 29), (18, 30), (17, 31), (18, 31),
+  (19, 30), (20, 29), (21, 28), (22, 27), (23, 26), (24, 25), (25, 24), (26, 23),
+  (27, 22), (28, 21), (29, 20), (30, 19), (31, 18), (31, 19), (30, 20), (29, 21),
+  (28, 22), (27, 23), (26, 24), (25, 25), (24, 26), (23, 27), (22, 28), (21, 29),
+  (20, 30), (19, 31), (20, 31), (21, 30), (22, 29), (23, 28), (24, 27), (25, 26),
+  (26, 25), (27, 24), (28, 23), (29, 22), (30, 21), (31, 20), (31, 21), (30, 22),
+  (29, 23), (28, 24), (27, 25), (26, 26), (25, 27), (24, 28), (23, 29), (22, 30),
+  (21, 31), (22, 31), (23, 30), (24, 29), (25, 28), (26, 27), (27, 26), (28, 25),
+  (29, 24), (30, 23), (31, 22), (31, 23), (30, 24), (29, 25), (28, 26), (27, 27),
+  (26, 28), (25, 29), (24, 30), (23, 31), (24, 31), (25, 30), (26, 29), (27, 28),
+  (28, 27), (29, 26), (30, 25), (31, 24), (31, 25), (30, 26), (29, 27), (28, 28),
+  (27, 29), (26, 30), (25, 31), (26, 31), (27, 30), (28, 29), (29, 28), (30, 27),
+  (31, 26), (31, 27), (30, 28), (29, 29), (28, 30), (27, 31), (28, 31), (29, 30),
+  (30, 29), (31, 28), (31, 29), (30, 30), (29, 31), (30, 31), (31, 30), (31, 31)
+];
+
 pub const scan_order_2d: [&[(u8, u8)]; SUPPORTED_TX_SIZES] = [
   &default_scan_4x4,
   &default_scan_8x8,
+  &default_scan_16x16,
+  &default_scan_32x32,
 ];
 
 // Offsets of coefficients which are looked at to determine
@@ -79,6 +280,151 @@ pub const Coeff_Base_Ctx_Offset_8x8: [[u8; 5]; 5] = [
   [21, 21, 21, 21, 21]
 ];
 
+// Intra prediction
+// The y mode context for each neighbouring block's y mode
+pub const Intra_Mode_Context: [usize; INTRA_MODES] = [0, 1, 2, 3, 4, 4, 4, 4, 3, 0, 1, 2, 0];
+
+// Base angle of each directional mode, in degrees. For the non-directional modes, this is 0
+pub const Mode_To_Angle: [i32; INTRA_MODES] = [0, 90, 180, 45, 135, 113, 157, 203, 67, 0, 0, 0, 0];
+
+// Directional mode corresponding to each filter intra mode, for use as a context
+pub const Filter_Intra_Mode_To_Intra_Dir: [PredictionMode; FILTER_INTRA_MODES] = [
+  PredictionMode::DC_PRED, PredictionMode::V_PRED, PredictionMode::H_PRED,
+  PredictionMode::D157_PRED, PredictionMode::DC_PRED
+];
+
+// Filter taps for each filter intra mode. Each 4x2 patch of pixels is predicted from
+// 7 neighbours: the top-left pixel, the 4 pixels above, and the 2 pixels to the left.
+// Indexed by [mode][position within the patch][neighbour]
+pub const Intra_Filter_Taps: [[[i8; 7]; 8]; FILTER_INTRA_MODES] = [
+  [
+    [ -6,  10,   0,   0,   0,  12,   0],
+    [ -5,   2,  10,   0,   0,   9,   0],
+    [ -3,   1,   1,  10,   0,   7,   0],
+    [ -3,   1,   1,   2,  10,   5,   0],
+    [ -4,   6,   0,   0,   0,   2,  12],
+    [ -3,   2,   6,   0,   0,   2,   9],
+    [ -3,   2,   2,   6,   0,   2,   7],
+    [ -3,   1,   2,   2,   6,   3,   5]
+  ],
+  [
+    [-10,  16,   0,   0,   0,  10,   0],
+    [ -6,   0,  16,   0,   0,   6,   0],
+    [ -4,   0,   0,  16,   0,   4,   0],
+    [ -2,   0,   0,   0,  16,   2,   0],
+    [-10,  16,   0,   0,   0,   0,  10],
+    [ -6,   0,  16,   0,   0,   0,   6],
+    [ -4,   0,   0,  16,   0,   0,   4],
+    [ -2,   0,   0,   0,  16,   0,   2]
+  ],
+  [
+    [ -8,   8,   0,   0,   0,  16,   0],
+    [ -8,   0,   8,   0,   0,  16,   0],
+    [ -8,   0,   0,   8,   0,  16,   0],
+    [ -8,   0,   0,   0,   8,  16,   0],
+    [ -4,   4,   0,   0,   0,   0,  16],
+    [ -4,   0,   4,   0,   0,   0,  16],
+    [ -4,   0,   0,   4,   0,   0,  16],
+    [ -4,   0,   0,   0,   4,   0,  16]
+  ],
+  [
+    [ -2,   8,   0,   0,   0,  10,   0],
+    [ -1,   3,   8,   0,   0,   6,   0],
+    [ -1,   2,   3,   8,   0,   4,   0],
+    [  0,   1,   2,   3,   8,   2,   0],
+    [ -1,   4,   0,   0,   0,   3,  10],
+    [ -1,   3,   4,   0,   0,   4,   6],
+    [ -1,   2,   3,   4,   0,   4,   4],
+    [ -1,   2,   2,   3,   4,   3,   3]
+  ],
+  [
+    [-12,  14,   0,   0,   0,  14,   0],
+    [-10,   0,  14,   0,   0,  12,   0],
+    [ -9,   0,   0,  14,   0,  11,   0],
+    [ -8,   0,   0,   0,  14,  10,   0],
+    [-10,  12,   0,   0,   0,   0,  14],
+    [ -9,   1,  12,   0,   0,   0,  12],
+    [ -8,   0,   0,  12,   0,   1,  11],
+    [ -7,   0,   0,   1,  12,   1,   9]
+  ]
+];
+
+// Smoothing kernels for the intra edge filter, indexed by [strength - 1]
+pub const Intra_Edge_Kernel: [[u32; 5]; 3] = [
+  [0, 4, 8, 4, 0],
+  [0, 5, 6, 5, 0],
+  [2, 4, 4, 4, 2]
+];
+
+// Filter taps for the superres upscaling process, indexed by the 1/64 pixel
+// position of each output pixel relative to the input pixels
+pub const Upscale_Filter: [[i32; 8]; 64] = [
+  [0, 0, 0, 128, 0, 0, 0, 0],
+  [0, 0, -1, 128, 2, -1, 0, 0],
+  [0, 1, -3, 127, 4, -2, 1, 0],
+  [0, 1, -4, 127, 6, -3, 1, 0],
+  [0, 2, -6, 126, 8, -3, 1, 0],
+  [0, 2, -7, 125, 11, -4, 1, 0],
+  [-1, 2, -8, 125, 13, -5, 2, 0],
+  [-1, 3, -9, 124, 15, -6, 2, 0],
+  [-1, 3, -10, 123, 18, -6, 2, -1],
+  [-1, 3, -11, 122, 20, -7, 3, -1],
+  [-1, 4, -12, 121, 22, -8, 3, -1],
+  [-1, 4, -13, 120, 25, -9, 3, -1],
+  [-1, 4, -14, 118, 28, -9, 3, -1],
+  [-1, 4, -15, 117, 30, -10, 4, -1],
+  [-1, 5, -16, 116, 32, -11, 4, -1],
+  [-1, 5, -16, 114, 35, -12, 4, -1],
+  [-1, 5, -17, 112, 38, -12, 4, -1],
+  [-1, 5, -18, 111, 40, -13, 5, -1],
+  [-1, 5, -18, 109, 43, -14, 5, -1],
+  [-1, 6, -19, 107, 45, -14, 5, -1],
+  [-1, 6, -19, 105, 48, -15, 5, -1],
+  [-1, 6, -19, 103, 51, -16, 5, -1],
+  [-1, 6, -20, 101, 53, -16, 6, -1],
+  [-1, 6, -20, 99, 56, -17, 6, -1],
+  [-1, 6, -20, 97, 58, -17, 6, -1],
+  [-1, 6, -20, 95, 61, -18, 6, -1],
+  [-2, 7, -20, 93, 64, -18, 6, -2],
+  [-2, 7, -20, 91, 66, -19, 6, -1],
+  [-2, 7, -20, 88, 69, -19, 6, -1],
+  [-2, 7, -20, 86, 71, -19, 6, -1],
+  [-2, 7, -20, 84, 74, -20, 7, -2],
+  [-2, 7, -20, 81, 76, -20, 7, -1],
+  [-2, 7, -20, 79, 79, -20, 7, -2],
+  [-1, 7, -20, 76, 81, -20, 7, -2],
+  [-2, 7, -20, 74, 84, -20, 7, -2],
+  [-1, 6, -19, 71, 86, -20, 7, -2],
+  [-1, 6, -19, 69, 88, -20, 7, -2],
+  [-1, 6, -19, 66, 91, -20, 7, -2],
+  [-2, 6, -18, 64, 93, -20, 7, -2],
+  [-1, 6, -18, 61, 95, -20, 6, -1],
+  [-1, 6, -17, 58, 97, -20, 6, -1],
+  [-1, 6, -17, 56, 99, -20, 6, -1],
+  [-1, 6, -16, 53, 101, -20, 6, -1],
+  [-1, 5, -16, 51, 103, -19, 6, -1],
+  [-1, 5, -15, 48, 105, -19, 6, -1],
+  [-1, 5, -14, 45, 107, -19, 6, -1],
+  [-1, 5, -14, 43, 109, -18, 5, -1],
+  [-1, 5, -13, 40, 111, -18, 5, -1],
+  [-1, 4, -12, 38, 112, -17, 5, -1],
+  [-1, 4, -12, 35, 114, -16, 5, -1],
+  [-1, 4, -11, 32, 116, -16, 5, -1],
+  [-1, 4, -10, 30, 117, -15, 4, -1],
+  [-1, 3, -9, 28, 118, -14, 4, -1],
+  [-1, 3, -9, 25, 120, -13, 4, -1],
+  [-1, 3, -8, 22, 121, -12, 4, -1],
+  [-1, 3, -7, 20, 122, -11, 3, -1],
+  [-1, 2, -6, 18, 123, -10, 3, -1],
+  [0, 2, -6, 15, 124, -9, 3, -1],
+  [0, 2, -5, 13, 125, -8, 2, -1],
+  [0, 1, -4, 11, 125, -7, 2, 0],
+  [0, 1, -3, 8, 126, -6, 2, 0],
+  [0, 1, -3, 6, 127, -4, 1, 0],
+  [0, 1, -2, 4, 127, -3, 1, 0],
+  [0, 0, -1, 2, 128, -1, 0, 0]
+];
+
 // av1_cospi_arr[i][j] = (int)round(cos(PI*j/128) * (1<<(cos_bit_min+i)));
 pub const av1_cospi_arr_data: [[i32; 64]; 4] = [
   // cos_bit = 10
@@ -97,7 +443,7 @@ pub const av1_cospi_arr_data: [[i32; 64]; 4] = [
     965,  921,  876,  830,  784,  737,  690,  642,  595,  546,  498,
     449,  400,  350,  301,  251,  201,  151,  100,  50 ],
   // cos_bit = 12
-  // Used for all inverse transforms
+  // Used for all inverse transforms, and for some larger forward transforms
   [ 4096, 4095, 4091, 4085, 4076, 4065, 4052, 4036, 4017, 3996, 3973,
     3948, 3920, 3889, 3857, 3822, 3784, 3745, 3703, 3659, 3612, 3564,
     3513, 3461, 3406, 3349, 3290, 3229, 3166, 3102, 3035, 2967, 2896,
@@ -105,7 +451,7 @@ pub const av1_cospi_arr_data: [[i32; 64]; 4] = [
     1931, 1842, 1751, 1660, 1567, 1474, 1380, 1285, 1189, 1092, 995,
     897,  799,  700,  601,  501,  401,  301,  201,  101 ],
   // cos_bit = 13
-  // Used for 4x4 and 8x8 forward transforms
+  // Used for most forward transforms
   [ 8192, 8190, 8182, 8170, 8153, 8130, 8103, 8071, 8035, 7993, 7946,
     7895, 7839, 7779, 7713, 7643, 7568, 7489, 7405, 7317, 7225, 7128,
     7027, 6921, 6811, 6698, 6580, 6458, 6333, 6203, 6070, 5933, 5793,
@@ -115,30 +461,40 @@ pub const av1_cospi_arr_data: [[i32; 64]; 4] = [
 ];
 
 pub const av1_txfm_stages: [usize; SUPPORTED_TX_SIZES] = [
-  4, // 4X4
-  6, // 8X8
+  4,  // 4X4
+  6,  // 8X8
+  8,  // 16x16
+  10, // 32x32
 ];
 
 pub const av1_txfm_fwd_shift: [[i32; 3]; SUPPORTED_TX_SIZES] = [
   [ 2,  0, 0 ], // 4x4
   [ 2, -1, 0 ], // 8x8
+  [ 2, -2, 0 ], // 16x16
+  [ 2, -4, 0 ], // 32x32
 ];
 
 // Maximum range of values after each forward transform stage,
 // rounded up to powers of 2
-pub const av1_txfm_fwd_range_mult2: [[i32; 6]; SUPPORTED_TX_SIZES] = [
-  [ 0, 2, 3, 3, 0, 0 ], // 4x4
-  [ 0, 2, 4, 5, 5, 5 ], // 8x8
+pub const av1_txfm_fwd_range_mult2: [[i32; 10]; SUPPORTED_TX_SIZES] = [
+  [ 0, 2, 3, 3, 0, 0, 0, 0, 0, 0 ], // 4x4
+  [ 0, 2, 4, 5, 5, 5, 0, 0, 0, 0 ], // 8x8
+  [ 0, 2, 4, 6, 7, 7, 7, 7, 0, 0 ], // 16x16
+  [ 0, 2, 4, 6, 8, 9, 9, 9, 9, 9 ], // 32x32
 ];
 
 pub const av1_txfm_inv_shift: [[i32; 2]; SUPPORTED_TX_SIZES] = [
   [  0, -4 ], // 4x4
   [ -1, -4 ], // 8x8
+  [ -2, -4 ], // 16x16
+  [ -2, -4 ], // 32x32
 ];
 
 pub const av1_txfm_inv_start_range: [i32; SUPPORTED_TX_SIZES] = [
   5, // 4x4
   6, // 8x8
+  7, // 16x16
+  7, // 32x32
 ];
 
 // DC and AC quantizers for a given qindex
@@ -186,3 +542,2086 @@ pub const qindex_to_ac_q: [i32; 256] = [
   1219, 1243, 1267, 1292, 1317, 1343, 1369, 1396, 1423, 1451, 1479, 1508, 1537,
   1567, 1597, 1628, 1660, 1692, 1725, 1759, 1793, 1828
 ];
+
+// Cost, in units of 1/512 bit, of coding a symbol with probability (128 + i) / 256
+// ie. prob_cost_table[i] = round(-log2((128 + i) / 256) * 512)
+// See rate.rs for how this is extended to the full range of 15-bit probabilities
+pub const prob_cost_table: [u16; 128] = [
+  512, 506, 501, 495, 489, 484, 478, 473, 467, 462, 456, 451,
+  446, 441, 435, 430, 425, 420, 415, 410, 405, 400, 395, 390,
+  385, 380, 375, 371, 366, 361, 356, 352, 347, 343, 338, 333,
+  329, 324, 320, 316, 311, 307, 302, 298, 294, 289, 285, 281,
+  277, 273, 268, 264, 260, 256, 252, 248, 244, 240, 236, 232,
+  228, 224, 220, 216, 212, 209, 205, 201, 197, 194, 190, 186,
+  182, 179, 175, 171, 168, 164, 161, 157, 153, 150, 146, 143,
+  139, 136, 132, 129, 125, 122, 119, 115, 112, 109, 105, 102,
+  99, 95, 92, 89, 86, 82, 79, 76, 73, 70, 66, 63,
+  60, 57, 54, 51, 48, 45, 42, 38, 35, 32, 29, 26,
+  23, 20, 18, 15, 12, 9, 6, 3
+];
+
+// Quantizer matrices, from the Quantizer_Matrix table in the spec.
+// There is one set of weights per level (excluding the flat level 15) and plane type.
+// The spec's table covers every transform size, but we only need the square ones
+// up to 32x32, which come first. The weights for each transform size start at Qm_Offset,
+// and are in raster order
+pub const QM_SUPPORTED_SIZE: usize = 16 + 64 + 256 + 1024;
+pub const Qm_Offset: [usize; SUPPORTED_TX_SIZES] = [0, 16, 80, 336];
+
+pub static Quantizer_Matrix: [[[u8; QM_SUPPORTED_SIZE]; PLANE_TYPES]; 15] = [
+  // Level 0
+  [
+    // Luma
+    [
+      // 4x4
+       32,  43,  73,  97,
+       43,  67,  94, 110,
+       73,  94, 137, 150,
+       97, 110, 150, 200,
+      // 8x8
+       32,  32,  38,  51,  68,  84,  95, 109,
+       32,  35,  40,  49,  63,  76,  89, 102,
+       38,  40,  54,  65,  78,  91,  98, 106,
+       51,  49,  65,  82,  97, 111, 113, 121,
+       68,  63,  78,  97, 117, 134, 138, 142,
+       84,  76,  91, 111, 134, 152, 159, 168,
+       95,  89,  98, 113, 138, 159, 183, 199,
+      109, 102, 106, 121, 142, 168, 199, 220,
+      // 16x16
+       32,  31,  31,  34,  36,  44,  48,  59,  65,  80,  83,  91,  97, 104, 111, 119,
+       31,  32,  32,  33,  34,  41,  44,  54,  59,  72,  75,  83,  90,  97, 104, 112,
+       31,  32,  33,  35,  36,  42,  45,  54,  59,  71,  74,  81,  86,  93, 100, 107,
+       34,  33,  35,  39,  42,  47,  51,  58,  63,  74,  76,  81,  84,  90,  97, 105,
+       36,  34,  36,  42,  48,  54,  57,  64,  68,  79,  81,  88,  91,  96, 102, 105,
+       44,  41,  42,  47,  54,  63,  67,  75,  79,  90,  92,  95, 100, 102, 109, 112,
+       48,  44,  45,  51,  57,  67,  71,  80,  85,  96,  99, 107, 108, 111, 117, 120,
+       59,  54,  54,  58,  64,  75,  80,  92,  98, 110, 113, 115, 116, 122, 125, 130,
+       65,  59,  59,  63,  68,  79,  85,  98, 105, 118, 121, 127, 130, 134, 135, 140,
+       80,  72,  71,  74,  79,  90,  96, 110, 118, 134, 137, 140, 143, 144, 146, 152,
+       83,  75,  74,  76,  81,  92,  99, 113, 121, 137, 140, 151, 152, 155, 158, 165,
+       91,  83,  81,  81,  88,  95, 107, 115, 127, 140, 151, 159, 166, 169, 173, 179,
+       97,  90,  86,  84,  91, 100, 108, 116, 130, 143, 152, 166, 174, 182, 189, 193,
+      104,  97,  93,  90,  96, 102, 111, 122, 134, 144, 155, 169, 182, 191, 200, 210,
+      111, 104, 100,  97, 102, 109, 117, 125, 135, 146, 158, 173, 189, 200, 210, 220,
+      119, 112, 107, 105, 105, 112, 120, 130, 140, 152, 165, 179, 193, 210, 220, 231,
+      // 32x32
+       32,  31,  31,  31,  31,  32,  34,  35,  36,  39,  44,  46,  48,  54,  59,  62,  65,  71,  80,  81,  83,  88,  91,  94,  97, 101, 104, 107, 111, 115, 119, 123,
+       31,  32,  32,  32,  32,  32,  34,  34,  35,  38,  42,  44,  46,  51,  56,  59,  62,  68,  76,  77,  78,  84,  86,  89,  92,  95,  99, 102, 105, 109, 113, 116,
+       31,  32,  32,  32,  32,  32,  33,  34,  34,  37,  41,  42,  44,  49,  54,  56,  59,  65,  72,  73,  75,  80,  83,  86,  90,  93,  97, 101, 104, 108, 112, 116,
+       31,  32,  32,  32,  33,  33,  34,  35,  35,  38,  41,  43,  45,  49,  54,  56,  59,  64,  72,  73,  74,  79,  82,  85,  88,  91,  94,  97, 101, 104, 107, 111,
+       31,  32,  32,  33,  33,  34,  35,  36,  36,  39,  42,  44,  45,  50,  54,  56,  59,  64,  71,  72,  74,  78,  81,  84,  86,  89,  93,  96, 100, 104, 107, 111,
+       32,  32,  32,  33,  34,  35,  37,  37,  38,  40,  42,  44,  46,  49,  53,  55,  58,  63,  69,  70,  72,  76,  79,  82,  85,  89,  93,  96,  99, 102, 106, 109,
+       34,  34,  33,  34,  35,  37,  39,  41,  42,  45,  47,  49,  51,  54,  58,  60,  63,  68,  74,  75,  76,  80,  81,  82,  84,  87,  90,  93,  97, 101, 105, 110,
+       35,  34,  34,  35,  36,  37,  41,  43,  45,  47,  50,  52,  53,  57,  61,  63,  65,  70,  76,  77,  79,  82,  84,  86,  89,  91,  92,  93,  96, 100, 103, 107,
+       36,  35,  34,  35,  36,  38,  42,  45,  48,  50,  54,  55,  57,  60,  64,  66,  68,  73,  79,  80,  81,  85,  88,  90,  91,  93,  96,  99, 102, 103, 105, 107,
+       39,  38,  37,  38,  39,  40,  45,  47,  50,  54,  58,  59,  61,  65,  69,  71,  73,  78,  84,  85,  86,  91,  92,  92,  95,  98, 100, 101, 103, 106, 110, 114,
+       44,  42,  41,  41,  42,  42,  47,  50,  54,  58,  63,  65,  67,  71,  75,  77,  79,  84,  90,  91,  92,  95,  95,  97, 100, 101, 102, 105, 109, 111, 112, 114,
+       46,  44,  42,  43,  44,  44,  49,  52,  55,  59,  65,  67,  69,  74,  78,  80,  82,  87,  93,  94,  95,  98, 100, 103, 102, 105, 108, 110, 111, 113, 117, 121,
+       48,  46,  44,  45,  45,  46,  51,  53,  57,  61,  67,  69,  71,  76,  80,  83,  85,  90,  96,  97,  99, 103, 107, 105, 108, 111, 111, 113, 117, 119, 120, 122,
+       54,  51,  49,  49,  50,  49,  54,  57,  60,  65,  71,  74,  76,  82,  87,  89,  92,  97, 104, 105, 106, 111, 110, 111, 114, 113, 116, 120, 120, 121, 125, 130,
+       59,  56,  54,  54,  54,  53,  58,  61,  64,  69,  75,  78,  80,  87,  92,  95,  98, 103, 110, 111, 113, 115, 115, 119, 116, 120, 122, 122, 125, 129, 130, 130,
+       62,  59,  56,  56,  56,  55,  60,  63,  66,  71,  77,  80,  83,  89,  95,  98, 101, 107, 114, 115, 117, 119, 123, 121, 125, 126, 125, 129, 131, 131, 135, 140,
+       65,  62,  59,  59,  59,  58,  63,  65,  68,  73,  79,  82,  85,  92,  98, 101, 105, 111, 118, 119, 121, 126, 127, 128, 130, 130, 134, 133, 135, 140, 140, 140,
+       71,  68,  65,  64,  64,  63,  68,  70,  73,  78,  84,  87,  90,  97, 103, 107, 111, 117, 125, 126, 128, 134, 132, 136, 133, 138, 137, 140, 143, 142, 145, 150,
+       80,  76,  72,  72,  71,  69,  74,  76,  79,  84,  90,  93,  96, 104, 110, 114, 118, 125, 134, 135, 137, 139, 140, 139, 143, 142, 144, 146, 146, 151, 152, 151,
+       81,  77,  73,  73,  72,  70,  75,  77,  80,  85,  91,  94,  97, 105, 111, 115, 119, 126, 135, 137, 138, 144, 147, 146, 148, 149, 151, 150, 156, 155, 157, 163,
+       83,  78,  75,  74,  74,  72,  76,  79,  81,  86,  92,  95,  99, 106, 113, 117, 121, 128, 137, 138, 140, 147, 151, 156, 152, 157, 155, 161, 158, 162, 165, 164,
+       88,  84,  80,  79,  78,  76,  80,  82,  85,  91,  95,  98, 103, 111, 115, 119, 126, 134, 139, 144, 147, 152, 154, 158, 163, 159, 165, 163, 168, 168, 169, 176,
+       91,  86,  83,  82,  81,  79,  81,  84,  88,  92,  95, 100, 107, 110, 115, 123, 127, 132, 140, 147, 151, 154, 159, 161, 166, 171, 169, 173, 173, 176, 179, 177,
+       94,  89,  86,  85,  84,  82,  82,  86,  90,  92,  97, 103, 105, 111, 119, 121, 128, 136, 139, 146, 156, 158, 161, 166, 168, 174, 179, 178, 180, 183, 183, 190,
+       97,  92,  90,  88,  86,  85,  84,  89,  91,  95, 100, 102, 108, 114, 116, 125, 130, 133, 143, 148, 152, 163, 166, 168, 174, 176, 182, 187, 189, 188, 193, 191,
+      101,  95,  93,  91,  89,  89,  87,  91,  93,  98, 101, 105, 111, 113, 120, 126, 130, 138, 142, 149, 157, 159, 171, 174, 176, 183, 184, 191, 195, 199, 197, 204,
+      104,  99,  97,  94,  93,  93,  90,  92,  96, 100, 102, 108, 111, 116, 122, 125, 134, 137, 144, 151, 155, 165, 169, 179, 182, 184, 191, 193, 200, 204, 210, 206,
+      107, 102, 101,  97,  96,  96,  93,  93,  99, 101, 105, 110, 113, 120, 122, 129, 133, 140, 146, 150, 161, 163, 173, 178, 187, 191, 193, 200, 202, 210, 214, 222,
+      111, 105, 104, 101, 100,  99,  97,  96, 102, 103, 109, 111, 117, 120, 125, 131, 135, 143, 146, 156, 158, 168, 173, 180, 189, 195, 200, 202, 210, 212, 220, 224,
+      115, 109, 108, 104, 104, 102, 101, 100, 103, 106, 111, 113, 119, 121, 129, 131, 140, 142, 151, 155, 162, 168, 176, 183, 188, 199, 204, 210, 212, 220, 222, 230,
+      119, 113, 112, 107, 107, 106, 105, 103, 105, 110, 112, 117, 120, 125, 130, 135, 140, 145, 152, 157, 165, 169, 179, 183, 193, 197, 210, 214, 220, 222, 231, 232,
+      123, 116, 116, 111, 111, 109, 110, 107, 107, 114, 114, 121, 122, 130, 130, 140, 140, 150, 151, 163, 164, 176, 177, 190, 191, 204, 206, 222, 224, 230, 232, 242,
+    ],
+    // Chroma
+    [
+      // 4x4
+       35,  46,  57,  66,
+       46,  60,  69,  71,
+       57,  69,  90,  90,
+       66,  71,  90, 109,
+      // 8x8
+       31,  38,  47,  50,  57,  63,  67,  71,
+       38,  47,  46,  47,  52,  57,  62,  67,
+       47,  46,  54,  57,  61,  66,  67,  68,
+       50,  47,  57,  66,  72,  77,  75,  75,
+       57,  52,  61,  72,  82,  88,  86,  84,
+       63,  57,  66,  77,  88,  96,  95,  95,
+       67,  62,  67,  75,  86,  95, 104, 107,
+       71,  67,  68,  75,  84,  95, 107, 113,
+      // 16x16
+       32,  30,  33,  41,  49,  49,  50,  54,  57,  63,  65,  68,  70,  72,  74,  76,
+       30,  32,  35,  42,  46,  45,  46,  49,  52,  57,  58,  62,  64,  67,  70,  72,
+       33,  35,  39,  45,  47,  45,  46,  49,  51,  56,  57,  60,  62,  64,  66,  69,
+       41,  42,  45,  48,  50,  49,  50,  52,  53,  57,  58,  59,  60,  61,  64,  67,
+       49,  46,  47,  50,  53,  53,  54,  55,  56,  60,  61,  64,  64,  65,  66,  66,
+       49,  45,  45,  49,  53,  58,  60,  62,  63,  67,  68,  67,  69,  68,  70,  70,
+       50,  46,  46,  50,  54,  60,  61,  65,  67,  71,  71,  74,  73,  73,  74,  74,
+       54,  49,  49,  52,  55,  62,  65,  71,  73,  78,  79,  78,  77,  78,  78,  78,
+       57,  52,  51,  53,  56,  63,  67,  73,  76,  82,  83,  84,  84,  84,  82,  83,
+       63,  57,  56,  57,  60,  67,  71,  78,  82,  89,  90,  90,  89,  88,  87,  88,
+       65,  58,  57,  58,  61,  68,  71,  79,  83,  90,  91,  94,  93,  93,  92,  93,
+       68,  62,  60,  59,  64,  67,  74,  78,  84,  90,  94,  98,  99,  98,  98,  98,
+       70,  64,  62,  60,  64,  69,  73,  77,  84,  89,  93,  99, 102, 103, 104, 104,
+       72,  67,  64,  61,  65,  68,  73,  78,  84,  88,  93,  98, 103, 106, 108, 109,
+       74,  70,  66,  64,  66,  70,  74,  78,  82,  87,  92,  98, 104, 108, 111, 112,
+       76,  72,  69,  67,  66,  70,  74,  78,  83,  88,  93,  98, 104, 109, 112, 116,
+      // 32x32
+       32,  31,  30,  32,  33,  36,  41,  45,  49,  48,  49,  50,  50,  52,  54,  56,  57,  60,  63,  64,  65,  67,  68,  69,  70,  71,  72,  73,  74,  75,  76,  78,
+       31,  31,  31,  33,  34,  38,  42,  45,  47,  47,  47,  47,  48,  50,  52,  53,  54,  57,  60,  61,  61,  63,  64,  65,  66,  67,  68,  69,  70,  71,  72,  74,
+       30,  31,  32,  33,  35,  40,  42,  44,  46,  45,  45,  45,  46,  47,  49,  51,  52,  54,  57,  58,  58,  61,  62,  63,  64,  66,  67,  68,  70,  71,  72,  74,
+       32,  33,  33,  35,  37,  41,  43,  45,  47,  46,  45,  46,  46,  47,  49,  50,  51,  54,  57,  57,  58,  60,  61,  62,  63,  64,  65,  66,  67,  68,  69,  70,
+       33,  34,  35,  37,  39,  43,  45,  46,  47,  46,  45,  46,  46,  47,  49,  50,  51,  53,  56,  57,  57,  59,  60,  61,  62,  63,  64,  65,  66,  68,  69,  70,
+       36,  38,  40,  41,  43,  47,  47,  47,  48,  46,  45,  46,  46,  47,  48,  49,  50,  52,  54,  55,  55,  57,  58,  59,  61,  62,  64,  65,  66,  67,  68,  69,
+       41,  42,  42,  43,  45,  47,  48,  49,  50,  49,  49,  49,  50,  50,  52,  52,  53,  55,  57,  58,  58,  60,  59,  59,  60,  61,  61,  63,  64,  66,  67,  69,
+       45,  45,  44,  45,  46,  47,  49,  50,  51,  51,  51,  51,  52,  52,  53,  54,  55,  57,  59,  59,  60,  61,  61,  62,  63,  63,  63,  63,  63,  64,  65,  66,
+       49,  47,  46,  47,  47,  48,  50,  51,  53,  53,  53,  54,  54,  54,  55,  56,  56,  58,  60,  61,  61,  63,  64,  64,  64,  64,  65,  66,  66,  66,  66,  66,
+       48,  47,  45,  46,  46,  46,  49,  51,  53,  54,  55,  56,  56,  57,  58,  59,  60,  61,  63,  64,  64,  66,  66,  65,  66,  67,  67,  67,  67,  68,  69,  70,
+       49,  47,  45,  45,  45,  45,  49,  51,  53,  55,  58,  59,  60,  61,  62,  63,  63,  65,  67,  67,  68,  69,  67,  68,  69,  68,  68,  69,  70,  70,  70,  70,
+       50,  47,  45,  46,  46,  46,  49,  51,  54,  56,  59,  60,  60,  62,  64,  64,  65,  67,  69,  69,  70,  70,  71,  71,  70,  70,  71,  71,  71,  71,  72,  74,
+       50,  48,  46,  46,  46,  46,  50,  52,  54,  56,  60,  60,  61,  63,  65,  66,  67,  68,  71,  71,  71,  73,  74,  72,  73,  74,  73,  73,  74,  74,  74,  74,
+       52,  50,  47,  47,  47,  47,  50,  52,  54,  57,  61,  62,  63,  66,  68,  69,  70,  72,  75,  75,  75,  77,  75,  75,  76,  75,  75,  76,  75,  75,  76,  77,
+       54,  52,  49,  49,  49,  48,  52,  53,  55,  58,  62,  64,  65,  68,  71,  72,  73,  75,  78,  78,  79,  79,  78,  79,  77,  78,  78,  77,  78,  79,  78,  78,
+       56,  53,  51,  50,  50,  49,  52,  54,  56,  59,  63,  64,  66,  69,  72,  73,  75,  77,  80,  80,  81,  81,  82,  80,  81,  81,  79,  81,  80,  79,  81,  82,
+       57,  54,  52,  51,  51,  50,  53,  55,  56,  60,  63,  65,  67,  70,  73,  75,  76,  79,  82,  82,  83,  85,  84,  83,  84,  83,  84,  82,  82,  84,  83,  82,
+       60,  57,  54,  54,  53,  52,  55,  57,  58,  61,  65,  67,  68,  72,  75,  77,  79,  82,  85,  85,  86,  88,  86,  87,  85,  86,  85,  85,  86,  84,  85,  86,
+       63,  60,  57,  57,  56,  54,  57,  59,  60,  63,  67,  69,  71,  75,  78,  80,  82,  85,  89,  89,  90,  90,  90,  89,  89,  88,  88,  88,  87,  88,  88,  87,
+       64,  61,  58,  57,  57,  55,  58,  59,  61,  64,  67,  69,  71,  75,  78,  80,  82,  85,  89,  90,  91,  92,  93,  92,  92,  91,  91,  90,  91,  90,  90,  92,
+       65,  61,  58,  58,  57,  55,  58,  60,  61,  64,  68,  70,  71,  75,  79,  81,  83,  86,  90,  91,  91,  94,  94,  96,  93,  94,  93,  94,  92,  93,  93,  92,
+       67,  63,  61,  60,  59,  57,  60,  61,  63,  66,  69,  70,  73,  77,  79,  81,  85,  88,  90,  92,  94,  96,  96,  97,  98,  95,  97,  95,  96,  95,  95,  96,
+       68,  64,  62,  61,  60,  58,  59,  61,  64,  66,  67,  71,  74,  75,  78,  82,  84,  86,  90,  93,  94,  96,  98,  98,  99, 100,  98,  99,  98,  98,  98,  97,
+       69,  65,  63,  62,  61,  59,  59,  62,  64,  65,  68,  71,  72,  75,  79,  80,  83,  87,  89,  92,  96,  97,  98, 100, 100, 101, 102, 101, 101, 101, 100, 102,
+       70,  66,  64,  63,  62,  61,  60,  63,  64,  66,  69,  70,  73,  76,  77,  81,  84,  85,  89,  92,  93,  98,  99, 100, 102, 102, 103, 104, 104, 103, 104, 102,
+       71,  67,  66,  64,  63,  62,  61,  63,  64,  67,  68,  70,  74,  75,  78,  81,  83,  86,  88,  91,  94,  95, 100, 101, 102, 104, 104, 105, 106, 107, 105, 107,
+       72,  68,  67,  65,  64,  64,  61,  63,  65,  67,  68,  71,  73,  75,  78,  79,  84,  85,  88,  91,  93,  97,  98, 102, 103, 104, 106, 106, 108, 108, 109, 107,
+       73,  69,  68,  66,  65,  65,  63,  63,  66,  67,  69,  71,  73,  76,  77,  81,  82,  85,  88,  90,  94,  95,  99, 101, 104, 105, 106, 109, 108, 110, 111, 112,
+       74,  70,  70,  67,  66,  66,  64,  63,  66,  67,  70,  71,  74,  75,  78,  80,  82,  86,  87,  91,  92,  96,  98, 101, 104, 106, 108, 108, 111, 111, 112, 113,
+       75,  71,  71,  68,  68,  67,  66,  64,  66,  68,  70,  71,  74,  75,  79,  79,  84,  84,  88,  90,  93,  95,  98, 101, 103, 107, 108, 110, 111, 113, 113, 115,
+       76,  72,  72,  69,  69,  68,  67,  65,  66,  69,  70,  72,  74,  76,  78,  81,  83,  85,  88,  90,  93,  95,  98, 100, 104, 105, 109, 111, 112, 113, 116, 115,
+       78,  74,  74,  70,  70,  69,  69,  66,  66,  70,  70,  74,  74,  77,  78,  82,  82,  86,  87,  92,  92,  96,  97, 102, 102, 107, 107, 112, 113, 115, 115, 118,
+    ],
+  ],
+  // Level 1
+  [
+    // Luma
+    [
+      // 4x4
+       32,  41,  69,  92,
+       41,  63,  88, 103,
+       69,  88, 127, 140,
+       92, 103, 140, 184,
+      // 8x8
+       32,  32,  37,  47,  62,  78,  90, 102,
+       32,  35,  39,  46,  58,  72,  84,  96,
+       37,  39,  51,  60,  71,  84,  93, 100,
+       47,  46,  60,  73,  87, 100, 106, 113,
+       62,  58,  71,  87, 105, 121, 129, 132,
+       78,  72,  84, 100, 121, 140, 148, 155,
+       90,  84,  93, 106, 129, 148, 169, 183,
+      102,  96, 100, 113, 132, 155, 183, 201,
+      // 16x16
+       32,  31,  31,  32,  36,  39,  47,  54,  61,  71,  80,  86,  92,  98, 104, 111,
+       31,  32,  32,  33,  34,  37,  44,  50,  56,  65,  73,  79,  85,  91,  98, 105,
+       31,  32,  33,  34,  36,  39,  45,  50,  56,  64,  71,  77,  82,  88,  94, 100,
+       32,  33,  34,  36,  40,  42,  47,  51,  57,  65,  71,  76,  80,  85,  91,  98,
+       36,  34,  36,  40,  48,  50,  56,  60,  65,  73,  79,  84,  86,  90,  95,  98,
+       39,  37,  39,  42,  50,  54,  60,  65,  70,  78,  84,  89,  95,  96, 102, 105,
+       47,  44,  45,  47,  56,  60,  69,  75,  81,  89,  95, 100, 102, 104, 109, 112,
+       54,  50,  50,  51,  60,  65,  75,  82,  89,  97, 104, 109, 110, 114, 117, 121,
+       61,  56,  56,  57,  65,  70,  81,  89,  97, 106, 113, 119, 122, 126, 125, 130,
+       71,  65,  64,  65,  73,  78,  89,  97, 106, 117, 125, 131, 134, 134, 136, 141,
+       80,  73,  71,  71,  79,  84,  95, 104, 113, 125, 134, 140, 142, 145, 146, 152,
+       86,  79,  77,  76,  84,  89, 100, 109, 119, 131, 140, 147, 154, 157, 160, 165,
+       92,  85,  82,  80,  86,  95, 102, 110, 122, 134, 142, 154, 162, 168, 174, 178,
+       98,  91,  88,  85,  90,  96, 104, 114, 126, 134, 145, 157, 168, 176, 184, 193,
+      104,  98,  94,  91,  95, 102, 109, 117, 125, 136, 146, 160, 174, 184, 193, 201,
+      111, 105, 100,  98,  98, 105, 112, 121, 130, 141, 152, 165, 178, 193, 201, 210,
+      // 32x32
+       32,  31,  31,  31,  31,  32,  32,  34,  36,  38,  39,  44,  47,  49,  54,  59,  61,  65,  71,  76,  80,  83,  86,  89,  92,  95,  98, 101, 104, 108, 111, 114,
+       31,  32,  32,  32,  32,  32,  33,  34,  35,  37,  38,  42,  45,  47,  51,  56,  58,  62,  68,  72,  76,  78,  82,  85,  88,  90,  93,  96,  99, 102, 105, 109,
+       31,  32,  32,  32,  32,  32,  33,  33,  34,  36,  37,  41,  44,  46,  50,  54,  56,  60,  65,  70,  73,  76,  79,  82,  85,  88,  91,  95,  98, 101, 105, 109,
+       31,  32,  32,  32,  32,  33,  33,  34,  35,  36,  38,  41,  44,  45,  49,  54,  56,  59,  65,  69,  72,  75,  78,  81,  84,  86,  89,  92,  95,  98, 101, 104,
+       31,  32,  32,  32,  33,  34,  34,  35,  36,  38,  39,  42,  45,  46,  50,  54,  56,  59,  64,  68,  71,  74,  77,  79,  82,  85,  88,  91,  94,  97, 100, 104,
+       32,  32,  32,  33,  34,  35,  36,  37,  38,  39,  40,  42,  45,  46,  49,  53,  55,  58,  63,  66,  69,  72,  74,  78,  81,  84,  87,  90,  93,  96,  99, 102,
+       32,  33,  33,  33,  34,  36,  36,  38,  40,  41,  42,  44,  47,  48,  51,  55,  57,  60,  65,  68,  71,  73,  76,  78,  80,  82,  85,  88,  91,  95,  98, 102,
+       34,  34,  33,  34,  35,  37,  38,  39,  42,  44,  45,  47,  50,  51,  54,  58,  60,  63,  68,  71,  74,  76,  79,  82,  85,  86,  87,  88,  90,  93,  96,  99,
+       36,  35,  34,  35,  36,  38,  40,  42,  48,  50,  50,  54,  56,  57,  60,  64,  65,  68,  73,  76,  79,  81,  84,  86,  86,  88,  90,  93,  95,  97,  98, 100,
+       38,  37,  36,  36,  38,  39,  41,  44,  50,  51,  52,  56,  58,  60,  63,  67,  68,  71,  76,  79,  82,  84,  87,  87,  90,  93,  94,  95,  96, 100, 103, 106,
+       39,  38,  37,  38,  39,  40,  42,  45,  50,  52,  54,  58,  60,  62,  65,  69,  70,  73,  78,  81,  84,  86,  89,  92,  95,  95,  96,  99, 102, 104, 105, 106,
+       44,  42,  41,  41,  42,  42,  44,  47,  54,  56,  58,  63,  66,  68,  71,  75,  77,  79,  84,  88,  90,  92,  95,  97,  97,  99, 102, 103, 103, 106, 109, 113,
+       47,  45,  44,  44,  45,  45,  47,  50,  56,  58,  60,  66,  69,  71,  75,  79,  81,  84,  89,  92,  95,  97, 100, 100, 102, 105, 104, 106, 109, 111, 112, 113,
+       49,  47,  46,  45,  46,  46,  48,  51,  57,  60,  62,  68,  71,  73,  77,  81,  83,  87,  92,  95,  98, 100, 103, 105, 107, 106, 109, 112, 112, 113, 117, 120,
+       54,  51,  50,  49,  50,  49,  51,  54,  60,  63,  65,  71,  75,  77,  82,  87,  89,  92,  97, 101, 104, 106, 109, 112, 110, 113, 114, 114, 117, 121, 121, 121,
+       59,  56,  54,  54,  54,  53,  55,  58,  64,  67,  69,  75,  79,  81,  87,  92,  94,  98, 103, 107, 110, 113, 116, 114, 117, 118, 117, 121, 122, 122, 125, 129,
+       61,  58,  56,  56,  56,  55,  57,  60,  65,  68,  70,  77,  81,  83,  89,  94,  97, 101, 106, 110, 113, 116, 119, 120, 122, 121, 126, 124, 125, 130, 130, 130,
+       65,  62,  60,  59,  59,  58,  60,  63,  68,  71,  73,  79,  84,  87,  92,  98, 101, 105, 111, 115, 118, 121, 124, 128, 125, 129, 128, 131, 133, 132, 135, 139,
+       71,  68,  65,  65,  64,  63,  65,  68,  73,  76,  78,  84,  89,  92,  97, 103, 106, 111, 117, 122, 125, 128, 131, 131, 134, 132, 134, 136, 136, 140, 141, 140,
+       76,  72,  70,  69,  68,  66,  68,  71,  76,  79,  81,  88,  92,  95, 101, 107, 110, 115, 122, 127, 130, 133, 136, 136, 138, 139, 141, 140, 145, 143, 146, 151,
+       80,  76,  73,  72,  71,  69,  71,  74,  79,  82,  84,  90,  95,  98, 104, 110, 113, 118, 125, 130, 134, 137, 140, 146, 142, 146, 145, 149, 146, 150, 152, 151,
+       83,  78,  76,  75,  74,  72,  73,  76,  81,  84,  86,  92,  97, 100, 106, 113, 116, 121, 128, 133, 137, 140, 144, 147, 152, 148, 154, 151, 156, 155, 156, 162,
+       86,  82,  79,  78,  77,  74,  76,  79,  84,  87,  89,  95, 100, 103, 109, 116, 119, 124, 131, 136, 140, 144, 147, 150, 154, 159, 157, 160, 160, 162, 165, 162,
+       89,  85,  82,  81,  79,  78,  78,  82,  86,  87,  92,  97, 100, 105, 112, 114, 120, 128, 131, 136, 146, 147, 150, 155, 156, 161, 166, 165, 167, 169, 169, 175,
+       92,  88,  85,  84,  82,  81,  80,  85,  86,  90,  95,  97, 102, 107, 110, 117, 122, 125, 134, 138, 142, 152, 154, 156, 162, 163, 168, 173, 174, 174, 178, 176,
+       95,  90,  88,  86,  85,  84,  82,  86,  88,  93,  95,  99, 105, 106, 113, 118, 121, 129, 132, 139, 146, 148, 159, 161, 163, 169, 170, 176, 180, 183, 181, 187,
+       98,  93,  91,  89,  88,  87,  85,  87,  90,  94,  96, 102, 104, 109, 114, 117, 126, 128, 134, 141, 145, 154, 157, 166, 168, 170, 176, 178, 184, 188, 193, 188,
+      101,  96,  95,  92,  91,  90,  88,  88,  93,  95,  99, 103, 106, 112, 114, 121, 124, 131, 136, 140, 149, 151, 160, 165, 173, 176, 178, 184, 186, 192, 196, 203,
+      104,  99,  98,  95,  94,  93,  91,  90,  95,  96, 102, 103, 109, 112, 117, 122, 125, 133, 136, 145, 146, 156, 160, 167, 174, 180, 184, 186, 193, 194, 201, 204,
+      108, 102, 101,  98,  97,  96,  95,  93,  97, 100, 104, 106, 111, 113, 121, 122, 130, 132, 140, 143, 150, 155, 162, 169, 174, 183, 188, 192, 194, 201, 202, 210,
+      111, 105, 105, 101, 100,  99,  98,  96,  98, 103, 105, 109, 112, 117, 121, 125, 130, 135, 141, 146, 152, 156, 165, 169, 178, 181, 193, 196, 201, 202, 210, 211,
+      114, 109, 109, 104, 104, 102, 102,  99, 100, 106, 106, 113, 113, 120, 121, 129, 130, 139, 140, 151, 151, 162, 162, 175, 176, 187, 188, 203, 204, 210, 211, 219,
+    ],
+    // Chroma
+    [
+      // 4x4
+       33,  45,  56,  64,
+       45,  58,  66,  69,
+       56,  66,  86,  87,
+       64,  69,  87, 105,
+      // 8x8
+       31,  38,  47,  48,  54,  61,  66,  69,
+       38,  47,  47,  46,  50,  55,  61,  65,
+       47,  47,  53,  55,  58,  63,  65,  66,
+       48,  46,  55,  62,  67,  72,  73,  73,
+       54,  50,  58,  67,  76,  83,  84,  82,
+       61,  55,  63,  72,  83,  91,  92,  92,
+       66,  61,  65,  73,  84,  92, 101, 103,
+       69,  65,  66,  73,  82,  92, 103, 109,
+      // 16x16
+       32,  30,  33,  38,  49,  48,  50,  52,  55,  60,  63,  66,  68,  70,  72,  74,
+       30,  31,  35,  41,  46,  46,  46,  48,  51,  55,  58,  60,  63,  65,  68,  70,
+       33,  35,  39,  44,  47,  46,  46,  47,  50,  53,  56,  58,  60,  62,  65,  67,
+       38,  41,  44,  47,  49,  48,  47,  48,  50,  53,  55,  58,  58,  60,  62,  65,
+       49,  46,  47,  49,  53,  53,  54,  54,  56,  58,  60,  62,  62,  63,  64,  64,
+       48,  46,  46,  48,  53,  54,  56,  57,  59,  61,  63,  65,  67,  66,  68,  68,
+       50,  46,  46,  47,  54,  56,  61,  63,  65,  68,  70,  72,  71,  71,  72,  72,
+       52,  48,  47,  48,  54,  57,  63,  66,  69,  72,  75,  76,  75,  76,  76,  76,
+       55,  51,  50,  50,  56,  59,  65,  69,  73,  77,  79,  81,  81,  81,  80,  80,
+       60,  55,  53,  53,  58,  61,  68,  72,  77,  82,  85,  87,  87,  85,  84,  85,
+       63,  58,  56,  55,  60,  63,  70,  75,  79,  85,  89,  91,  91,  90,  89,  90,
+       66,  60,  58,  58,  62,  65,  72,  76,  81,  87,  91,  94,  96,  95,  95,  95,
+       68,  63,  60,  58,  62,  67,  71,  75,  81,  87,  91,  96,  99, 100, 100, 100,
+       70,  65,  62,  60,  63,  66,  71,  76,  81,  85,  90,  95, 100, 103, 104, 105,
+       72,  68,  65,  62,  64,  68,  72,  76,  80,  84,  89,  95, 100, 104, 107, 108,
+       74,  70,  67,  65,  64,  68,  72,  76,  80,  85,  90,  95, 100, 105, 108, 111,
+      // 32x32
+       32,  31,  30,  31,  33,  36,  38,  41,  49,  49,  48,  49,  50,  51,  52,  54,  55,  57,  60,  62,  63,  65,  66,  67,  68,  69,  70,  71,  72,  73,  74,  75,
+       31,  31,  31,  32,  34,  38,  40,  42,  47,  47,  47,  47,  48,  48,  50,  52,  53,  54,  57,  59,  60,  61,  63,  64,  65,  66,  67,  67,  68,  69,  70,  71,
+       30,  31,  31,  32,  35,  39,  41,  42,  46,  46,  46,  45,  46,  47,  48,  50,  51,  52,  55,  57,  58,  59,  60,  62,  63,  64,  65,  67,  68,  69,  70,  71,
+       31,  32,  32,  33,  36,  40,  41,  43,  46,  46,  45,  45,  46,  46,  47,  49,  50,  51,  54,  56,  57,  58,  59,  61,  62,  63,  63,  64,  65,  66,  67,  68,
+       33,  34,  35,  36,  39,  43,  44,  45,  47,  46,  46,  45,  46,  47,  47,  49,  50,  51,  53,  55,  56,  57,  58,  59,  60,  61,  62,  63,  65,  66,  67,  68,
+       36,  38,  39,  40,  43,  47,  47,  47,  48,  47,  46,  45,  46,  46,  47,  48,  49,  50,  52,  53,  54,  55,  56,  58,  59,  61,  62,  63,  64,  65,  66,  66,
+       38,  40,  41,  41,  44,  47,  47,  48,  49,  48,  48,  47,  47,  47,  48,  49,  50,  51,  53,  54,  55,  56,  58,  58,  58,  59,  60,  61,  62,  64,  65,  66,
+       41,  42,  42,  43,  45,  47,  48,  48,  50,  50,  49,  49,  50,  50,  50,  52,  52,  53,  55,  56,  57,  58,  59,  60,  61,  61,  61,  61,  62,  63,  63,  64,
+       49,  47,  46,  46,  47,  48,  49,  50,  53,  53,  53,  53,  54,  54,  54,  55,  56,  56,  58,  59,  60,  61,  62,  63,  62,  62,  63,  64,  64,  64,  64,  64,
+       49,  47,  46,  46,  46,  47,  48,  50,  53,  53,  54,  55,  55,  55,  56,  57,  58,  58,  60,  61,  62,  63,  64,  64,  64,  65,  65,  65,  65,  66,  67,  68,
+       48,  47,  46,  45,  46,  46,  48,  49,  53,  54,  54,  55,  56,  56,  57,  58,  59,  60,  61,  63,  63,  64,  65,  66,  67,  66,  66,  67,  68,  68,  68,  68,
+       49,  47,  45,  45,  45,  45,  47,  49,  53,  55,  55,  58,  59,  60,  61,  62,  63,  63,  65,  66,  67,  68,  69,  69,  68,  68,  69,  69,  69,  69,  70,  71,
+       50,  48,  46,  46,  46,  46,  47,  50,  54,  55,  56,  59,  61,  61,  63,  64,  65,  66,  68,  69,  70,  71,  72,  71,  71,  72,  71,  71,  72,  72,  72,  71,
+       51,  48,  47,  46,  47,  46,  47,  50,  54,  55,  56,  60,  61,  62,  64,  66,  66,  67,  69,  70,  71,  72,  73,  73,  74,  73,  73,  74,  73,  73,  74,  75,
+       52,  50,  48,  47,  47,  47,  48,  50,  54,  56,  57,  61,  63,  64,  66,  68,  69,  70,  72,  74,  75,  75,  76,  77,  75,  76,  76,  75,  76,  77,  76,  75,
+       54,  52,  50,  49,  49,  48,  49,  52,  55,  57,  58,  62,  64,  66,  68,  71,  72,  73,  75,  77,  78,  79,  80,  78,  79,  78,  77,  78,  78,  77,  78,  79,
+       55,  53,  51,  50,  50,  49,  50,  52,  56,  58,  59,  63,  65,  66,  69,  72,  73,  74,  77,  78,  79,  80,  81,  81,  81,  80,  81,  80,  80,  81,  80,  79,
+       57,  54,  52,  51,  51,  50,  51,  53,  56,  58,  60,  63,  66,  67,  70,  73,  74,  76,  79,  80,  82,  83,  84,  85,  83,  84,  83,  83,  83,  82,  82,  83,
+       60,  57,  55,  54,  53,  52,  53,  55,  58,  60,  61,  65,  68,  69,  72,  75,  77,  79,  82,  84,  85,  86,  87,  86,  87,  85,  85,  85,  84,  86,  85,  84,
+       62,  59,  57,  56,  55,  53,  54,  56,  59,  61,  63,  66,  69,  70,  74,  77,  78,  80,  84,  86,  87,  88,  90,  89,  89,  88,  88,  87,  88,  87,  87,  88,
+       63,  60,  58,  57,  56,  54,  55,  57,  60,  62,  63,  67,  70,  71,  75,  78,  79,  82,  85,  87,  89,  90,  91,  93,  91,  91,  90,  91,  89,  90,  90,  89,
+       65,  61,  59,  58,  57,  55,  56,  58,  61,  63,  64,  68,  71,  72,  75,  79,  80,  83,  86,  88,  90,  91,  93,  94,  95,  92,  94,  92,  93,  92,  91,  93,
+       66,  63,  60,  59,  58,  56,  58,  59,  62,  64,  65,  69,  72,  73,  76,  80,  81,  84,  87,  90,  91,  93,  94,  95,  96,  97,  95,  95,  95,  95,  95,  93,
+       67,  64,  62,  61,  59,  58,  58,  60,  63,  64,  66,  69,  71,  73,  77,  78,  81,  85,  86,  89,  93,  94,  95,  97,  97,  98,  99,  97,  97,  97,  96,  98,
+       68,  65,  63,  62,  60,  59,  58,  61,  62,  64,  67,  68,  71,  74,  75,  79,  81,  83,  87,  89,  91,  95,  96,  97,  99,  98, 100, 100, 100,  99, 100,  98,
+       69,  66,  64,  63,  61,  61,  59,  61,  62,  65,  66,  68,  72,  73,  76,  78,  80,  84,  85,  88,  91,  92,  97,  98,  98, 101, 100, 102, 102, 103, 101, 102,
+       70,  67,  65,  63,  62,  62,  60,  61,  63,  65,  66,  69,  71,  73,  76,  77,  81,  83,  85,  88,  90,  94,  95,  99, 100, 100, 103, 102, 104, 104, 105, 103,
+       71,  67,  67,  64,  63,  63,  61,  61,  64,  65,  67,  69,  71,  74,  75,  78,  80,  83,  85,  87,  91,  92,  95,  97, 100, 102, 102, 105, 104, 106, 106, 108,
+       72,  68,  68,  65,  65,  64,  62,  62,  64,  65,  68,  69,  72,  73,  76,  78,  80,  83,  84,  88,  89,  93,  95,  97, 100, 102, 104, 104, 107, 106, 108, 108,
+       73,  69,  69,  66,  66,  65,  64,  63,  64,  66,  68,  69,  72,  73,  77,  77,  81,  82,  86,  87,  90,  92,  95,  97,  99, 103, 104, 106, 106, 109, 108, 110,
+       74,  70,  70,  67,  67,  66,  65,  63,  64,  67,  68,  70,  72,  74,  76,  78,  80,  82,  85,  87,  90,  91,  95,  96, 100, 101, 105, 106, 108, 108, 111, 110,
+       75,  71,  71,  68,  68,  66,  66,  64,  64,  68,  68,  71,  71,  75,  75,  79,  79,  83,  84,  88,  89,  93,  93,  98,  98, 102, 103, 108, 108, 110, 110, 113,
+    ],
+  ],
+  // Level 2
+  [
+    // Luma
+    [
+      // 4x4
+       32,  38,  63,  86,
+       38,  56,  78,  97,
+       63,  78, 113, 130,
+       86,  97, 130, 169,
+      // 8x8
+       32,  32,  35,  46,  57,  76,  85,  96,
+       32,  34,  37,  45,  54,  70,  79,  90,
+       35,  37,  48,  56,  64,  79,  87,  93,
+       46,  45,  56,  70,  80,  96, 100, 105,
+       57,  54,  64,  80,  93, 111, 121, 122,
+       76,  70,  79,  96, 111, 134, 138, 144,
+       85,  79,  87, 100, 121, 138, 156, 168,
+       96,  90,  93, 105, 122, 144, 168, 184,
+      // 16x16
+       32,  31,  31,  32,  34,  39,  44,  49,  58,  65,  71,  81,  87,  93,  98, 104,
+       31,  32,  32,  32,  34,  38,  41,  46,  54,  60,  66,  75,  81,  86,  92,  98,
+       31,  32,  33,  34,  36,  39,  42,  46,  53,  59,  64,  73,  78,  83,  88,  94,
+       32,  32,  34,  35,  37,  40,  42,  46,  52,  58,  63,  71,  75,  80,  86,  92,
+       34,  34,  36,  37,  42,  47,  50,  53,  59,  65,  70,  77,  82,  85,  89,  92,
+       39,  38,  39,  40,  47,  54,  58,  62,  68,  73,  78,  85,  90,  90,  96,  98,
+       44,  41,  42,  42,  50,  58,  63,  68,  74,  79,  84,  91,  96,  98, 102, 104,
+       49,  46,  46,  46,  53,  62,  68,  73,  81,  87,  92,  99, 103, 107, 109, 112,
+       58,  54,  53,  52,  59,  68,  74,  81,  90,  97, 102, 110, 114, 118, 117, 121,
+       65,  60,  59,  58,  65,  73,  79,  87,  97, 105, 111, 120, 125, 125, 126, 130,
+       71,  66,  64,  63,  70,  78,  84,  92, 102, 111, 117, 127, 133, 134, 136, 141,
+       81,  75,  73,  71,  77,  85,  91,  99, 110, 120, 127, 137, 143, 145, 148, 152,
+       87,  81,  78,  75,  82,  90,  96, 103, 114, 125, 133, 143, 150, 156, 160, 163,
+       93,  86,  83,  80,  85,  90,  98, 107, 118, 125, 134, 145, 156, 163, 169, 177,
+       98,  92,  88,  86,  89,  96, 102, 109, 117, 126, 136, 148, 160, 169, 176, 184,
+      104,  98,  94,  92,  92,  98, 104, 112, 121, 130, 141, 152, 163, 177, 184, 191,
+      // 32x32
+       32,  31,  31,  31,  31,  32,  32,  34,  34,  36,  39,  41,  44,  48,  49,  54,  58,  59,  65,  69,  71,  80,  81,  83,  87,  90,  93,  95,  98, 101, 104, 107,
+       31,  32,  32,  32,  32,  32,  32,  34,  34,  35,  38,  39,  42,  46,  47,  51,  55,  57,  62,  66,  68,  76,  77,  78,  83,  85,  88,  90,  93,  96,  99, 101,
+       31,  32,  32,  32,  32,  32,  32,  33,  34,  34,  38,  39,  41,  45,  46,  50,  54,  55,  60,  64,  66,  73,  75,  76,  81,  83,  86,  89,  92,  95,  98, 101,
+       31,  32,  32,  32,  32,  32,  32,  33,  34,  34,  37,  38,  41,  44,  45,  49,  53,  54,  59,  63,  65,  72,  74,  75,  79,  81,  84,  86,  89,  91,  94,  97,
+       31,  32,  32,  32,  33,  33,  34,  35,  36,  36,  39,  40,  42,  45,  46,  50,  53,  54,  59,  63,  64,  71,  73,  74,  78,  80,  83,  85,  88,  91,  94,  97,
+       32,  32,  32,  32,  33,  34,  34,  36,  36,  37,  40,  40,  42,  45,  46,  49,  53,  54,  58,  62,  63,  70,  72,  73,  77,  79,  82,  85,  87,  90,  92,  95,
+       32,  32,  32,  32,  34,  34,  35,  37,  37,  38,  40,  41,  42,  45,  46,  49,  52,  54,  58,  61,  63,  69,  71,  72,  75,  78,  80,  83,  86,  89,  92,  95,
+       34,  34,  33,  33,  35,  36,  37,  39,  41,  42,  45,  46,  47,  50,  51,  54,  57,  59,  63,  66,  68,  74,  75,  76,  80,  81,  82,  83,  85,  87,  90,  93,
+       34,  34,  34,  34,  36,  36,  37,  41,  42,  45,  47,  48,  50,  53,  53,  56,  59,  61,  65,  68,  70,  76,  77,  78,  82,  83,  85,  88,  89,  90,  92,  93,
+       36,  35,  34,  34,  36,  37,  38,  42,  45,  48,  50,  51,  54,  56,  57,  60,  63,  64,  68,  71,  73,  79,  80,  81,  85,  87,  89,  89,  90,  93,  96,  99,
+       39,  38,  38,  37,  39,  40,  40,  45,  47,  50,  54,  55,  58,  61,  62,  65,  68,  69,  73,  76,  78,  84,  85,  86,  90,  89,  90,  93,  96,  97,  98,  99,
+       41,  39,  39,  38,  40,  40,  41,  46,  48,  51,  55,  56,  59,  62,  63,  67,  70,  71,  75,  78,  80,  86,  87,  88,  91,  93,  96,  97,  97,  99, 102, 105,
+       44,  42,  41,  41,  42,  42,  42,  47,  50,  54,  58,  59,  63,  66,  68,  71,  74,  75,  79,  83,  84,  90,  91,  92,  96,  98,  98,  99, 102, 104, 104, 105,
+       48,  46,  45,  44,  45,  45,  45,  50,  53,  56,  61,  62,  66,  70,  71,  76,  79,  80,  85,  88,  90,  96,  97,  98, 101, 100, 102, 105, 105, 105, 109, 112,
+       49,  47,  46,  45,  46,  46,  46,  51,  53,  57,  62,  63,  68,  71,  73,  77,  81,  82,  87,  90,  92,  98,  99, 100, 103, 106, 107, 106, 109, 112, 112, 112,
+       54,  51,  50,  49,  50,  49,  49,  54,  56,  60,  65,  67,  71,  76,  77,  82,  86,  87,  92,  96,  97, 104, 105, 106, 110, 110, 109, 113, 114, 113, 116, 120,
+       58,  55,  54,  53,  53,  53,  52,  57,  59,  63,  68,  70,  74,  79,  81,  86,  90,  91,  97, 100, 102, 109, 110, 111, 114, 114, 118, 116, 117, 121, 121, 120,
+       59,  57,  55,  54,  54,  54,  54,  59,  61,  64,  69,  71,  75,  80,  82,  87,  91,  93,  99, 102, 104, 111, 112, 113, 117, 121, 120, 122, 124, 122, 125, 129,
+       65,  62,  60,  59,  59,  58,  58,  63,  65,  68,  73,  75,  79,  85,  87,  92,  97,  99, 105, 109, 111, 118, 120, 121, 125, 124, 125, 127, 126, 130, 130, 129,
+       69,  66,  64,  63,  63,  62,  61,  66,  68,  71,  76,  78,  83,  88,  90,  96, 100, 102, 109, 113, 115, 123, 125, 126, 129, 130, 131, 130, 134, 133, 135, 139,
+       71,  68,  66,  65,  64,  63,  63,  68,  70,  73,  78,  80,  84,  90,  92,  97, 102, 104, 111, 115, 117, 125, 127, 128, 133, 136, 134, 139, 136, 139, 141, 140,
+       80,  76,  73,  72,  71,  70,  69,  74,  76,  79,  84,  86,  90,  96,  98, 104, 109, 111, 118, 123, 125, 134, 136, 137, 142, 138, 143, 140, 144, 144, 144, 149,
+       81,  77,  75,  74,  73,  72,  71,  75,  77,  80,  85,  87,  91,  97,  99, 105, 110, 112, 120, 125, 127, 136, 137, 139, 143, 148, 145, 148, 148, 150, 152, 149,
+       83,  78,  76,  75,  74,  73,  72,  76,  78,  81,  86,  88,  92,  98, 100, 106, 111, 113, 121, 126, 128, 137, 139, 140, 145, 149, 153, 153, 154, 155, 155, 161,
+       87,  83,  81,  79,  78,  77,  75,  80,  82,  85,  90,  91,  96, 101, 103, 110, 114, 117, 125, 129, 133, 142, 143, 145, 150, 151, 156, 159, 160, 160, 163, 161,
+       90,  85,  83,  81,  80,  79,  78,  81,  83,  87,  89,  93,  98, 100, 106, 110, 114, 121, 124, 130, 136, 138, 148, 149, 151, 156, 157, 162, 166, 168, 166, 172,
+       93,  88,  86,  84,  83,  82,  80,  82,  85,  89,  90,  96,  98, 102, 107, 109, 118, 120, 125, 131, 134, 143, 145, 153, 156, 157, 163, 164, 169, 172, 177, 172,
+       95,  90,  89,  86,  85,  85,  83,  83,  88,  89,  93,  97,  99, 105, 106, 113, 116, 122, 127, 130, 139, 140, 148, 153, 159, 162, 164, 169, 170, 176, 179, 185,
+       98,  93,  92,  89,  88,  87,  86,  85,  89,  90,  96,  97, 102, 105, 109, 114, 117, 124, 126, 134, 136, 144, 148, 154, 160, 166, 169, 170, 176, 177, 184, 186,
+      101,  96,  95,  91,  91,  90,  89,  87,  90,  93,  97,  99, 104, 105, 112, 113, 121, 122, 130, 133, 139, 144, 150, 155, 160, 168, 172, 176, 177, 184, 185, 191,
+      104,  99,  98,  94,  94,  92,  92,  90,  92,  96,  98, 102, 104, 109, 112, 116, 121, 125, 130, 135, 141, 144, 152, 155, 163, 166, 177, 179, 184, 185, 191, 192,
+      107, 101, 101,  97,  97,  95,  95,  93,  93,  99,  99, 105, 105, 112, 112, 120, 120, 129, 129, 139, 140, 149, 149, 161, 161, 172, 172, 185, 186, 191, 192, 199,
+    ],
+    // Chroma
+    [
+      // 4x4
+       32,  45,  53,  63,
+       45,  55,  62,  67,
+       53,  62,  80,  84,
+       63,  67,  84, 101,
+      // 8x8
+       31,  36,  47,  48,  52,  60,  64,  67,
+       36,  43,  47,  46,  49,  55,  59,  63,
+       47,  47,  53,  54,  55,  60,  63,  64,
+       48,  46,  54,  61,  65,  70,  71,  71,
+       52,  49,  55,  65,  71,  78,  81,  79,
+       60,  55,  60,  70,  78,  89,  89,  89,
+       64,  59,  63,  71,  81,  89,  97,  99,
+       67,  63,  64,  71,  79,  89,  99, 104,
+      // 16x16
+       32,  30,  33,  36,  44,  48,  49,  51,  54,  57,  60,  64,  67,  68,  70,  72,
+       30,  31,  35,  39,  44,  46,  46,  47,  50,  53,  55,  59,  61,  64,  66,  68,
+       33,  35,  39,  43,  46,  46,  45,  47,  49,  51,  53,  57,  59,  61,  63,  65,
+       36,  39,  43,  47,  47,  46,  45,  46,  48,  50,  52,  55,  57,  58,  61,  63,
+       44,  44,  46,  47,  50,  51,  51,  51,  53,  54,  56,  59,  61,  61,  63,  62,
+       48,  46,  46,  46,  51,  54,  55,  56,  58,  60,  61,  64,  65,  64,  66,  66,
+       49,  46,  45,  45,  51,  55,  58,  60,  62,  63,  65,  68,  69,  69,  69,  69,
+       51,  47,  47,  46,  51,  56,  60,  62,  65,  67,  69,  72,  73,  74,  73,  73,
+       54,  50,  49,  48,  53,  58,  62,  65,  70,  73,  75,  78,  79,  79,  77,  77,
+       57,  53,  51,  50,  54,  60,  63,  67,  73,  76,  79,  82,  84,  83,  82,  82,
+       60,  55,  53,  52,  56,  61,  65,  69,  75,  79,  82,  86,  88,  87,  86,  87,
+       64,  59,  57,  55,  59,  64,  68,  72,  78,  82,  86,  90,  93,  92,  91,  92,
+       67,  61,  59,  57,  61,  65,  69,  73,  79,  84,  88,  93,  95,  96,  96,  96,
+       68,  64,  61,  58,  61,  64,  69,  74,  79,  83,  87,  92,  96,  99, 100, 101,
+       70,  66,  63,  61,  63,  66,  69,  73,  77,  82,  86,  91,  96, 100, 103, 104,
+       72,  68,  65,  63,  62,  66,  69,  73,  77,  82,  87,  92,  96, 101, 104, 106,
+      // 32x32
+       32,  31,  30,  30,  33,  35,  36,  41,  44,  49,  48,  48,  49,  50,  51,  52,  54,  55,  57,  59,  60,  63,  64,  65,  67,  68,  68,  69,  70,  71,  72,  73,
+       31,  31,  31,  31,  34,  36,  38,  42,  44,  47,  47,  47,  47,  48,  48,  50,  51,  52,  54,  56,  57,  60,  61,  61,  63,  64,  65,  66,  67,  67,  68,  69,
+       30,  31,  31,  31,  35,  37,  39,  42,  44,  47,  46,  46,  46,  47,  47,  48,  50,  51,  53,  54,  55,  58,  59,  60,  61,  63,  64,  65,  66,  67,  68,  69,
+       30,  31,  31,  32,  35,  37,  40,  42,  44,  46,  45,  45,  45,  46,  46,  47,  49,  50,  52,  53,  54,  57,  58,  58,  60,  61,  62,  63,  63,  64,  65,  66,
+       33,  34,  35,  35,  39,  41,  43,  45,  46,  47,  46,  46,  45,  46,  47,  47,  49,  49,  51,  53,  53,  56,  57,  57,  59,  60,  61,  62,  63,  64,  65,  66,
+       35,  36,  37,  37,  41,  43,  45,  46,  46,  47,  46,  46,  45,  46,  46,  47,  48,  49,  50,  52,  53,  55,  56,  56,  58,  59,  60,  61,  62,  63,  64,  64,
+       36,  38,  39,  40,  43,  45,  47,  47,  47,  48,  46,  46,  45,  46,  46,  47,  48,  48,  50,  51,  52,  54,  55,  55,  57,  58,  58,  59,  61,  62,  63,  64,
+       41,  42,  42,  42,  45,  46,  47,  48,  49,  50,  49,  49,  49,  50,  50,  50,  51,  52,  53,  54,  55,  57,  58,  58,  60,  60,  59,  59,  60,  61,  61,  62,
+       44,  44,  44,  44,  46,  46,  47,  49,  50,  51,  51,  51,  51,  51,  51,  52,  53,  53,  54,  56,  56,  59,  59,  59,  61,  61,  61,  62,  63,  62,  62,  62,
+       49,  47,  47,  46,  47,  47,  48,  50,  51,  53,  53,  53,  53,  54,  54,  54,  55,  55,  56,  58,  58,  60,  61,  61,  63,  63,  64,  63,  63,  64,  65,  66,
+       48,  47,  46,  45,  46,  46,  46,  49,  51,  53,  54,  54,  55,  56,  56,  57,  58,  59,  60,  61,  61,  63,  64,  64,  65,  65,  64,  65,  66,  66,  66,  66,
+       48,  47,  46,  45,  46,  46,  46,  49,  51,  53,  54,  55,  56,  57,  57,  58,  59,  60,  61,  62,  63,  65,  65,  65,  66,  67,  68,  67,  67,  67,  68,  69,
+       49,  47,  46,  45,  45,  45,  45,  49,  51,  53,  55,  56,  58,  59,  60,  61,  62,  62,  63,  65,  65,  67,  68,  68,  69,  70,  69,  69,  69,  70,  69,  69,
+       50,  48,  47,  46,  46,  46,  46,  50,  51,  54,  56,  57,  59,  61,  62,  63,  64,  65,  66,  68,  68,  70,  71,  71,  72,  71,  71,  72,  71,  71,  71,  72,
+       51,  48,  47,  46,  47,  46,  46,  50,  51,  54,  56,  57,  60,  62,  62,  64,  65,  66,  67,  69,  69,  71,  72,  72,  73,  74,  74,  72,  73,  74,  73,  73,
+       52,  50,  48,  47,  47,  47,  47,  50,  52,  54,  57,  58,  61,  63,  64,  66,  68,  68,  70,  72,  72,  75,  75,  75,  77,  76,  75,  76,  76,  74,  75,  76,
+       54,  51,  50,  49,  49,  48,  48,  51,  53,  55,  58,  59,  62,  64,  65,  68,  70,  70,  73,  74,  75,  77,  78,  78,  79,  78,  79,  78,  77,  78,  77,  77,
+       55,  52,  51,  50,  49,  49,  48,  52,  53,  55,  59,  60,  62,  65,  66,  68,  70,  71,  73,  75,  76,  78,  79,  79,  80,  81,  80,  80,  81,  79,  79,  81,
+       57,  54,  53,  52,  51,  50,  50,  53,  54,  56,  60,  61,  63,  66,  67,  70,  73,  73,  76,  78,  79,  82,  82,  83,  84,  83,  83,  83,  82,  83,  82,  81,
+       59,  56,  54,  53,  53,  52,  51,  54,  56,  58,  61,  62,  65,  68,  69,  72,  74,  75,  78,  80,  81,  84,  85,  85,  86,  86,  86,  84,  85,  84,  84,  85,
+       60,  57,  55,  54,  53,  53,  52,  55,  56,  58,  61,  63,  65,  68,  69,  72,  75,  76,  79,  81,  82,  85,  86,  86,  88,  88,  87,  88,  86,  87,  87,  85,
+       63,  60,  58,  57,  56,  55,  54,  57,  59,  60,  63,  65,  67,  70,  71,  75,  77,  78,  82,  84,  85,  89,  89,  90,  92,  89,  91,  89,  90,  89,  88,  89,
+       64,  61,  59,  58,  57,  56,  55,  58,  59,  61,  64,  65,  68,  71,  72,  75,  78,  79,  82,  85,  86,  89,  90,  91,  93,  94,  92,  92,  91,  91,  92,  90,
+       65,  61,  60,  58,  57,  56,  55,  58,  59,  61,  64,  65,  68,  71,  72,  75,  78,  79,  83,  85,  86,  90,  91,  91,  93,  94,  95,  94,  94,  94,  93,  94,
+       67,  63,  61,  60,  59,  58,  57,  60,  61,  63,  65,  66,  69,  72,  73,  77,  79,  80,  84,  86,  88,  92,  93,  93,  95,  95,  96,  97,  96,  95,  96,  94,
+       68,  64,  63,  61,  60,  59,  58,  60,  61,  63,  65,  67,  70,  71,  74,  76,  78,  81,  83,  86,  88,  89,  94,  94,  95,  97,  97,  98,  99,  99,  97,  99,
+       68,  65,  64,  62,  61,  60,  58,  59,  61,  64,  64,  68,  69,  71,  74,  75,  79,  80,  83,  86,  87,  91,  92,  95,  96,  97,  99,  99, 100, 100, 101,  99,
+       69,  66,  65,  63,  62,  61,  59,  59,  62,  63,  65,  67,  69,  72,  72,  76,  78,  80,  83,  84,  88,  89,  92,  94,  97,  98,  99, 101, 100, 102, 102, 104,
+       70,  67,  66,  63,  63,  62,  61,  60,  63,  63,  66,  67,  69,  71,  73,  76,  77,  81,  82,  85,  86,  90,  91,  94,  96,  99, 100, 100, 103, 102, 104, 104,
+       71,  67,  67,  64,  64,  63,  62,  61,  62,  64,  66,  67,  70,  71,  74,  74,  78,  79,  83,  84,  87,  89,  91,  94,  95,  99, 100, 102, 102, 104, 104, 106,
+       72,  68,  68,  65,  65,  64,  63,  61,  62,  65,  66,  68,  69,  71,  73,  75,  77,  79,  82,  84,  87,  88,  92,  93,  96,  97, 101, 102, 104, 104, 106, 106,
+       73,  69,  69,  66,  66,  64,  64,  62,  62,  66,  66,  69,  69,  72,  73,  76,  77,  81,  81,  85,  85,  89,  90,  94,  94,  99,  99, 104, 104, 106, 106, 108,
+    ],
+  ],
+  // Level 3
+  [
+    // Luma
+    [
+      // 4x4
+       32,  37,  58,  81,
+       37,  54,  72,  91,
+       58,  72, 102, 121,
+       81,  91, 121, 156,
+      // 8x8
+       32,  32,  35,  42,  53,  68,  78,  90,
+       32,  33,  36,  42,  51,  64,  74,  84,
+       35,  36,  46,  52,  60,  72,  80,  87,
+       42,  42,  52,  63,  73,  84,  92,  98,
+       53,  51,  60,  73,  86, 100, 109, 114,
+       68,  64,  72,  84, 100, 117, 128, 133,
+       78,  74,  80,  92, 109, 128, 140, 155,
+       90,  84,  87,  98, 114, 133, 155, 168,
+      // 16x16
+       32,  31,  31,  32,  34,  36,  41,  47,  54,  59,  65,  74,  82,  87,  92,  97,
+       31,  32,  32,  32,  34,  35,  39,  45,  50,  55,  61,  69,  76,  81,  87,  92,
+       31,  32,  33,  33,  35,  36,  40,  44,  49,  54,  59,  67,  73,  78,  83,  88,
+       32,  32,  33,  35,  37,  38,  41,  45,  49,  53,  58,  65,  71,  75,  80,  86,
+       34,  34,  35,  37,  39,  42,  46,  50,  54,  58,  63,  70,  76,  80,  84,  85,
+       36,  35,  36,  38,  42,  48,  52,  56,  60,  64,  68,  75,  80,  85,  90,  91,
+       41,  39,  40,  41,  46,  52,  57,  62,  67,  71,  75,  83,  88,  92,  95,  97,
+       47,  45,  44,  45,  50,  56,  62,  69,  75,  79,  84,  91,  97, 100, 102, 104,
+       54,  50,  49,  49,  54,  60,  67,  75,  82,  87,  92, 100, 106, 110, 109, 112,
+       59,  55,  54,  53,  58,  64,  71,  79,  87,  92,  98, 106, 112, 117, 117, 121,
+       65,  61,  59,  58,  63,  68,  75,  84,  92,  98, 105, 114, 120, 125, 126, 130,
+       74,  69,  67,  65,  70,  75,  83,  91, 100, 106, 114, 123, 131, 135, 137, 140,
+       82,  76,  73,  71,  76,  80,  88,  97, 106, 112, 120, 131, 139, 144, 148, 150,
+       87,  81,  78,  75,  80,  85,  92, 100, 110, 117, 125, 135, 144, 150, 155, 162,
+       92,  87,  83,  80,  84,  90,  95, 102, 109, 117, 126, 137, 148, 155, 162, 168,
+       97,  92,  88,  86,  85,  91,  97, 104, 112, 121, 130, 140, 150, 162, 168, 174,
+      // 32x32
+       32,  31,  31,  31,  31,  31,  32,  32,  34,  35,  36,  39,  41,  44,  47,  48,  54,  56,  59,  64,  65,  71,  74,  80,  82,  83,  87,  90,  92,  95,  97, 100,
+       31,  32,  32,  32,  32,  32,  32,  33,  34,  35,  35,  38,  40,  42,  45,  46,  51,  53,  56,  61,  62,  68,  71,  76,  78,  78,  83,  85,  88,  90,  92,  95,
+       31,  32,  32,  32,  32,  32,  32,  33,  34,  34,  35,  38,  39,  42,  45,  45,  50,  52,  55,  60,  61,  67,  69,  74,  76,  77,  81,  84,  87,  89,  92,  95,
+       31,  32,  32,  32,  32,  32,  32,  33,  33,  34,  34,  37,  38,  41,  44,  44,  49,  51,  54,  58,  59,  65,  68,  72,  74,  75,  79,  81,  84,  86,  88,  90,
+       31,  32,  32,  32,  33,  33,  33,  34,  35,  36,  36,  39,  40,  42,  44,  45,  49,  51,  54,  58,  59,  64,  67,  71,  73,  74,  78,  80,  83,  85,  88,  90,
+       31,  32,  32,  32,  33,  33,  34,  34,  35,  36,  36,  39,  40,  42,  45,  45,  50,  51,  54,  58,  59,  64,  67,  71,  73,  74,  78,  80,  82,  84,  86,  89,
+       32,  32,  32,  32,  33,  34,  35,  36,  37,  38,  38,  40,  41,  42,  45,  46,  49,  51,  53,  57,  58,  63,  65,  69,  71,  72,  75,  78,  80,  83,  86,  89,
+       32,  33,  33,  33,  34,  34,  36,  36,  38,  39,  40,  42,  43,  44,  47,  47,  51,  53,  55,  59,  60,  65,  67,  71,  73,  73,  77,  78,  80,  82,  84,  86,
+       34,  34,  34,  33,  35,  35,  37,  38,  39,  42,  42,  45,  46,  47,  50,  51,  54,  56,  58,  62,  63,  68,  70,  74,  76,  76,  80,  82,  84,  85,  85,  86,
+       35,  35,  34,  34,  36,  36,  38,  39,  42,  46,  47,  49,  50,  52,  55,  55,  59,  60,  62,  66,  67,  72,  74,  78,  79,  80,  83,  84,  85,  87,  90,  92,
+       36,  35,  35,  34,  36,  36,  38,  40,  42,  47,  48,  50,  52,  54,  56,  57,  60,  61,  64,  67,  68,  73,  75,  79,  80,  81,  85,  87,  90,  91,  91,  92,
+       39,  38,  38,  37,  39,  39,  40,  42,  45,  49,  50,  54,  55,  58,  60,  61,  65,  66,  69,  72,  73,  78,  80,  84,  86,  86,  90,  91,  91,  92,  95,  97,
+       41,  40,  39,  38,  40,  40,  41,  43,  46,  50,  52,  55,  57,  60,  62,  63,  67,  69,  71,  75,  75,  80,  83,  86,  88,  89,  92,  93,  95,  97,  97,  98,
+       44,  42,  42,  41,  42,  42,  42,  44,  47,  52,  54,  58,  60,  63,  66,  67,  71,  73,  75,  79,  79,  84,  86,  90,  92,  92,  96,  98,  98,  98, 101, 104,
+       47,  45,  45,  44,  44,  45,  45,  47,  50,  55,  56,  60,  62,  66,  69,  70,  75,  77,  79,  83,  84,  89,  91,  95,  97,  97, 100,  99, 102, 105, 104, 104,
+       48,  46,  45,  44,  45,  45,  46,  47,  51,  55,  57,  61,  63,  67,  70,  71,  76,  78,  80,  84,  85,  90,  93,  96,  98,  99, 102, 106, 106, 105, 108, 111,
+       54,  51,  50,  49,  49,  50,  49,  51,  54,  59,  60,  65,  67,  71,  75,  76,  82,  84,  87,  91,  92,  97, 100, 104, 106, 106, 110, 108, 109, 112, 112, 111,
+       56,  53,  52,  51,  51,  51,  51,  53,  56,  60,  61,  66,  69,  73,  77,  78,  84,  86,  89,  93,  94, 100, 102, 106, 108, 109, 112, 113, 115, 114, 116, 119,
+       59,  56,  55,  54,  54,  54,  53,  55,  58,  62,  64,  69,  71,  75,  79,  80,  87,  89,  92,  97,  98, 103, 106, 110, 112, 113, 117, 118, 117, 121, 121, 119,
+       64,  61,  60,  58,  58,  58,  57,  59,  62,  66,  67,  72,  75,  79,  83,  84,  91,  93,  97, 102, 103, 109, 112, 116, 118, 119, 122, 121, 125, 123, 125, 128,
+       65,  62,  61,  59,  59,  59,  58,  60,  63,  67,  68,  73,  75,  79,  84,  85,  92,  94,  98, 103, 105, 111, 114, 118, 120, 121, 125, 129, 126, 129, 130, 129,
+       71,  68,  67,  65,  64,  64,  63,  65,  68,  72,  73,  78,  80,  84,  89,  90,  97, 100, 103, 109, 111, 117, 120, 125, 127, 128, 133, 130, 134, 133, 133, 137,
+       74,  71,  69,  68,  67,  67,  65,  67,  70,  74,  75,  80,  83,  86,  91,  93, 100, 102, 106, 112, 114, 120, 123, 128, 131, 131, 135, 137, 137, 138, 140, 137,
+       80,  76,  74,  72,  71,  71,  69,  71,  74,  78,  79,  84,  86,  90,  95,  96, 104, 106, 110, 116, 118, 125, 128, 134, 136, 137, 142, 141, 142, 143, 143, 147,
+       82,  78,  76,  74,  73,  73,  71,  73,  76,  79,  80,  86,  88,  92,  97,  98, 106, 108, 112, 118, 120, 127, 131, 136, 139, 139, 144, 147, 148, 147, 150, 148,
+       83,  78,  77,  75,  74,  74,  72,  73,  76,  80,  81,  86,  89,  92,  97,  99, 106, 109, 113, 119, 121, 128, 131, 137, 139, 140, 145, 150, 152, 155, 152, 157,
+       87,  83,  81,  79,  78,  78,  75,  77,  80,  83,  85,  90,  92,  96, 100, 102, 110, 112, 117, 122, 125, 133, 135, 142, 144, 145, 150, 151, 155, 158, 162, 158,
+       90,  85,  84,  81,  80,  80,  78,  78,  82,  84,  87,  91,  93,  98,  99, 106, 108, 113, 118, 121, 129, 130, 137, 141, 147, 150, 151, 156, 156, 161, 164, 169,
+       92,  88,  87,  84,  83,  82,  80,  80,  84,  85,  90,  91,  95,  98, 102, 106, 109, 115, 117, 125, 126, 134, 137, 142, 148, 152, 155, 156, 162, 162, 168, 170,
+       95,  90,  89,  86,  85,  84,  83,  82,  85,  87,  91,  92,  97,  98, 105, 105, 112, 114, 121, 123, 129, 133, 138, 143, 147, 155, 158, 161, 162, 168, 168, 174,
+       97,  92,  92,  88,  88,  86,  86,  84,  85,  90,  91,  95,  97, 101, 104, 108, 112, 116, 121, 125, 130, 133, 140, 143, 150, 152, 162, 164, 168, 168, 174, 175,
+      100,  95,  95,  90,  90,  89,  89,  86,  86,  92,  92,  97,  98, 104, 104, 111, 111, 119, 119, 128, 129, 137, 137, 147, 148, 157, 158, 169, 170, 174, 175, 181,
+    ],
+    // Chroma
+    [
+      // 4x4
+       32,  45,  51,  61,
+       45,  54,  59,  65,
+       51,  59,  75,  81,
+       61,  65,  81,  97,
+      // 8x8
+       31,  34,  46,  47,  50,  57,  61,  65,
+       34,  39,  47,  45,  48,  53,  57,  61,
+       46,  47,  52,  52,  54,  58,  61,  62,
+       47,  45,  52,  58,  62,  65,  68,  68,
+       50,  48,  54,  62,  68,  73,  77,  76,
+       57,  53,  58,  65,  73,  82,  86,  86,
+       61,  57,  61,  68,  77,  86,  91,  95,
+       65,  61,  62,  68,  76,  86,  95, 100,
+      // 16x16
+       32,  31,  33,  36,  41,  49,  49,  50,  52,  54,  57,  61,  64,  67,  68,  70,
+       31,  31,  34,  39,  42,  47,  46,  47,  49,  51,  53,  57,  60,  62,  64,  66,
+       33,  34,  37,  42,  44,  47,  46,  46,  47,  49,  51,  55,  57,  59,  61,  63,
+       36,  39,  42,  47,  47,  48,  46,  46,  47,  48,  50,  53,  55,  57,  59,  61,
+       41,  42,  44,  47,  48,  50,  49,  50,  50,  52,  53,  56,  58,  60,  61,  60,
+       49,  47,  47,  48,  50,  53,  53,  54,  54,  55,  56,  59,  61,  63,  64,  64,
+       49,  46,  46,  46,  49,  53,  55,  57,  59,  60,  61,  64,  66,  67,  67,  67,
+       50,  47,  46,  46,  50,  54,  57,  61,  63,  64,  66,  69,  70,  72,  71,  71,
+       52,  49,  47,  47,  50,  54,  59,  63,  66,  68,  70,  73,  75,  77,  75,  75,
+       54,  51,  49,  48,  52,  55,  60,  64,  68,  71,  73,  76,  79,  80,  79,  79,
+       57,  53,  51,  50,  53,  56,  61,  66,  70,  73,  76,  80,  82,  84,  83,  84,
+       61,  57,  55,  53,  56,  59,  64,  69,  73,  76,  80,  84,  87,  89,  88,  88,
+       64,  60,  57,  55,  58,  61,  66,  70,  75,  79,  82,  87,  91,  93,  93,  93,
+       67,  62,  59,  57,  60,  63,  67,  72,  77,  80,  84,  89,  93,  95,  96,  97,
+       68,  64,  61,  59,  61,  64,  67,  71,  75,  79,  83,  88,  93,  96,  99, 100,
+       70,  66,  63,  61,  60,  64,  67,  71,  75,  79,  84,  88,  93,  97, 100, 102,
+      // 32x32
+       32,  31,  31,  30,  33,  33,  36,  38,  41,  47,  49,  48,  49,  49,  50,  50,  52,  53,  54,  56,  57,  60,  61,  63,  64,  65,  67,  67,  68,  69,  70,  71,
+       31,  31,  31,  31,  34,  34,  38,  40,  42,  46,  47,  47,  47,  47,  48,  48,  50,  50,  52,  54,  54,  57,  58,  60,  61,  61,  63,  64,  65,  65,  66,  67,
+       31,  31,  31,  31,  34,  35,  39,  40,  42,  46,  47,  46,  46,  46,  47,  47,  49,  50,  51,  53,  53,  56,  57,  59,  60,  60,  62,  63,  64,  65,  66,  67,
+       30,  31,  31,  32,  34,  35,  40,  41,  42,  45,  46,  45,  45,  45,  46,  46,  47,  48,  49,  51,  52,  54,  55,  57,  58,  58,  60,  61,  62,  62,  63,  64,
+       33,  34,  34,  34,  37,  38,  42,  43,  44,  46,  47,  46,  46,  45,  46,  46,  47,  48,  49,  51,  51,  53,  55,  56,  57,  57,  59,  60,  61,  62,  63,  64,
+       33,  34,  35,  35,  38,  39,  43,  44,  45,  47,  47,  46,  46,  45,  46,  46,  47,  48,  49,  51,  51,  53,  54,  56,  57,  57,  59,  60,  60,  61,  62,  62,
+       36,  38,  39,  40,  42,  43,  47,  47,  47,  47,  48,  46,  46,  45,  46,  46,  47,  47,  48,  49,  50,  52,  53,  54,  55,  55,  57,  58,  59,  60,  61,  62,
+       38,  40,  40,  41,  43,  44,  47,  47,  48,  48,  49,  48,  47,  47,  47,  47,  48,  49,  49,  51,  51,  53,  54,  55,  56,  56,  58,  58,  58,  59,  60,  60,
+       41,  42,  42,  42,  44,  45,  47,  48,  48,  50,  50,  49,  49,  49,  50,  50,  50,  51,  52,  53,  53,  55,  56,  57,  58,  58,  60,  61,  61,  61,  60,  60,
+       47,  46,  46,  45,  46,  47,  47,  48,  50,  52,  52,  52,  52,  52,  53,  53,  53,  54,  55,  55,  56,  58,  58,  60,  60,  61,  62,  61,  61,  62,  63,  64,
+       49,  47,  47,  46,  47,  47,  48,  49,  50,  52,  53,  53,  53,  53,  54,  54,  54,  55,  55,  56,  56,  58,  59,  60,  61,  61,  63,  63,  64,  64,  64,  64,
+       48,  47,  46,  45,  46,  46,  46,  48,  49,  52,  53,  54,  55,  55,  56,  56,  57,  58,  58,  59,  60,  61,  62,  63,  64,  64,  66,  65,  65,  65,  66,  67,
+       49,  47,  46,  45,  46,  46,  46,  47,  49,  52,  53,  55,  55,  57,  57,  58,  59,  59,  60,  61,  61,  63,  64,  65,  66,  66,  67,  67,  67,  68,  67,  67,
+       49,  47,  46,  45,  45,  45,  45,  47,  49,  52,  53,  55,  57,  58,  59,  60,  61,  62,  62,  63,  63,  65,  66,  67,  68,  68,  69,  70,  69,  68,  69,  70,
+       50,  48,  47,  46,  46,  46,  46,  47,  50,  53,  54,  56,  57,  59,  61,  61,  63,  64,  64,  66,  66,  68,  69,  70,  70,  71,  72,  70,  71,  72,  71,  70,
+       50,  48,  47,  46,  46,  46,  46,  47,  50,  53,  54,  56,  58,  60,  61,  61,  63,  64,  65,  66,  67,  68,  69,  71,  71,  71,  73,  74,  73,  72,  73,  74,
+       52,  50,  49,  47,  47,  47,  47,  48,  50,  53,  54,  57,  59,  61,  63,  63,  66,  67,  68,  70,  70,  72,  73,  75,  75,  75,  77,  75,  75,  76,  75,  74,
+       53,  50,  50,  48,  48,  48,  47,  49,  51,  54,  55,  58,  59,  62,  64,  64,  67,  68,  69,  71,  71,  73,  74,  76,  77,  77,  78,  78,  78,  76,  77,  78,
+       54,  52,  51,  49,  49,  49,  48,  49,  52,  55,  55,  58,  60,  62,  64,  65,  68,  69,  71,  73,  73,  75,  76,  78,  79,  79,  80,  80,  79,  80,  79,  78,
+       56,  54,  53,  51,  51,  51,  49,  51,  53,  55,  56,  59,  61,  63,  66,  66,  70,  71,  73,  75,  76,  78,  79,  81,  82,  82,  83,  81,  83,  81,  81,  82,
+       57,  54,  53,  52,  51,  51,  50,  51,  53,  56,  56,  60,  61,  63,  66,  67,  70,  71,  73,  76,  76,  79,  80,  82,  82,  83,  84,  85,  83,  84,  84,  82,
+       60,  57,  56,  54,  53,  53,  52,  53,  55,  58,  58,  61,  63,  65,  68,  68,  72,  73,  75,  78,  79,  82,  83,  85,  86,  86,  88,  86,  87,  86,  85,  86,
+       61,  58,  57,  55,  55,  54,  53,  54,  56,  58,  59,  62,  64,  66,  69,  69,  73,  74,  76,  79,  80,  83,  84,  86,  87,  88,  89,  89,  88,  88,  88,  86,
+       63,  60,  59,  57,  56,  56,  54,  55,  57,  60,  60,  63,  65,  67,  70,  71,  75,  76,  78,  81,  82,  85,  86,  89,  90,  90,  92,  91,  91,  90,  89,  91,
+       64,  61,  60,  58,  57,  57,  55,  56,  58,  60,  61,  64,  66,  68,  70,  71,  75,  77,  79,  82,  82,  86,  87,  90,  91,  91,  93,  93,  93,  92,  93,  91,
+       65,  61,  60,  58,  57,  57,  55,  56,  58,  61,  61,  64,  66,  68,  71,  71,  75,  77,  79,  82,  83,  86,  88,  90,  91,  91,  93,  94,  95,  95,  93,  95,
+       67,  63,  62,  60,  59,  59,  57,  58,  60,  62,  63,  66,  67,  69,  72,  73,  77,  78,  80,  83,  84,  88,  89,  92,  93,  93,  95,  95,  96,  96,  97,  95,
+       67,  64,  63,  61,  60,  60,  58,  58,  61,  61,  63,  65,  67,  70,  70,  74,  75,  78,  80,  81,  85,  86,  89,  91,  93,  94,  95,  97,  97,  98,  98, 100,
+       68,  65,  64,  62,  61,  60,  59,  58,  61,  61,  64,  65,  67,  69,  71,  73,  75,  78,  79,  83,  83,  87,  88,  91,  93,  95,  96,  97,  99,  98, 100, 100,
+       69,  65,  65,  62,  62,  61,  60,  59,  61,  62,  64,  65,  68,  68,  72,  72,  76,  76,  80,  81,  84,  86,  88,  90,  92,  95,  96,  98,  98, 100, 100, 101,
+       70,  66,  66,  63,  63,  62,  61,  60,  60,  63,  64,  66,  67,  69,  71,  73,  75,  77,  79,  81,  84,  85,  88,  89,  93,  93,  97,  98, 100, 100, 102, 101,
+       71,  67,  67,  64,  64,  62,  62,  60,  60,  64,  64,  67,  67,  70,  70,  74,  74,  78,  78,  82,  82,  86,  86,  91,  91,  95,  95, 100, 100, 101, 101, 104,
+    ],
+  ],
+  // Level 4
+  [
+    // Luma
+    [
+      // 4x4
+       32,  34,  53,  75,
+       34,  49,  64,  81,
+       53,  64,  91, 112,
+       75,  81, 112, 140,
+      // 8x8
+       32,  32,  34,  39,  50,  62,  76,  84,
+       32,  33,  35,  40,  48,  59,  71,  79,
+       34,  35,  39,  46,  53,  63,  74,  81,
+       39,  40,  46,  56,  65,  75,  86,  92,
+       50,  48,  53,  65,  78,  90, 101, 106,
+       62,  59,  63,  75,  90, 105, 118, 123,
+       76,  71,  74,  86, 101, 118, 134, 142,
+       84,  79,  81,  92, 106, 123, 142, 153,
+      // 16x16
+       32,  31,  31,  32,  33,  36,  39,  44,  48,  54,  59,  66,  74,  81,  86,  91,
+       31,  32,  32,  32,  33,  35,  38,  42,  46,  51,  56,  63,  70,  77,  81,  86,
+       31,  32,  32,  33,  34,  35,  38,  41,  45,  49,  54,  60,  67,  73,  77,  82,
+       32,  32,  33,  34,  36,  37,  40,  42,  45,  49,  53,  59,  66,  71,  75,  80,
+       33,  33,  34,  36,  38,  42,  44,  46,  50,  53,  57,  63,  69,  74,  78,  80,
+       36,  35,  35,  37,  42,  48,  50,  54,  57,  60,  64,  69,  75,  80,  84,  85,
+       39,  38,  38,  40,  44,  50,  54,  58,  61,  65,  69,  74,  80,  85,  89,  91,
+       44,  42,  41,  42,  46,  54,  58,  63,  67,  71,  75,  80,  86,  91,  95,  97,
+       48,  46,  45,  45,  50,  57,  61,  67,  71,  76,  80,  86,  93,  98, 101, 104,
+       54,  51,  49,  49,  53,  60,  65,  71,  76,  82,  87,  93, 100, 105, 109, 112,
+       59,  56,  54,  53,  57,  64,  69,  75,  80,  87,  92,  99, 106, 112, 116, 120,
+       66,  63,  60,  59,  63,  69,  74,  80,  86,  93,  99, 107, 115, 121, 125, 129,
+       74,  70,  67,  66,  69,  75,  80,  86,  93, 100, 106, 115, 123, 130, 135, 138,
+       81,  77,  73,  71,  74,  80,  85,  91,  98, 105, 112, 121, 130, 137, 142, 148,
+       86,  81,  77,  75,  78,  84,  89,  95, 101, 109, 116, 125, 135, 142, 147, 153,
+       91,  86,  82,  80,  80,  85,  91,  97, 104, 112, 120, 129, 138, 148, 153, 159,
+      // 32x32
+       32,  31,  31,  31,  31,  31,  32,  32,  33,  34,  36,  36,  39,  41,  44,  46,  48,  52,  54,  58,  59,  65,  66,  71,  74,  80,  81,  83,  86,  89,  91,  93,
+       31,  32,  32,  32,  32,  32,  32,  32,  33,  34,  35,  35,  38,  39,  42,  44,  46,  50,  51,  56,  56,  62,  63,  68,  71,  76,  77,  78,  82,  84,  86,  88,
+       31,  32,  32,  32,  32,  32,  32,  32,  33,  34,  35,  35,  38,  39,  42,  44,  46,  49,  51,  55,  56,  61,  63,  67,  70,  75,  77,  78,  81,  84,  86,  88,
+       31,  32,  32,  32,  32,  32,  32,  32,  33,  33,  34,  34,  37,  38,  41,  42,  44,  48,  49,  53,  54,  59,  60,  65,  68,  72,  74,  75,  78,  80,  82,  84,
+       31,  32,  32,  32,  32,  33,  33,  33,  34,  34,  35,  35,  38,  39,  41,  43,  45,  48,  49,  53,  54,  59,  60,  65,  67,  72,  73,  74,  77,  80,  82,  84,
+       31,  32,  32,  32,  33,  33,  33,  34,  35,  35,  36,  36,  39,  40,  42,  44,  45,  48,  50,  53,  54,  59,  60,  64,  67,  71,  73,  74,  77,  79,  81,  83,
+       32,  32,  32,  32,  33,  33,  34,  35,  36,  36,  37,  38,  40,  40,  42,  44,  45,  48,  49,  53,  53,  58,  59,  63,  66,  70,  71,  72,  75,  78,  80,  83,
+       32,  32,  32,  32,  33,  34,  35,  35,  36,  37,  38,  38,  40,  41,  42,  44,  46,  48,  49,  53,  53,  58,  59,  63,  65,  69,  71,  72,  74,  77,  79,  80,
+       33,  33,  33,  33,  34,  35,  36,  36,  38,  39,  42,  42,  44,  45,  46,  48,  50,  52,  53,  57,  57,  62,  63,  67,  69,  73,  74,  75,  78,  79,  80,  81,
+       34,  34,  34,  33,  34,  35,  36,  37,  39,  39,  42,  43,  45,  46,  47,  49,  51,  53,  54,  58,  58,  63,  64,  68,  70,  74,  75,  76,  79,  81,  84,  86,
+       36,  35,  35,  34,  35,  36,  37,  38,  42,  42,  48,  48,  50,  51,  54,  55,  57,  59,  60,  63,  64,  68,  69,  73,  75,  79,  80,  81,  84,  85,  85,  86,
+       36,  35,  35,  34,  35,  36,  38,  38,  42,  43,  48,  49,  51,  52,  54,  55,  57,  59,  60,  64,  64,  68,  69,  73,  75,  79,  80,  81,  84,  86,  88,  91,
+       39,  38,  38,  37,  38,  39,  40,  40,  44,  45,  50,  51,  54,  55,  58,  59,  61,  64,  65,  68,  69,  73,  74,  78,  80,  84,  85,  86,  89,  91,  91,  91,
+       41,  39,  39,  38,  39,  40,  40,  41,  45,  46,  51,  52,  55,  56,  59,  61,  63,  65,  67,  70,  70,  75,  76,  80,  82,  86,  87,  88,  91,  92,  94,  96,
+       44,  42,  42,  41,  41,  42,  42,  42,  46,  47,  54,  54,  58,  59,  63,  65,  67,  70,  71,  75,  75,  79,  80,  84,  86,  90,  91,  92,  95,  97,  97,  97,
+       46,  44,  44,  42,  43,  44,  44,  44,  48,  49,  55,  55,  59,  61,  65,  67,  69,  72,  74,  77,  78,  82,  83,  87,  89,  93,  94,  95,  98,  98, 100, 103,
+       48,  46,  46,  44,  45,  45,  45,  46,  50,  51,  57,  57,  61,  63,  67,  69,  71,  74,  76,  80,  80,  85,  86,  90,  93,  96,  98,  99, 101, 104, 104, 103,
+       52,  50,  49,  48,  48,  48,  48,  48,  52,  53,  59,  59,  64,  65,  70,  72,  74,  78,  80,  84,  85,  90,  91,  95,  97, 101, 103, 104, 106, 106, 107, 110,
+       54,  51,  51,  49,  49,  50,  49,  49,  53,  54,  60,  60,  65,  67,  71,  74,  76,  80,  82,  86,  87,  92,  93,  97, 100, 104, 105, 106, 109, 112, 112, 110,
+       58,  56,  55,  53,  53,  53,  53,  53,  57,  58,  63,  64,  68,  70,  75,  77,  80,  84,  86,  91,  91,  97,  98, 103, 105, 110, 111, 112, 115, 114, 115, 118,
+       59,  56,  56,  54,  54,  54,  53,  53,  57,  58,  64,  64,  69,  70,  75,  78,  80,  85,  87,  91,  92,  98,  99, 103, 106, 110, 112, 113, 116, 119, 120, 119,
+       65,  62,  61,  59,  59,  59,  58,  58,  62,  63,  68,  68,  73,  75,  79,  82,  85,  90,  92,  97,  98, 105, 106, 111, 114, 118, 120, 121, 124, 123, 123, 126,
+       66,  63,  63,  60,  60,  60,  59,  59,  63,  64,  69,  69,  74,  76,  80,  83,  86,  91,  93,  98,  99, 106, 107, 112, 115, 119, 121, 122, 125, 128, 129, 126,
+       71,  68,  67,  65,  65,  64,  63,  63,  67,  68,  73,  73,  78,  80,  84,  87,  90,  95,  97, 103, 103, 111, 112, 117, 120, 125, 127, 128, 131, 132, 132, 135,
+       74,  71,  70,  68,  67,  67,  66,  65,  69,  70,  75,  75,  80,  82,  86,  89,  93,  97, 100, 105, 106, 114, 115, 120, 123, 128, 130, 131, 135, 135, 138, 136,
+       80,  76,  75,  72,  72,  71,  70,  69,  73,  74,  79,  79,  84,  86,  90,  93,  96, 101, 104, 110, 110, 118, 119, 125, 128, 134, 136, 137, 140, 142, 140, 144,
+       81,  77,  77,  74,  73,  73,  71,  71,  74,  75,  80,  80,  85,  87,  91,  94,  98, 103, 105, 111, 112, 120, 121, 127, 130, 136, 137, 139, 142, 145, 148, 144,
+       83,  78,  78,  75,  74,  74,  72,  72,  75,  76,  81,  81,  86,  88,  92,  95,  99, 104, 106, 112, 113, 121, 122, 128, 131, 137, 139, 140, 144, 148, 150, 155,
+       86,  82,  81,  78,  77,  77,  75,  74,  78,  79,  84,  84,  89,  91,  95,  98, 101, 106, 109, 115, 116, 124, 125, 131, 135, 140, 142, 144, 147, 149, 153, 155,
+       89,  84,  84,  80,  80,  79,  78,  77,  79,  81,  85,  86,  91,  92,  97,  98, 104, 106, 112, 114, 119, 123, 128, 132, 135, 142, 145, 148, 149, 153, 154, 159,
+       91,  86,  86,  82,  82,  81,  80,  79,  80,  84,  85,  88,  91,  94,  97, 100, 104, 107, 112, 115, 120, 123, 129, 132, 138, 140, 148, 150, 153, 154, 159, 159,
+       93,  88,  88,  84,  84,  83,  83,  80,  81,  86,  86,  91,  91,  96,  97, 103, 103, 110, 110, 118, 119, 126, 126, 135, 136, 144, 144, 155, 155, 159, 159, 164,
+    ],
+    // Chroma
+    [
+      // 4x4
+       32,  46,  49,  58,
+       46,  53,  55,  62,
+       49,  55,  70,  78,
+       58,  62,  78,  91,
+      // 8x8
+       31,  34,  42,  47,  49,  54,  60,  64,
+       34,  39,  45,  46,  47,  51,  56,  59,
+       42,  45,  48,  49,  50,  53,  57,  60,
+       47,  46,  49,  55,  58,  61,  65,  66,
+       49,  47,  50,  58,  65,  69,  73,  74,
+       54,  51,  53,  61,  69,  76,  82,  83,
+       60,  56,  57,  65,  73,  82,  89,  92,
+       64,  59,  60,  66,  74,  83,  92,  96,
+      // 16x16
+       32,  31,  31,  35,  40,  49,  48,  49,  50,  52,  54,  57,  61,  64,  66,  68,
+       31,  31,  32,  37,  41,  47,  47,  46,  48,  49,  51,  54,  57,  60,  62,  64,
+       31,  32,  34,  39,  43,  46,  46,  45,  46,  47,  49,  52,  55,  57,  59,  61,
+       35,  37,  39,  44,  46,  47,  46,  45,  46,  47,  48,  51,  53,  56,  57,  59,
+       40,  41,  43,  46,  48,  50,  49,  48,  49,  49,  51,  53,  55,  57,  59,  59,
+       49,  47,  46,  47,  50,  53,  53,  53,  54,  54,  55,  57,  59,  61,  62,  62,
+       48,  47,  46,  46,  49,  53,  54,  55,  56,  57,  58,  60,  62,  64,  65,  65,
+       49,  46,  45,  45,  48,  53,  55,  58,  60,  61,  62,  64,  66,  68,  69,  69,
+       50,  48,  46,  46,  49,  54,  56,  60,  61,  63,  65,  67,  69,  71,  72,  72,
+       52,  49,  47,  47,  49,  54,  57,  61,  63,  66,  68,  71,  73,  75,  76,  77,
+       54,  51,  49,  48,  51,  55,  58,  62,  65,  68,  71,  74,  76,  78,  80,  81,
+       57,  54,  52,  51,  53,  57,  60,  64,  67,  71,  74,  77,  80,  83,  84,  85,
+       61,  57,  55,  53,  55,  59,  62,  66,  69,  73,  76,  80,  84,  87,  89,  89,
+       64,  60,  57,  56,  57,  61,  64,  68,  71,  75,  78,  83,  87,  90,  92,  94,
+       66,  62,  59,  57,  59,  62,  65,  69,  72,  76,  80,  84,  89,  92,  94,  96,
+       68,  64,  61,  59,  59,  62,  65,  69,  72,  77,  81,  85,  89,  94,  96,  98,
+      // 32x32
+       32,  31,  31,  30,  31,  33,  35,  36,  40,  41,  49,  49,  48,  48,  49,  50,  50,  52,  52,  54,  54,  57,  57,  60,  61,  63,  64,  65,  66,  67,  68,  69,
+       31,  31,  31,  31,  32,  34,  37,  38,  41,  42,  47,  47,  47,  47,  47,  47,  48,  49,  50,  52,  52,  54,  55,  57,  58,  60,  61,  61,  63,  64,  64,  65,
+       31,  31,  31,  31,  32,  35,  37,  39,  41,  42,  47,  47,  47,  46,  46,  47,  48,  49,  49,  51,  51,  54,  54,  56,  57,  59,  60,  61,  62,  63,  64,  65,
+       30,  31,  31,  32,  33,  35,  38,  40,  42,  42,  46,  46,  45,  45,  45,  45,  46,  47,  47,  49,  49,  52,  52,  54,  55,  57,  58,  58,  60,  61,  61,  62,
+       31,  32,  32,  33,  34,  37,  39,  41,  43,  43,  46,  46,  46,  45,  45,  46,  46,  47,  47,  49,  49,  51,  52,  54,  55,  57,  57,  58,  59,  60,  61,  62,
+       33,  34,  35,  35,  37,  39,  41,  43,  44,  45,  47,  47,  46,  46,  45,  46,  46,  47,  47,  49,  49,  51,  51,  53,  54,  56,  57,  57,  58,  59,  60,  61,
+       35,  37,  37,  38,  39,  41,  44,  46,  46,  46,  47,  47,  46,  46,  45,  46,  46,  47,  47,  48,  48,  50,  51,  52,  53,  55,  56,  56,  57,  58,  59,  61,
+       36,  38,  39,  40,  41,  43,  46,  47,  47,  47,  48,  47,  46,  46,  45,  46,  46,  46,  47,  48,  48,  50,  50,  52,  53,  54,  55,  55,  56,  57,  58,  58,
+       40,  41,  41,  42,  43,  44,  46,  47,  48,  48,  50,  49,  49,  49,  48,  49,  49,  49,  49,  51,  51,  52,  53,  54,  55,  57,  57,  58,  59,  59,  59,  59,
+       41,  42,  42,  42,  43,  45,  46,  47,  48,  48,  50,  50,  49,  49,  49,  49,  50,  50,  50,  52,  52,  53,  53,  55,  56,  57,  58,  58,  59,  60,  61,  62,
+       49,  47,  47,  46,  46,  47,  47,  48,  50,  50,  53,  53,  53,  53,  53,  54,  54,  54,  54,  55,  55,  56,  57,  58,  59,  60,  61,  61,  62,  62,  62,  62,
+       49,  47,  47,  46,  46,  47,  47,  47,  49,  50,  53,  53,  53,  53,  54,  54,  54,  54,  54,  55,  56,  57,  57,  59,  59,  61,  61,  62,  63,  63,  64,  65,
+       48,  47,  47,  45,  46,  46,  46,  46,  49,  49,  53,  53,  54,  54,  55,  56,  56,  57,  57,  58,  58,  60,  60,  61,  62,  63,  64,  64,  65,  66,  65,  65,
+       48,  47,  46,  45,  45,  46,  46,  46,  49,  49,  53,  53,  54,  55,  56,  57,  57,  58,  58,  59,  60,  61,  61,  63,  63,  65,  65,  65,  66,  66,  67,  68,
+       49,  47,  46,  45,  45,  45,  45,  45,  48,  49,  53,  54,  55,  56,  58,  59,  60,  61,  61,  62,  62,  63,  64,  65,  66,  67,  68,  68,  69,  70,  69,  68,
+       50,  47,  47,  45,  46,  46,  46,  46,  49,  49,  54,  54,  56,  57,  59,  60,  60,  62,  62,  63,  64,  65,  65,  67,  68,  69,  69,  70,  70,  70,  71,  71,
+       50,  48,  48,  46,  46,  46,  46,  46,  49,  50,  54,  54,  56,  57,  60,  60,  61,  63,  63,  65,  65,  67,  67,  68,  69,  71,  71,  71,  72,  73,  72,  71,
+       52,  49,  49,  47,  47,  47,  47,  46,  49,  50,  54,  54,  57,  58,  61,  62,  63,  65,  65,  67,  67,  69,  70,  71,  72,  73,  74,  74,  75,  74,  74,  75,
+       52,  50,  49,  47,  47,  47,  47,  47,  49,  50,  54,  54,  57,  58,  61,  62,  63,  65,  66,  68,  68,  70,  71,  72,  73,  75,  75,  75,  76,  77,  77,  75,
+       54,  52,  51,  49,  49,  49,  48,  48,  51,  52,  55,  55,  58,  59,  62,  63,  65,  67,  68,  70,  70,  73,  73,  75,  76,  78,  78,  78,  79,  78,  78,  79,
+       54,  52,  51,  49,  49,  49,  48,  48,  51,  52,  55,  56,  58,  60,  62,  64,  65,  67,  68,  70,  71,  73,  74,  75,  76,  78,  78,  79,  80,  81,  81,  79,
+       57,  54,  54,  52,  51,  51,  50,  50,  52,  53,  56,  57,  60,  61,  63,  65,  67,  69,  70,  73,  73,  76,  77,  79,  80,  82,  82,  83,  84,  83,  82,  83,
+       57,  55,  54,  52,  52,  51,  51,  50,  53,  53,  57,  57,  60,  61,  64,  65,  67,  70,  71,  73,  74,  77,  77,  79,  80,  82,  83,  83,  84,  85,  85,  83,
+       60,  57,  56,  54,  54,  53,  52,  52,  54,  55,  58,  59,  61,  63,  65,  67,  68,  71,  72,  75,  75,  79,  79,  82,  83,  85,  86,  86,  87,  87,  86,  87,
+       61,  58,  57,  55,  55,  54,  53,  53,  55,  56,  59,  59,  62,  63,  66,  68,  69,  72,  73,  76,  76,  80,  80,  83,  84,  86,  87,  88,  89,  89,  89,  87,
+       63,  60,  59,  57,  57,  56,  55,  54,  57,  57,  60,  61,  63,  65,  67,  69,  71,  73,  75,  78,  78,  82,  82,  85,  86,  89,  89,  90,  91,  92,  90,  91,
+       64,  61,  60,  58,  57,  57,  56,  55,  57,  58,  61,  61,  64,  65,  68,  69,  71,  74,  75,  78,  78,  82,  83,  86,  87,  89,  90,  91,  92,  93,  94,  91,
+       65,  61,  61,  58,  58,  57,  56,  55,  58,  58,  61,  62,  64,  65,  68,  70,  71,  74,  75,  78,  79,  83,  83,  86,  88,  90,  91,  91,  93,  94,  94,  96,
+       66,  63,  62,  60,  59,  58,  57,  56,  59,  59,  62,  63,  65,  66,  69,  70,  72,  75,  76,  79,  80,  84,  84,  87,  89,  91,  92,  93,  94,  94,  96,  96,
+       67,  64,  63,  61,  60,  59,  58,  57,  59,  60,  62,  63,  66,  66,  70,  70,  73,  74,  77,  78,  81,  83,  85,  87,  89,  92,  93,  94,  94,  96,  96,  97,
+       68,  64,  64,  61,  61,  60,  59,  58,  59,  61,  62,  64,  65,  67,  69,  71,  72,  74,  77,  78,  81,  82,  85,  86,  89,  90,  94,  94,  96,  96,  98,  97,
+       69,  65,  65,  62,  62,  61,  61,  58,  59,  62,  62,  65,  65,  68,  68,  71,  71,  75,  75,  79,  79,  83,  83,  87,  87,  91,  91,  96,  96,  97,  97,  99,
+    ],
+  ],
+  // Level 5
+  [
+    // Luma
+    [
+      // 4x4
+       32,  34,  49,  72,
+       34,  48,  60,  79,
+       49,  60,  82, 104,
+       72,  79, 104, 134,
+      // 8x8
+       32,  32,  34,  38,  46,  56,  68,  78,
+       32,  33,  35,  39,  45,  54,  64,  74,
+       34,  35,  39,  45,  51,  58,  68,  76,
+       38,  39,  45,  54,  61,  69,  78,  86,
+       46,  45,  51,  61,  71,  80,  90,  99,
+       56,  54,  58,  69,  80,  92, 103, 113,
+       68,  64,  68,  78,  90, 103, 117, 128,
+       78,  74,  76,  86,  99, 113, 128, 140,
+      // 16x16
+       32,  31,  31,  31,  32,  34,  36,  39,  44,  48,  54,  59,  65,  71,  80,  83,
+       31,  32,  32,  32,  32,  34,  35,  38,  42,  46,  51,  56,  62,  68,  76,  78,
+       31,  32,  32,  32,  32,  33,  34,  37,  41,  44,  49,  54,  59,  65,  72,  75,
+       31,  32,  32,  33,  34,  35,  36,  39,  42,  45,  50,  54,  59,  64,  71,  74,
+       32,  32,  32,  34,  35,  37,  38,  40,  42,  46,  49,  53,  58,  63,  69,  72,
+       34,  34,  33,  35,  37,  39,  42,  45,  47,  51,  54,  58,  63,  68,  74,  76,
+       36,  35,  34,  36,  38,  42,  48,  50,  54,  57,  60,  64,  68,  73,  79,  81,
+       39,  38,  37,  39,  40,  45,  50,  54,  58,  61,  65,  69,  73,  78,  84,  86,
+       44,  42,  41,  42,  42,  47,  54,  58,  63,  67,  71,  75,  79,  84,  90,  92,
+       48,  46,  44,  45,  46,  51,  57,  61,  67,  71,  76,  80,  85,  90,  96,  99,
+       54,  51,  49,  50,  49,  54,  60,  65,  71,  76,  82,  87,  92,  97, 104, 106,
+       59,  56,  54,  54,  53,  58,  64,  69,  75,  80,  87,  92,  98, 103, 110, 113,
+       65,  62,  59,  59,  58,  63,  68,  73,  79,  85,  92,  98, 105, 111, 118, 121,
+       71,  68,  65,  64,  63,  68,  73,  78,  84,  90,  97, 103, 111, 117, 125, 128,
+       80,  76,  72,  71,  69,  74,  79,  84,  90,  96, 104, 110, 118, 125, 134, 137,
+       83,  78,  75,  74,  72,  76,  81,  86,  92,  99, 106, 113, 121, 128, 137, 140,
+      // 32x32
+       32,  31,  31,  31,  31,  31,  31,  32,  32,  34,  34,  36,  36,  39,  39,  44,  44,  48,  48,  54,  54,  59,  59,  65,  65,  71,  71,  80,  80,  83,  83,  87,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  34,  34,  35,  35,  38,  38,  42,  42,  46,  46,  51,  51,  56,  56,  62,  62,  68,  68,  76,  76,  78,  78,  83,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  34,  34,  35,  35,  38,  38,  42,  42,  46,  46,  51,  51,  56,  56,  62,  62,  68,  68,  76,  76,  78,  78,  83,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  34,  34,  37,  37,  41,  41,  44,  44,  49,  49,  54,  54,  59,  59,  65,  65,  72,  72,  75,  75,  79,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  34,  34,  37,  37,  41,  41,  44,  44,  49,  49,  54,  54,  59,  59,  65,  65,  72,  72,  75,  75,  79,
+       31,  32,  32,  32,  32,  33,  33,  34,  34,  35,  35,  36,  36,  39,  39,  42,  42,  45,  45,  50,  50,  54,  54,  59,  59,  64,  64,  71,  71,  74,  74,  77,
+       31,  32,  32,  32,  32,  33,  33,  34,  34,  35,  35,  36,  36,  39,  39,  42,  42,  45,  45,  50,  50,  54,  54,  59,  59,  64,  64,  71,  71,  74,  74,  77,
+       32,  32,  32,  32,  32,  34,  34,  35,  35,  37,  37,  38,  38,  40,  40,  42,  42,  46,  46,  49,  49,  53,  53,  58,  58,  63,  63,  69,  69,  72,  72,  75,
+       32,  32,  32,  32,  32,  34,  34,  35,  35,  37,  37,  38,  38,  40,  40,  42,  42,  46,  46,  49,  49,  53,  53,  58,  58,  63,  63,  69,  69,  72,  72,  75,
+       34,  34,  34,  33,  33,  35,  35,  37,  37,  39,  39,  42,  42,  45,  45,  47,  47,  51,  51,  54,  54,  58,  58,  63,  63,  68,  68,  74,  74,  76,  76,  80,
+       34,  34,  34,  33,  33,  35,  35,  37,  37,  39,  39,  42,  42,  45,  45,  47,  47,  51,  51,  54,  54,  58,  58,  63,  63,  68,  68,  74,  74,  76,  76,  80,
+       36,  35,  35,  34,  34,  36,  36,  38,  38,  42,  42,  48,  48,  50,  50,  54,  54,  57,  57,  60,  60,  64,  64,  68,  68,  73,  73,  79,  79,  81,  81,  84,
+       36,  35,  35,  34,  34,  36,  36,  38,  38,  42,  42,  48,  48,  50,  50,  54,  54,  57,  57,  60,  60,  64,  64,  68,  68,  73,  73,  79,  79,  81,  81,  84,
+       39,  38,  38,  37,  37,  39,  39,  40,  40,  45,  45,  50,  50,  54,  54,  58,  58,  61,  61,  65,  65,  69,  69,  73,  73,  78,  78,  84,  84,  86,  86,  90,
+       39,  38,  38,  37,  37,  39,  39,  40,  40,  45,  45,  50,  50,  54,  54,  58,  58,  61,  61,  65,  65,  69,  69,  73,  73,  78,  78,  84,  84,  86,  86,  90,
+       44,  42,  42,  41,  41,  42,  42,  42,  42,  47,  47,  54,  54,  58,  58,  63,  63,  67,  67,  71,  71,  75,  75,  79,  79,  84,  84,  90,  90,  92,  92,  96,
+       44,  42,  42,  41,  41,  42,  42,  42,  42,  47,  47,  54,  54,  58,  58,  63,  63,  67,  67,  71,  71,  75,  75,  79,  79,  84,  84,  90,  90,  92,  92,  96,
+       48,  46,  46,  44,  44,  45,  45,  46,  46,  51,  51,  57,  57,  61,  61,  67,  67,  71,  71,  76,  76,  80,  80,  85,  85,  90,  90,  96,  96,  99,  99, 102,
+       48,  46,  46,  44,  44,  45,  45,  46,  46,  51,  51,  57,  57,  61,  61,  67,  67,  71,  71,  76,  76,  80,  80,  85,  85,  90,  90,  96,  96,  99,  99, 102,
+       54,  51,  51,  49,  49,  50,  50,  49,  49,  54,  54,  60,  60,  65,  65,  71,  71,  76,  76,  82,  82,  87,  87,  92,  92,  97,  97, 104, 104, 106, 106, 109,
+       54,  51,  51,  49,  49,  50,  50,  49,  49,  54,  54,  60,  60,  65,  65,  71,  71,  76,  76,  82,  82,  87,  87,  92,  92,  97,  97, 104, 104, 106, 106, 109,
+       59,  56,  56,  54,  54,  54,  54,  53,  53,  58,  58,  64,  64,  69,  69,  75,  75,  80,  80,  87,  87,  92,  92,  98,  98, 103, 103, 110, 110, 113, 113, 116,
+       59,  56,  56,  54,  54,  54,  54,  53,  53,  58,  58,  64,  64,  69,  69,  75,  75,  80,  80,  87,  87,  92,  92,  98,  98, 103, 103, 110, 110, 113, 113, 116,
+       65,  62,  62,  59,  59,  59,  59,  58,  58,  63,  63,  68,  68,  73,  73,  79,  79,  85,  85,  92,  92,  98,  98, 105, 105, 111, 111, 118, 118, 121, 121, 124,
+       65,  62,  62,  59,  59,  59,  59,  58,  58,  63,  63,  68,  68,  73,  73,  79,  79,  85,  85,  92,  92,  98,  98, 105, 105, 111, 111, 118, 118, 121, 121, 124,
+       71,  68,  68,  65,  65,  64,  64,  63,  63,  68,  68,  73,  73,  78,  78,  84,  84,  90,  90,  97,  97, 103, 103, 111, 111, 117, 117, 125, 125, 128, 128, 132,
+       71,  68,  68,  65,  65,  64,  64,  63,  63,  68,  68,  73,  73,  78,  78,  84,  84,  90,  90,  97,  97, 103, 103, 111, 111, 117, 117, 125, 125, 128, 128, 132,
+       80,  76,  76,  72,  72,  71,  71,  69,  69,  74,  74,  79,  79,  84,  84,  90,  90,  96,  96, 104, 104, 110, 110, 118, 118, 125, 125, 134, 134, 137, 137, 141,
+       80,  76,  76,  72,  72,  71,  71,  69,  69,  74,  74,  79,  79,  84,  84,  90,  90,  96,  96, 104, 104, 110, 110, 118, 118, 125, 125, 134, 134, 137, 137, 141,
+       83,  78,  78,  75,  75,  74,  74,  72,  72,  76,  76,  81,  81,  86,  86,  92,  92,  99,  99, 106, 106, 113, 113, 121, 121, 128, 128, 137, 137, 140, 140, 144,
+       83,  78,  78,  75,  75,  74,  74,  72,  72,  76,  76,  81,  81,  86,  86,  92,  92,  99,  99, 106, 106, 113, 113, 121, 121, 128, 128, 137, 137, 140, 140, 144,
+       87,  83,  83,  79,  79,  77,  77,  75,  75,  80,  80,  84,  84,  90,  90,  96,  96, 102, 102, 109, 109, 116, 116, 124, 124, 132, 132, 141, 141, 144, 144, 149,
+    ],
+    // Chroma
+    [
+      // 4x4
+       32,  46,  47,  57,
+       46,  53,  54,  60,
+       47,  54,  66,  75,
+       57,  60,  75,  89,
+      // 8x8
+       31,  34,  42,  47,  48,  52,  57,  61,
+       34,  39,  45,  46,  46,  49,  53,  57,
+       42,  45,  48,  49,  50,  52,  55,  58,
+       47,  46,  49,  54,  56,  58,  61,  64,
+       48,  46,  50,  56,  61,  65,  68,  71,
+       52,  49,  52,  58,  65,  71,  75,  79,
+       57,  53,  55,  61,  68,  75,  82,  86,
+       61,  57,  58,  64,  71,  79,  86,  91,
+      // 16x16
+       32,  31,  30,  33,  36,  41,  49,  48,  49,  50,  52,  54,  57,  60,  63,  65,
+       31,  31,  31,  34,  38,  42,  47,  47,  47,  48,  50,  52,  54,  57,  60,  61,
+       30,  31,  32,  35,  40,  42,  46,  45,  45,  46,  47,  49,  52,  54,  57,  58,
+       33,  34,  35,  39,  43,  45,  47,  46,  45,  46,  47,  49,  51,  53,  56,  57,
+       36,  38,  40,  43,  47,  47,  48,  46,  45,  46,  47,  48,  50,  52,  54,  55,
+       41,  42,  42,  45,  47,  48,  50,  49,  49,  50,  50,  52,  53,  55,  57,  58,
+       49,  47,  46,  47,  48,  50,  53,  53,  53,  54,  54,  55,  56,  58,  60,  61,
+       48,  47,  45,  46,  46,  49,  53,  54,  55,  56,  57,  58,  60,  61,  63,  64,
+       49,  47,  45,  45,  45,  49,  53,  55,  58,  60,  61,  62,  63,  65,  67,  68,
+       50,  48,  46,  46,  46,  50,  54,  56,  60,  61,  63,  65,  67,  68,  71,  71,
+       52,  50,  47,  47,  47,  50,  54,  57,  61,  63,  66,  68,  70,  72,  75,  75,
+       54,  52,  49,  49,  48,  52,  55,  58,  62,  65,  68,  71,  73,  75,  78,  79,
+       57,  54,  52,  51,  50,  53,  56,  60,  63,  67,  70,  73,  76,  79,  82,  83,
+       60,  57,  54,  53,  52,  55,  58,  61,  65,  68,  72,  75,  79,  82,  85,  86,
+       63,  60,  57,  56,  54,  57,  60,  63,  67,  71,  75,  78,  82,  85,  89,  90,
+       65,  61,  58,  57,  55,  58,  61,  64,  68,  71,  75,  79,  83,  86,  90,  91,
+      // 32x32
+       32,  31,  31,  30,  30,  33,  33,  36,  36,  41,  41,  49,  49,  48,  48,  49,  49,  50,  50,  52,  52,  54,  54,  57,  57,  60,  60,  63,  63,  65,  65,  67,
+       31,  31,  31,  31,  31,  34,  34,  38,  38,  42,  42,  47,  47,  47,  47,  47,  47,  48,  48,  50,  50,  52,  52,  54,  54,  57,  57,  60,  60,  61,  61,  63,
+       31,  31,  31,  31,  31,  34,  34,  38,  38,  42,  42,  47,  47,  47,  47,  47,  47,  48,  48,  50,  50,  52,  52,  54,  54,  57,  57,  60,  60,  61,  61,  63,
+       30,  31,  31,  32,  32,  35,  35,  40,  40,  42,  42,  46,  46,  45,  45,  45,  45,  46,  46,  47,  47,  49,  49,  52,  52,  54,  54,  57,  57,  58,  58,  60,
+       30,  31,  31,  32,  32,  35,  35,  40,  40,  42,  42,  46,  46,  45,  45,  45,  45,  46,  46,  47,  47,  49,  49,  52,  52,  54,  54,  57,  57,  58,  58,  60,
+       33,  34,  34,  35,  35,  39,  39,  43,  43,  45,  45,  47,  47,  46,  46,  45,  45,  46,  46,  47,  47,  49,  49,  51,  51,  53,  53,  56,  56,  57,  57,  59,
+       33,  34,  34,  35,  35,  39,  39,  43,  43,  45,  45,  47,  47,  46,  46,  45,  45,  46,  46,  47,  47,  49,  49,  51,  51,  53,  53,  56,  56,  57,  57,  59,
+       36,  38,  38,  40,  40,  43,  43,  47,  47,  47,  47,  48,  48,  46,  46,  45,  45,  46,  46,  47,  47,  48,  48,  50,  50,  52,  52,  54,  54,  55,  55,  57,
+       36,  38,  38,  40,  40,  43,  43,  47,  47,  47,  47,  48,  48,  46,  46,  45,  45,  46,  46,  47,  47,  48,  48,  50,  50,  52,  52,  54,  54,  55,  55,  57,
+       41,  42,  42,  42,  42,  45,  45,  47,  47,  48,  48,  50,  50,  49,  49,  49,  49,  50,  50,  50,  50,  52,  52,  53,  53,  55,  55,  57,  57,  58,  58,  60,
+       41,  42,  42,  42,  42,  45,  45,  47,  47,  48,  48,  50,  50,  49,  49,  49,  49,  50,  50,  50,  50,  52,  52,  53,  53,  55,  55,  57,  57,  58,  58,  60,
+       49,  47,  47,  46,  46,  47,  47,  48,  48,  50,  50,  53,  53,  53,  53,  53,  53,  54,  54,  54,  54,  55,  55,  56,  56,  58,  58,  60,  60,  61,  61,  62,
+       49,  47,  47,  46,  46,  47,  47,  48,  48,  50,  50,  53,  53,  53,  53,  53,  53,  54,  54,  54,  54,  55,  55,  56,  56,  58,  58,  60,  60,  61,  61,  62,
+       48,  47,  47,  45,  45,  46,  46,  46,  46,  49,  49,  53,  53,  54,  54,  55,  55,  56,  56,  57,  57,  58,  58,  60,  60,  61,  61,  63,  63,  64,  64,  66,
+       48,  47,  47,  45,  45,  46,  46,  46,  46,  49,  49,  53,  53,  54,  54,  55,  55,  56,  56,  57,  57,  58,  58,  60,  60,  61,  61,  63,  63,  64,  64,  66,
+       49,  47,  47,  45,  45,  45,  45,  45,  45,  49,  49,  53,  53,  55,  55,  58,  58,  60,  60,  61,  61,  62,  62,  63,  63,  65,  65,  67,  67,  68,  68,  69,
+       49,  47,  47,  45,  45,  45,  45,  45,  45,  49,  49,  53,  53,  55,  55,  58,  58,  60,  60,  61,  61,  62,  62,  63,  63,  65,  65,  67,  67,  68,  68,  69,
+       50,  48,  48,  46,  46,  46,  46,  46,  46,  50,  50,  54,  54,  56,  56,  60,  60,  61,  61,  63,  63,  65,  65,  67,  67,  68,  68,  71,  71,  71,  71,  72,
+       50,  48,  48,  46,  46,  46,  46,  46,  46,  50,  50,  54,  54,  56,  56,  60,  60,  61,  61,  63,  63,  65,  65,  67,  67,  68,  68,  71,  71,  71,  71,  72,
+       52,  50,  50,  47,  47,  47,  47,  47,  47,  50,  50,  54,  54,  57,  57,  61,  61,  63,  63,  66,  66,  68,  68,  70,  70,  72,  72,  75,  75,  75,  75,  76,
+       52,  50,  50,  47,  47,  47,  47,  47,  47,  50,  50,  54,  54,  57,  57,  61,  61,  63,  63,  66,  66,  68,  68,  70,  70,  72,  72,  75,  75,  75,  75,  76,
+       54,  52,  52,  49,  49,  49,  49,  48,  48,  52,  52,  55,  55,  58,  58,  62,  62,  65,  65,  68,  68,  71,  71,  73,  73,  75,  75,  78,  78,  79,  79,  80,
+       54,  52,  52,  49,  49,  49,  49,  48,  48,  52,  52,  55,  55,  58,  58,  62,  62,  65,  65,  68,  68,  71,  71,  73,  73,  75,  75,  78,  78,  79,  79,  80,
+       57,  54,  54,  52,  52,  51,  51,  50,  50,  53,  53,  56,  56,  60,  60,  63,  63,  67,  67,  70,  70,  73,  73,  76,  76,  79,  79,  82,  82,  83,  83,  84,
+       57,  54,  54,  52,  52,  51,  51,  50,  50,  53,  53,  56,  56,  60,  60,  63,  63,  67,  67,  70,  70,  73,  73,  76,  76,  79,  79,  82,  82,  83,  83,  84,
+       60,  57,  57,  54,  54,  53,  53,  52,  52,  55,  55,  58,  58,  61,  61,  65,  65,  68,  68,  72,  72,  75,  75,  79,  79,  82,  82,  85,  85,  86,  86,  88,
+       60,  57,  57,  54,  54,  53,  53,  52,  52,  55,  55,  58,  58,  61,  61,  65,  65,  68,  68,  72,  72,  75,  75,  79,  79,  82,  82,  85,  85,  86,  86,  88,
+       63,  60,  60,  57,  57,  56,  56,  54,  54,  57,  57,  60,  60,  63,  63,  67,  67,  71,  71,  75,  75,  78,  78,  82,  82,  85,  85,  89,  89,  90,  90,  92,
+       63,  60,  60,  57,  57,  56,  56,  54,  54,  57,  57,  60,  60,  63,  63,  67,  67,  71,  71,  75,  75,  78,  78,  82,  82,  85,  85,  89,  89,  90,  90,  92,
+       65,  61,  61,  58,  58,  57,  57,  55,  55,  58,  58,  61,  61,  64,  64,  68,  68,  71,  71,  75,  75,  79,  79,  83,  83,  86,  86,  90,  90,  91,  91,  93,
+       65,  61,  61,  58,  58,  57,  57,  55,  55,  58,  58,  61,  61,  64,  64,  68,  68,  71,  71,  75,  75,  79,  79,  83,  83,  86,  86,  90,  90,  91,  91,  93,
+       67,  63,  63,  60,  60,  59,  59,  57,  57,  60,  60,  62,  62,  66,  66,  69,  69,  72,  72,  76,  76,  80,  80,  84,  84,  88,  88,  92,  92,  93,  93,  95,
+    ],
+  ],
+  // Level 6
+  [
+    // Luma
+    [
+      // 4x4
+       32,  33,  45,  62,
+       33,  39,  51,  64,
+       45,  51,  71,  87,
+       62,  64,  87, 108,
+      // 8x8
+       31,  32,  32,  35,  42,  51,  59,  69,
+       32,  32,  33,  35,  41,  49,  56,  65,
+       32,  33,  35,  38,  43,  49,  56,  64,
+       35,  35,  38,  48,  54,  59,  66,  73,
+       42,  41,  43,  54,  63,  71,  77,  85,
+       51,  49,  49,  59,  71,  81,  89,  97,
+       59,  56,  56,  66,  77,  89,  98, 108,
+       69,  65,  64,  73,  85,  97, 108, 119,
+      // 16x16
+       32,  31,  31,  31,  32,  34,  35,  38,  41,  45,  48,  54,  59,  65,  71,  80,
+       31,  32,  32,  32,  32,  34,  35,  37,  40,  43,  46,  51,  56,  62,  68,  76,
+       31,  32,  32,  32,  32,  33,  34,  36,  38,  41,  44,  49,  54,  59,  65,  72,
+       31,  32,  32,  33,  34,  35,  36,  38,  40,  42,  45,  50,  54,  59,  64,  71,
+       32,  32,  32,  34,  35,  37,  38,  39,  41,  43,  46,  49,  53,  58,  63,  69,
+       34,  34,  33,  35,  37,  39,  42,  44,  46,  48,  51,  54,  58,  63,  68,  74,
+       35,  35,  34,  36,  38,  42,  46,  48,  50,  53,  55,  59,  62,  67,  72,  78,
+       38,  37,  36,  38,  39,  44,  48,  51,  54,  57,  59,  63,  67,  71,  76,  82,
+       41,  40,  38,  40,  41,  46,  50,  54,  57,  60,  63,  67,  71,  75,  80,  86,
+       45,  43,  41,  42,  43,  48,  53,  57,  60,  65,  68,  72,  76,  81,  85,  91,
+       48,  46,  44,  45,  46,  51,  55,  59,  63,  68,  71,  76,  80,  85,  90,  96,
+       54,  51,  49,  50,  49,  54,  59,  63,  67,  72,  76,  82,  87,  92,  97, 104,
+       59,  56,  54,  54,  53,  58,  62,  67,  71,  76,  80,  87,  92,  98, 103, 110,
+       65,  62,  59,  59,  58,  63,  67,  71,  75,  81,  85,  92,  98, 105, 111, 118,
+       71,  68,  65,  64,  63,  68,  72,  76,  80,  85,  90,  97, 103, 111, 117, 125,
+       80,  76,  72,  71,  69,  74,  78,  82,  86,  91,  96, 104, 110, 118, 125, 134,
+      // 32x32
+       32,  31,  31,  31,  31,  31,  31,  32,  32,  32,  34,  34,  35,  36,  38,  39,  41,  44,  45,  48,  48,  53,  54,  57,  59,  62,  65,  67,  71,  72,  80,  80,
+       31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  34,  34,  35,  35,  37,  38,  40,  42,  43,  46,  46,  51,  52,  55,  56,  59,  62,  64,  68,  69,  76,  76,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  34,  34,  35,  35,  37,  38,  40,  42,  43,  46,  46,  51,  51,  55,  56,  59,  62,  64,  68,  69,  76,  76,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  34,  34,  36,  38,  39,  41,  42,  45,  45,  49,  50,  53,  54,  57,  60,  62,  66,  66,  73,  73,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  34,  34,  36,  37,  38,  41,  41,  44,  44,  49,  49,  52,  54,  56,  59,  61,  65,  65,  72,  72,
+       31,  32,  32,  32,  32,  32,  33,  33,  33,  33,  34,  34,  35,  35,  37,  38,  39,  41,  42,  45,  45,  49,  49,  52,  54,  56,  59,  61,  64,  65,  72,  72,
+       31,  32,  32,  32,  32,  33,  33,  33,  34,  34,  35,  35,  36,  36,  38,  39,  40,  42,  42,  45,  45,  49,  50,  52,  54,  56,  59,  60,  64,  65,  71,  71,
+       32,  32,  32,  32,  32,  33,  33,  34,  34,  34,  35,  35,  36,  37,  38,  39,  40,  42,  43,  45,  45,  49,  49,  52,  54,  56,  59,  60,  64,  64,  70,  70,
+       32,  32,  32,  32,  32,  33,  34,  34,  35,  35,  37,  37,  38,  38,  39,  40,  41,  42,  43,  46,  46,  49,  49,  52,  53,  55,  58,  59,  63,  63,  69,  69,
+       32,  32,  32,  32,  33,  33,  34,  34,  35,  35,  37,  37,  38,  38,  40,  41,  41,  43,  43,  46,  46,  49,  50,  52,  54,  56,  58,  60,  63,  64,  70,  70,
+       34,  34,  34,  33,  33,  34,  35,  35,  37,  37,  39,  39,  42,  42,  44,  45,  46,  47,  48,  51,  51,  54,  54,  57,  58,  60,  63,  64,  68,  68,  74,  74,
+       34,  34,  34,  33,  33,  34,  35,  35,  37,  37,  39,  39,  42,  42,  44,  45,  46,  47,  48,  51,  51,  54,  54,  57,  58,  60,  63,  64,  68,  68,  74,  74,
+       35,  35,  35,  34,  34,  35,  36,  36,  38,  38,  42,  42,  46,  47,  48,  49,  50,  52,  53,  55,  55,  58,  59,  61,  62,  64,  67,  68,  72,  72,  78,  78,
+       36,  35,  35,  34,  34,  35,  36,  37,  38,  38,  42,  42,  47,  48,  50,  50,  52,  54,  54,  57,  57,  59,  60,  62,  64,  66,  68,  69,  73,  73,  79,  79,
+       38,  37,  37,  36,  36,  37,  38,  38,  39,  40,  44,  44,  48,  50,  51,  52,  54,  56,  57,  59,  59,  62,  63,  65,  67,  69,  71,  72,  76,  76,  82,  82,
+       39,  38,  38,  38,  37,  38,  39,  39,  40,  41,  45,  45,  49,  50,  52,  54,  55,  58,  58,  61,  61,  64,  65,  67,  69,  71,  73,  74,  78,  78,  84,  84,
+       41,  40,  40,  39,  38,  39,  40,  40,  41,  41,  46,  46,  50,  52,  54,  55,  57,  60,  60,  63,  63,  67,  67,  70,  71,  73,  75,  77,  80,  81,  86,  86,
+       44,  42,  42,  41,  41,  41,  42,  42,  42,  43,  47,  47,  52,  54,  56,  58,  60,  63,  64,  67,  67,  71,  71,  74,  75,  77,  79,  81,  84,  85,  90,  90,
+       45,  43,  43,  42,  41,  42,  42,  43,  43,  43,  48,  48,  53,  54,  57,  58,  60,  64,  65,  68,  68,  72,  72,  75,  76,  78,  81,  82,  85,  86,  91,  91,
+       48,  46,  46,  45,  44,  45,  45,  45,  46,  46,  51,  51,  55,  57,  59,  61,  63,  67,  68,  71,  71,  75,  76,  79,  80,  83,  85,  87,  90,  91,  96,  96,
+       48,  46,  46,  45,  44,  45,  45,  45,  46,  46,  51,  51,  55,  57,  59,  61,  63,  67,  68,  71,  71,  75,  76,  79,  80,  83,  85,  87,  90,  91,  96,  96,
+       53,  51,  51,  49,  49,  49,  49,  49,  49,  49,  54,  54,  58,  59,  62,  64,  67,  71,  72,  75,  75,  81,  81,  85,  86,  89,  91,  93,  97,  97, 103, 103,
+       54,  52,  51,  50,  49,  49,  50,  49,  49,  50,  54,  54,  59,  60,  63,  65,  67,  71,  72,  76,  76,  81,  82,  85,  87,  89,  92,  94,  97,  98, 104, 104,
+       57,  55,  55,  53,  52,  52,  52,  52,  52,  52,  57,  57,  61,  62,  65,  67,  70,  74,  75,  79,  79,  85,  85,  89,  90,  93,  96,  98, 102, 102, 108, 108,
+       59,  56,  56,  54,  54,  54,  54,  54,  53,  54,  58,  58,  62,  64,  67,  69,  71,  75,  76,  80,  80,  86,  87,  90,  92,  95,  98,  99, 103, 104, 110, 110,
+       62,  59,  59,  57,  56,  56,  56,  56,  55,  56,  60,  60,  64,  66,  69,  71,  73,  77,  78,  83,  83,  89,  89,  93,  95,  98, 101, 103, 107, 108, 114, 114,
+       65,  62,  62,  60,  59,  59,  59,  59,  58,  58,  63,  63,  67,  68,  71,  73,  75,  79,  81,  85,  85,  91,  92,  96,  98, 101, 105, 106, 111, 111, 118, 118,
+       67,  64,  64,  62,  61,  61,  60,  60,  59,  60,  64,  64,  68,  69,  72,  74,  77,  81,  82,  87,  87,  93,  94,  98,  99, 103, 106, 108, 113, 113, 120, 120,
+       71,  68,  68,  66,  65,  64,  64,  64,  63,  63,  68,  68,  72,  73,  76,  78,  80,  84,  85,  90,  90,  97,  97, 102, 103, 107, 111, 113, 117, 118, 125, 125,
+       72,  69,  69,  66,  65,  65,  65,  64,  63,  64,  68,  68,  72,  73,  76,  78,  81,  85,  86,  91,  91,  97,  98, 102, 104, 108, 111, 113, 118, 119, 126, 126,
+       80,  76,  76,  73,  72,  72,  71,  70,  69,  70,  74,  74,  78,  79,  82,  84,  86,  90,  91,  96,  96, 103, 104, 108, 110, 114, 118, 120, 125, 126, 134, 134,
+       80,  76,  76,  73,  72,  72,  71,  70,  69,  70,  74,  74,  78,  79,  82,  84,  86,  90,  91,  96,  96, 103, 104, 108, 110, 114, 118, 120, 125, 126, 134, 134,
+    ],
+    // Chroma
+    [
+      // 4x4
+       31,  42,  47,  53,
+       42,  48,  50,  54,
+       47,  50,  61,  67,
+       53,  54,  67,  78,
+      // 8x8
+       31,  32,  38,  48,  47,  50,  53,  57,
+       32,  35,  42,  47,  45,  47,  50,  54,
+       38,  42,  47,  48,  45,  47,  49,  52,
+       48,  47,  48,  53,  53,  54,  56,  58,
+       47,  45,  45,  53,  58,  61,  63,  65,
+       50,  47,  47,  54,  61,  66,  69,  72,
+       53,  50,  49,  56,  63,  69,  73,  77,
+       57,  54,  52,  58,  65,  72,  77,  82,
+      // 16x16
+       32,  31,  30,  33,  36,  41,  47,  49,  49,  49,  50,  52,  54,  57,  60,  63,
+       31,  31,  31,  34,  38,  42,  46,  47,  47,  47,  48,  50,  52,  54,  57,  60,
+       30,  31,  32,  35,  40,  42,  45,  46,  45,  45,  46,  47,  49,  52,  54,  57,
+       33,  34,  35,  39,  43,  45,  47,  46,  46,  45,  46,  47,  49,  51,  53,  56,
+       36,  38,  40,  43,  47,  47,  47,  47,  46,  45,  46,  47,  48,  50,  52,  54,
+       41,  42,  42,  45,  47,  48,  50,  50,  49,  49,  50,  50,  52,  53,  55,  57,
+       47,  46,  45,  47,  47,  50,  52,  52,  52,  52,  53,  53,  55,  56,  58,  60,
+       49,  47,  46,  46,  47,  50,  52,  53,  54,  55,  55,  56,  57,  58,  60,  62,
+       49,  47,  45,  46,  46,  49,  52,  54,  55,  57,  58,  59,  60,  61,  63,  65,
+       49,  47,  45,  45,  45,  49,  52,  55,  57,  59,  60,  61,  63,  64,  66,  68,
+       50,  48,  46,  46,  46,  50,  53,  55,  58,  60,  61,  63,  65,  67,  68,  71,
+       52,  50,  47,  47,  47,  50,  53,  56,  59,  61,  63,  66,  68,  70,  72,  75,
+       54,  52,  49,  49,  48,  52,  55,  57,  60,  63,  65,  68,  71,  73,  75,  78,
+       57,  54,  52,  51,  50,  53,  56,  58,  61,  64,  67,  70,  73,  76,  79,  82,
+       60,  57,  54,  53,  52,  55,  58,  60,  63,  66,  68,  72,  75,  79,  82,  85,
+       63,  60,  57,  56,  54,  57,  60,  62,  65,  68,  71,  75,  78,  82,  85,  89,
+      // 32x32
+       32,  31,  31,  30,  30,  32,  33,  34,  36,  37,  41,  41,  47,  49,  49,  48,  49,  49,  49,  50,  50,  52,  52,  54,  54,  56,  57,  58,  60,  60,  63,  63,
+       31,  31,  31,  31,  31,  32,  34,  35,  38,  38,  42,  42,  46,  48,  47,  47,  47,  47,  47,  48,  48,  50,  50,  51,  52,  53,  54,  55,  57,  57,  60,  60,
+       31,  31,  31,  31,  31,  33,  34,  35,  38,  39,  42,  42,  46,  47,  47,  47,  47,  47,  47,  48,  48,  49,  50,  51,  52,  53,  54,  55,  57,  57,  60,  60,
+       30,  31,  31,  31,  31,  33,  35,  36,  39,  40,  42,  42,  46,  47,  46,  46,  46,  45,  46,  47,  47,  48,  48,  50,  50,  51,  52,  53,  55,  55,  58,  58,
+       30,  31,  31,  31,  32,  33,  35,  36,  40,  40,  42,  42,  45,  46,  46,  45,  45,  45,  45,  46,  46,  47,  47,  49,  49,  51,  52,  52,  54,  54,  57,  57,
+       32,  32,  33,  33,  33,  35,  37,  38,  41,  42,  43,  43,  46,  47,  46,  46,  45,  45,  45,  46,  46,  47,  47,  49,  49,  50,  51,  52,  54,  54,  57,  57,
+       33,  34,  34,  35,  35,  37,  39,  40,  43,  43,  45,  45,  47,  47,  46,  46,  46,  45,  45,  46,  46,  47,  47,  49,  49,  50,  51,  52,  53,  54,  56,  56,
+       34,  35,  35,  36,  36,  38,  40,  41,  44,  44,  45,  45,  47,  47,  47,  46,  46,  45,  45,  46,  46,  47,  47,  48,  49,  50,  51,  51,  53,  53,  55,  55,
+       36,  38,  38,  39,  40,  41,  43,  44,  47,  47,  47,  47,  47,  48,  47,  46,  46,  45,  45,  46,  46,  46,  47,  48,  48,  49,  50,  50,  52,  52,  54,  54,
+       37,  38,  39,  40,  40,  42,  43,  44,  47,  47,  47,  47,  48,  48,  47,  47,  46,  45,  46,  46,  46,  47,  47,  48,  48,  49,  50,  51,  52,  52,  55,  55,
+       41,  42,  42,  42,  42,  43,  45,  45,  47,  47,  48,  48,  50,  50,  50,  49,  49,  49,  49,  50,  50,  50,  50,  51,  52,  52,  53,  54,  55,  55,  57,  57,
+       41,  42,  42,  42,  42,  43,  45,  45,  47,  47,  48,  48,  50,  50,  50,  49,  49,  49,  49,  50,  50,  50,  50,  51,  52,  52,  53,  54,  55,  55,  57,  57,
+       47,  46,  46,  46,  45,  46,  47,  47,  47,  48,  50,  50,  52,  52,  52,  52,  52,  52,  52,  53,  53,  53,  53,  54,  55,  55,  56,  56,  58,  58,  60,  60,
+       49,  48,  47,  47,  46,  47,  47,  47,  48,  48,  50,  50,  52,  53,  53,  53,  53,  53,  53,  54,  54,  54,  54,  55,  55,  56,  56,  57,  58,  58,  60,  60,
+       49,  47,  47,  46,  46,  46,  46,  47,  47,  47,  50,  50,  52,  53,  53,  54,  54,  55,  55,  55,  55,  56,  56,  57,  57,  58,  58,  59,  60,  60,  62,  62,
+       48,  47,  47,  46,  45,  46,  46,  46,  46,  47,  49,  49,  52,  53,  54,  54,  55,  55,  56,  56,  56,  57,  57,  58,  58,  59,  60,  60,  61,  62,  63,  63,
+       49,  47,  47,  46,  45,  45,  46,  46,  46,  46,  49,  49,  52,  53,  54,  55,  55,  57,  57,  58,  58,  59,  59,  60,  60,  61,  61,  62,  63,  63,  65,  65,
+       49,  47,  47,  45,  45,  45,  45,  45,  45,  45,  49,  49,  52,  53,  55,  55,  57,  58,  59,  60,  60,  61,  61,  62,  62,  63,  63,  64,  65,  65,  67,  67,
+       49,  47,  47,  46,  45,  45,  45,  45,  45,  46,  49,  49,  52,  53,  55,  56,  57,  59,  59,  60,  60,  61,  61,  62,  63,  63,  64,  65,  66,  66,  68,  68,
+       50,  48,  48,  47,  46,  46,  46,  46,  46,  46,  50,  50,  53,  54,  55,  56,  58,  60,  60,  61,  61,  63,  63,  65,  65,  66,  67,  67,  68,  69,  71,  71,
+       50,  48,  48,  47,  46,  46,  46,  46,  46,  46,  50,  50,  53,  54,  55,  56,  58,  60,  60,  61,  61,  63,  63,  65,  65,  66,  67,  67,  68,  69,  71,  71,
+       52,  50,  49,  48,  47,  47,  47,  47,  46,  47,  50,  50,  53,  54,  56,  57,  59,  61,  61,  63,  63,  66,  66,  67,  68,  69,  70,  71,  72,  72,  74,  74,
+       52,  50,  50,  48,  47,  47,  47,  47,  47,  47,  50,  50,  53,  54,  56,  57,  59,  61,  61,  63,  63,  66,  66,  68,  68,  69,  70,  71,  72,  73,  75,  75,
+       54,  51,  51,  50,  49,  49,  49,  48,  48,  48,  51,  51,  54,  55,  57,  58,  60,  62,  62,  65,  65,  67,  68,  69,  70,  71,  72,  73,  74,  75,  77,  77,
+       54,  52,  52,  50,  49,  49,  49,  49,  48,  48,  52,  52,  55,  55,  57,  58,  60,  62,  63,  65,  65,  68,  68,  70,  71,  72,  73,  74,  75,  76,  78,  78,
+       56,  53,  53,  51,  51,  50,  50,  50,  49,  49,  52,  52,  55,  56,  58,  59,  61,  63,  63,  66,  66,  69,  69,  71,  72,  73,  75,  75,  77,  77,  80,  80,
+       57,  54,  54,  52,  52,  51,  51,  51,  50,  50,  53,  53,  56,  56,  58,  60,  61,  63,  64,  67,  67,  70,  70,  72,  73,  75,  76,  77,  79,  79,  82,  82,
+       58,  55,  55,  53,  52,  52,  52,  51,  50,  51,  54,  54,  56,  57,  59,  60,  62,  64,  65,  67,  67,  71,  71,  73,  74,  75,  77,  78,  80,  80,  83,  83,
+       60,  57,  57,  55,  54,  54,  53,  53,  52,  52,  55,  55,  58,  58,  60,  61,  63,  65,  66,  68,  68,  72,  72,  74,  75,  77,  79,  80,  82,  82,  85,  85,
+       60,  57,  57,  55,  54,  54,  54,  53,  52,  52,  55,  55,  58,  58,  60,  62,  63,  65,  66,  69,  69,  72,  73,  75,  76,  77,  79,  80,  82,  82,  85,  85,
+       63,  60,  60,  58,  57,  57,  56,  55,  54,  55,  57,  57,  60,  60,  62,  63,  65,  67,  68,  71,  71,  74,  75,  77,  78,  80,  82,  83,  85,  85,  89,  89,
+       63,  60,  60,  58,  57,  57,  56,  55,  54,  55,  57,  57,  60,  60,  62,  63,  65,  67,  68,  71,  71,  74,  75,  77,  78,  80,  82,  83,  85,  85,  89,  89,
+    ],
+  ],
+  // Level 7
+  [
+    // Luma
+    [
+      // 4x4
+       32,  33,  42,  55,
+       33,  38,  46,  57,
+       42,  46,  63,  75,
+       55,  57,  75,  92,
+      // 8x8
+       31,  32,  32,  34,  38,  46,  52,  63,
+       32,  32,  32,  34,  37,  44,  49,  59,
+       32,  32,  35,  37,  40,  45,  49,  58,
+       34,  34,  37,  42,  47,  52,  56,  65,
+       38,  37,  40,  47,  54,  60,  65,  73,
+       46,  44,  45,  52,  60,  69,  75,  84,
+       52,  49,  49,  56,  65,  75,  82,  92,
+       63,  59,  58,  65,  73,  84,  92, 105,
+      // 16x16
+       32,  31,  31,  31,  32,  32,  34,  36,  38,  41,  44,  48,  54,  58,  61,  65,
+       31,  32,  32,  32,  32,  32,  34,  35,  38,  40,  42,  46,  51,  55,  58,  62,
+       31,  32,  32,  32,  32,  32,  33,  34,  37,  38,  41,  44,  49,  53,  56,  59,
+       31,  32,  32,  33,  33,  33,  35,  36,  38,  40,  42,  45,  49,  53,  56,  59,
+       32,  32,  32,  33,  34,  34,  36,  37,  39,  40,  42,  45,  49,  53,  55,  59,
+       32,  32,  32,  33,  34,  35,  37,  38,  40,  41,  42,  46,  49,  52,  55,  58,
+       34,  34,  33,  35,  36,  37,  39,  42,  44,  46,  47,  51,  54,  57,  60,  63,
+       36,  35,  34,  36,  37,  38,  42,  48,  50,  52,  54,  57,  60,  63,  65,  68,
+       38,  38,  37,  38,  39,  40,  44,  50,  52,  54,  57,  60,  64,  67,  69,  72,
+       41,  40,  38,  40,  40,  41,  46,  52,  54,  57,  60,  63,  67,  70,  73,  75,
+       44,  42,  41,  42,  42,  42,  47,  54,  57,  60,  63,  67,  71,  74,  77,  79,
+       48,  46,  44,  45,  45,  46,  51,  57,  60,  63,  67,  71,  76,  79,  82,  85,
+       54,  51,  49,  49,  49,  49,  54,  60,  64,  67,  71,  76,  82,  86,  89,  92,
+       58,  55,  53,  53,  53,  52,  57,  63,  67,  70,  74,  79,  86,  90,  93,  97,
+       61,  58,  56,  56,  55,  55,  60,  65,  69,  73,  77,  82,  89,  93,  97, 101,
+       65,  62,  59,  59,  59,  58,  63,  68,  72,  75,  79,  85,  92,  97, 101, 105,
+      // 32x32
+       32,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  33,  34,  34,  36,  36,  38,  39,  41,  44,  44,  47,  48,  50,  54,  54,  58,  59,  61,  65,  65,  70,
+       31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  33,  34,  34,  35,  35,  38,  38,  40,  42,  42,  46,  47,  49,  52,  52,  56,  57,  59,  63,  63,  67,
+       31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  34,  34,  35,  35,  38,  38,  40,  42,  42,  45,  46,  48,  51,  51,  55,  56,  58,  62,  62,  67,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  34,  34,  35,  35,  37,  38,  39,  42,  42,  45,  45,  47,  50,  50,  54,  55,  57,  61,  61,  65,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  34,  34,  34,  37,  37,  38,  41,  41,  44,  44,  46,  49,  49,  53,  54,  56,  59,  59,  64,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  34,  34,  34,  37,  37,  38,  41,  41,  44,  44,  46,  49,  49,  53,  54,  56,  59,  59,  64,
+       31,  32,  32,  32,  32,  32,  33,  33,  33,  33,  33,  34,  35,  35,  36,  36,  38,  39,  40,  42,  42,  44,  45,  47,  49,  49,  53,  54,  56,  59,  59,  63,
+       31,  32,  32,  32,  32,  32,  33,  33,  33,  34,  34,  35,  35,  36,  36,  36,  38,  39,  40,  42,  42,  45,  45,  47,  50,  50,  53,  54,  56,  59,  59,  63,
+       32,  32,  32,  32,  32,  32,  33,  33,  34,  34,  34,  35,  36,  36,  37,  37,  39,  39,  40,  42,  42,  45,  45,  47,  49,  49,  53,  54,  55,  59,  59,  63,
+       32,  32,  32,  32,  32,  32,  33,  34,  34,  35,  35,  36,  37,  37,  38,  38,  40,  40,  41,  42,  42,  45,  46,  47,  49,  49,  52,  53,  55,  58,  58,  62,
+       32,  32,  32,  32,  32,  32,  33,  34,  34,  35,  35,  36,  37,  37,  38,  38,  40,  40,  41,  42,  42,  45,  46,  47,  49,  49,  52,  53,  55,  58,  58,  62,
+       33,  33,  33,  33,  33,  33,  34,  35,  35,  36,  36,  38,  39,  40,  42,  42,  43,  44,  45,  46,  46,  49,  50,  51,  53,  53,  56,  57,  59,  62,  62,  66,
+       34,  34,  34,  34,  33,  33,  35,  35,  36,  37,  37,  39,  39,  41,  42,  42,  44,  45,  46,  47,  47,  50,  51,  52,  54,  54,  57,  58,  60,  63,  63,  67,
+       34,  34,  34,  34,  34,  34,  35,  36,  36,  37,  37,  40,  41,  42,  45,  45,  46,  47,  48,  50,  50,  52,  53,  54,  56,  56,  59,  60,  62,  65,  65,  69,
+       36,  35,  35,  35,  34,  34,  36,  36,  37,  38,  38,  42,  42,  45,  48,  48,  50,  50,  52,  54,  54,  56,  57,  58,  60,  60,  63,  64,  65,  68,  68,  72,
+       36,  35,  35,  35,  34,  34,  36,  36,  37,  38,  38,  42,  42,  45,  48,  48,  50,  50,  52,  54,  54,  56,  57,  58,  60,  60,  63,  64,  65,  68,  68,  72,
+       38,  38,  38,  37,  37,  37,  38,  38,  39,  40,  40,  43,  44,  46,  50,  50,  52,  53,  54,  57,  57,  59,  60,  61,  64,  64,  67,  68,  69,  72,  72,  76,
+       39,  38,  38,  38,  37,  37,  39,  39,  39,  40,  40,  44,  45,  47,  50,  50,  53,  54,  55,  58,  58,  60,  61,  62,  65,  65,  68,  69,  70,  73,  73,  77,
+       41,  40,  40,  39,  38,  38,  40,  40,  40,  41,  41,  45,  46,  48,  52,  52,  54,  55,  57,  60,  60,  62,  63,  65,  67,  67,  70,  71,  73,  75,  75,  79,
+       44,  42,  42,  42,  41,  41,  42,  42,  42,  42,  42,  46,  47,  50,  54,  54,  57,  58,  60,  63,  63,  66,  67,  68,  71,  71,  74,  75,  77,  79,  79,  83,
+       44,  42,  42,  42,  41,  41,  42,  42,  42,  42,  42,  46,  47,  50,  54,  54,  57,  58,  60,  63,  63,  66,  67,  68,  71,  71,  74,  75,  77,  79,  79,  83,
+       47,  46,  45,  45,  44,  44,  44,  45,  45,  45,  45,  49,  50,  52,  56,  56,  59,  60,  62,  66,  66,  69,  70,  72,  75,  75,  78,  79,  81,  84,  84,  88,
+       48,  47,  46,  45,  44,  44,  45,  45,  45,  46,  46,  50,  51,  53,  57,  57,  60,  61,  63,  67,  67,  70,  71,  73,  76,  76,  79,  80,  82,  85,  85,  89,
+       50,  49,  48,  47,  46,  46,  47,  47,  47,  47,  47,  51,  52,  54,  58,  58,  61,  62,  65,  68,  68,  72,  73,  75,  78,  78,  82,  83,  85,  88,  88,  92,
+       54,  52,  51,  50,  49,  49,  49,  50,  49,  49,  49,  53,  54,  56,  60,  60,  64,  65,  67,  71,  71,  75,  76,  78,  82,  82,  86,  87,  89,  92,  92,  96,
+       54,  52,  51,  50,  49,  49,  49,  50,  49,  49,  49,  53,  54,  56,  60,  60,  64,  65,  67,  71,  71,  75,  76,  78,  82,  82,  86,  87,  89,  92,  92,  96,
+       58,  56,  55,  54,  53,  53,  53,  53,  53,  52,  52,  56,  57,  59,  63,  63,  67,  68,  70,  74,  74,  78,  79,  82,  86,  86,  90,  91,  93,  97,  97, 101,
+       59,  57,  56,  55,  54,  54,  54,  54,  54,  53,  53,  57,  58,  60,  64,  64,  68,  69,  71,  75,  75,  79,  80,  83,  87,  87,  91,  92,  94,  98,  98, 102,
+       61,  59,  58,  57,  56,  56,  56,  56,  55,  55,  55,  59,  60,  62,  65,  65,  69,  70,  73,  77,  77,  81,  82,  85,  89,  89,  93,  94,  97, 101, 101, 105,
+       65,  63,  62,  61,  59,  59,  59,  59,  59,  58,  58,  62,  63,  65,  68,  68,  72,  73,  75,  79,  79,  84,  85,  88,  92,  92,  97,  98, 101, 105, 105, 109,
+       65,  63,  62,  61,  59,  59,  59,  59,  59,  58,  58,  62,  63,  65,  68,  68,  72,  73,  75,  79,  79,  84,  85,  88,  92,  92,  97,  98, 101, 105, 105, 109,
+       70,  67,  67,  65,  64,  64,  63,  63,  63,  62,  62,  66,  67,  69,  72,  72,  76,  77,  79,  83,  83,  88,  89,  92,  96,  96, 101, 102, 105, 109, 109, 114,
+    ],
+    // Chroma
+    [
+      // 4x4
+       31,  41,  46,  51,
+       41,  48,  48,  51,
+       46,  48,  58,  62,
+       51,  51,  62,  71,
+      // 8x8
+       31,  31,  38,  44,  47,  48,  50,  55,
+       31,  32,  40,  44,  45,  46,  47,  52,
+       38,  40,  47,  47,  46,  46,  47,  50,
+       44,  44,  47,  50,  51,  51,  52,  54,
+       47,  45,  46,  51,  54,  56,  57,  60,
+       48,  46,  46,  51,  56,  61,  63,  66,
+       50,  47,  47,  52,  57,  63,  66,  70,
+       55,  52,  50,  54,  60,  66,  70,  76,
+      // 16x16
+       32,  31,  30,  33,  34,  36,  41,  49,  48,  49,  49,  50,  52,  54,  55,  57,
+       31,  31,  31,  34,  36,  38,  42,  47,  47,  47,  47,  48,  50,  51,  53,  54,
+       30,  31,  32,  34,  37,  40,  42,  46,  45,  45,  45,  46,  47,  49,  50,  52,
+       33,  34,  34,  37,  40,  42,  44,  47,  46,  46,  45,  46,  47,  49,  50,  51,
+       34,  36,  37,  40,  42,  45,  46,  47,  46,  46,  45,  46,  47,  48,  49,  50,
+       36,  38,  40,  42,  45,  47,  47,  48,  47,  46,  45,  46,  47,  48,  49,  50,
+       41,  42,  42,  44,  46,  47,  48,  50,  50,  49,  49,  50,  50,  51,  52,  53,
+       49,  47,  46,  47,  47,  48,  50,  53,  53,  53,  53,  54,  54,  55,  56,  56,
+       48,  47,  45,  46,  46,  47,  50,  53,  54,  54,  55,  56,  57,  58,  58,  59,
+       49,  47,  45,  46,  46,  46,  49,  53,  54,  55,  57,  58,  59,  60,  60,  61,
+       49,  47,  45,  45,  45,  45,  49,  53,  55,  57,  58,  60,  61,  62,  63,  63,
+       50,  48,  46,  46,  46,  46,  50,  54,  56,  58,  60,  61,  63,  65,  66,  67,
+       52,  50,  47,  47,  47,  47,  50,  54,  57,  59,  61,  63,  66,  68,  69,  70,
+       54,  51,  49,  49,  48,  48,  51,  55,  58,  60,  62,  65,  68,  70,  71,  73,
+       55,  53,  50,  50,  49,  49,  52,  56,  58,  60,  63,  66,  69,  71,  73,  74,
+       57,  54,  52,  51,  50,  50,  53,  56,  59,  61,  63,  67,  70,  73,  74,  76,
+      // 32x32
+       32,  31,  31,  31,  30,  30,  33,  33,  34,  36,  36,  40,  41,  44,  49,  49,  48,  48,  49,  49,  49,  50,  50,  51,  52,  52,  54,  54,  55,  57,  57,  59,
+       31,  31,  31,  31,  31,  31,  33,  34,  36,  38,  38,  41,  42,  44,  48,  48,  47,  47,  47,  47,  47,  48,  49,  49,  50,  50,  52,  52,  53,  55,  55,  57,
+       31,  31,  31,  31,  31,  31,  34,  34,  36,  38,  38,  41,  42,  44,  47,  47,  47,  47,  47,  47,  47,  48,  48,  49,  50,  50,  51,  52,  53,  54,  54,  56,
+       31,  31,  31,  31,  31,  31,  34,  35,  36,  39,  39,  41,  42,  44,  47,  47,  46,  46,  46,  46,  46,  47,  47,  48,  49,  49,  50,  51,  52,  53,  53,  55,
+       30,  31,  31,  31,  32,  32,  34,  35,  37,  40,  40,  42,  42,  44,  46,  46,  45,  45,  45,  45,  45,  46,  46,  47,  47,  47,  49,  49,  50,  52,  52,  54,
+       30,  31,  31,  31,  32,  32,  34,  35,  37,  40,  40,  42,  42,  44,  46,  46,  45,  45,  45,  45,  45,  46,  46,  47,  47,  47,  49,  49,  50,  52,  52,  54,
+       33,  33,  34,  34,  34,  34,  37,  38,  40,  42,  42,  44,  44,  45,  47,  47,  46,  46,  46,  45,  45,  46,  46,  47,  47,  47,  49,  49,  50,  51,  51,  53,
+       33,  34,  34,  35,  35,  35,  38,  39,  40,  43,  43,  44,  45,  46,  47,  47,  46,  46,  46,  45,  45,  46,  46,  47,  47,  47,  49,  49,  50,  51,  51,  53,
+       34,  36,  36,  36,  37,  37,  40,  40,  42,  45,  45,  45,  46,  46,  47,  47,  46,  46,  46,  45,  45,  46,  46,  47,  47,  47,  48,  49,  49,  50,  50,  52,
+       36,  38,  38,  39,  40,  40,  42,  43,  45,  47,  47,  47,  47,  47,  48,  48,  47,  46,  46,  45,  45,  46,  46,  46,  47,  47,  48,  48,  49,  50,  50,  51,
+       36,  38,  38,  39,  40,  40,  42,  43,  45,  47,  47,  47,  47,  47,  48,  48,  47,  46,  46,  45,  45,  46,  46,  46,  47,  47,  48,  48,  49,  50,  50,  51,
+       40,  41,  41,  41,  42,  42,  44,  44,  45,  47,  47,  48,  48,  49,  50,  50,  49,  49,  49,  48,  48,  49,  49,  49,  49,  49,  51,  51,  51,  52,  52,  54,
+       41,  42,  42,  42,  42,  42,  44,  45,  46,  47,  47,  48,  48,  49,  50,  50,  50,  49,  49,  49,  49,  50,  50,  50,  50,  50,  51,  52,  52,  53,  53,  55,
+       44,  44,  44,  44,  44,  44,  45,  46,  46,  47,  47,  49,  49,  50,  51,  51,  51,  51,  51,  51,  51,  51,  51,  51,  52,  52,  53,  53,  54,  54,  54,  56,
+       49,  48,  47,  47,  46,  46,  47,  47,  47,  48,  48,  50,  50,  51,  53,  53,  53,  53,  53,  53,  53,  54,  54,  54,  54,  54,  55,  55,  56,  56,  56,  58,
+       49,  48,  47,  47,  46,  46,  47,  47,  47,  48,  48,  50,  50,  51,  53,  53,  53,  53,  53,  53,  53,  54,  54,  54,  54,  54,  55,  55,  56,  56,  56,  58,
+       48,  47,  47,  46,  45,  45,  46,  46,  46,  47,  47,  49,  50,  51,  53,  53,  54,  54,  54,  55,  55,  56,  56,  56,  57,  57,  58,  58,  58,  59,  59,  60,
+       48,  47,  47,  46,  45,  45,  46,  46,  46,  46,  46,  49,  49,  51,  53,  53,  54,  54,  55,  55,  55,  56,  56,  57,  57,  57,  58,  58,  59,  60,  60,  61,
+       49,  47,  47,  46,  45,  45,  46,  46,  46,  46,  46,  49,  49,  51,  53,  53,  54,  55,  55,  57,  57,  57,  58,  58,  59,  59,  60,  60,  60,  61,  61,  63,
+       49,  47,  47,  46,  45,  45,  45,  45,  45,  45,  45,  48,  49,  51,  53,  53,  55,  55,  57,  58,  58,  59,  60,  60,  61,  61,  62,  62,  63,  63,  63,  65,
+       49,  47,  47,  46,  45,  45,  45,  45,  45,  45,  45,  48,  49,  51,  53,  53,  55,  55,  57,  58,  58,  59,  60,  60,  61,  61,  62,  62,  63,  63,  63,  65,
+       50,  48,  48,  47,  46,  46,  46,  46,  46,  46,  46,  49,  50,  51,  54,  54,  56,  56,  57,  59,  59,  61,  61,  62,  63,  63,  64,  64,  65,  66,  66,  67,
+       50,  49,  48,  47,  46,  46,  46,  46,  46,  46,  46,  49,  50,  51,  54,  54,  56,  56,  58,  60,  60,  61,  61,  62,  63,  63,  65,  65,  66,  67,  67,  68,
+       51,  49,  49,  48,  47,  47,  47,  47,  47,  46,  46,  49,  50,  51,  54,  54,  56,  57,  58,  60,  60,  62,  62,  63,  65,  65,  66,  66,  67,  68,  68,  70,
+       52,  50,  50,  49,  47,  47,  47,  47,  47,  47,  47,  49,  50,  52,  54,  54,  57,  57,  59,  61,  61,  63,  63,  65,  66,  66,  68,  68,  69,  70,  70,  72,
+       52,  50,  50,  49,  47,  47,  47,  47,  47,  47,  47,  49,  50,  52,  54,  54,  57,  57,  59,  61,  61,  63,  63,  65,  66,  66,  68,  68,  69,  70,  70,  72,
+       54,  52,  51,  50,  49,  49,  49,  49,  48,  48,  48,  51,  51,  53,  55,  55,  58,  58,  60,  62,  62,  64,  65,  66,  68,  68,  70,  70,  71,  73,  73,  74,
+       54,  52,  52,  51,  49,  49,  49,  49,  49,  48,  48,  51,  52,  53,  55,  55,  58,  58,  60,  62,  62,  64,  65,  66,  68,  68,  70,  71,  72,  73,  73,  75,
+       55,  53,  53,  52,  50,  50,  50,  50,  49,  49,  49,  51,  52,  54,  56,  56,  58,  59,  60,  63,  63,  65,  66,  67,  69,  69,  71,  72,  73,  74,  74,  76,
+       57,  55,  54,  53,  52,  52,  51,  51,  50,  50,  50,  52,  53,  54,  56,  56,  59,  60,  61,  63,  63,  66,  67,  68,  70,  70,  73,  73,  74,  76,  76,  78,
+       57,  55,  54,  53,  52,  52,  51,  51,  50,  50,  50,  52,  53,  54,  56,  56,  59,  60,  61,  63,  63,  66,  67,  68,  70,  70,  73,  73,  74,  76,  76,  78,
+       59,  57,  56,  55,  54,  54,  53,  53,  52,  51,  51,  54,  55,  56,  58,  58,  60,  61,  63,  65,  65,  67,  68,  70,  72,  72,  74,  75,  76,  78,  78,  80,
+    ],
+  ],
+  // Level 8
+  [
+    // Luma
+    [
+      // 4x4
+       32,  32,  38,  51,
+       32,  35,  40,  49,
+       38,  40,  54,  64,
+       51,  49,  64,  81,
+      // 8x8
+       31,  32,  32,  34,  35,  41,  47,  53,
+       32,  32,  32,  33,  34,  40,  44,  50,
+       32,  32,  34,  35,  37,  41,  45,  51,
+       34,  33,  35,  39,  42,  47,  51,  55,
+       35,  34,  37,  42,  48,  53,  57,  61,
+       41,  40,  41,  47,  53,  60,  65,  70,
+       47,  44,  45,  51,  57,  65,  71,  77,
+       53,  50,  51,  55,  61,  70,  77,  85,
+      // 16x16
+       32,  31,  31,  31,  31,  32,  32,  34,  36,  38,  39,  44,  47,  49,  54,  59,
+       31,  32,  32,  32,  32,  32,  33,  34,  35,  37,  38,  42,  45,  47,  51,  56,
+       31,  32,  32,  32,  32,  32,  33,  33,  34,  36,  37,  41,  44,  46,  50,  54,
+       31,  32,  32,  32,  32,  33,  33,  34,  35,  36,  38,  41,  44,  45,  49,  54,
+       31,  32,  32,  32,  33,  34,  34,  35,  36,  38,  39,  42,  45,  46,  50,  54,
+       32,  32,  32,  33,  34,  35,  36,  37,  38,  39,  40,  42,  45,  46,  49,  53,
+       32,  33,  33,  33,  34,  36,  36,  38,  40,  41,  42,  44,  47,  48,  51,  55,
+       34,  34,  33,  34,  35,  37,  38,  39,  42,  44,  45,  47,  50,  51,  54,  58,
+       36,  35,  34,  35,  36,  38,  40,  42,  48,  50,  50,  54,  56,  57,  60,  64,
+       38,  37,  36,  36,  38,  39,  41,  44,  50,  51,  52,  56,  58,  60,  63,  67,
+       39,  38,  37,  38,  39,  40,  42,  45,  50,  52,  54,  58,  60,  62,  65,  69,
+       44,  42,  41,  41,  42,  42,  44,  47,  54,  56,  58,  63,  66,  68,  71,  75,
+       47,  45,  44,  44,  45,  45,  47,  50,  56,  58,  60,  66,  69,  71,  75,  79,
+       49,  47,  46,  45,  46,  46,  48,  51,  57,  60,  62,  68,  71,  73,  77,  81,
+       54,  51,  50,  49,  50,  49,  51,  54,  60,  63,  65,  71,  75,  77,  82,  87,
+       59,  56,  54,  54,  54,  53,  55,  58,  64,  67,  69,  75,  79,  81,  87,  92,
+      // 32x32
+       32,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  34,  34,  35,  36,  36,  38,  39,  39,  42,  44,  44,  47,  48,  49,  53,  54,  55,  59,  59,
+       31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  33,  34,  34,  34,  35,  35,  37,  39,  39,  41,  43,  43,  46,  47,  48,  51,  52,  53,  57,  57,
+       31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  34,  34,  34,  35,  35,  37,  38,  38,  41,  42,  43,  45,  46,  47,  51,  51,  53,  56,  56,
+       31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  34,  34,  34,  35,  35,  37,  38,  38,  41,  42,  42,  45,  46,  47,  51,  51,  52,  56,  56,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  34,  34,  34,  36,  37,  37,  40,  41,  41,  44,  45,  46,  49,  50,  51,  54,  54,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  34,  34,  34,  36,  37,  37,  40,  41,  41,  44,  44,  45,  49,  49,  50,  54,  54,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  34,  34,  34,  35,  35,  36,  38,  38,  40,  41,  41,  44,  45,  45,  49,  49,  50,  54,  54,
+       31,  32,  32,  32,  32,  32,  32,  33,  33,  33,  34,  34,  34,  35,  35,  35,  36,  36,  38,  39,  39,  41,  42,  42,  44,  45,  46,  49,  50,  51,  54,  54,
+       31,  32,  32,  32,  32,  32,  32,  33,  33,  33,  34,  34,  34,  35,  35,  36,  36,  36,  38,  39,  39,  41,  42,  42,  45,  45,  46,  49,  50,  51,  54,  54,
+       32,  32,  32,  32,  32,  32,  33,  33,  33,  34,  34,  34,  35,  35,  35,  36,  37,  37,  38,  39,  39,  41,  42,  42,  45,  45,  46,  49,  49,  51,  54,  54,
+       32,  32,  32,  32,  32,  32,  33,  34,  34,  34,  35,  35,  36,  37,  37,  37,  38,  38,  39,  40,  40,  42,  42,  43,  45,  46,  46,  49,  49,  50,  53,  53,
+       32,  32,  32,  32,  32,  32,  33,  34,  34,  34,  35,  35,  36,  37,  37,  37,  38,  38,  39,  40,  40,  42,  42,  43,  45,  46,  46,  49,  49,  50,  53,  53,
+       32,  33,  33,  33,  33,  33,  33,  34,  34,  35,  36,  36,  36,  38,  38,  39,  40,  40,  41,  42,  42,  44,  44,  45,  47,  47,  48,  51,  51,  52,  55,  55,
+       34,  34,  34,  34,  33,  33,  34,  35,  35,  35,  37,  37,  38,  39,  39,  41,  42,  42,  44,  45,  45,  47,  47,  48,  50,  51,  51,  54,  54,  55,  58,  58,
+       34,  34,  34,  34,  33,  33,  34,  35,  35,  35,  37,  37,  38,  39,  39,  41,  42,  42,  44,  45,  45,  47,  47,  48,  50,  51,  51,  54,  54,  55,  58,  58,
+       35,  34,  34,  34,  34,  34,  34,  35,  36,  36,  37,  37,  39,  41,  41,  43,  45,  45,  47,  47,  47,  49,  50,  51,  53,  53,  54,  57,  57,  58,  61,  61,
+       36,  35,  35,  35,  34,  34,  35,  36,  36,  37,  38,  38,  40,  42,  42,  45,  48,  48,  50,  50,  50,  53,  54,  54,  56,  57,  57,  59,  60,  61,  64,  64,
+       36,  35,  35,  35,  34,  34,  35,  36,  36,  37,  38,  38,  40,  42,  42,  45,  48,  48,  50,  50,  50,  53,  54,  54,  56,  57,  57,  59,  60,  61,  64,  64,
+       38,  37,  37,  37,  36,  36,  36,  38,  38,  38,  39,  39,  41,  44,  44,  47,  50,  50,  51,  52,  52,  55,  56,  56,  58,  59,  60,  62,  63,  64,  67,  67,
+       39,  39,  38,  38,  37,  37,  38,  39,  39,  39,  40,  40,  42,  45,  45,  47,  50,  50,  52,  54,  54,  56,  58,  58,  60,  61,  62,  64,  65,  66,  69,  69,
+       39,  39,  38,  38,  37,  37,  38,  39,  39,  39,  40,  40,  42,  45,  45,  47,  50,  50,  52,  54,  54,  56,  58,  58,  60,  61,  62,  64,  65,  66,  69,  69,
+       42,  41,  41,  41,  40,  40,  40,  41,  41,  41,  42,  42,  44,  47,  47,  49,  53,  53,  55,  56,  56,  60,  61,  62,  64,  65,  66,  69,  69,  70,  73,  73,
+       44,  43,  42,  42,  41,  41,  41,  42,  42,  42,  42,  42,  44,  47,  47,  50,  54,  54,  56,  58,  58,  61,  63,  64,  66,  67,  68,  71,  71,  72,  75,  75,
+       44,  43,  43,  42,  41,  41,  41,  42,  42,  42,  43,  43,  45,  48,  48,  51,  54,  54,  56,  58,  58,  62,  64,  64,  66,  67,  68,  71,  72,  73,  76,  76,
+       47,  46,  45,  45,  44,  44,  44,  44,  45,  45,  45,  45,  47,  50,  50,  53,  56,  56,  58,  60,  60,  64,  66,  66,  69,  70,  71,  74,  75,  76,  79,  79,
+       48,  47,  46,  46,  45,  44,  45,  45,  45,  45,  46,  46,  47,  51,  51,  53,  57,  57,  59,  61,  61,  65,  67,  67,  70,  71,  72,  75,  76,  77,  80,  80,
+       49,  48,  47,  47,  46,  45,  45,  46,  46,  46,  46,  46,  48,  51,  51,  54,  57,  57,  60,  62,  62,  66,  68,  68,  71,  72,  73,  77,  77,  78,  81,  81,
+       53,  51,  51,  51,  49,  49,  49,  49,  49,  49,  49,  49,  51,  54,  54,  57,  59,  59,  62,  64,  64,  69,  71,  71,  74,  75,  77,  81,  81,  83,  86,  86,
+       54,  52,  51,  51,  50,  49,  49,  50,  50,  49,  49,  49,  51,  54,  54,  57,  60,  60,  63,  65,  65,  69,  71,  72,  75,  76,  77,  81,  82,  83,  87,  87,
+       55,  53,  53,  52,  51,  50,  50,  51,  51,  51,  50,  50,  52,  55,  55,  58,  61,  61,  64,  66,  66,  70,  72,  73,  76,  77,  78,  83,  83,  85,  88,  88,
+       59,  57,  56,  56,  54,  54,  54,  54,  54,  54,  53,  53,  55,  58,  58,  61,  64,  64,  67,  69,  69,  73,  75,  76,  79,  80,  81,  86,  87,  88,  92,  92,
+       59,  57,  56,  56,  54,  54,  54,  54,  54,  54,  53,  53,  55,  58,  58,  61,  64,  64,  67,  69,  69,  73,  75,  76,  79,  80,  81,  86,  87,  88,  92,  92,
+    ],
+    // Chroma
+    [
+      // 4x4
+       31,  38,  47,  49,
+       38,  47,  46,  46,
+       47,  46,  54,  57,
+       49,  46,  57,  66,
+      // 8x8
+       31,  31,  35,  42,  48,  47,  49,  51,
+       31,  32,  36,  42,  46,  45,  46,  48,
+       35,  36,  41,  45,  47,  45,  46,  48,
+       42,  42,  45,  48,  50,  49,  50,  51,
+       48,  46,  47,  50,  53,  53,  54,  54,
+       47,  45,  45,  49,  53,  57,  59,  60,
+       49,  46,  46,  50,  54,  59,  61,  64,
+       51,  48,  48,  51,  54,  60,  64,  68,
+      // 16x16
+       32,  31,  30,  31,  33,  36,  38,  41,  49,  49,  48,  49,  50,  51,  52,  54,
+       31,  31,  31,  32,  34,  38,  40,  42,  47,  47,  47,  47,  48,  48,  50,  52,
+       30,  31,  31,  32,  35,  39,  41,  42,  46,  46,  46,  45,  46,  47,  48,  50,
+       31,  32,  32,  33,  36,  40,  41,  43,  46,  46,  45,  45,  46,  46,  47,  49,
+       33,  34,  35,  36,  39,  43,  44,  45,  47,  46,  46,  45,  46,  47,  47,  49,
+       36,  38,  39,  40,  43,  47,  47,  47,  48,  47,  46,  45,  46,  46,  47,  48,
+       38,  40,  41,  41,  44,  47,  47,  48,  49,  48,  48,  47,  47,  47,  48,  49,
+       41,  42,  42,  43,  45,  47,  48,  48,  50,  50,  49,  49,  50,  50,  50,  52,
+       49,  47,  46,  46,  47,  48,  49,  50,  53,  53,  53,  53,  54,  54,  54,  55,
+       49,  47,  46,  46,  46,  47,  48,  50,  53,  53,  54,  55,  55,  55,  56,  57,
+       48,  47,  46,  45,  46,  46,  48,  49,  53,  54,  54,  55,  56,  56,  57,  58,
+       49,  47,  45,  45,  45,  45,  47,  49,  53,  55,  55,  58,  59,  60,  61,  62,
+       50,  48,  46,  46,  46,  46,  47,  50,  54,  55,  56,  59,  61,  61,  63,  64,
+       51,  48,  47,  46,  47,  46,  47,  50,  54,  55,  56,  60,  61,  62,  64,  66,
+       52,  50,  48,  47,  47,  47,  48,  50,  54,  56,  57,  61,  63,  64,  66,  68,
+       54,  52,  50,  49,  49,  48,  49,  52,  55,  57,  58,  62,  64,  66,  68,  71,
+      // 32x32
+       32,  31,  31,  31,  30,  30,  31,  33,  33,  34,  36,  36,  38,  41,  41,  45,  49,  49,  49,  48,  48,  49,  49,  49,  50,  50,  51,  52,  52,  53,  54,  54,
+       31,  31,  31,  31,  31,  31,  31,  34,  34,  35,  38,  38,  39,  42,  42,  45,  48,  48,  47,  47,  47,  47,  47,  47,  49,  49,  49,  50,  50,  51,  53,  53,
+       31,  31,  31,  31,  31,  31,  32,  34,  34,  35,  38,  38,  40,  42,  42,  45,  47,  47,  47,  47,  47,  47,  47,  47,  48,  48,  48,  49,  50,  50,  52,  52,
+       31,  31,  31,  31,  31,  31,  32,  34,  34,  36,  38,  38,  40,  42,  42,  45,  47,  47,  47,  47,  47,  47,  46,  47,  48,  48,  48,  49,  49,  50,  52,  52,
+       30,  31,  31,  31,  31,  31,  32,  35,  35,  36,  39,  39,  41,  42,  42,  44,  46,  46,  46,  46,  46,  45,  45,  45,  46,  47,  47,  48,  48,  48,  50,  50,
+       30,  31,  31,  31,  31,  32,  32,  35,  35,  36,  40,  40,  41,  42,  42,  44,  46,  46,  46,  45,  45,  45,  45,  45,  46,  46,  46,  47,  47,  48,  49,  49,
+       31,  31,  32,  32,  32,  32,  33,  35,  36,  37,  40,  40,  41,  43,  43,  44,  46,  46,  46,  45,  45,  45,  45,  45,  46,  46,  46,  47,  47,  48,  49,  49,
+       33,  34,  34,  34,  35,  35,  35,  38,  38,  40,  43,  43,  43,  44,  44,  46,  47,  47,  46,  46,  46,  45,  45,  45,  46,  46,  47,  47,  47,  48,  49,  49,
+       33,  34,  34,  34,  35,  35,  36,  38,  39,  40,  43,  43,  44,  45,  45,  46,  47,  47,  46,  46,  46,  45,  45,  45,  46,  46,  47,  47,  47,  48,  49,  49,
+       34,  35,  35,  36,  36,  36,  37,  40,  40,  41,  44,  44,  45,  45,  45,  46,  47,  47,  47,  46,  46,  45,  45,  45,  46,  46,  46,  47,  47,  48,  49,  49,
+       36,  38,  38,  38,  39,  40,  40,  43,  43,  44,  47,  47,  47,  47,  47,  47,  48,  48,  47,  46,  46,  45,  45,  45,  46,  46,  46,  46,  47,  47,  48,  48,
+       36,  38,  38,  38,  39,  40,  40,  43,  43,  44,  47,  47,  47,  47,  47,  47,  48,  48,  47,  46,  46,  45,  45,  45,  46,  46,  46,  46,  47,  47,  48,  48,
+       38,  39,  40,  40,  41,  41,  41,  43,  44,  45,  47,  47,  47,  48,  48,  48,  49,  49,  48,  48,  48,  47,  47,  47,  47,  47,  47,  48,  48,  48,  49,  49,
+       41,  42,  42,  42,  42,  42,  43,  44,  45,  45,  47,  47,  48,  48,  48,  49,  50,  50,  50,  49,  49,  49,  49,  49,  50,  50,  50,  50,  50,  51,  52,  52,
+       41,  42,  42,  42,  42,  42,  43,  44,  45,  45,  47,  47,  48,  48,  48,  49,  50,  50,  50,  49,  49,  49,  49,  49,  50,  50,  50,  50,  50,  51,  52,  52,
+       45,  45,  45,  45,  44,  44,  44,  46,  46,  46,  47,  47,  48,  49,  49,  50,  51,  51,  51,  51,  51,  51,  51,  51,  52,  52,  52,  52,  52,  52,  53,  53,
+       49,  48,  47,  47,  46,  46,  46,  47,  47,  47,  48,  48,  49,  50,  50,  51,  53,  53,  53,  53,  53,  53,  53,  53,  54,  54,  54,  54,  54,  54,  55,  55,
+       49,  48,  47,  47,  46,  46,  46,  47,  47,  47,  48,  48,  49,  50,  50,  51,  53,  53,  53,  53,  53,  53,  53,  53,  54,  54,  54,  54,  54,  54,  55,  55,
+       49,  47,  47,  47,  46,  46,  46,  46,  46,  47,  47,  47,  48,  50,  50,  51,  53,  53,  53,  54,  54,  54,  55,  55,  55,  55,  55,  56,  56,  56,  57,  57,
+       48,  47,  47,  47,  46,  45,  45,  46,  46,  46,  46,  46,  48,  49,  49,  51,  53,  53,  54,  54,  54,  55,  55,  56,  56,  56,  56,  57,  57,  58,  58,  58,
+       48,  47,  47,  47,  46,  45,  45,  46,  46,  46,  46,  46,  48,  49,  49,  51,  53,  53,  54,  54,  54,  55,  55,  56,  56,  56,  56,  57,  57,  58,  58,  58,
+       49,  47,  47,  47,  45,  45,  45,  45,  45,  45,  45,  45,  47,  49,  49,  51,  53,  53,  54,  55,  55,  57,  57,  58,  58,  59,  59,  60,  60,  60,  61,  61,
+       49,  47,  47,  46,  45,  45,  45,  45,  45,  45,  45,  45,  47,  49,  49,  51,  53,  53,  55,  55,  55,  57,  58,  58,  59,  60,  60,  61,  61,  61,  62,  62,
+       49,  47,  47,  47,  45,  45,  45,  45,  45,  45,  45,  45,  47,  49,  49,  51,  53,  53,  55,  56,  56,  58,  58,  59,  59,  60,  60,  61,  61,  62,  63,  63,
+       50,  49,  48,  48,  46,  46,  46,  46,  46,  46,  46,  46,  47,  50,  50,  52,  54,  54,  55,  56,  56,  58,  59,  59,  61,  61,  61,  63,  63,  63,  64,  64,
+       50,  49,  48,  48,  47,  46,  46,  46,  46,  46,  46,  46,  47,  50,  50,  52,  54,  54,  55,  56,  56,  59,  60,  60,  61,  61,  62,  63,  63,  64,  65,  65,
+       51,  49,  48,  48,  47,  46,  46,  47,  47,  46,  46,  46,  47,  50,  50,  52,  54,  54,  55,  56,  56,  59,  60,  60,  61,  62,  62,  64,  64,  64,  66,  66,
+       52,  50,  49,  49,  48,  47,  47,  47,  47,  47,  46,  46,  48,  50,  50,  52,  54,  54,  56,  57,  57,  60,  61,  61,  63,  63,  64,  66,  66,  67,  68,  68,
+       52,  50,  50,  49,  48,  47,  47,  47,  47,  47,  47,  47,  48,  50,  50,  52,  54,  54,  56,  57,  57,  60,  61,  61,  63,  63,  64,  66,  66,  67,  68,  68,
+       53,  51,  50,  50,  48,  48,  48,  48,  48,  48,  47,  47,  48,  51,  51,  52,  54,  54,  56,  58,  58,  60,  61,  62,  63,  64,  64,  67,  67,  68,  69,  69,
+       54,  53,  52,  52,  50,  49,  49,  49,  49,  49,  48,  48,  49,  52,  52,  53,  55,  55,  57,  58,  58,  61,  62,  63,  64,  65,  66,  68,  68,  69,  71,  71,
+       54,  53,  52,  52,  50,  49,  49,  49,  49,  49,  48,  48,  49,  52,  52,  53,  55,  55,  57,  58,  58,  61,  62,  63,  64,  65,  66,  68,  68,  69,  71,  71,
+    ],
+  ],
+  // Level 9
+  [
+    // Luma
+    [
+      // 4x4
+       32,  32,  35,  43,
+       32,  34,  37,  43,
+       35,  37,  48,  54,
+       43,  43,  54,  65,
+      // 8x8
+       31,  31,  32,  32,  34,  37,  43,  47,
+       31,  32,  32,  32,  34,  36,  41,  44,
+       32,  32,  33,  34,  35,  38,  42,  45,
+       32,  32,  34,  35,  37,  39,  42,  46,
+       34,  34,  35,  37,  41,  45,  49,  52,
+       37,  36,  38,  39,  45,  51,  56,  59,
+       43,  41,  42,  42,  49,  56,  63,  67,
+       47,  44,  45,  46,  52,  59,  67,  71,
+      // 16x16
+       32,  31,  31,  31,  31,  31,  32,  32,  34,  35,  36,  39,  41,  44,  47,  48,
+       31,  32,  32,  32,  32,  32,  32,  33,  34,  35,  35,  38,  40,  42,  45,  46,
+       31,  32,  32,  32,  32,  32,  32,  33,  34,  34,  35,  38,  39,  42,  45,  45,
+       31,  32,  32,  32,  32,  32,  32,  33,  33,  34,  34,  37,  38,  41,  44,  44,
+       31,  32,  32,  32,  33,  33,  33,  34,  35,  36,  36,  39,  40,  42,  44,  45,
+       31,  32,  32,  32,  33,  33,  34,  34,  35,  36,  36,  39,  40,  42,  45,  45,
+       32,  32,  32,  32,  33,  34,  35,  36,  37,  38,  38,  40,  41,  42,  45,  46,
+       32,  33,  33,  33,  34,  34,  36,  36,  38,  39,  40,  42,  43,  44,  47,  47,
+       34,  34,  34,  33,  35,  35,  37,  38,  39,  42,  42,  45,  46,  47,  50,  51,
+       35,  35,  34,  34,  36,  36,  38,  39,  42,  46,  47,  49,  50,  52,  55,  55,
+       36,  35,  35,  34,  36,  36,  38,  40,  42,  47,  48,  50,  52,  54,  56,  57,
+       39,  38,  38,  37,  39,  39,  40,  42,  45,  49,  50,  54,  55,  58,  60,  61,
+       41,  40,  39,  38,  40,  40,  41,  43,  46,  50,  52,  55,  57,  60,  62,  63,
+       44,  42,  42,  41,  42,  42,  42,  44,  47,  52,  54,  58,  60,  63,  66,  67,
+       47,  45,  45,  44,  44,  45,  45,  47,  50,  55,  56,  60,  62,  66,  69,  70,
+       48,  46,  45,  44,  45,  45,  46,  47,  51,  55,  57,  61,  63,  67,  70,  71,
+      // 32x32
+       32,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  34,  34,  34,  35,  36,  36,  38,  39,  39,  41,  44,  44,  45,  47,  48,  48,  51,
+       31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  33,  34,  34,  34,  35,  35,  35,  37,  39,  39,  40,  43,  43,  44,  46,  47,  47,  50,
+       31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  34,  34,  34,  35,  35,  35,  37,  38,  38,  40,  42,  42,  43,  45,  46,  46,  49,
+       31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  34,  34,  34,  35,  35,  35,  37,  38,  38,  40,  42,  42,  43,  45,  46,  46,  49,
+       31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  34,  34,  34,  34,  35,  35,  36,  38,  38,  39,  42,  42,  42,  45,  45,  45,  48,
+       31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  34,  34,  34,  34,  36,  37,  37,  38,  41,  41,  41,  44,  44,  44,  47,
+       31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  34,  34,  34,  34,  36,  37,  37,  38,  41,  41,  41,  44,  44,  44,  47,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  34,  34,  34,  34,  35,  35,  36,  38,  38,  39,  41,  41,  42,  44,  45,  45,  47,
+       31,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  33,  33,  34,  35,  35,  35,  36,  36,  36,  37,  39,  39,  40,  42,  42,  42,  44,  45,  45,  48,
+       31,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  34,  34,  34,  35,  35,  35,  36,  36,  36,  38,  39,  39,  40,  42,  42,  42,  45,  45,  45,  48,
+       31,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  34,  34,  34,  35,  35,  35,  36,  36,  36,  38,  39,  39,  40,  42,  42,  42,  45,  45,  45,  48,
+       32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  34,  35,  35,  35,  36,  36,  36,  37,  37,  37,  39,  40,  40,  41,  42,  42,  43,  45,  45,  45,  48,
+       32,  32,  32,  32,  32,  32,  32,  33,  33,  34,  34,  35,  35,  35,  36,  37,  37,  37,  38,  38,  38,  39,  40,  40,  41,  42,  42,  43,  45,  46,  46,  48,
+       32,  32,  32,  32,  32,  32,  32,  33,  33,  34,  34,  35,  35,  35,  36,  37,  37,  37,  38,  38,  38,  39,  40,  40,  41,  42,  42,  43,  45,  46,  46,  48,
+       32,  33,  33,  33,  33,  33,  33,  33,  34,  34,  34,  35,  36,  36,  36,  38,  38,  38,  39,  40,  40,  41,  42,  42,  43,  44,  44,  45,  47,  47,  47,  50,
+       34,  34,  34,  34,  34,  33,  33,  34,  35,  35,  35,  36,  37,  37,  38,  39,  39,  40,  42,  42,  42,  44,  45,  45,  46,  47,  47,  48,  50,  51,  51,  53,
+       34,  34,  34,  34,  34,  33,  33,  34,  35,  35,  35,  36,  37,  37,  38,  39,  39,  40,  42,  42,  42,  44,  45,  45,  46,  47,  47,  48,  50,  51,  51,  53,
+       34,  34,  34,  34,  34,  34,  34,  34,  35,  35,  35,  36,  37,  37,  38,  40,  40,  41,  43,  44,  44,  45,  46,  46,  47,  49,  49,  49,  51,  52,  52,  54,
+       35,  35,  35,  35,  34,  34,  34,  34,  36,  36,  36,  37,  38,  38,  39,  42,  42,  43,  46,  47,  47,  48,  49,  49,  50,  52,  52,  53,  55,  55,  55,  57,
+       36,  35,  35,  35,  35,  34,  34,  35,  36,  36,  36,  37,  38,  38,  40,  42,  42,  44,  47,  48,  48,  50,  50,  50,  52,  54,  54,  54,  56,  57,  57,  58,
+       36,  35,  35,  35,  35,  34,  34,  35,  36,  36,  36,  37,  38,  38,  40,  42,  42,  44,  47,  48,  48,  50,  50,  50,  52,  54,  54,  54,  56,  57,  57,  58,
+       38,  37,  37,  37,  36,  36,  36,  36,  37,  38,  38,  39,  39,  39,  41,  44,  44,  45,  48,  50,  50,  51,  52,  52,  54,  56,  56,  57,  58,  59,  59,  61,
+       39,  39,  38,  38,  38,  37,  37,  38,  39,  39,  39,  40,  40,  40,  42,  45,  45,  46,  49,  50,  50,  52,  54,  54,  55,  58,  58,  58,  60,  61,  61,  63,
+       39,  39,  38,  38,  38,  37,  37,  38,  39,  39,  39,  40,  40,  40,  42,  45,  45,  46,  49,  50,  50,  52,  54,  54,  55,  58,  58,  58,  60,  61,  61,  63,
+       41,  40,  40,  40,  39,  38,  38,  39,  40,  40,  40,  41,  41,  41,  43,  46,  46,  47,  50,  52,  52,  54,  55,  55,  57,  60,  60,  60,  62,  63,  63,  66,
+       44,  43,  42,  42,  42,  41,  41,  41,  42,  42,  42,  42,  42,  42,  44,  47,  47,  49,  52,  54,  54,  56,  58,  58,  60,  63,  63,  64,  66,  67,  67,  69,
+       44,  43,  42,  42,  42,  41,  41,  41,  42,  42,  42,  42,  42,  42,  44,  47,  47,  49,  52,  54,  54,  56,  58,  58,  60,  63,  63,  64,  66,  67,  67,  69,
+       45,  44,  43,  43,  42,  41,  41,  42,  42,  42,  42,  43,  43,  43,  45,  48,  48,  49,  53,  54,  54,  57,  58,  58,  60,  64,  64,  65,  67,  68,  68,  70,
+       47,  46,  45,  45,  45,  44,  44,  44,  44,  45,  45,  45,  45,  45,  47,  50,  50,  51,  55,  56,  56,  58,  60,  60,  62,  66,  66,  67,  69,  70,  70,  73,
+       48,  47,  46,  46,  45,  44,  44,  45,  45,  45,  45,  45,  46,  46,  47,  51,  51,  52,  55,  57,  57,  59,  61,  61,  63,  67,  67,  68,  70,  71,  71,  74,
+       48,  47,  46,  46,  45,  44,  44,  45,  45,  45,  45,  45,  46,  46,  47,  51,  51,  52,  55,  57,  57,  59,  61,  61,  63,  67,  67,  68,  70,  71,  71,  74,
+       51,  50,  49,  49,  48,  47,  47,  47,  48,  48,  48,  48,  48,  48,  50,  53,  53,  54,  57,  58,  58,  61,  63,  63,  66,  69,  69,  70,  73,  74,  74,  77,
+    ],
+    // Chroma
+    [
+      // 4x4
+       31,  37,  47,  47,
+       37,  44,  47,  45,
+       47,  47,  53,  53,
+       47,  45,  53,  59,
+      // 8x8
+       31,  31,  34,  37,  43,  48,  47,  49,
+       31,  32,  35,  40,  43,  46,  45,  46,
+       34,  35,  39,  43,  45,  46,  45,  46,
+       37,  40,  43,  47,  47,  47,  45,  46,
+       43,  43,  45,  47,  49,  50,  50,  50,
+       48,  46,  46,  47,  50,  53,  55,  55,
+       47,  45,  45,  45,  50,  55,  58,  60,
+       49,  46,  46,  46,  50,  55,  60,  61,
+      // 16x16
+       32,  31,  31,  30,  33,  33,  36,  38,  41,  47,  49,  48,  49,  49,  50,  50,
+       31,  31,  31,  31,  34,  34,  38,  40,  42,  46,  47,  47,  47,  47,  48,  48,
+       31,  31,  31,  31,  34,  35,  39,  40,  42,  46,  47,  46,  46,  46,  47,  47,
+       30,  31,  31,  32,  34,  35,  40,  41,  42,  45,  46,  45,  45,  45,  46,  46,
+       33,  34,  34,  34,  37,  38,  42,  43,  44,  46,  47,  46,  46,  45,  46,  46,
+       33,  34,  35,  35,  38,  39,  43,  44,  45,  47,  47,  46,  46,  45,  46,  46,
+       36,  38,  39,  40,  42,  43,  47,  47,  47,  47,  48,  46,  46,  45,  46,  46,
+       38,  40,  40,  41,  43,  44,  47,  47,  48,  48,  49,  48,  47,  47,  47,  47,
+       41,  42,  42,  42,  44,  45,  47,  48,  48,  50,  50,  49,  49,  49,  50,  50,
+       47,  46,  46,  45,  46,  47,  47,  48,  50,  52,  52,  52,  52,  52,  53,  53,
+       49,  47,  47,  46,  47,  47,  48,  49,  50,  52,  53,  53,  53,  53,  54,  54,
+       48,  47,  46,  45,  46,  46,  46,  48,  49,  52,  53,  54,  55,  55,  56,  56,
+       49,  47,  46,  45,  46,  46,  46,  47,  49,  52,  53,  55,  55,  57,  57,  58,
+       49,  47,  46,  45,  45,  45,  45,  47,  49,  52,  53,  55,  57,  58,  59,  60,
+       50,  48,  47,  46,  46,  46,  46,  47,  50,  53,  54,  56,  57,  59,  61,  61,
+       50,  48,  47,  46,  46,  46,  46,  47,  50,  53,  54,  56,  58,  60,  61,  61,
+      // 32x32
+       32,  31,  31,  31,  31,  30,  30,  31,  33,  33,  33,  35,  36,  36,  38,  41,  41,  43,  47,  49,  49,  49,  48,  48,  49,  49,  49,  49,  50,  50,  50,  51,
+       31,  31,  31,  31,  31,  31,  31,  31,  33,  34,  34,  36,  37,  37,  39,  42,  42,  43,  47,  48,  48,  48,  47,  47,  47,  47,  47,  48,  49,  49,  49,  50,
+       31,  31,  31,  31,  31,  31,  31,  32,  34,  34,  34,  37,  38,  38,  40,  42,  42,  43,  46,  47,  47,  47,  47,  47,  47,  47,  47,  47,  48,  48,  48,  49,
+       31,  31,  31,  31,  31,  31,  31,  32,  34,  34,  34,  37,  38,  38,  40,  42,  42,  43,  46,  47,  47,  47,  47,  47,  47,  47,  47,  47,  48,  48,  48,  49,
+       31,  31,  31,  31,  31,  31,  31,  32,  34,  35,  35,  37,  39,  39,  40,  42,  42,  43,  46,  47,  47,  46,  46,  46,  46,  46,  46,  46,  47,  47,  47,  48,
+       30,  31,  31,  31,  31,  32,  32,  32,  34,  35,  35,  38,  40,  40,  41,  42,  42,  43,  45,  46,  46,  46,  45,  45,  45,  45,  45,  45,  46,  46,  46,  47,
+       30,  31,  31,  31,  31,  32,  32,  32,  34,  35,  35,  38,  40,  40,  41,  42,  42,  43,  45,  46,  46,  46,  45,  45,  45,  45,  45,  45,  46,  46,  46,  47,
+       31,  31,  32,  32,  32,  32,  32,  33,  35,  36,  36,  38,  40,  40,  41,  43,  43,  43,  46,  46,  46,  46,  45,  45,  45,  45,  45,  45,  46,  46,  46,  47,
+       33,  33,  34,  34,  34,  34,  34,  35,  37,  38,  38,  41,  42,  42,  43,  44,  44,  45,  46,  47,  47,  46,  46,  46,  46,  45,  45,  45,  46,  46,  46,  47,
+       33,  34,  34,  34,  35,  35,  35,  36,  38,  39,  39,  41,  43,  43,  44,  45,  45,  45,  47,  47,  47,  46,  46,  46,  46,  45,  45,  45,  46,  46,  46,  47,
+       33,  34,  34,  34,  35,  35,  35,  36,  38,  39,  39,  41,  43,  43,  44,  45,  45,  45,  47,  47,  47,  46,  46,  46,  46,  45,  45,  45,  46,  46,  46,  47,
+       35,  36,  37,  37,  37,  38,  38,  38,  41,  41,  41,  44,  46,  46,  46,  46,  46,  46,  47,  47,  47,  47,  46,  46,  46,  45,  45,  45,  46,  46,  46,  47,
+       36,  37,  38,  38,  39,  40,  40,  40,  42,  43,  43,  46,  47,  47,  47,  47,  47,  47,  47,  48,  48,  47,  46,  46,  46,  45,  45,  45,  46,  46,  46,  46,
+       36,  37,  38,  38,  39,  40,  40,  40,  42,  43,  43,  46,  47,  47,  47,  47,  47,  47,  47,  48,  48,  47,  46,  46,  46,  45,  45,  45,  46,  46,  46,  46,
+       38,  39,  40,  40,  40,  41,  41,  41,  43,  44,  44,  46,  47,  47,  47,  48,  48,  48,  48,  49,  49,  48,  48,  48,  47,  47,  47,  47,  47,  47,  47,  48,
+       41,  42,  42,  42,  42,  42,  42,  43,  44,  45,  45,  46,  47,  47,  48,  48,  48,  49,  50,  50,  50,  50,  49,  49,  49,  49,  49,  49,  50,  50,  50,  50,
+       41,  42,  42,  42,  42,  42,  42,  43,  44,  45,  45,  46,  47,  47,  48,  48,  48,  49,  50,  50,  50,  50,  49,  49,  49,  49,  49,  49,  50,  50,  50,  50,
+       43,  43,  43,  43,  43,  43,  43,  43,  45,  45,  45,  46,  47,  47,  48,  49,  49,  49,  50,  51,  51,  50,  50,  50,  50,  50,  50,  50,  50,  50,  50,  51,
+       47,  47,  46,  46,  46,  45,  45,  46,  46,  47,  47,  47,  47,  47,  48,  50,  50,  50,  52,  52,  52,  52,  52,  52,  52,  52,  52,  52,  53,  53,  53,  53,
+       49,  48,  47,  47,  47,  46,  46,  46,  47,  47,  47,  47,  48,  48,  49,  50,  50,  51,  52,  53,  53,  53,  53,  53,  53,  53,  53,  53,  54,  54,  54,  54,
+       49,  48,  47,  47,  47,  46,  46,  46,  47,  47,  47,  47,  48,  48,  49,  50,  50,  51,  52,  53,  53,  53,  53,  53,  53,  53,  53,  53,  54,  54,  54,  54,
+       49,  48,  47,  47,  46,  46,  46,  46,  46,  46,  46,  47,  47,  47,  48,  50,  50,  50,  52,  53,  53,  53,  54,  54,  54,  55,  55,  55,  55,  55,  55,  56,
+       48,  47,  47,  47,  46,  45,  45,  45,  46,  46,  46,  46,  46,  46,  48,  49,  49,  50,  52,  53,  53,  54,  54,  54,  55,  55,  55,  56,  56,  56,  56,  57,
+       48,  47,  47,  47,  46,  45,  45,  45,  46,  46,  46,  46,  46,  46,  48,  49,  49,  50,  52,  53,  53,  54,  54,  54,  55,  55,  55,  56,  56,  56,  56,  57,
+       49,  47,  47,  47,  46,  45,  45,  45,  46,  46,  46,  46,  46,  46,  47,  49,  49,  50,  52,  53,  53,  54,  55,  55,  55,  57,  57,  57,  57,  58,  58,  58,
+       49,  47,  47,  47,  46,  45,  45,  45,  45,  45,  45,  45,  45,  45,  47,  49,  49,  50,  52,  53,  53,  55,  55,  55,  57,  58,  58,  59,  59,  60,  60,  60,
+       49,  47,  47,  47,  46,  45,  45,  45,  45,  45,  45,  45,  45,  45,  47,  49,  49,  50,  52,  53,  53,  55,  55,  55,  57,  58,  58,  59,  59,  60,  60,  60,
+       49,  48,  47,  47,  46,  45,  45,  45,  45,  45,  45,  45,  45,  45,  47,  49,  49,  50,  52,  53,  53,  55,  56,  56,  57,  59,  59,  59,  60,  60,  60,  61,
+       50,  49,  48,  48,  47,  46,  46,  46,  46,  46,  46,  46,  46,  46,  47,  50,  50,  50,  53,  54,  54,  55,  56,  56,  57,  59,  59,  60,  61,  61,  61,  62,
+       50,  49,  48,  48,  47,  46,  46,  46,  46,  46,  46,  46,  46,  46,  47,  50,  50,  50,  53,  54,  54,  55,  56,  56,  58,  60,  60,  60,  61,  61,  61,  63,
+       50,  49,  48,  48,  47,  46,  46,  46,  46,  46,  46,  46,  46,  46,  47,  50,  50,  50,  53,  54,  54,  55,  56,  56,  58,  60,  60,  60,  61,  61,  61,  63,
+       51,  50,  49,  49,  48,  47,  47,  47,  47,  47,  47,  47,  46,  46,  48,  50,  50,  51,  53,  54,  54,  56,  57,  57,  58,  60,  60,  61,  62,  63,  63,  64,
+    ],
+  ],
+  // Level 10
+  [
+    // Luma
+    [
+      // 4x4
+       32,  32,  34,  38,
+       32,  33,  35,  39,
+       34,  35,  39,  45,
+       38,  39,  45,  54,
+      // 8x8
+       31,  31,  32,  32,  33,  34,  37,  41,
+       31,  32,  32,  32,  33,  34,  36,  39,
+       32,  32,  32,  33,  34,  35,  37,  40,
+       32,  32,  33,  34,  35,  36,  38,  41,
+       33,  33,  34,  35,  37,  39,  41,  44,
+       34,  34,  35,  36,  39,  43,  46,  49,
+       37,  36,  37,  38,  41,  46,  51,  54,
+       41,  39,  40,  41,  44,  49,  54,  58,
+      // 16x16
+       32,  31,  31,  31,  31,  31,  31,  32,  32,  34,  34,  36,  36,  39,  39,  44,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  34,  34,  35,  35,  38,  38,  42,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  34,  34,  35,  35,  38,  38,  42,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  34,  34,  37,  37,  41,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  34,  34,  37,  37,  41,
+       31,  32,  32,  32,  32,  33,  33,  34,  34,  35,  35,  36,  36,  39,  39,  42,
+       31,  32,  32,  32,  32,  33,  33,  34,  34,  35,  35,  36,  36,  39,  39,  42,
+       32,  32,  32,  32,  32,  34,  34,  35,  35,  37,  37,  38,  38,  40,  40,  42,
+       32,  32,  32,  32,  32,  34,  34,  35,  35,  37,  37,  38,  38,  40,  40,  42,
+       34,  34,  34,  33,  33,  35,  35,  37,  37,  39,  39,  42,  42,  45,  45,  47,
+       34,  34,  34,  33,  33,  35,  35,  37,  37,  39,  39,  42,  42,  45,  45,  47,
+       36,  35,  35,  34,  34,  36,  36,  38,  38,  42,  42,  48,  48,  50,  50,  54,
+       36,  35,  35,  34,  34,  36,  36,  38,  38,  42,  42,  48,  48,  50,  50,  54,
+       39,  38,  38,  37,  37,  39,  39,  40,  40,  45,  45,  50,  50,  54,  54,  58,
+       39,  38,  38,  37,  37,  39,  39,  40,  40,  45,  45,  50,  50,  54,  54,  58,
+       44,  42,  42,  41,  41,  42,  42,  42,  42,  47,  47,  54,  54,  58,  58,  63,
+      // 32x32
+       32,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  33,  34,  34,  34,  35,  36,  36,  36,  37,  39,  39,  39,  41,  44,  44,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  33,  34,  34,  34,  34,  35,  35,  35,  37,  39,  39,  39,  41,  43,  43,
+       31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  34,  34,  34,  34,  35,  35,  35,  37,  38,  38,  38,  40,  42,  42,
+       31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  34,  34,  34,  34,  35,  35,  35,  37,  38,  38,  38,  40,  42,  42,
+       31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  34,  34,  34,  34,  35,  35,  35,  37,  38,  38,  38,  40,  42,  42,
+       31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  34,  34,  34,  34,  35,  35,  35,  36,  38,  38,  38,  39,  41,  41,
+       31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  34,  34,  34,  34,  36,  37,  37,  37,  39,  41,  41,
+       31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  34,  34,  34,  34,  36,  37,  37,  37,  39,  41,  41,
+       31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  34,  34,  34,  34,  36,  37,  37,  37,  39,  41,  41,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  33,  33,  33,  34,  34,  34,  34,  35,  35,  35,  35,  37,  38,  38,  38,  40,  41,  41,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  33,  34,  34,  34,  34,  35,  35,  35,  36,  36,  36,  36,  38,  39,  39,  39,  40,  42,  42,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  33,  34,  34,  34,  34,  35,  35,  35,  36,  36,  36,  36,  38,  39,  39,  39,  40,  42,  42,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  33,  34,  34,  34,  34,  35,  35,  35,  36,  36,  36,  36,  38,  39,  39,  39,  40,  42,  42,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  34,  34,  34,  34,  35,  36,  36,  36,  36,  37,  37,  37,  38,  40,  40,  40,  41,  42,  42,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  34,  34,  34,  34,  35,  35,  35,  36,  37,  37,  37,  37,  38,  38,  38,  39,  40,  40,  40,  41,  42,  42,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  34,  34,  34,  34,  35,  35,  35,  36,  37,  37,  37,  37,  38,  38,  38,  39,  40,  40,  40,  41,  42,  42,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  34,  34,  34,  34,  35,  35,  35,  36,  37,  37,  37,  37,  38,  38,  38,  39,  40,  40,  40,  41,  42,  42,
+       33,  33,  33,  33,  33,  33,  33,  33,  33,  34,  34,  34,  34,  35,  36,  36,  36,  37,  38,  38,  38,  39,  40,  40,  40,  41,  42,  42,  42,  44,  45,  45,
+       34,  34,  34,  34,  34,  34,  33,  33,  33,  34,  35,  35,  35,  36,  37,  37,  37,  38,  39,  39,  39,  41,  42,  42,  42,  44,  45,  45,  45,  46,  47,  47,
+       34,  34,  34,  34,  34,  34,  33,  33,  33,  34,  35,  35,  35,  36,  37,  37,  37,  38,  39,  39,  39,  41,  42,  42,  42,  44,  45,  45,  45,  46,  47,  47,
+       34,  34,  34,  34,  34,  34,  33,  33,  33,  34,  35,  35,  35,  36,  37,  37,  37,  38,  39,  39,  39,  41,  42,  42,  42,  44,  45,  45,  45,  46,  47,  47,
+       35,  34,  34,  34,  34,  34,  34,  34,  34,  35,  36,  36,  36,  36,  37,  37,  37,  39,  41,  41,  41,  43,  45,  45,  45,  46,  47,  47,  47,  49,  50,  50,
+       36,  35,  35,  35,  35,  35,  34,  34,  34,  35,  36,  36,  36,  37,  38,  38,  38,  40,  42,  42,  42,  45,  48,  48,  48,  49,  50,  50,  50,  52,  54,  54,
+       36,  35,  35,  35,  35,  35,  34,  34,  34,  35,  36,  36,  36,  37,  38,  38,  38,  40,  42,  42,  42,  45,  48,  48,  48,  49,  50,  50,  50,  52,  54,  54,
+       36,  35,  35,  35,  35,  35,  34,  34,  34,  35,  36,  36,  36,  37,  38,  38,  38,  40,  42,  42,  42,  45,  48,  48,  48,  49,  50,  50,  50,  52,  54,  54,
+       37,  37,  37,  37,  37,  36,  36,  36,  36,  37,  38,  38,  38,  38,  39,  39,  39,  41,  44,  44,  44,  46,  49,  49,  49,  51,  52,  52,  52,  54,  56,  56,
+       39,  39,  38,  38,  38,  38,  37,  37,  37,  38,  39,  39,  39,  40,  40,  40,  40,  42,  45,  45,  45,  47,  50,  50,  50,  52,  54,  54,  54,  56,  58,  58,
+       39,  39,  38,  38,  38,  38,  37,  37,  37,  38,  39,  39,  39,  40,  40,  40,  40,  42,  45,  45,  45,  47,  50,  50,  50,  52,  54,  54,  54,  56,  58,  58,
+       39,  39,  38,  38,  38,  38,  37,  37,  37,  38,  39,  39,  39,  40,  40,  40,  40,  42,  45,  45,  45,  47,  50,  50,  50,  52,  54,  54,  54,  56,  58,  58,
+       41,  41,  40,  40,  40,  39,  39,  39,  39,  40,  40,  40,  40,  41,  41,  41,  41,  44,  46,  46,  46,  49,  52,  52,  52,  54,  56,  56,  56,  58,  60,  60,
+       44,  43,  42,  42,  42,  41,  41,  41,  41,  41,  42,  42,  42,  42,  42,  42,  42,  45,  47,  47,  47,  50,  54,  54,  54,  56,  58,  58,  58,  60,  63,  63,
+       44,  43,  42,  42,  42,  41,  41,  41,  41,  41,  42,  42,  42,  42,  42,  42,  42,  45,  47,  47,  47,  50,  54,  54,  54,  56,  58,  58,  58,  60,  63,  63,
+    ],
+    // Chroma
+    [
+      // 4x4
+       31,  34,  42,  47,
+       34,  39,  45,  46,
+       42,  45,  48,  49,
+       47,  46,  49,  54,
+      // 8x8
+       31,  31,  32,  35,  39,  45,  48,  48,
+       31,  31,  33,  37,  41,  44,  46,  46,
+       32,  33,  35,  39,  42,  45,  46,  45,
+       35,  37,  39,  43,  45,  47,  47,  46,
+       39,  41,  42,  45,  47,  48,  48,  47,
+       45,  44,  45,  47,  48,  50,  51,  51,
+       48,  46,  46,  47,  48,  51,  53,  54,
+       48,  46,  45,  46,  47,  51,  54,  56,
+      // 16x16
+       32,  31,  31,  30,  30,  33,  33,  36,  36,  41,  41,  49,  49,  48,  48,  49,
+       31,  31,  31,  31,  31,  34,  34,  38,  38,  42,  42,  47,  47,  47,  47,  47,
+       31,  31,  31,  31,  31,  34,  34,  38,  38,  42,  42,  47,  47,  47,  47,  47,
+       30,  31,  31,  32,  32,  35,  35,  40,  40,  42,  42,  46,  46,  45,  45,  45,
+       30,  31,  31,  32,  32,  35,  35,  40,  40,  42,  42,  46,  46,  45,  45,  45,
+       33,  34,  34,  35,  35,  39,  39,  43,  43,  45,  45,  47,  47,  46,  46,  45,
+       33,  34,  34,  35,  35,  39,  39,  43,  43,  45,  45,  47,  47,  46,  46,  45,
+       36,  38,  38,  40,  40,  43,  43,  47,  47,  47,  47,  48,  48,  46,  46,  45,
+       36,  38,  38,  40,  40,  43,  43,  47,  47,  47,  47,  48,  48,  46,  46,  45,
+       41,  42,  42,  42,  42,  45,  45,  47,  47,  48,  48,  50,  50,  49,  49,  49,
+       41,  42,  42,  42,  42,  45,  45,  47,  47,  48,  48,  50,  50,  49,  49,  49,
+       49,  47,  47,  46,  46,  47,  47,  48,  48,  50,  50,  53,  53,  53,  53,  53,
+       49,  47,  47,  46,  46,  47,  47,  48,  48,  50,  50,  53,  53,  53,  53,  53,
+       48,  47,  47,  45,  45,  46,  46,  46,  46,  49,  49,  53,  53,  54,  54,  55,
+       48,  47,  47,  45,  45,  46,  46,  46,  46,  49,  49,  53,  53,  54,  54,  55,
+       49,  47,  47,  45,  45,  45,  45,  45,  45,  49,  49,  53,  53,  55,  55,  58,
+      // 32x32
+       32,  31,  31,  31,  31,  31,  30,  30,  30,  32,  33,  33,  33,  35,  36,  36,  36,  39,  41,  41,  41,  45,  49,  49,  49,  49,  48,  48,  48,  49,  49,  49,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  34,  34,  34,  35,  37,  37,  37,  39,  42,  42,  42,  45,  48,  48,  48,  48,  48,  48,  48,  48,  48,  48,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  33,  34,  34,  34,  36,  38,  38,  38,  40,  42,  42,  42,  45,  47,  47,  47,  47,  47,  47,  47,  47,  47,  47,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  33,  34,  34,  34,  36,  38,  38,  38,  40,  42,  42,  42,  45,  47,  47,  47,  47,  47,  47,  47,  47,  47,  47,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  33,  34,  34,  34,  36,  38,  38,  38,  40,  42,  42,  42,  45,  47,  47,  47,  47,  47,  47,  47,  47,  47,  47,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  33,  35,  35,  35,  37,  39,  39,  39,  41,  42,  42,  42,  44,  47,  47,  47,  46,  46,  46,  46,  46,  46,  46,
+       30,  31,  31,  31,  31,  31,  32,  32,  32,  33,  35,  35,  35,  37,  40,  40,  40,  41,  42,  42,  42,  44,  46,  46,  46,  46,  45,  45,  45,  45,  45,  45,
+       30,  31,  31,  31,  31,  31,  32,  32,  32,  33,  35,  35,  35,  37,  40,  40,  40,  41,  42,  42,  42,  44,  46,  46,  46,  46,  45,  45,  45,  45,  45,  45,
+       30,  31,  31,  31,  31,  31,  32,  32,  32,  33,  35,  35,  35,  37,  40,  40,  40,  41,  42,  42,  42,  44,  46,  46,  46,  46,  45,  45,  45,  45,  45,  45,
+       32,  32,  33,  33,  33,  33,  33,  33,  33,  35,  37,  37,  37,  39,  41,  41,  41,  42,  43,  43,  43,  45,  47,  47,  47,  46,  46,  46,  46,  45,  45,  45,
+       33,  34,  34,  34,  34,  35,  35,  35,  35,  37,  39,  39,  39,  41,  43,  43,  43,  44,  45,  45,  45,  46,  47,  47,  47,  47,  46,  46,  46,  46,  45,  45,
+       33,  34,  34,  34,  34,  35,  35,  35,  35,  37,  39,  39,  39,  41,  43,  43,  43,  44,  45,  45,  45,  46,  47,  47,  47,  47,  46,  46,  46,  46,  45,  45,
+       33,  34,  34,  34,  34,  35,  35,  35,  35,  37,  39,  39,  39,  41,  43,  43,  43,  44,  45,  45,  45,  46,  47,  47,  47,  47,  46,  46,  46,  46,  45,  45,
+       35,  35,  36,  36,  36,  37,  37,  37,  37,  39,  41,  41,  41,  43,  45,  45,  45,  45,  46,  46,  46,  47,  47,  47,  47,  47,  46,  46,  46,  46,  45,  45,
+       36,  37,  38,  38,  38,  39,  40,  40,  40,  41,  43,  43,  43,  45,  47,  47,  47,  47,  47,  47,  47,  47,  48,  48,  48,  47,  46,  46,  46,  46,  45,  45,
+       36,  37,  38,  38,  38,  39,  40,  40,  40,  41,  43,  43,  43,  45,  47,  47,  47,  47,  47,  47,  47,  47,  48,  48,  48,  47,  46,  46,  46,  46,  45,  45,
+       36,  37,  38,  38,  38,  39,  40,  40,  40,  41,  43,  43,  43,  45,  47,  47,  47,  47,  47,  47,  47,  47,  48,  48,  48,  47,  46,  46,  46,  46,  45,  45,
+       39,  39,  40,  40,  40,  41,  41,  41,  41,  42,  44,  44,  44,  45,  47,  47,  47,  47,  48,  48,  48,  48,  49,  49,  49,  48,  48,  48,  48,  47,  47,  47,
+       41,  42,  42,  42,  42,  42,  42,  42,  42,  43,  45,  45,  45,  46,  47,  47,  47,  48,  48,  48,  48,  49,  50,  50,  50,  50,  49,  49,  49,  49,  49,  49,
+       41,  42,  42,  42,  42,  42,  42,  42,  42,  43,  45,  45,  45,  46,  47,  47,  47,  48,  48,  48,  48,  49,  50,  50,  50,  50,  49,  49,  49,  49,  49,  49,
+       41,  42,  42,  42,  42,  42,  42,  42,  42,  43,  45,  45,  45,  46,  47,  47,  47,  48,  48,  48,  48,  49,  50,  50,  50,  50,  49,  49,  49,  49,  49,  49,
+       45,  45,  45,  45,  45,  44,  44,  44,  44,  45,  46,  46,  46,  47,  47,  47,  47,  48,  49,  49,  49,  50,  51,  51,  51,  51,  51,  51,  51,  51,  51,  51,
+       49,  48,  47,  47,  47,  47,  46,  46,  46,  47,  47,  47,  47,  47,  48,  48,  48,  49,  50,  50,  50,  51,  53,  53,  53,  53,  53,  53,  53,  53,  53,  53,
+       49,  48,  47,  47,  47,  47,  46,  46,  46,  47,  47,  47,  47,  47,  48,  48,  48,  49,  50,  50,  50,  51,  53,  53,  53,  53,  53,  53,  53,  53,  53,  53,
+       49,  48,  47,  47,  47,  47,  46,  46,  46,  47,  47,  47,  47,  47,  48,  48,  48,  49,  50,  50,  50,  51,  53,  53,  53,  53,  53,  53,  53,  53,  53,  53,
+       49,  48,  47,  47,  47,  46,  46,  46,  46,  46,  47,  47,  47,  47,  47,  47,  47,  48,  50,  50,  50,  51,  53,  53,  53,  53,  53,  53,  53,  54,  54,  54,
+       48,  48,  47,  47,  47,  46,  45,  45,  45,  46,  46,  46,  46,  46,  46,  46,  46,  48,  49,  49,  49,  51,  53,  53,  53,  53,  54,  54,  54,  55,  55,  55,
+       48,  48,  47,  47,  47,  46,  45,  45,  45,  46,  46,  46,  46,  46,  46,  46,  46,  48,  49,  49,  49,  51,  53,  53,  53,  53,  54,  54,  54,  55,  55,  55,
+       48,  48,  47,  47,  47,  46,  45,  45,  45,  46,  46,  46,  46,  46,  46,  46,  46,  48,  49,  49,  49,  51,  53,  53,  53,  53,  54,  54,  54,  55,  55,  55,
+       49,  48,  47,  47,  47,  46,  45,  45,  45,  45,  46,  46,  46,  46,  46,  46,  46,  47,  49,  49,  49,  51,  53,  53,  53,  54,  55,  55,  55,  56,  57,  57,
+       49,  48,  47,  47,  47,  46,  45,  45,  45,  45,  45,  45,  45,  45,  45,  45,  45,  47,  49,  49,  49,  51,  53,  53,  53,  54,  55,  55,  55,  57,  58,  58,
+       49,  48,  47,  47,  47,  46,  45,  45,  45,  45,  45,  45,  45,  45,  45,  45,  45,  47,  49,  49,  49,  51,  53,  53,  53,  54,  55,  55,  55,  57,  58,  58,
+    ],
+  ],
+  // Level 11
+  [
+    // Luma
+    [
+      // 4x4
+       32,  32,  32,  35,
+       32,  32,  33,  35,
+       32,  33,  35,  38,
+       35,  35,  38,  46,
+      // 8x8
+       31,  31,  31,  32,  32,  32,  34,  35,
+       31,  32,  32,  32,  32,  33,  34,  35,
+       31,  32,  32,  32,  32,  33,  33,  34,
+       32,  32,  32,  33,  34,  34,  35,  36,
+       32,  32,  32,  34,  35,  35,  36,  38,
+       32,  33,  33,  34,  35,  36,  38,  40,
+       34,  34,  33,  35,  36,  38,  39,  42,
+       35,  35,  34,  36,  38,  40,  42,  48,
+      // 16x16
+       32,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  33,  34,  34,  36,  36,
+       31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  33,  34,  34,  35,  35,
+       31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  34,  34,  35,  35,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  34,  34,  35,  35,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  34,  34,  34,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  34,  34,  34,
+       31,  32,  32,  32,  32,  32,  33,  33,  33,  33,  33,  34,  35,  35,  36,  36,
+       31,  32,  32,  32,  32,  32,  33,  33,  33,  34,  34,  35,  35,  36,  36,  36,
+       32,  32,  32,  32,  32,  32,  33,  33,  34,  34,  34,  35,  36,  36,  37,  37,
+       32,  32,  32,  32,  32,  32,  33,  34,  34,  35,  35,  36,  37,  37,  38,  38,
+       32,  32,  32,  32,  32,  32,  33,  34,  34,  35,  35,  36,  37,  37,  38,  38,
+       33,  33,  33,  33,  33,  33,  34,  35,  35,  36,  36,  38,  39,  40,  42,  42,
+       34,  34,  34,  34,  33,  33,  35,  35,  36,  37,  37,  39,  39,  41,  42,  42,
+       34,  34,  34,  34,  34,  34,  35,  36,  36,  37,  37,  40,  41,  42,  45,  45,
+       36,  35,  35,  35,  34,  34,  36,  36,  37,  38,  38,  42,  42,  45,  48,  48,
+       36,  35,  35,  35,  34,  34,  36,  36,  37,  38,  38,  42,  42,  45,  48,  48,
+      // 32x32
+       32,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  33,  34,  34,  34,  34,  35,  36,  36,  36,  37,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  34,  34,  34,  34,  35,  35,  35,  35,  37,
+       31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  34,  34,  34,  34,  35,  35,  35,  35,  36,
+       31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  34,  34,  34,  34,  35,  35,  35,  35,  36,
+       31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  34,  34,  34,  34,  35,  35,  35,  35,  36,
+       31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  34,  34,  34,  34,  35,  35,  35,  35,  36,
+       31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  34,  34,  34,  34,  34,  35,  35,  35,  36,
+       31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  33,  34,  34,  34,  34,  34,  35,
+       31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  33,  34,  34,  34,  34,  34,  35,
+       31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  33,  34,  34,  34,  34,  34,  35,
+       31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  33,  34,  34,  34,  34,  34,  35,
+       31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  33,  33,  33,  33,  33,  33,  34,  34,  34,  34,  34,  35,  35,  35,  35,  36,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  33,  33,  33,  33,  33,  33,  34,  34,  35,  35,  35,  35,  36,  36,  36,  36,  37,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  33,  33,  34,  34,  34,  34,  34,  35,  35,  35,  35,  36,  36,  36,  36,  36,  37,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  33,  33,  34,  34,  34,  34,  34,  35,  35,  35,  35,  36,  36,  36,  36,  36,  37,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  33,  33,  34,  34,  34,  34,  34,  35,  35,  35,  35,  36,  36,  36,  36,  36,  37,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  33,  34,  34,  34,  34,  34,  35,  35,  36,  36,  36,  36,  37,  37,  37,  37,  38,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  34,  34,  34,  34,  35,  35,  35,  35,  35,  36,  36,  36,  36,  37,  37,  38,  38,  38,  39,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  34,  34,  34,  34,  35,  35,  35,  35,  36,  36,  37,  37,  37,  37,  38,  38,  38,  38,  39,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  34,  34,  34,  34,  35,  35,  35,  35,  36,  36,  37,  37,  37,  37,  38,  38,  38,  38,  39,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  34,  34,  34,  34,  35,  35,  35,  35,  36,  36,  37,  37,  37,  37,  38,  38,  38,  38,  39,
+       32,  32,  33,  33,  33,  33,  33,  33,  33,  33,  33,  33,  34,  34,  34,  34,  35,  35,  36,  36,  36,  36,  37,  38,  38,  38,  38,  39,  40,  40,  40,  41,
+       33,  33,  33,  33,  33,  33,  33,  33,  33,  33,  33,  34,  34,  35,  35,  35,  35,  36,  36,  36,  36,  37,  38,  39,  39,  39,  40,  41,  42,  42,  42,  42,
+       34,  34,  34,  34,  34,  34,  34,  33,  33,  33,  33,  34,  35,  35,  35,  35,  36,  36,  37,  37,  37,  38,  39,  39,  39,  39,  41,  42,  42,  42,  42,  43,
+       34,  34,  34,  34,  34,  34,  34,  33,  33,  33,  33,  34,  35,  35,  35,  35,  36,  36,  37,  37,  37,  38,  39,  39,  39,  39,  41,  42,  42,  42,  42,  43,
+       34,  34,  34,  34,  34,  34,  34,  33,  33,  33,  33,  34,  35,  35,  35,  35,  36,  36,  37,  37,  37,  38,  39,  39,  39,  39,  41,  42,  42,  42,  42,  43,
+       34,  34,  34,  34,  34,  34,  34,  34,  34,  34,  34,  34,  35,  36,  36,  36,  36,  37,  37,  37,  37,  38,  40,  41,  41,  41,  42,  44,  45,  45,  45,  45,
+       35,  35,  35,  35,  35,  35,  34,  34,  34,  34,  34,  35,  36,  36,  36,  36,  37,  37,  38,  38,  38,  39,  41,  42,  42,  42,  44,  46,  47,  47,  47,  48,
+       36,  35,  35,  35,  35,  35,  35,  34,  34,  34,  34,  35,  36,  36,  36,  36,  37,  38,  38,  38,  38,  40,  42,  42,  42,  42,  45,  47,  48,  48,  48,  49,
+       36,  35,  35,  35,  35,  35,  35,  34,  34,  34,  34,  35,  36,  36,  36,  36,  37,  38,  38,  38,  38,  40,  42,  42,  42,  42,  45,  47,  48,  48,  48,  49,
+       36,  35,  35,  35,  35,  35,  35,  34,  34,  34,  34,  35,  36,  36,  36,  36,  37,  38,  38,  38,  38,  40,  42,  42,  42,  42,  45,  47,  48,  48,  48,  49,
+       37,  37,  36,  36,  36,  36,  36,  35,  35,  35,  35,  36,  37,  37,  37,  37,  38,  39,  39,  39,  39,  41,  42,  43,  43,  43,  45,  48,  49,  49,  49,  50,
+    ],
+    // Chroma
+    [
+      // 4x4
+       31,  32,  38,  46,
+       32,  34,  41,  46,
+       38,  41,  47,  47,
+       46,  46,  47,  52,
+      // 8x8
+       31,  31,  30,  34,  36,  39,  42,  48,
+       31,  31,  31,  34,  37,  40,  42,  47,
+       30,  31,  32,  35,  39,  41,  42,  46,
+       34,  34,  35,  39,  42,  44,  45,  47,
+       36,  37,  39,  42,  46,  47,  47,  47,
+       39,  40,  41,  44,  47,  47,  48,  49,
+       42,  42,  42,  45,  47,  48,  48,  50,
+       48,  47,  46,  47,  47,  49,  50,  53,
+      // 16x16
+       32,  31,  31,  31,  30,  30,  33,  33,  34,  36,  36,  40,  41,  44,  49,  49,
+       31,  31,  31,  31,  31,  31,  33,  34,  36,  38,  38,  41,  42,  44,  48,  48,
+       31,  31,  31,  31,  31,  31,  34,  34,  36,  38,  38,  41,  42,  44,  47,  47,
+       31,  31,  31,  31,  31,  31,  34,  35,  36,  39,  39,  41,  42,  44,  47,  47,
+       30,  31,  31,  31,  32,  32,  34,  35,  37,  40,  40,  42,  42,  44,  46,  46,
+       30,  31,  31,  31,  32,  32,  34,  35,  37,  40,  40,  42,  42,  44,  46,  46,
+       33,  33,  34,  34,  34,  34,  37,  38,  40,  42,  42,  44,  44,  45,  47,  47,
+       33,  34,  34,  35,  35,  35,  38,  39,  40,  43,  43,  44,  45,  46,  47,  47,
+       34,  36,  36,  36,  37,  37,  40,  40,  42,  45,  45,  45,  46,  46,  47,  47,
+       36,  38,  38,  39,  40,  40,  42,  43,  45,  47,  47,  47,  47,  47,  48,  48,
+       36,  38,  38,  39,  40,  40,  42,  43,  45,  47,  47,  47,  47,  47,  48,  48,
+       40,  41,  41,  41,  42,  42,  44,  44,  45,  47,  47,  48,  48,  49,  50,  50,
+       41,  42,  42,  42,  42,  42,  44,  45,  46,  47,  47,  48,  48,  49,  50,  50,
+       44,  44,  44,  44,  44,  44,  45,  46,  46,  47,  47,  49,  49,  50,  51,  51,
+       49,  48,  47,  47,  46,  46,  47,  47,  47,  48,  48,  50,  50,  51,  53,  53,
+       49,  48,  47,  47,  46,  46,  47,  47,  47,  48,  48,  50,  50,  51,  53,  53,
+      // 32x32
+       32,  31,  31,  31,  31,  31,  31,  30,  30,  30,  30,  31,  33,  33,  33,  33,  34,  36,  36,  36,  36,  38,  40,  41,  41,  41,  44,  47,  49,  49,  49,  49,
+       31,  31,  31,  31,  31,  31,  31,  31,  30,  30,  30,  32,  33,  34,  34,  34,  35,  36,  37,  37,  37,  39,  41,  42,  42,  42,  44,  47,  48,  48,  48,  48,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  33,  34,  34,  34,  36,  37,  38,  38,  38,  39,  41,  42,  42,  42,  44,  46,  48,  48,  48,  47,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  34,  34,  34,  34,  36,  37,  38,  38,  38,  40,  41,  42,  42,  42,  44,  46,  47,  47,  47,  47,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  34,  34,  34,  34,  36,  37,  38,  38,  38,  40,  41,  42,  42,  42,  44,  46,  47,  47,  47,  47,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  34,  34,  34,  34,  36,  37,  38,  38,  38,  40,  41,  42,  42,  42,  44,  46,  47,  47,  47,  47,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  33,  34,  35,  35,  35,  36,  38,  39,  39,  39,  40,  41,  42,  42,  42,  44,  46,  47,  47,  47,  47,
+       30,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  33,  34,  35,  35,  35,  37,  38,  39,  39,  39,  41,  42,  42,  42,  42,  44,  46,  46,  46,  46,  46,
+       30,  30,  31,  31,  31,  31,  31,  31,  32,  32,  32,  33,  34,  35,  35,  35,  37,  39,  40,  40,  40,  41,  42,  42,  42,  42,  44,  45,  46,  46,  46,  46,
+       30,  30,  31,  31,  31,  31,  31,  31,  32,  32,  32,  33,  34,  35,  35,  35,  37,  39,  40,  40,  40,  41,  42,  42,  42,  42,  44,  45,  46,  46,  46,  46,
+       30,  30,  31,  31,  31,  31,  31,  31,  32,  32,  32,  33,  34,  35,  35,  35,  37,  39,  40,  40,  40,  41,  42,  42,  42,  42,  44,  45,  46,  46,  46,  46,
+       31,  32,  32,  32,  32,  32,  33,  33,  33,  33,  33,  34,  36,  37,  37,  37,  38,  40,  41,  41,  41,  42,  43,  43,  43,  43,  44,  46,  46,  46,  46,  46,
+       33,  33,  33,  34,  34,  34,  34,  34,  34,  34,  34,  36,  37,  38,  38,  38,  40,  41,  42,  42,  42,  43,  44,  44,  44,  44,  45,  46,  47,  47,  47,  46,
+       33,  34,  34,  34,  34,  34,  35,  35,  35,  35,  35,  37,  38,  39,  39,  39,  40,  42,  43,  43,  43,  44,  44,  45,  45,  45,  46,  47,  47,  47,  47,  47,
+       33,  34,  34,  34,  34,  34,  35,  35,  35,  35,  35,  37,  38,  39,  39,  39,  40,  42,  43,  43,  43,  44,  44,  45,  45,  45,  46,  47,  47,  47,  47,  47,
+       33,  34,  34,  34,  34,  34,  35,  35,  35,  35,  35,  37,  38,  39,  39,  39,  40,  42,  43,  43,  43,  44,  44,  45,  45,  45,  46,  47,  47,  47,  47,  47,
+       34,  35,  36,  36,  36,  36,  36,  37,  37,  37,  37,  38,  40,  40,  40,  40,  42,  44,  45,  45,  45,  45,  45,  46,  46,  46,  46,  47,  47,  47,  47,  47,
+       36,  36,  37,  37,  37,  37,  38,  38,  39,  39,  39,  40,  41,  42,  42,  42,  44,  46,  46,  46,  46,  47,  47,  47,  47,  47,  47,  47,  47,  47,  47,  47,
+       36,  37,  38,  38,  38,  38,  39,  39,  40,  40,  40,  41,  42,  43,  43,  43,  45,  46,  47,  47,  47,  47,  47,  47,  47,  47,  47,  47,  48,  48,  48,  47,
+       36,  37,  38,  38,  38,  38,  39,  39,  40,  40,  40,  41,  42,  43,  43,  43,  45,  46,  47,  47,  47,  47,  47,  47,  47,  47,  47,  47,  48,  48,  48,  47,
+       36,  37,  38,  38,  38,  38,  39,  39,  40,  40,  40,  41,  42,  43,  43,  43,  45,  46,  47,  47,  47,  47,  47,  47,  47,  47,  47,  47,  48,  48,  48,  47,
+       38,  39,  39,  40,  40,  40,  40,  41,  41,  41,  41,  42,  43,  44,  44,  44,  45,  47,  47,  47,  47,  47,  48,  48,  48,  48,  48,  48,  49,  49,  49,  48,
+       40,  41,  41,  41,  41,  41,  41,  42,  42,  42,  42,  43,  44,  44,  44,  44,  45,  47,  47,  47,  47,  48,  48,  48,  48,  48,  49,  49,  50,  50,  50,  49,
+       41,  42,  42,  42,  42,  42,  42,  42,  42,  42,  42,  43,  44,  45,  45,  45,  46,  47,  47,  47,  47,  48,  48,  48,  48,  48,  49,  50,  50,  50,  50,  50,
+       41,  42,  42,  42,  42,  42,  42,  42,  42,  42,  42,  43,  44,  45,  45,  45,  46,  47,  47,  47,  47,  48,  48,  48,  48,  48,  49,  50,  50,  50,  50,  50,
+       41,  42,  42,  42,  42,  42,  42,  42,  42,  42,  42,  43,  44,  45,  45,  45,  46,  47,  47,  47,  47,  48,  48,  48,  48,  48,  49,  50,  50,  50,  50,  50,
+       44,  44,  44,  44,  44,  44,  44,  44,  44,  44,  44,  44,  45,  46,  46,  46,  46,  47,  47,  47,  47,  48,  49,  49,  49,  49,  50,  51,  51,  51,  51,  51,
+       47,  47,  46,  46,  46,  46,  46,  46,  45,  45,  45,  46,  46,  47,  47,  47,  47,  47,  47,  47,  47,  48,  49,  50,  50,  50,  51,  52,  52,  52,  52,  52,
+       49,  48,  48,  47,  47,  47,  47,  46,  46,  46,  46,  46,  47,  47,  47,  47,  47,  47,  48,  48,  48,  49,  50,  50,  50,  50,  51,  52,  53,  53,  53,  53,
+       49,  48,  48,  47,  47,  47,  47,  46,  46,  46,  46,  46,  47,  47,  47,  47,  47,  47,  48,  48,  48,  49,  50,  50,  50,  50,  51,  52,  53,  53,  53,  53,
+       49,  48,  48,  47,  47,  47,  47,  46,  46,  46,  46,  46,  47,  47,  47,  47,  47,  47,  48,  48,  48,  49,  50,  50,  50,  50,  51,  52,  53,  53,  53,  53,
+       49,  48,  47,  47,  47,  47,  47,  46,  46,  46,  46,  46,  46,  47,  47,  47,  47,  47,  47,  47,  47,  48,  49,  50,  50,  50,  51,  52,  53,  53,  53,  53,
+    ],
+  ],
+  // Level 12
+  [
+    // Luma
+    [
+      // 4x4
+       31,  32,  32,  32,
+       32,  32,  32,  33,
+       32,  32,  33,  34,
+       32,  33,  34,  35,
+      // 8x8
+       31,  31,  31,  31,  32,  32,  32,  33,
+       31,  32,  32,  32,  32,  32,  32,  33,
+       31,  32,  32,  32,  32,  32,  32,  33,
+       31,  32,  32,  32,  32,  32,  32,  33,
+       32,  32,  32,  32,  33,  33,  34,  35,
+       32,  32,  32,  32,  33,  34,  34,  35,
+       32,  32,  32,  32,  34,  34,  35,  36,
+       33,  33,  33,  33,  35,  35,  36,  38,
+      // 16x16
+       32,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  34,
+       31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  33,  34,
+       31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  34,
+       31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  34,
+       31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  34,
+       31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,
+       31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  34,
+       31,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  33,  33,  34,  35,
+       31,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  34,  34,  34,  35,
+       31,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  34,  34,  34,  35,
+       32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  34,  35,  35,  35,  36,
+       32,  32,  32,  32,  32,  32,  32,  33,  33,  34,  34,  35,  35,  35,  36,  37,
+       32,  32,  32,  32,  32,  32,  32,  33,  33,  34,  34,  35,  35,  35,  36,  37,
+       32,  33,  33,  33,  33,  33,  33,  33,  34,  34,  34,  35,  36,  36,  36,  38,
+       34,  34,  34,  34,  34,  33,  33,  34,  35,  35,  35,  36,  37,  37,  38,  39,
+      // 32x32
+       32,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  33,  34,  34,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  34,  34,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  34,  34,
+       31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  34,  34,
+       31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  34,  34,
+       31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  34,  34,
+       31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  34,  34,
+       31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  34,  34,
+       31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  34,  34,
+       31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,
+       31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  33,
+       31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  33,
+       31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  33,
+       31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  33,
+       31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  33,  33,  33,  33,  33,  34,  34,
+       31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  33,  33,  33,  33,  33,  33,  33,  33,  34,  34,  34,  34,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  33,  33,  33,  33,  33,  33,  33,  33,  34,  34,  34,  35,  35,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  33,  33,  33,  33,  34,  34,  34,  34,  34,  34,  35,  35,  35,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  33,  33,  33,  33,  34,  34,  34,  34,  34,  34,  35,  35,  35,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  33,  33,  33,  33,  34,  34,  34,  34,  34,  34,  35,  35,  35,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  33,  33,  33,  33,  34,  34,  34,  34,  34,  34,  35,  35,  35,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  33,  33,  33,  34,  34,  34,  34,  34,  34,  34,  35,  35,  35,  35,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  33,  33,  33,  34,  34,  34,  35,  35,  35,  35,  35,  35,  36,  36,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  34,  34,  34,  34,  34,  34,  35,  35,  35,  35,  35,  35,  36,  36,  36,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  34,  34,  34,  34,  34,  35,  35,  35,  35,  35,  35,  36,  36,  37,  37,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  34,  34,  34,  34,  34,  35,  35,  35,  35,  35,  35,  36,  36,  37,  37,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  34,  34,  34,  34,  34,  35,  35,  35,  35,  35,  35,  36,  36,  37,  37,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  33,  33,  34,  34,  34,  34,  34,  34,  35,  35,  35,  35,  35,  35,  36,  36,  37,  37,
+       32,  32,  33,  33,  33,  33,  33,  33,  33,  33,  33,  33,  33,  33,  33,  34,  34,  34,  34,  34,  34,  35,  35,  35,  36,  36,  36,  36,  36,  37,  38,  38,
+       33,  33,  33,  33,  33,  33,  33,  33,  33,  33,  33,  33,  33,  33,  33,  34,  34,  35,  35,  35,  35,  35,  35,  36,  36,  36,  36,  36,  37,  38,  38,  38,
+       34,  34,  34,  34,  34,  34,  34,  34,  34,  33,  33,  33,  33,  33,  34,  34,  35,  35,  35,  35,  35,  35,  36,  36,  37,  37,  37,  37,  38,  38,  39,  39,
+       34,  34,  34,  34,  34,  34,  34,  34,  34,  33,  33,  33,  33,  33,  34,  34,  35,  35,  35,  35,  35,  35,  36,  36,  37,  37,  37,  37,  38,  38,  39,  39,
+    ],
+    // Chroma
+    [
+      // 4x4
+       31,  31,  34,  38,
+       31,  32,  35,  40,
+       34,  35,  39,  43,
+       38,  40,  43,  47,
+      // 8x8
+       31,  31,  31,  30,  34,  35,  37,  40,
+       31,  31,  31,  31,  34,  35,  38,  41,
+       31,  31,  31,  31,  35,  36,  39,  41,
+       30,  31,  31,  32,  35,  36,  40,  42,
+       34,  34,  35,  35,  39,  40,  43,  44,
+       35,  35,  36,  36,  40,  41,  44,  45,
+       37,  38,  39,  40,  43,  44,  47,  47,
+       40,  41,  41,  42,  44,  45,  47,  48,
+      // 16x16
+       32,  31,  31,  31,  31,  30,  30,  31,  33,  33,  33,  35,  36,  36,  38,  41,
+       31,  31,  31,  31,  31,  31,  31,  31,  33,  34,  34,  36,  37,  37,  39,  42,
+       31,  31,  31,  31,  31,  31,  31,  32,  34,  34,  34,  37,  38,  38,  40,  42,
+       31,  31,  31,  31,  31,  31,  31,  32,  34,  34,  34,  37,  38,  38,  40,  42,
+       31,  31,  31,  31,  31,  31,  31,  32,  34,  35,  35,  37,  39,  39,  40,  42,
+       30,  31,  31,  31,  31,  32,  32,  32,  34,  35,  35,  38,  40,  40,  41,  42,
+       30,  31,  31,  31,  31,  32,  32,  32,  34,  35,  35,  38,  40,  40,  41,  42,
+       31,  31,  32,  32,  32,  32,  32,  33,  35,  36,  36,  38,  40,  40,  41,  43,
+       33,  33,  34,  34,  34,  34,  34,  35,  37,  38,  38,  41,  42,  42,  43,  44,
+       33,  34,  34,  34,  35,  35,  35,  36,  38,  39,  39,  41,  43,  43,  44,  45,
+       33,  34,  34,  34,  35,  35,  35,  36,  38,  39,  39,  41,  43,  43,  44,  45,
+       35,  36,  37,  37,  37,  38,  38,  38,  41,  41,  41,  44,  46,  46,  46,  46,
+       36,  37,  38,  38,  39,  40,  40,  40,  42,  43,  43,  46,  47,  47,  47,  47,
+       36,  37,  38,  38,  39,  40,  40,  40,  42,  43,  43,  46,  47,  47,  47,  47,
+       38,  39,  40,  40,  40,  41,  41,  41,  43,  44,  44,  46,  47,  47,  47,  48,
+       41,  42,  42,  42,  42,  42,  42,  43,  44,  45,  45,  46,  47,  47,  48,  48,
+      // 32x32
+       32,  31,  31,  31,  31,  31,  31,  31,  31,  30,  30,  30,  30,  30,  31,  32,  33,  33,  33,  33,  33,  34,  35,  36,  36,  36,  36,  37,  38,  40,  41,  41,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  30,  30,  30,  30,  31,  32,  33,  34,  34,  34,  34,  35,  36,  37,  37,  37,  37,  37,  39,  40,  42,  42,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  33,  34,  34,  34,  34,  35,  36,  37,  37,  37,  37,  38,  39,  40,  42,  42,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  34,  34,  34,  34,  34,  35,  36,  38,  38,  38,  38,  38,  40,  41,  42,  42,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  33,  34,  34,  34,  34,  34,  35,  37,  38,  38,  38,  38,  39,  40,  41,  42,  42,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  33,  34,  34,  34,  34,  34,  35,  37,  38,  38,  38,  38,  39,  40,  41,  42,  42,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  33,  34,  34,  34,  34,  34,  35,  37,  38,  38,  38,  38,  39,  40,  41,  42,  42,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  33,  34,  34,  34,  34,  34,  36,  37,  38,  38,  38,  38,  39,  40,  41,  42,  42,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  33,  34,  35,  35,  35,  35,  36,  37,  38,  39,  39,  39,  39,  40,  41,  42,  42,
+       30,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  33,  34,  35,  35,  35,  35,  36,  37,  39,  39,  39,  39,  40,  40,  41,  42,  42,
+       30,  30,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  33,  34,  35,  35,  35,  35,  36,  38,  39,  40,  40,  40,  40,  41,  42,  42,  42,
+       30,  30,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  33,  34,  35,  35,  35,  35,  36,  38,  39,  40,  40,  40,  40,  41,  42,  42,  42,
+       30,  30,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  33,  34,  35,  35,  35,  35,  36,  38,  39,  40,  40,  40,  40,  41,  42,  42,  42,
+       30,  30,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  33,  34,  35,  35,  35,  35,  36,  38,  39,  40,  40,  40,  40,  41,  42,  42,  42,
+       31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  34,  35,  36,  36,  36,  36,  37,  38,  40,  40,  40,  40,  41,  41,  42,  43,  43,
+       32,  32,  32,  32,  33,  33,  33,  33,  33,  33,  33,  33,  33,  33,  34,  35,  36,  37,  37,  37,  37,  38,  39,  41,  41,  41,  41,  42,  42,  43,  43,  43,
+       33,  33,  33,  34,  34,  34,  34,  34,  34,  34,  34,  34,  34,  34,  35,  36,  37,  38,  38,  38,  38,  39,  41,  42,  42,  42,  42,  43,  43,  44,  44,  44,
+       33,  34,  34,  34,  34,  34,  34,  34,  35,  35,  35,  35,  35,  35,  36,  37,  38,  39,  39,  39,  39,  40,  41,  43,  43,  43,  43,  43,  44,  44,  45,  45,
+       33,  34,  34,  34,  34,  34,  34,  34,  35,  35,  35,  35,  35,  35,  36,  37,  38,  39,  39,  39,  39,  40,  41,  43,  43,  43,  43,  43,  44,  44,  45,  45,
+       33,  34,  34,  34,  34,  34,  34,  34,  35,  35,  35,  35,  35,  35,  36,  37,  38,  39,  39,  39,  39,  40,  41,  43,  43,  43,  43,  43,  44,  44,  45,  45,
+       33,  34,  34,  34,  34,  34,  34,  34,  35,  35,  35,  35,  35,  35,  36,  37,  38,  39,  39,  39,  39,  40,  41,  43,  43,  43,  43,  43,  44,  44,  45,  45,
+       34,  35,  35,  35,  35,  35,  35,  36,  36,  36,  36,  36,  36,  36,  37,  38,  39,  40,  40,  40,  40,  41,  42,  44,  44,  44,  44,  44,  45,  45,  45,  45,
+       35,  36,  36,  36,  37,  37,  37,  37,  37,  37,  38,  38,  38,  38,  38,  39,  41,  41,  41,  41,  41,  42,  44,  45,  46,  46,  46,  46,  46,  46,  46,  46,
+       36,  37,  37,  38,  38,  38,  38,  38,  38,  39,  39,  39,  39,  39,  40,  41,  42,  43,  43,  43,  43,  44,  45,  46,  47,  47,  47,  47,  47,  47,  47,  47,
+       36,  37,  37,  38,  38,  38,  38,  38,  39,  39,  40,  40,  40,  40,  40,  41,  42,  43,  43,  43,  43,  44,  46,  47,  47,  47,  47,  47,  47,  47,  47,  47,
+       36,  37,  37,  38,  38,  38,  38,  38,  39,  39,  40,  40,  40,  40,  40,  41,  42,  43,  43,  43,  43,  44,  46,  47,  47,  47,  47,  47,  47,  47,  47,  47,
+       36,  37,  37,  38,  38,  38,  38,  38,  39,  39,  40,  40,  40,  40,  40,  41,  42,  43,  43,  43,  43,  44,  46,  47,  47,  47,  47,  47,  47,  47,  47,  47,
+       37,  37,  38,  38,  39,  39,  39,  39,  39,  40,  40,  40,  40,  40,  41,  42,  43,  43,  43,  43,  43,  44,  46,  47,  47,  47,  47,  47,  47,  47,  47,  47,
+       38,  39,  39,  40,  40,  40,  40,  40,  40,  40,  41,  41,  41,  41,  41,  42,  43,  44,  44,  44,  44,  45,  46,  47,  47,  47,  47,  47,  47,  48,  48,  48,
+       40,  40,  40,  41,  41,  41,  41,  41,  41,  41,  42,  42,  42,  42,  42,  43,  44,  44,  44,  44,  44,  45,  46,  47,  47,  47,  47,  47,  48,  48,  48,  48,
+       41,  42,  42,  42,  42,  42,  42,  42,  42,  42,  42,  42,  42,  42,  43,  43,  44,  45,  45,  45,  45,  45,  46,  47,  47,  47,  47,  47,  48,  48,  48,  48,
+       41,  42,  42,  42,  42,  42,  42,  42,  42,  42,  42,  42,  42,  42,  43,  43,  44,  45,  45,  45,  45,  45,  46,  47,  47,  47,  47,  47,  48,  48,  48,  48,
+    ],
+  ],
+  // Level 13
+  [
+    // Luma
+    [
+      // 4x4
+       31,  31,  31,  32,
+       31,  32,  32,  32,
+       31,  32,  32,  32,
+       32,  32,  32,  33,
+      // 8x8
+       31,  31,  31,  31,  31,  31,  32,  32,
+       31,  32,  32,  32,  32,  32,  32,  32,
+       31,  32,  32,  32,  32,  32,  32,  32,
+       31,  32,  32,  32,  32,  32,  32,  32,
+       31,  32,  32,  32,  32,  32,  32,  32,
+       31,  32,  32,  32,  32,  32,  32,  32,
+       32,  32,  32,  32,  32,  32,  33,  33,
+       32,  32,  32,  32,  32,  32,  33,  33,
+      // 16x16
+       32,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,
+       31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  33,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  33,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  33,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  33,
+      // 32x32
+       32,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,
+       31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  33,  33,  33,  33,
+       31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  33,  33,  33,  33,  33,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  33,  33,  33,  33,  33,  33,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  33,  33,  33,  33,  33,  33,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  33,  33,  33,  33,  33,  33,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  33,  33,  33,  33,  33,  33,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  33,  33,  33,  33,  33,  33,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  33,  33,  33,  33,  33,  33,
+       31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  33,  33,  33,  33,  33,  33,
+       32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  33,  33,  33,  33,  33,  33,  33,
+    ],
+    // Chroma
+    [
+      // 4x4
+       31,  31,  31,  34,
+       31,  31,  31,  35,
+       31,  31,  32,  35,
+       34,  35,  35,  39,
+      // 8x8
+       31,  31,  31,  31,  30,  31,  33,  33,
+       31,  31,  31,  31,  31,  32,  34,  34,
+       31,  31,  31,  31,  31,  32,  34,  34,
+       31,  31,  31,  31,  31,  32,  35,  35,
+       30,  31,  31,  31,  32,  32,  35,  35,
+       31,  32,  32,  32,  32,  33,  36,  36,
+       33,  34,  34,  35,  35,  36,  39,  39,
+       33,  34,  34,  35,  35,  36,  39,  39,
+      // 16x16
+       32,  31,  31,  31,  31,  31,  31,  30,  30,  30,  30,  31,  33,  33,  33,  33,
+       31,  31,  31,  31,  31,  31,  31,  31,  30,  30,  30,  32,  33,  34,  34,  34,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  33,  34,  34,  34,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  34,  34,  34,  34,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  34,  34,  34,  34,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  34,  34,  34,  34,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  33,  34,  35,  35,  35,
+       30,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  33,  34,  35,  35,  35,
+       30,  30,  31,  31,  31,  31,  31,  31,  32,  32,  32,  33,  34,  35,  35,  35,
+       30,  30,  31,  31,  31,  31,  31,  31,  32,  32,  32,  33,  34,  35,  35,  35,
+       30,  30,  31,  31,  31,  31,  31,  31,  32,  32,  32,  33,  34,  35,  35,  35,
+       31,  32,  32,  32,  32,  32,  33,  33,  33,  33,  33,  34,  36,  37,  37,  37,
+       33,  33,  33,  34,  34,  34,  34,  34,  34,  34,  34,  36,  37,  38,  38,  38,
+       33,  34,  34,  34,  34,  34,  35,  35,  35,  35,  35,  37,  38,  39,  39,  39,
+       33,  34,  34,  34,  34,  34,  35,  35,  35,  35,  35,  37,  38,  39,  39,  39,
+       33,  34,  34,  34,  34,  34,  35,  35,  35,  35,  35,  37,  38,  39,  39,  39,
+      // 32x32
+       32,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  30,  30,  30,  30,  30,  30,  30,  30,  31,  31,  32,  33,  33,  33,  33,  33,  33,  33,  34,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  30,  30,  30,  30,  30,  30,  30,  31,  31,  32,  33,  33,  33,  33,  33,  33,  33,  34,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  30,  30,  30,  30,  30,  30,  31,  32,  32,  33,  34,  34,  34,  34,  34,  34,  34,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  33,  33,  34,  34,  34,  34,  34,  34,  35,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  33,  33,  34,  34,  34,  34,  34,  34,  35,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  33,  34,  34,  34,  34,  34,  34,  34,  35,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  33,  34,  34,  34,  34,  34,  34,  34,  35,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  33,  34,  34,  34,  34,  34,  34,  34,  35,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  33,  34,  34,  34,  34,  34,  34,  34,  35,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  33,  34,  34,  34,  34,  34,  34,  34,  35,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  33,  34,  34,  34,  34,  34,  34,  34,  35,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  33,  34,  35,  35,  35,  35,  35,  35,  35,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  33,  33,  34,  35,  35,  35,  35,  35,  35,  35,
+       30,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  33,  33,  34,  35,  35,  35,  35,  35,  35,  36,
+       30,  30,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  33,  34,  34,  35,  35,  35,  35,  35,  35,  36,
+       30,  30,  30,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  33,  34,  34,  35,  35,  35,  35,  35,  35,  36,
+       30,  30,  30,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  33,  34,  34,  35,  35,  35,  35,  35,  35,  36,
+       30,  30,  30,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  33,  34,  34,  35,  35,  35,  35,  35,  35,  36,
+       30,  30,  30,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  33,  34,  34,  35,  35,  35,  35,  35,  35,  36,
+       30,  30,  30,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  33,  34,  34,  35,  35,  35,  35,  35,  35,  36,
+       30,  30,  30,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  33,  34,  34,  35,  35,  35,  35,  35,  35,  36,
+       31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  34,  34,  35,  36,  36,  36,  36,  36,  36,  37,
+       31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  33,  33,  33,  33,  33,  33,  33,  33,  33,  34,  34,  35,  36,  37,  37,  37,  37,  37,  37,  37,
+       32,  32,  32,  33,  33,  33,  33,  33,  33,  33,  33,  33,  33,  33,  34,  34,  34,  34,  34,  34,  34,  34,  35,  36,  37,  37,  37,  37,  37,  37,  37,  38,
+       33,  33,  33,  33,  33,  34,  34,  34,  34,  34,  34,  34,  34,  34,  34,  34,  34,  34,  34,  34,  34,  35,  36,  37,  37,  38,  38,  38,  38,  38,  38,  39,
+       33,  33,  34,  34,  34,  34,  34,  34,  34,  34,  34,  35,  35,  35,  35,  35,  35,  35,  35,  35,  35,  36,  37,  37,  38,  39,  39,  39,  39,  39,  39,  40,
+       33,  33,  34,  34,  34,  34,  34,  34,  34,  34,  34,  35,  35,  35,  35,  35,  35,  35,  35,  35,  35,  36,  37,  37,  38,  39,  39,  39,  39,  39,  39,  40,
+       33,  33,  34,  34,  34,  34,  34,  34,  34,  34,  34,  35,  35,  35,  35,  35,  35,  35,  35,  35,  35,  36,  37,  37,  38,  39,  39,  39,  39,  39,  39,  40,
+       33,  33,  34,  34,  34,  34,  34,  34,  34,  34,  34,  35,  35,  35,  35,  35,  35,  35,  35,  35,  35,  36,  37,  37,  38,  39,  39,  39,  39,  39,  39,  40,
+       33,  33,  34,  34,  34,  34,  34,  34,  34,  34,  34,  35,  35,  35,  35,  35,  35,  35,  35,  35,  35,  36,  37,  37,  38,  39,  39,  39,  39,  39,  39,  40,
+       33,  33,  34,  34,  34,  34,  34,  34,  34,  34,  34,  35,  35,  35,  35,  35,  35,  35,  35,  35,  35,  36,  37,  37,  38,  39,  39,  39,  39,  39,  39,  40,
+       34,  34,  34,  35,  35,  35,  35,  35,  35,  35,  35,  35,  35,  36,  36,  36,  36,  36,  36,  36,  36,  37,  37,  38,  39,  40,  40,  40,  40,  40,  40,  40,
+    ],
+  ],
+  // Level 14
+  [
+    // Luma
+    [
+      // 4x4
+       31,  31,  31,  31,
+       31,  32,  32,  32,
+       31,  32,  32,  32,
+       31,  32,  32,  32,
+      // 8x8
+       31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  32,  32,  32,  32,  32,
+       31,  31,  32,  32,  32,  32,  32,  32,
+       31,  31,  32,  32,  32,  32,  32,  32,
+       31,  31,  32,  32,  32,  32,  32,  32,
+       31,  31,  32,  32,  32,  32,  32,  32,
+       31,  31,  32,  32,  32,  32,  32,  32,
+      // 16x16
+       32,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+      // 32x32
+       32,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+       31,  31,  31,  31,  31,  31,  31,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,  32,
+    ],
+    // Chroma
+    [
+      // 4x4
+       31,  31,  31,  31,
+       31,  31,  31,  31,
+       31,  31,  31,  31,
+       31,  31,  31,  31,
+      // 8x8
+       31,  31,  31,  31,  31,  31,  31,  30,
+       31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,
+       30,  31,  31,  31,  31,  31,  31,  31,
+      // 16x16
+       32,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  30,  30,  30,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  30,  30,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  30,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       30,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       30,  30,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       30,  30,  30,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,
+      // 32x32
+       32,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  30,  30,  30,  30,  30,  30,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  30,  30,  30,  30,  30,  30,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  30,  30,  30,  30,  30,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  30,  30,  30,  30,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  30,  30,  30,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       30,  30,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       30,  30,  30,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       30,  30,  30,  30,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       30,  30,  30,  30,  30,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,
+       30,  30,  30,  30,  30,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,
+       30,  30,  30,  30,  30,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  31,  32,  32,
+    ],
+  ],
+];
@@ -9,6 +9,62 @@
 
 use crate::util::*;
 
+// Common interface for anything which consumes entropy-coded symbols.
+// This is implemented both by EntropyWriter, which generates real output,
+// and by RateEstimator (see rate.rs), which only counts how many bits
+// the symbols would take. This allows the same syntax-writing code to be
+// used for both purposes.
+pub trait SymbolWriter {
+  // Write an entropy-coded symbol using the given CDF
+  //
+  // Note: Each CDF contains two implicit values:
+  // * cdf[-1] = 0, so that when symbol == 0 "lo" is implicitly 0
+  // * cdf[num_symbols - 1] = 32768, so that the probabilities sum to 1
+  //
+  // We do not store these values in the cdf array, and instead handle these cases
+  // with ifs in implementations of this function
+  fn write_symbol(&mut self, symbol: usize, cdf: &[u16]);
+
+  // Helper function: Write a single bit symbol, without needing extra syntax fluff to convert
+  // from a single probability to a CDF
+  // Note that, due to the way CDFs are encoded, the specified probability is the probability
+  // of this bit being zero
+  fn write_bit(&mut self, value: usize, p_zero: u16) {
+    assert!(value == 0 || value == 1);
+    self.write_symbol(value, &[p_zero]);
+  }
+
+  // Helper function: Write a flag which is logically a boolean
+  // This is just syntactic sugar over self.write_bit(), mapping false => 0 and true => 1
+  fn write_bool(&mut self, value: bool, p_false: u16) {
+    self.write_symbol(value as usize, &[p_false]);
+  }
+
+  // Write an N-bit literal value. This means N bits, which are encoded
+  // in high-to-low order with each bit having a 50:50 probability distribution
+  fn write_literal(&mut self, value: u32, nbits: u32) {
+    assert!(nbits <= 32);
+    assert!(nbits == 32 || value < (1 << nbits));
+    for shift in (0..nbits).rev() {
+      let bit = (value >> shift) & 1;
+      self.write_bit(bit as usize, 16384);
+    }
+  }
+
+  // Encode a given value using a Golomb code
+  fn write_golomb(&mut self, mut value: u32) {
+    //println!("  Golomb({})", value);
+    // Because the "standard" Golomb code cannot represent 0, we actually Golomb-code `value + 1`
+    value += 1;
+
+    let length = floor_log2(value);
+    // Write `length` zero bits, then the full value, including the leading 1 bit
+    // (which acts as a delimiter, allowing the decoder to figure out the correct length)
+    self.write_literal(0, length);
+    self.write_literal(value, length + 1);
+  }
+}
+
 pub struct EntropyWriter {
   // We need to be able to modify already-written bytes for carry propagation,
   // so we have to write into a Vec<u8> rather than a generic Write instance
@@ -19,6 +75,22 @@ pub struct EntropyWriter {
   count: i32
 }
 
+// Saved state of an EntropyWriter, which allows any symbols written after
+// this point to be undone
+pub struct EntropyWriterCheckpoint {
+  low: u64,
+  range: u32,
+  count: i32,
+  data_len: usize,
+
+  // Symbols written after the checkpoint can carry into bytes which were already
+  // emitted before it. As a carry stops at the first byte which isn't 0xFF, the only
+  // bytes which can be affected are the last non-0xFF byte and the run of 0xFF bytes
+  // after it. So we save those bytes, starting from index `carry_start`.
+  carry_start: usize,
+  carry_bytes: Vec<u8>,
+}
+
 impl EntropyWriter {
   pub fn new() -> Self {
     Self {
@@ -50,16 +122,97 @@ impl EntropyWriter {
     panic!("Carry propagated too far in entropy encoder");
   }
 
-  // Write an entropy-coded symbol using the given CDF
+  // Return the number of bits used so far, in units of 1/8 bit
+  // This is a worst-case estimate of how many bits would be needed to terminate
+  // the stream right now, based on the same logic as od_ec_tell_frac() in libaom.
+  // It's mainly useful for measuring the difference between two points in the stream.
+  pub fn tell_frac(&self) -> u32 {
+    // Whole bits: the 10 here counteracts the -9 offset in the initial value of `count`,
+    // and adds one extra bit for termination
+    let nbits = ((self.count + 10) as u32 + 8 * self.data.len() as u32) << 3;
+
+    // Account for the fractional bit which is still left in the range,
+    // by computing 3 bits of log2(range)
+    let mut r = self.range;
+    let mut l = 0;
+    for _ in 0..3 {
+      r = (r * r) >> 15;
+      let b = r >> 16;
+      l = (l << 1) | b;
+      r >>= b;
+    }
+
+    return nbits - l;
+  }
+
+  // Save the current state, so that we can come back to it later using restore()
+  pub fn checkpoint(&self) -> EntropyWriterCheckpoint {
+    let carry_start = self.data.iter().rposition(|&byte| byte != 255).unwrap_or(0);
+
+    EntropyWriterCheckpoint {
+      low: self.low,
+      range: self.range,
+      count: self.count,
+      data_len: self.data.len(),
+      carry_start: carry_start,
+      carry_bytes: self.data[carry_start..].to_vec(),
+    }
+  }
+
+  // Undo all symbols written since the given checkpoint was taken
+  pub fn restore(&mut self, checkpoint: &EntropyWriterCheckpoint) {
+    assert!(self.data.len() >= checkpoint.data_len);
+    self.data.truncate(checkpoint.data_len);
+    self.data[checkpoint.carry_start..].copy_from_slice(&checkpoint.carry_bytes);
+
+    self.low = checkpoint.low;
+    self.range = checkpoint.range;
+    self.count = checkpoint.count;
+  }
+
+  // Finalize entropy block and return the generated bytes.
+  // This takes care of two important requirements specified by AV1:
+  // 1) The encoder must output enough extra bits to ensure that the decoder can
+  //    unambiguously recover the correct value of all symbols
+  // 2) There must be a trailing 1 bit at the end of each entropy coded block.
+  //    Note that, if this is the last entropy coded block in a TILE_GROUP or FRAME OBU,
+  //    then this also serves as the mandatory trailing 1 bit at the end of any OBU's content.
+  pub fn finalize(mut self) -> Box<[u8]> {
+    let mut s = self.count + 10;
+    let m = 0x3FFF;
+    
+    // Inject a 1 bit in the right place
+    let mut e = ((self.low + m) & !m) | (m + 1);
+    let mut n = (1u64 << (self.count + 16)) - 1;
+
+    // TODO: I think this can be simplified into a single round of
+    // propagate_carry() + write_be_bytes(), but need to check that we won't overflow
+    // any intermediate values
+    while s > 0 {
+      let val = e >> (self.count + 16);
+
+      // Propagate carry backwards into existing data
+      if (val & 0x100) != 0 {
+        self.propagate_carry();
+      }
+
+      // Add new byte
+      self.data.push((val & 0xFF) as u8);
+
+      e &= n;
+      s -= 8;
+      self.count -= 8;
+      n >>= 8;
+    }
+
+    // Pull out and return entropy coded data, but drop the rest of `self`
+    return self.data.into_boxed_slice();
+  }
+}
+
+impl SymbolWriter for EntropyWriter {
   // This does not yet implement CDF adaptation, so that must be turned off in the sequence header
-  //
-  // Note: Each CDF contains two implicit values:
-  // * cdf[-1] = 0, so that when symbol == 0 "lo" is implicitly 0
-  // * cdf[num_symbols - 1] = 32768, so that the probabilities sum to 1
-  //
-  // We do not store these values in the cdf array, and instead handle these cases
-  // with ifs in this function
-  pub fn write_symbol(&mut self, symbol: usize, cdf: &[u16]) {
+  fn write_symbol(&mut self, symbol: usize, cdf: &[u16]) {
     //println!("  Symbol({}, CDF = {:?})", symbol, cdf);
     let num_symbols = cdf.len() + 1;
     let inv_hi = if symbol == num_symbols - 1 { 0 } else { 32768 - (cdf[symbol] as u32) };
@@ -67,12 +220,12 @@ impl EntropyWriter {
     // Update range to include new symbol
     if symbol == 0 {
       // inv_lo = 32768 implicitly
-      self.range -= ((self.range >> 8) * (inv_hi >> 6) >> 1) + 4 * (num_symbols - 1) as u32;
+      self.range -= (((self.range >> 8) * (inv_hi >> 6)) >> 1) + 4 * (num_symbols - 1) as u32;
     } else {
       let inv_lo = 32768 - (cdf[symbol - 1] as u32);
 
-      let u = ((self.range >> 8) * (inv_lo >> 6) >> 1) + 4 * (num_symbols - symbol) as u32;
-      let v = ((self.range >> 8) * (inv_hi >> 6) >> 1) + 4 * (num_symbols - symbol - 1) as u32;
+      let u = (((self.range >> 8) * (inv_lo >> 6)) >> 1) + 4 * (num_symbols - symbol) as u32;
+      let v = (((self.range >> 8) * (inv_hi >> 6)) >> 1) + 4 * (num_symbols - symbol - 1) as u32;
       self.low += (self.range - u) as u64;
       self.range = u - v;
     }
@@ -85,10 +238,10 @@ impl EntropyWriter {
       let c = self.count + 24 - (num_bytes_ready << 3);
 
       let mut output = self.low >> c;
-      self.low = self.low & ((1u64 << c) - 1);
+      self.low &= (1u64 << c) - 1;
 
       let carry = output & (1u64 << (num_bytes_ready << 3));
-      output = output & ((1u64 << (num_bytes_ready << 3)) - 1);
+      output &= (1u64 << (num_bytes_ready << 3)) - 1;
 
       // Propagate carry backwards into existing data
       if carry != 0 {
@@ -105,82 +258,120 @@ impl EntropyWriter {
     self.range <<= d;
     self.count = s;
   }
+}
 
-  // Helper function: Write a single bit symbol, without needing extra syntax fluff to convert
-  // from a single probability to a CDF
-  // Note that, due to the way CDFs are encoded, the specified probability is the probability
-  // of this bit being zero
-  pub fn write_bit(&mut self, value: usize, p_zero: u16) {
-    assert!(value == 0 || value == 1);
-    self.write_symbol(value, &[p_zero]);
-  }
+// Decoder for the output of EntropyWriter, used to check that the syntax we write
+// reads back as intended. This follows the symbol decoding process in the spec
+// (init_symbol() and read_symbol()), without CDF adaptation
+#[cfg(test)]
+pub struct EntropyReader<'a> {
+  data: &'a [u8],
+  bit_pos: usize,
+  max_bits: i32,
 
-  // Helper function: Write a flag which is logically a boolean
-  // This is just syntactic sugar over self.write_bit(), mapping false => 0 and true => 1
-  pub fn write_bool(&mut self, value: bool, p_false: u16) {
-    self.write_symbol(value as usize, &[p_false]);
+  value: u32,
+  range: u32,
+}
+
+#[cfg(test)]
+impl<'a> EntropyReader<'a> {
+  pub fn new(data: &'a [u8]) -> Self {
+    let num_bits = min(data.len() * 8, 15);
+    let mut reader = Self {
+      data: data,
+      bit_pos: 0,
+      max_bits: 8 * data.len() as i32 - 15,
+      value: 0,
+      range: 1 << 15,
+    };
+    let buf = reader.read_raw_bits(num_bits);
+    let padded_buf = buf << (15 - num_bits);
+    reader.value = ((1 << 15) - 1) ^ padded_buf;
+    return reader;
   }
 
-  // Write an N-bit literal value. This means N bits, which are encoded
-  // in high-to-low order with each bit having a 50:50 probability distribution
-  pub fn write_literal(&mut self, value: u32, nbits: u32) {
-    assert!(nbits <= 32);
-    assert!(nbits == 32 || value < (1 << nbits));
-    for shift in (0..nbits).rev() {
-      let bit = (value >> shift) & 1;
-      self.write_bit(bit as usize, 16384);
+  // Read bits directly from the underlying data, most significant bit first
+  fn read_raw_bits(&mut self, nbits: usize) -> u32 {
+    let mut value = 0;
+    for _ in 0..nbits {
+      let bit = (self.data[self.bit_pos / 8] >> (7 - self.bit_pos % 8)) & 1;
+      value = (value << 1) | bit as u32;
+      self.bit_pos += 1;
     }
+    return value;
   }
 
-  // Encode a given value using a Golomb code
-  pub fn write_golomb(&mut self, mut value: u32) {
-    //println!("  Golomb({})", value);
-    // Because the "standard" Golomb code cannot represent 0, we actually Golomb-code `value + 1`
-    value += 1;
+  pub fn read_symbol(&mut self, cdf: &[u16]) -> usize {
+    let num_symbols = cdf.len() + 1;
 
-    let length = floor_log2(value);
-    // Write `length` zero bits, then the full value, including the leading 1 bit
-    // (which acts as a delimiter, allowing the decoder to figure out the correct length)
-    self.write_literal(0, length);
-    self.write_literal(value, length + 1);
+    // Find the symbol whose interval contains the current value. As in EntropyWriter,
+    // the final CDF value of 32768 is implicit
+    let mut cur = self.range;
+    let mut prev;
+    let mut symbol = 0;
+    loop {
+      prev = cur;
+      let f = if symbol == num_symbols - 1 { 0 } else { 32768 - (cdf[symbol] as u32) };
+      cur = (((self.range >> 8) * (f >> 6)) >> 1) + 4 * (num_symbols - symbol - 1) as u32;
+      if self.value >= cur {
+        break;
+      }
+      symbol += 1;
+    }
+    self.range = prev - cur;
+    self.value -= cur;
+
+    // Renormalize, reading in new bits as needed
+    let bits = 15 - floor_log2(self.range);
+    self.range <<= bits;
+    let num_bits = min(bits as i32, max(0, self.max_bits)) as u32;
+    let new_data = self.read_raw_bits(num_bits as usize);
+    let padded_data = new_data << (bits - num_bits);
+    self.value = padded_data ^ (((self.value + 1) << bits) - 1);
+    self.max_bits -= bits as i32;
+
+    return symbol;
   }
 
-  // Finalize entropy block and return the generated bytes.
-  // This takes care of two important requirements specified by AV1:
-  // 1) The encoder must output enough extra bits to ensure that the decoder can
-  //    unambiguously recover the correct value of all symbols
-  // 2) There must be a trailing 1 bit at the end of each entropy coded block.
-  //    Note that, if this is the last entropy coded block in a TILE_GROUP or FRAME OBU,
-  //    then this also serves as the mandatory trailing 1 bit at the end of any OBU's content.
-  pub fn finalize(mut self) -> Box<[u8]> {
-    let mut s = self.count + 10;
-    let m = 0x3FFF;
-    
-    // Inject a 1 bit in the right place
-    let mut e = ((self.low + m) & !m) | (m + 1);
-    let mut n = (1u64 << (self.count + 16)) - 1;
+  pub fn read_bit(&mut self, p_zero: u16) -> usize {
+    self.read_symbol(&[p_zero])
+  }
 
-    // TODO: I think this can be simplified into a single round of
-    // propagate_carry() + write_be_bytes(), but need to check that we won't overflow
-    // any intermediate values
-    while s > 0 {
-      let val = e >> (self.count + 16);
+  pub fn read_bool(&mut self, p_false: u16) -> bool {
+    self.read_symbol(&[p_false]) == 1
+  }
 
-      // Propagate carry backwards into existing data
-      if (val & 0x100) != 0 {
-        self.propagate_carry();
-      }
+  pub fn read_literal(&mut self, nbits: u32) -> u32 {
+    let mut value = 0;
+    for _ in 0..nbits {
+      value = (value << 1) | self.read_bit(16384) as u32;
+    }
+    return value;
+  }
+}
 
-      // Add new byte
-      self.data.push((val & 0xFF) as u8);
+#[cfg(test)]
+mod tests {
+  use super::*;
 
-      e = e & n;
-      s -= 8;
-      self.count -= 8;
-      n >>= 8;
+  #[test]
+  fn symbols_round_trip() {
+    let cdf = [4096, 11264, 19328];
+    let symbols: Vec<usize> = (0..1000).map(|i| (i * 7 + i / 13) % 4).collect();
+
+    let mut w = EntropyWriter::new();
+    for &symbol in &symbols {
+      w.write_symbol(symbol, &cdf);
     }
+    w.write_literal(0x12345, 17);
+    w.write_bool(true, 32000);
+    let data = w.finalize();
 
-    // Pull out and return entropy coded data, but drop the rest of `self`
-    return self.data.into_boxed_slice();
+    let mut r = EntropyReader::new(&data);
+    for &symbol in &symbols {
+      assert_eq!(r.read_symbol(&cdf), symbol);
+    }
+    assert_eq!(r.read_literal(17), 0x12345);
+    assert!(r.read_bool(32000));
   }
 }
@@ -7,6 +7,8 @@
 // Media Patent License 1.0 was not distributed with this source code in the
 // PATENTS file, you can obtain it at www.aomedia.org/license/patent.
 
+// Variant names are taken from the AV1 spec
+#[allow(clippy::upper_case_acronyms)]
 pub enum Partition {
   NONE = 0,
   HORZ = 1,
@@ -19,3 +21,54 @@ pub enum Partition {
   HORZ_4 = 8,
   VERT_4 = 9
 }
+
+// Intra prediction modes
+// The directional modes are V_PRED through D67_PRED, and are named after their
+// angle, measured anticlockwise from the positive x axis (so V_PRED is 90 degrees)
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum PredictionMode {
+  DC_PRED = 0,
+  V_PRED = 1,
+  H_PRED = 2,
+  D45_PRED = 3,
+  D135_PRED = 4,
+  D113_PRED = 5,
+  D157_PRED = 6,
+  D203_PRED = 7,
+  D67_PRED = 8,
+  SMOOTH_PRED = 9,
+  SMOOTH_V_PRED = 10,
+  SMOOTH_H_PRED = 11,
+  PAETH_PRED = 12,
+  // Inter prediction modes, which share the same numbering in the spec.
+  // NEARESTMV and NEARMV use a motion vector from the reference MV stack,
+  // GLOBALMV uses the global motion (which we always set to zero), and
+  // NEWMV codes a motion vector relative to an entry in the stack
+  NEARESTMV = 13,
+  NEARMV = 14,
+  GLOBALMV = 15,
+  NEWMV = 16
+}
+
+impl PredictionMode {
+  pub fn is_directional(self) -> bool {
+    self as usize >= PredictionMode::V_PRED as usize && self as usize <= PredictionMode::D67_PRED as usize
+  }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FrameType {
+  KEY_FRAME = 0,
+  INTER_FRAME = 1
+}
+
+// Recursive filter intra modes, which are an alternative to DC_PRED for luma
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FilterIntraMode {
+  FILTER_DC_PRED = 0,
+  FILTER_V_PRED = 1,
+  FILTER_H_PRED = 2,
+  FILTER_D157_PRED = 3,
+  FILTER_PAETH_PRED = 4
+}
@@ -0,0 +1,653 @@
+// Copyright (c) 2024-2025, The tinyavif contributors. All rights reserved
+//
+// This source code is subject to the terms of the BSD 2 Clause License and
+// the Alliance for Open Media Patent License 1.0. If the BSD 2 Clause License
+// was not distributed with this source code in the LICENSE file, you can
+// obtain it at www.aomedia.org/license/software. If the Alliance for Open
+// Media Patent License 1.0 was not distributed with this source code in the
+// PATENTS file, you can obtain it at www.aomedia.org/license/patent.
+
+// Film grain synthesis
+//
+// Film grain and sensor noise are essentially random, so they're extremely
+// expensive to code exactly, and are mostly destroyed at any useful quantizer anyway.
+// AV1 instead lets us describe the grain with a small set of parameters, which the
+// decoder uses to generate similar-looking grain and add it to the decoded image.
+//
+// The grain is generated from white Gaussian noise, passed through an autoregressive (AR)
+// filter to give it the right spatial correlation. It's then scaled by a piecewise-linear
+// function of the decoded pixel value, as grain is often stronger at some intensities
+// than others.
+//
+// On the encoder side, we look for the flattest areas of the source, where nearly all
+// of the variation is noise, and fit the grain parameters to the noise there.
+// The measured noise spectrum is then used to denoise the whole image, and it's
+// this denoised image which we actually encode.
+
+use crate::array2d::Array2D;
+use crate::bitcode::BitWriter;
+use crate::enums::FrameType;
+use crate::frame::Frame;
+use crate::util::*;
+
+// Limits on the number of points in each scaling function
+pub const MAX_NUM_Y_POINTS: usize = 14;
+pub const MAX_NUM_UV_POINTS: usize = 10;
+
+pub const MAX_AR_COEFF_LAG: usize = 3;
+
+// Standard deviation of the white noise which the decoder feeds into the AR filter,
+// for 8-bit video with grain_scale_shift = 0
+const GRAIN_WHITE_NOISE_STD: f64 = 32.0;
+
+// The decoder's random number generator seed. For still images, any value works
+const GRAIN_SEED: u16 = 7391;
+
+// When encoding a sequence, the seed is advanced by this much for each frame,
+// so that the grain pattern changes over time. This matches libaom
+pub const GRAIN_SEED_INCREMENT: u16 = 3381;
+
+// Block size used for the DCT-domain denoiser
+const DENOISE_BLOCK_SIZE: usize = 8;
+// Step between overlapping denoiser blocks
+const DENOISE_BLOCK_STEP: usize = 2;
+
+// Size of the luma blocks which we consider for grain estimation
+const FLAT_BLOCK_SIZE: usize = 32;
+// Fraction of blocks, ordered from flattest to most textured, used to estimate the grain
+const FLAT_BLOCK_FRACTION: f64 = 0.25;
+
+// AR lag used for estimated grain. This is the maximum allowed, for the best possible fit
+const ESTIMATED_AR_COEFF_LAG: usize = 3;
+
+// The scaling functions are estimated by splitting the range of pixel values into this many bins,
+// and generating one point per bin. Bins with too few samples to give a reliable estimate are skipped
+const SCALING_BINS: usize = 8;
+const MIN_SAMPLES_PER_BIN: usize = 256;
+
+// Ratio between the standard deviation and the median absolute value of a Gaussian distribution
+const MAD_TO_STD: f64 = 1.4826;
+
+// If the luma grain is weaker than this (standard deviation, in pixel units) everywhere,
+// the image is considered clean and film grain isn't used
+const MIN_GRAIN_STD: f64 = 0.75;
+
+// Film grain parameters for one frame, using the same names and units as film_grain_params()
+// in the spec
+#[derive(Clone, Debug)]
+pub struct FilmGrainParams {
+  pub grain_seed: u16,
+
+  // Scaling functions, as lists of (pixel value, scaling) points with strictly increasing
+  // pixel values. If chroma_scaling_from_luma is set, the chroma planes use the luma
+  // scaling function and their own lists must be empty. With 4:2:0 subsampling, either
+  // both or neither of the chroma planes must have scaling points, and neither can
+  // unless luma does
+  pub scaling_points_y: Vec<[u8; 2]>,
+  pub chroma_scaling_from_luma: bool,
+  pub scaling_points_cb: Vec<[u8; 2]>,
+  pub scaling_points_cr: Vec<[u8; 2]>,
+  // Scaling values are in units of 2^-scaling_shift, with scaling_shift in the range 8-11
+  pub scaling_shift: u8,
+
+  // AR filter coefficients, in units of 2^-ar_coeff_shift, with ar_coeff_shift in the range 6-9.
+  // Luma has 2 * lag * (lag + 1) coefficients, for the causal neighbours in raster order.
+  // Each chroma plane has one more, which is applied to the co-located luma grain
+  pub ar_coeff_lag: usize,
+  pub ar_coeffs_y: Vec<i8>,
+  pub ar_coeffs_cb: Vec<i8>,
+  pub ar_coeffs_cr: Vec<i8>,
+  pub ar_coeff_shift: u8,
+
+  // Extra right shift applied to the white noise, in the range 0-3
+  pub grain_scale_shift: u8,
+
+  // Each chroma scaling function is indexed by
+  // ((luma * (luma_mult - 128) + chroma * (mult - 128)) >> 6) + (offset - 256).
+  // These are ignored if chroma_scaling_from_luma is set
+  pub cb_mult: u8,
+  pub cb_luma_mult: u8,
+  pub cb_offset: u16,
+  pub cr_mult: u8,
+  pub cr_luma_mult: u8,
+  pub cr_offset: u16,
+
+  // Whether to blend the grain across the boundaries of the 32x32 blocks it's applied in
+  pub overlap_flag: bool,
+  // Whether to clip the output to the "TV" range of 16-235 (luma) or 16-240 (chroma)
+  pub clip_to_restricted_range: bool,
+}
+
+impl FilmGrainParams {
+  pub fn num_pos_luma(&self) -> usize {
+    2 * self.ar_coeff_lag * (self.ar_coeff_lag + 1)
+  }
+
+  // Check that these parameters can be coded, and meet the constraints the spec places on them
+  pub fn validate(&self) -> Result<(), String> {
+    let check_range = |name: &str, value: usize, lo: usize, hi: usize| {
+      if value < lo || value > hi {
+        Err(format!("Film grain {} = {} is out of range (must be {}-{})", name, value, lo, hi))
+      } else {
+        Ok(())
+      }
+    };
+    check_range("ar_coeff_lag", self.ar_coeff_lag, 0, MAX_AR_COEFF_LAG)?;
+    check_range("ar_coeff_shift", self.ar_coeff_shift as usize, 6, 9)?;
+    check_range("grain_scale_shift", self.grain_scale_shift as usize, 0, 3)?;
+    check_range("scaling_shift", self.scaling_shift as usize, 8, 11)?;
+    check_range("cb_offset", self.cb_offset as usize, 0, 511)?;
+    check_range("cr_offset", self.cr_offset as usize, 0, 511)?;
+    check_range("num_y_points", self.scaling_points_y.len(), 0, MAX_NUM_Y_POINTS)?;
+    check_range("num_cb_points", self.scaling_points_cb.len(), 0, MAX_NUM_UV_POINTS)?;
+    check_range("num_cr_points", self.scaling_points_cr.len(), 0, MAX_NUM_UV_POINTS)?;
+
+    for (plane, points) in [("Y", &self.scaling_points_y), ("Cb", &self.scaling_points_cb), ("Cr", &self.scaling_points_cr)] {
+      if points.windows(2).any(|pair| pair[0][0] >= pair[1][0]) {
+        return Err(format!("Film grain {} scaling points must have strictly increasing values", plane));
+      }
+    }
+
+    let has_chroma_points = !self.scaling_points_cb.is_empty() || !self.scaling_points_cr.is_empty();
+    if self.chroma_scaling_from_luma && has_chroma_points {
+      return Err("Film grain chroma scaling points can't be given when chroma_scaling_from_luma is set".to_string());
+    }
+    // These two follow from using 4:2:0 subsampling
+    if self.scaling_points_y.is_empty() && has_chroma_points {
+      return Err("Film grain chroma scaling points require luma scaling points".to_string());
+    }
+    if self.scaling_points_cb.is_empty() != self.scaling_points_cr.is_empty() {
+      return Err("Film grain scaling points must be given for both chroma planes or neither".to_string());
+    }
+
+    let num_pos_luma = self.num_pos_luma();
+    if self.ar_coeffs_y.len() != num_pos_luma ||
+       self.ar_coeffs_cb.len() != num_pos_luma + 1 ||
+       self.ar_coeffs_cr.len() != num_pos_luma + 1 {
+      return Err(format!("Film grain with ar_coeff_lag = {} needs {} luma and {} chroma AR coefficients",
+                         self.ar_coeff_lag, num_pos_luma, num_pos_luma + 1));
+    }
+
+    return Ok(());
+  }
+
+  // Write film_grain_params() for a frame with grain applied
+  pub fn write(&self, w: &mut BitWriter, frame_type: FrameType) {
+    assert!(self.validate().is_ok());
+    let num_y_points = self.scaling_points_y.len();
+    let num_cb_points = self.scaling_points_cb.len();
+    let num_cr_points = self.scaling_points_cr.len();
+
+    w.write_bit(1); // apply_grain
+    w.write_bits(self.grain_seed as u64, 16);
+    // update_grain is implied for key frames. Inter frames could copy the parameters
+    // from a reference frame instead, but we always send them in full
+    if frame_type == FrameType::INTER_FRAME {
+      w.write_bit(1); // update_grain
+    }
+
+    w.write_bits(num_y_points as u64, 4);
+    for [value, scaling] in &self.scaling_points_y {
+      w.write_bits(*value as u64, 8);
+      w.write_bits(*scaling as u64, 8);
+    }
+
+    w.write_bool(self.chroma_scaling_from_luma);
+    // With 4:2:0 subsampling, the chroma scaling functions are only coded
+    // if there is luma grain
+    if !self.chroma_scaling_from_luma && num_y_points > 0 {
+      w.write_bits(num_cb_points as u64, 4);
+      for [value, scaling] in &self.scaling_points_cb {
+        w.write_bits(*value as u64, 8);
+        w.write_bits(*scaling as u64, 8);
+      }
+      w.write_bits(num_cr_points as u64, 4);
+      for [value, scaling] in &self.scaling_points_cr {
+        w.write_bits(*value as u64, 8);
+        w.write_bits(*scaling as u64, 8);
+      }
+    }
+
+    w.write_bits((self.scaling_shift - 8) as u64, 2);
+    w.write_bits(self.ar_coeff_lag as u64, 2);
+
+    // The chroma AR filters only have a luma coefficient if there is luma grain
+    let num_pos_luma = self.num_pos_luma();
+    let num_pos_chroma = if num_y_points > 0 {
+      for i in 0..num_pos_luma {
+        w.write_bits((self.ar_coeffs_y[i] as i32 + 128) as u64, 8);
+      }
+      num_pos_luma + 1
+    } else {
+      num_pos_luma
+    };
+    if self.chroma_scaling_from_luma || num_cb_points > 0 {
+      for i in 0..num_pos_chroma {
+        w.write_bits((self.ar_coeffs_cb[i] as i32 + 128) as u64, 8);
+      }
+    }
+    if self.chroma_scaling_from_luma || num_cr_points > 0 {
+      for i in 0..num_pos_chroma {
+        w.write_bits((self.ar_coeffs_cr[i] as i32 + 128) as u64, 8);
+      }
+    }
+
+    w.write_bits((self.ar_coeff_shift - 6) as u64, 2);
+    w.write_bits(self.grain_scale_shift as u64, 2);
+    if num_cb_points > 0 {
+      w.write_bits(self.cb_mult as u64, 8);
+      w.write_bits(self.cb_luma_mult as u64, 8);
+      w.write_bits(self.cb_offset as u64, 9);
+    }
+    if num_cr_points > 0 {
+      w.write_bits(self.cr_mult as u64, 8);
+      w.write_bits(self.cr_luma_mult as u64, 8);
+      w.write_bits(self.cr_offset as u64, 9);
+    }
+    w.write_bool(self.overlap_flag);
+    w.write_bool(self.clip_to_restricted_range);
+  }
+}
+
+// Orthonormal DCT-II basis for the denoiser, indexed as [frequency][position]
+fn dct_basis() -> DenoiseBlock {
+  let n = DENOISE_BLOCK_SIZE as f64;
+  let mut basis = [[0.0; DENOISE_BLOCK_SIZE]; DENOISE_BLOCK_SIZE];
+  for k in 0..DENOISE_BLOCK_SIZE {
+    let scale = if k == 0 { (1.0 / n).sqrt() } else { (2.0 / n).sqrt() };
+    for i in 0..DENOISE_BLOCK_SIZE {
+      basis[k][i] = scale * (std::f64::consts::PI * (2 * i + 1) as f64 * k as f64 / (2.0 * n)).cos();
+    }
+  }
+  return basis;
+}
+
+type DenoiseBlock = [[f64; DENOISE_BLOCK_SIZE]; DENOISE_BLOCK_SIZE];
+
+// 2D DCT of a block, or the inverse if `inverse` is set
+fn transform_block(basis: &DenoiseBlock, input: &DenoiseBlock, inverse: bool) -> DenoiseBlock {
+  let mut tmp = [[0.0; DENOISE_BLOCK_SIZE]; DENOISE_BLOCK_SIZE];
+  let mut output = [[0.0; DENOISE_BLOCK_SIZE]; DENOISE_BLOCK_SIZE];
+  let coeff = |k: usize, i: usize| if inverse { basis[i][k] } else { basis[k][i] };
+  for row in 0..DENOISE_BLOCK_SIZE {
+    for k in 0..DENOISE_BLOCK_SIZE {
+      tmp[row][k] = (0..DENOISE_BLOCK_SIZE).map(|i| coeff(k, i) * input[row][i]).sum();
+    }
+  }
+  for col in 0..DENOISE_BLOCK_SIZE {
+    for k in 0..DENOISE_BLOCK_SIZE {
+      output[k][col] = (0..DENOISE_BLOCK_SIZE).map(|i| coeff(k, i) * tmp[i][col]).sum();
+    }
+  }
+  return output;
+}
+
+fn load_block(pixels: &Array2D<f64>, y0: usize, x0: usize) -> DenoiseBlock {
+  let mut block = [[0.0; DENOISE_BLOCK_SIZE]; DENOISE_BLOCK_SIZE];
+  for i in 0..DENOISE_BLOCK_SIZE {
+    for j in 0..DENOISE_BLOCK_SIZE {
+      block[i][j] = pixels[y0 + i][x0 + j];
+    }
+  }
+  return block;
+}
+
+// Positions of overlapping blocks covering a dimension of the given size, making sure
+// that the last block reaches the edge
+fn block_positions(size: usize, block_size: usize, step: usize) -> Vec<usize> {
+  let mut positions: Vec<usize> = (0 ..= size - block_size).step_by(step).collect();
+  if *positions.last().unwrap() != size - block_size {
+    positions.push(size - block_size);
+  }
+  return positions;
+}
+
+// Measure the average power of each DCT coefficient of the noise in the given blocks
+fn noise_spectrum(noise: &Array2D<f64>, blocks: &[(usize, usize)], block_size: usize) -> DenoiseBlock {
+  let basis = dct_basis();
+  let mut power = [[0.0; DENOISE_BLOCK_SIZE]; DENOISE_BLOCK_SIZE];
+  let mut count = 0;
+  for &(y0, x0) in blocks {
+    for y in (y0 .. y0 + block_size).step_by(DENOISE_BLOCK_SIZE) {
+      for x in (x0 .. x0 + block_size).step_by(DENOISE_BLOCK_SIZE) {
+        let coeffs = transform_block(&basis, &load_block(noise, y, x), false);
+        for i in 0..DENOISE_BLOCK_SIZE {
+          for j in 0..DENOISE_BLOCK_SIZE {
+            power[i][j] += coeffs[i][j] * coeffs[i][j];
+          }
+        }
+        count += 1;
+      }
+    }
+  }
+  for i in 0..DENOISE_BLOCK_SIZE {
+    for j in 0..DENOISE_BLOCK_SIZE {
+      power[i][j] /= count as f64;
+    }
+  }
+  return power;
+}
+
+// Denoise one plane, using Wiener filtering of overlapping DCT blocks
+//
+// The noise is assumed to have the same power spectrum throughout the plane.
+// Each coefficient is scaled by (signal power) / (signal power + noise power),
+// estimating the signal power as the measured power minus the noise power.
+// The results from all of the overlapping blocks are then averaged
+fn denoise_plane(pixels: &Array2D<f64>, noise_power: &DenoiseBlock) -> Array2D<f64> {
+  let height = pixels.rows();
+  let width = pixels.cols();
+  let basis = dct_basis();
+
+  let mut sum: Array2D<f64> = Array2D::zeroed(height, width);
+  let mut weight: Array2D<f64> = Array2D::zeroed(height, width);
+  for y0 in block_positions(height, DENOISE_BLOCK_SIZE, DENOISE_BLOCK_STEP) {
+    for x0 in block_positions(width, DENOISE_BLOCK_SIZE, DENOISE_BLOCK_STEP) {
+      let mut coeffs = transform_block(&basis, &load_block(pixels, y0, x0), false);
+      for i in 0..DENOISE_BLOCK_SIZE {
+        for j in 0..DENOISE_BLOCK_SIZE {
+          if i == 0 && j == 0 {
+            continue;
+          }
+          let power = coeffs[i][j] * coeffs[i][j];
+          if power > 0.0 {
+            coeffs[i][j] *= (power - noise_power[i][j]).max(0.0) / power;
+          }
+        }
+      }
+      let block = transform_block(&basis, &coeffs, true);
+      for i in 0..DENOISE_BLOCK_SIZE {
+        for j in 0..DENOISE_BLOCK_SIZE {
+          sum[y0 + i][x0 + j] += block[i][j];
+          weight[y0 + i][x0 + j] += 1.0;
+        }
+      }
+    }
+  }
+
+  return Array2D::new_with(height, width, |y, x| sum[y][x] / weight[y][x]);
+}
+
+// Least-squares fit of a plane a + b * (x - centre) + c * (y - centre) to a square block,
+// returning (a, b, c)
+fn fit_plane(pixels: &Array2D<f64>, y0: usize, x0: usize, size: usize) -> (f64, f64, f64) {
+  // With coordinates centred on the block, the fit separates into independent
+  // fits for the mean and the horizontal and vertical slopes
+  let centre = (size - 1) as f64 / 2.0;
+  let mut sum = 0.0;
+  let mut sum_x = 0.0;
+  let mut sum_y = 0.0;
+  for i in 0..size {
+    for j in 0..size {
+      let value = pixels[y0 + i][x0 + j];
+      sum += value;
+      sum_x += value * (j as f64 - centre);
+      sum_y += value * (i as f64 - centre);
+    }
+  }
+  let coord_sq: f64 = (0..size).map(|i| (i as f64 - centre) * (i as f64 - centre)).sum::<f64>() * size as f64;
+  return (sum / (size * size) as f64, sum_x / coord_sq, sum_y / coord_sq);
+}
+
+// Split the given blocks of a plane into a smooth part (the best-fit plane in each block)
+// and the noise on top of it. Returns (smooth, noise), which are zero outside the blocks
+fn extract_noise(pixels: &Array2D<f64>, blocks: &[(usize, usize)], block_size: usize) -> (Array2D<f64>, Array2D<f64>) {
+  let centre = (block_size - 1) as f64 / 2.0;
+  let mut smooth = Array2D::zeroed(pixels.rows(), pixels.cols());
+  let mut noise = Array2D::zeroed(pixels.rows(), pixels.cols());
+  for &(y0, x0) in blocks {
+    let (mean, slope_x, slope_y) = fit_plane(pixels, y0, x0, block_size);
+    for i in 0..block_size {
+      for j in 0..block_size {
+        let value = mean + slope_x * (j as f64 - centre) + slope_y * (i as f64 - centre);
+        smooth[y0 + i][x0 + j] = value;
+        noise[y0 + i][x0 + j] = pixels[y0 + i][x0 + j] - value;
+      }
+    }
+  }
+  return (smooth, noise);
+}
+
+// Find the flattest luma blocks, measured by their variance after removing the best-fit
+// plane. Unlike the plain variance, this doesn't penalize smooth gradients.
+// Returns (row, col) positions in units of FLAT_BLOCK_SIZE
+fn find_flat_blocks(luma: &Array2D<f64>) -> Vec<(usize, usize)> {
+  let mut blocks = Vec::new();
+  for row in 0 .. luma.rows() / FLAT_BLOCK_SIZE {
+    for col in 0 .. luma.cols() / FLAT_BLOCK_SIZE {
+      let y0 = row * FLAT_BLOCK_SIZE;
+      let x0 = col * FLAT_BLOCK_SIZE;
+      let (mean, slope_x, slope_y) = fit_plane(luma, y0, x0, FLAT_BLOCK_SIZE);
+      let centre = (FLAT_BLOCK_SIZE - 1) as f64 / 2.0;
+      let mut variance = 0.0;
+      for i in 0..FLAT_BLOCK_SIZE {
+        for j in 0..FLAT_BLOCK_SIZE {
+          let error = luma[y0 + i][x0 + j] - (mean + slope_x * (j as f64 - centre) + slope_y * (i as f64 - centre));
+          variance += error * error;
+        }
+      }
+      blocks.push((variance, row, col));
+    }
+  }
+  blocks.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+  let num_flat_blocks = (blocks.len() as f64 * FLAT_BLOCK_FRACTION).ceil() as usize;
+  return blocks[..num_flat_blocks].iter().map(|&(_, row, col)| (row, col)).collect();
+}
+
+// Solve the linear system A x = b, using Gaussian elimination with partial pivoting.
+// Returns None if the system is singular
+fn solve_linear_system(mut a: Vec<Vec<f64>>, mut b: Vec<f64>) -> Option<Vec<f64>> {
+  let n = b.len();
+  for col in 0..n {
+    let pivot = (col..n).max_by(|&i, &j| a[i][col].abs().total_cmp(&a[j][col].abs())).unwrap();
+    if a[pivot][col].abs() < 1e-9 {
+      return None;
+    }
+    a.swap(col, pivot);
+    b.swap(col, pivot);
+    for row in col+1 .. n {
+      let factor = a[row][col] / a[col][col];
+      for k in col..n {
+        a[row][k] -= factor * a[col][k];
+      }
+      b[row] -= factor * b[col];
+    }
+  }
+
+  let mut x = vec![0.0; n];
+  for row in (0..n).rev() {
+    let rest: f64 = (row+1 .. n).map(|k| a[row][k] * x[k]).sum();
+    x[row] = (b[row] - rest) / a[row][row];
+  }
+  return Some(x);
+}
+
+// Offsets of the causal neighbours used by the AR filter, in the order used by the spec
+fn ar_offsets(lag: usize) -> Vec<(isize, isize)> {
+  let lag = lag as isize;
+  let mut offsets = Vec::new();
+  for dy in -lag ..= 0 {
+    for dx in -lag ..= lag {
+      if dy == 0 && dx == 0 {
+        break;
+      }
+      offsets.push((dy, dx));
+    }
+  }
+  return offsets;
+}
+
+// Grain statistics for one plane
+struct PlaneGrain {
+  // AR coefficients, as real numbers
+  ar_coeffs: Vec<f64>,
+  // Scaling function, as (pixel value, standard deviation of the AR filter input in pixel units)
+  points: Vec<(u8, f64)>,
+}
+
+// Fit an AR model to the noise in the given blocks of one plane, then measure how the strength
+// of the remaining (white) part of the noise varies with the underlying pixel value
+fn estimate_plane_grain(smooth: &Array2D<f64>, noise: &Array2D<f64>,
+                        blocks: &[(usize, usize)], block_size: usize) -> Option<PlaneGrain> {
+  let lag = ESTIMATED_AR_COEFF_LAG;
+  let offsets = ar_offsets(lag);
+  let n = offsets.len();
+
+  // Only use pixels whose whole neighbourhood lies within the same block
+  let positions = || blocks.iter().flat_map(move |&(y0, x0)| {
+    (y0 + lag .. y0 + block_size).flat_map(move |y| (x0 + lag .. x0 + block_size - lag).map(move |x| (y, x)))
+  });
+  let neighbours = |y: usize, x: usize| {
+    offsets.iter().map(move |&(dy, dx)| noise[(y as isize + dy) as usize][(x as isize + dx) as usize])
+  };
+
+  // Least-squares fit of the AR coefficients, via the normal equations
+  let mut ata = vec![vec![0.0; n]; n];
+  let mut atb = vec![0.0; n];
+  for (y, x) in positions() {
+    let values: Vec<f64> = neighbours(y, x).collect();
+    for i in 0..n {
+      for j in 0..n {
+        ata[i][j] += values[i] * values[j];
+      }
+      atb[i] += values[i] * noise[y][x];
+    }
+  }
+  let ar_coeffs = solve_linear_system(ata, atb)?;
+
+  // Measure the strength of the AR model's residual, binned by pixel value.
+  // Some of the "flat" blocks may still contain edges, so we use the median absolute
+  // residual rather than the standard deviation, as this is much less affected by outliers
+  let bin_size = 256 / SCALING_BINS;
+  let mut bin_residuals: [Vec<f64>; SCALING_BINS] = std::array::from_fn(|_| Vec::new());
+  for (y, x) in positions() {
+    let prediction: f64 = neighbours(y, x).zip(&ar_coeffs).map(|(value, coeff)| value * coeff).sum();
+    let bin = clamp(smooth[y][x].round() as usize, 0, 255) / bin_size;
+    bin_residuals[bin].push((noise[y][x] - prediction).abs());
+  }
+
+  let mut points = Vec::new();
+  for (bin, residuals) in bin_residuals.iter_mut().enumerate() {
+    if residuals.len() >= MIN_SAMPLES_PER_BIN {
+      residuals.sort_by(|a, b| a.total_cmp(b));
+      let std = MAD_TO_STD * residuals[residuals.len() / 2];
+      points.push(((bin * bin_size + bin_size / 2) as u8, std));
+    }
+  }
+
+  Some(PlaneGrain {
+    ar_coeffs: ar_coeffs,
+    points: points,
+  })
+}
+
+// Estimate film grain parameters for an image, and denoise it accordingly.
+// Returns the denoised image and the grain parameters, or None if the image doesn't
+// appear to have any significant grain
+pub fn estimate_film_grain(source: &Frame) -> Option<(Frame, FilmGrainParams)> {
+  let planes: [Array2D<f64>; 3] = std::array::from_fn(|plane| {
+    let src = source.plane(plane);
+    Array2D::new_with(src.crop_height(), src.crop_width(), |y, x| src.pixels()[y][x] as f64)
+  });
+
+  let flat_blocks = find_flat_blocks(&planes[0]);
+  if flat_blocks.is_empty() {
+    return None;
+  }
+
+  let mut denoised_frame = Frame::new(source.y().crop_height(), source.y().crop_width());
+  let mut grain: [Option<PlaneGrain>; 3] = [None, None, None];
+  for plane in 0..3 {
+    // Chroma blocks cover the same area as the luma blocks
+    let block_size = if plane == 0 { FLAT_BLOCK_SIZE } else { FLAT_BLOCK_SIZE / 2 };
+    let blocks: Vec<(usize, usize)> = flat_blocks.iter().map(|&(row, col)| (row * block_size, col * block_size)).collect();
+    let (smooth, noise) = extract_noise(&planes[plane], &blocks, block_size);
+    grain[plane] = estimate_plane_grain(&smooth, &noise, &blocks, block_size);
+
+    let denoised = denoise_plane(&planes[plane], &noise_spectrum(&noise, &blocks, block_size));
+    let dst = denoised_frame.plane_mut(plane);
+    for y in 0..denoised.rows() {
+      for x in 0..denoised.cols() {
+        dst.pixels_mut()[y][x] = denoised[y][x].round().clamp(0.0, 255.0) as u8;
+      }
+    }
+    dst.fill_padding();
+  }
+
+  let [Some(grain_y), grain_cb, grain_cr] = grain else {
+    return None;
+  };
+  let max_luma_std = grain_y.points.iter().map(|&(_, std)| std).fold(0.0, f64::max);
+  if max_luma_std < MIN_GRAIN_STD {
+    return None;
+  }
+
+  // With 4:2:0 subsampling, we need scaling points for both chroma planes or neither
+  let (grain_cb, grain_cr) = match (grain_cb, grain_cr) {
+    (Some(cb), Some(cr)) if !cb.points.is_empty() && !cr.points.is_empty() => (Some(cb), Some(cr)),
+    _ => (None, None),
+  };
+  let planes = [Some(&grain_y), grain_cb.as_ref(), grain_cr.as_ref()];
+
+  // Pick the finest scaling_shift for which all of the scaling values fit in 8 bits
+  let max_std = planes.iter().flatten()
+    .flat_map(|grain| grain.points.iter().map(|&(_, std)| std))
+    .fold(0.0, f64::max);
+  let scaling_shift = (8..=11).rev()
+    .find(|&shift| max_std * (1 << shift) as f64 / GRAIN_WHITE_NOISE_STD <= 255.0)
+    .unwrap_or(8);
+  let scaling_points = |grain: Option<&PlaneGrain>| -> Vec<[u8; 2]> {
+    match grain {
+      Some(grain) => grain.points.iter().map(|&(value, std)| {
+        [value, (std * (1 << scaling_shift) as f64 / GRAIN_WHITE_NOISE_STD).round().min(255.0) as u8]
+      }).collect(),
+      None => Vec::new(),
+    }
+  };
+
+  // Similarly, pick the finest ar_coeff_shift for which all of the coefficients fit in 8 bits
+  let max_coeff = planes.iter().flatten()
+    .flat_map(|grain| grain.ar_coeffs.iter().map(|coeff| coeff.abs()))
+    .fold(0.0, f64::max);
+  let ar_coeff_shift = (6..=9).rev()
+    .find(|&shift| max_coeff * (1 << shift) as f64 <= 127.0)
+    .unwrap_or(6);
+  // Chroma planes get an extra coefficient for the luma grain, which we leave as zero
+  let ar_coeffs = |grain: Option<&PlaneGrain>, extra: usize| -> Vec<i8> {
+    let mut coeffs: Vec<i8> = match grain {
+      Some(grain) => grain.ar_coeffs.iter().map(|coeff| {
+        (coeff * (1 << ar_coeff_shift) as f64).round().clamp(-128.0, 127.0) as i8
+      }).collect(),
+      None => vec![0; ar_offsets(ESTIMATED_AR_COEFF_LAG).len()],
+    };
+    coeffs.resize(coeffs.len() + extra, 0);
+    coeffs
+  };
+
+  let params = FilmGrainParams {
+    grain_seed: GRAIN_SEED,
+    scaling_points_y: scaling_points(planes[0]),
+    chroma_scaling_from_luma: false,
+    scaling_points_cb: scaling_points(planes[1]),
+    scaling_points_cr: scaling_points(planes[2]),
+    scaling_shift: scaling_shift,
+    ar_coeff_lag: ESTIMATED_AR_COEFF_LAG,
+    ar_coeffs_y: ar_coeffs(planes[0], 0),
+    ar_coeffs_cb: ar_coeffs(planes[1], 1),
+    ar_coeffs_cr: ar_coeffs(planes[2], 1),
+    ar_coeff_shift: ar_coeff_shift,
+    grain_scale_shift: 0,
+    // Index each chroma scaling function by the chroma value alone
+    cb_mult: 128 + 64,
+    cb_luma_mult: 128,
+    cb_offset: 256,
+    cr_mult: 128 + 64,
+    cr_luma_mult: 128,
+    cr_offset: 256,
+    overlap_flag: true,
+    clip_to_restricted_range: false,
+  };
+
+  return Some((denoised_frame, params));
+}
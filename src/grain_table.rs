@@ -0,0 +1,302 @@
+// Copyright (c) 2024-2025, The tinyavif contributors. All rights reserved
+//
+// This source code is subject to the terms of the BSD 2 Clause License and
+// the Alliance for Open Media Patent License 1.0. If the BSD 2 Clause License
+// was not distributed with this source code in the LICENSE file, you can
+// obtain it at www.aomedia.org/license/software. If the Alliance for Open
+// Media Patent License 1.0 was not distributed with this source code in the
+// PATENTS file, you can obtain it at www.aomedia.org/license/patent.
+
+// Film grain table files
+//
+// These are text files which give explicit film grain parameters, in the format used by
+// aomenc's --film-grain-table option. The file starts with the line "filmgrn1", followed
+// by a list of entries, each of which looks like:
+//
+//   E <start time> <end time> <apply_grain> <grain_seed> <update_parameters>
+//     p <ar_coeff_lag> <ar_coeff_shift> <grain_scale_shift> <scaling_shift>
+//       <chroma_scaling_from_luma> <overlap_flag> <cb_mult> <cb_luma_mult> <cb_offset>
+//       <cr_mult> <cr_luma_mult> <cr_offset>
+//     sY <num_y_points> <value> <scaling> ...
+//     sCb <num_cb_points> <value> <scaling> ...
+//     sCr <num_cr_points> <value> <scaling> ...
+//     cY <ar_coeffs_y> ...
+//     cCb <ar_coeffs_cb> ...
+//     cCr <ar_coeffs_cr> ...
+//
+// where everything from the "p" line onwards is only present if update_parameters is 1.
+// Otherwise, the parameters from the previous entry are reused.
+//
+// Each entry applies to frames with timestamps in the range [start time, end time),
+// in units of 1/10,000,000 seconds. As in aomenc, frames which aren't covered by any
+// entry have no grain applied.
+
+use std::path::Path;
+use std::str::SplitWhitespace;
+
+use crate::film_grain::*;
+
+const GRAIN_TABLE_MAGIC: &str = "filmgrn1";
+
+// Timestamps in the table are in units of 1/GRAIN_TABLE_TICKS_PER_SEC seconds
+const GRAIN_TABLE_TICKS_PER_SEC: i64 = 10_000_000;
+
+struct FilmGrainTableEntry {
+  start_time: i64,
+  end_time: i64,
+  // None if this entry has apply_grain = 0
+  params: Option<FilmGrainParams>,
+}
+
+pub struct FilmGrainTable {
+  entries: Vec<FilmGrainTableEntry>,
+}
+
+impl FilmGrainTable {
+  // Find the parameters for the frame with the given timestamp, or None if
+  // no grain should be applied to it
+  pub fn lookup(&self, timestamp: i64) -> Option<&FilmGrainParams> {
+    self.entries.iter()
+      .find(|entry| entry.start_time <= timestamp && timestamp < entry.end_time)
+      .and_then(|entry| entry.params.as_ref())
+  }
+
+  // Whether any entry applies grain
+  pub fn has_grain(&self) -> bool {
+    self.entries.iter().any(|entry| entry.params.is_some())
+  }
+}
+
+// Timestamp of the given frame, in the units used by film grain tables.
+// `frame_rate` is the (numerator, denominator) pair from the Y4M header
+pub fn grain_table_timestamp(frame_index: usize, frame_rate: (u32, u32)) -> i64 {
+  (frame_index as i64 * GRAIN_TABLE_TICKS_PER_SEC * frame_rate.1 as i64) / frame_rate.0 as i64
+}
+
+// Reads whitespace-separated tokens from a table file, with error messages for bad input
+struct TableReader<'a> {
+  tokens: SplitWhitespace<'a>,
+}
+
+impl<'a> TableReader<'a> {
+  fn next_token(&mut self) -> Option<&'a str> {
+    self.tokens.next()
+  }
+
+  // Check that the next token is the given keyword
+  fn expect(&mut self, keyword: &str) -> Result<(), String> {
+    match self.next_token() {
+      Some(token) if token == keyword => Ok(()),
+      Some(token) => Err(format!("Invalid film grain table: expected \"{}\", found \"{}\"", keyword, token)),
+      None => Err(format!("Invalid film grain table: expected \"{}\", found end of file", keyword)),
+    }
+  }
+
+  // Read an integer, which must be in the range [lo, hi]
+  fn read_int(&mut self, name: &str, lo: i64, hi: i64) -> Result<i64, String> {
+    let token = self.next_token()
+      .ok_or_else(|| format!("Invalid film grain table: expected {}, found end of file", name))?;
+    let value: i64 = token.parse()
+      .map_err(|_| format!("Invalid film grain table: expected {}, found \"{}\"", name, token))?;
+    if value < lo || value > hi {
+      return Err(format!("Film grain {} = {} is out of range (must be {}-{})", name, value, lo, hi));
+    }
+    return Ok(value);
+  }
+
+  fn read_bool(&mut self, name: &str) -> Result<bool, String> {
+    Ok(self.read_int(name, 0, 1)? == 1)
+  }
+
+  fn read_scaling_points(&mut self, plane: &str, max_points: usize) -> Result<Vec<[u8; 2]>, String> {
+    self.expect(&format!("s{}", plane))?;
+    let num_points = self.read_int(&format!("num_{}_points", plane.to_lowercase()), 0, max_points as i64)?;
+    let mut points = Vec::new();
+    for _ in 0..num_points {
+      let value = self.read_int(&format!("{} scaling point value", plane), 0, 255)?;
+      let scaling = self.read_int(&format!("{} scaling point scaling", plane), 0, 255)?;
+      points.push([value as u8, scaling as u8]);
+    }
+    return Ok(points);
+  }
+
+  fn read_ar_coeffs(&mut self, plane: &str, count: usize) -> Result<Vec<i8>, String> {
+    self.expect(&format!("c{}", plane))?;
+    let mut coeffs = Vec::new();
+    for _ in 0..count {
+      coeffs.push(self.read_int(&format!("{} AR coefficient", plane), -128, 127)? as i8);
+    }
+    return Ok(coeffs);
+  }
+
+  // Read the parameters which follow the "E" line of an entry with update_parameters = 1
+  fn read_params(&mut self, grain_seed: u16) -> Result<FilmGrainParams, String> {
+    self.expect("p")?;
+    let ar_coeff_lag = self.read_int("ar_coeff_lag", 0, MAX_AR_COEFF_LAG as i64)? as usize;
+    let ar_coeff_shift = self.read_int("ar_coeff_shift", 6, 9)? as u8;
+    let grain_scale_shift = self.read_int("grain_scale_shift", 0, 3)? as u8;
+    let scaling_shift = self.read_int("scaling_shift", 8, 11)? as u8;
+    let chroma_scaling_from_luma = self.read_bool("chroma_scaling_from_luma")?;
+    let overlap_flag = self.read_bool("overlap_flag")?;
+    let cb_mult = self.read_int("cb_mult", 0, 255)? as u8;
+    let cb_luma_mult = self.read_int("cb_luma_mult", 0, 255)? as u8;
+    let cb_offset = self.read_int("cb_offset", 0, 511)? as u16;
+    let cr_mult = self.read_int("cr_mult", 0, 255)? as u8;
+    let cr_luma_mult = self.read_int("cr_luma_mult", 0, 255)? as u8;
+    let cr_offset = self.read_int("cr_offset", 0, 511)? as u16;
+
+    let scaling_points_y = self.read_scaling_points("Y", MAX_NUM_Y_POINTS)?;
+    let scaling_points_cb = self.read_scaling_points("Cb", MAX_NUM_UV_POINTS)?;
+    let scaling_points_cr = self.read_scaling_points("Cr", MAX_NUM_UV_POINTS)?;
+
+    let num_pos_luma = 2 * ar_coeff_lag * (ar_coeff_lag + 1);
+    let ar_coeffs_y = self.read_ar_coeffs("Y", num_pos_luma)?;
+    let ar_coeffs_cb = self.read_ar_coeffs("Cb", num_pos_luma + 1)?;
+    let ar_coeffs_cr = self.read_ar_coeffs("Cr", num_pos_luma + 1)?;
+
+    Ok(FilmGrainParams {
+      grain_seed: grain_seed,
+      scaling_points_y: scaling_points_y,
+      chroma_scaling_from_luma: chroma_scaling_from_luma,
+      scaling_points_cb: scaling_points_cb,
+      scaling_points_cr: scaling_points_cr,
+      scaling_shift: scaling_shift,
+      ar_coeff_lag: ar_coeff_lag,
+      ar_coeffs_y: ar_coeffs_y,
+      ar_coeffs_cb: ar_coeffs_cb,
+      ar_coeffs_cr: ar_coeffs_cr,
+      ar_coeff_shift: ar_coeff_shift,
+      grain_scale_shift: grain_scale_shift,
+      cb_mult: cb_mult,
+      cb_luma_mult: cb_luma_mult,
+      cb_offset: cb_offset,
+      cr_mult: cr_mult,
+      cr_luma_mult: cr_luma_mult,
+      cr_offset: cr_offset,
+      overlap_flag: overlap_flag,
+      // This isn't stored in the table format
+      clip_to_restricted_range: false,
+    })
+  }
+}
+
+// Load a film grain table
+pub fn read_film_grain_table(path: &Path) -> Result<FilmGrainTable, String> {
+  let text = std::fs::read_to_string(path).map_err(|err| format!("Could not read film grain table: {}", err))?;
+  return parse_film_grain_table(&text);
+}
+
+// Parse the contents of a film grain table file
+fn parse_film_grain_table(text: &str) -> Result<FilmGrainTable, String> {
+  let mut reader = TableReader {
+    tokens: text.split_whitespace(),
+  };
+  reader.expect(GRAIN_TABLE_MAGIC)?;
+
+  let mut entries = Vec::new();
+  let mut prev_params: Option<FilmGrainParams> = None;
+  loop {
+    match reader.next_token() {
+      None => break,
+      Some("E") => {},
+      Some(token) => return Err(format!("Invalid film grain table: expected \"E\", found \"{}\"", token)),
+    }
+    let start_time = reader.read_int("start time", i64::MIN, i64::MAX)?;
+    let end_time = reader.read_int("end time", i64::MIN, i64::MAX)?;
+    let apply_grain = reader.read_bool("apply_grain")?;
+    let grain_seed = reader.read_int("random seed", 0, 65535)? as u16;
+    let update_parameters = reader.read_bool("update_parameters")?;
+
+    let params = if update_parameters {
+      reader.read_params(grain_seed)?
+    } else {
+      let mut params = prev_params.ok_or("Invalid film grain table: first entry must set update_parameters")?;
+      params.grain_seed = grain_seed;
+      params
+    };
+
+    if apply_grain {
+      params.validate()?;
+    }
+    entries.push(FilmGrainTableEntry {
+      start_time: start_time,
+      end_time: end_time,
+      params: if apply_grain { Some(params.clone()) } else { None },
+    });
+    prev_params = Some(params);
+  }
+
+  return Ok(FilmGrainTable {
+    entries: entries,
+  });
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // A table in the format written by aomenc, with a full set of parameters in the first
+  // entry, a second entry which reuses them with a new seed, and a final entry without grain
+  const SAMPLE_TABLE: &str = "filmgrn1
+E 0 3336666 1 7391 1
+\tp 3 7 0 11 0 1 128 192 256 128 192 256
+\tsY 14  0 20 20 5 39 4 59 3 78 3 98 3 118 3 137 3 157 3 177 3 196 3 216 4 235 4 255 4
+\tsCb 2 0 12 255 14
+\tsCr 2 0 10 255 12
+\tcY 1 -4 1 4 8 3 -2 -6 9 14 -27 -25 -2 4 5 15 -80 94 28 -3 -2 6 -47 121
+\tcCb -3 1 -4 6 -1 2 -2 1 11 -10 -2 -16 -1 3 -2 -14 -26 65 19 -3 -5 2 -6 75 -1
+\tcCr 0 0 -4 8 -1 0 1 2 -1 -9 4 -7 -5 -2 -5 -14 0 45 18 3 -3 4 8 49 5
+E 3336666 6673333 1 7392 0
+E 6673333 10010000 0 7393 0
+";
+
+  #[test]
+  fn parse_aomenc_table() {
+    let table = parse_film_grain_table(SAMPLE_TABLE).unwrap();
+    assert!(table.has_grain());
+
+    let params = table.lookup(0).unwrap();
+    assert_eq!(params.grain_seed, 7391);
+    assert_eq!(params.ar_coeff_lag, 3);
+    assert_eq!(params.ar_coeff_shift, 7);
+    assert_eq!(params.grain_scale_shift, 0);
+    assert_eq!(params.scaling_shift, 11);
+    assert!(!params.chroma_scaling_from_luma);
+    assert!(params.overlap_flag);
+    assert_eq!((params.cb_mult, params.cb_luma_mult, params.cb_offset), (128, 192, 256));
+    assert_eq!((params.cr_mult, params.cr_luma_mult, params.cr_offset), (128, 192, 256));
+    assert_eq!(params.scaling_points_y.len(), 14);
+    assert_eq!(params.scaling_points_y[0], [0, 20]);
+    assert_eq!(params.scaling_points_y[13], [255, 4]);
+    assert_eq!(params.scaling_points_cb, vec![[0, 12], [255, 14]]);
+    assert_eq!(params.scaling_points_cr, vec![[0, 10], [255, 12]]);
+    assert_eq!(params.ar_coeffs_y.len(), 24);
+    assert_eq!(params.ar_coeffs_y[23], 121);
+    assert_eq!(params.ar_coeffs_cb.len(), 25);
+    assert_eq!(params.ar_coeffs_cb[24], -1);
+    assert_eq!(params.ar_coeffs_cr.len(), 25);
+    assert_eq!(params.ar_coeffs_cr[24], 5);
+
+    // The second entry starts exactly at the end of the first, and only changes the seed
+    let params = table.lookup(3336666).unwrap();
+    assert_eq!(params.grain_seed, 7392);
+    assert_eq!(params.scaling_points_y.len(), 14);
+
+    // The last entry, and anything after it, has no grain
+    assert!(table.lookup(6673333).is_none());
+    assert!(table.lookup(20000000).is_none());
+  }
+
+  #[test]
+  fn reject_bad_tables() {
+    // Wrong magic number
+    assert!(parse_film_grain_table("filmgrn2\n").is_err());
+    // The first entry has nothing to reuse
+    assert!(parse_film_grain_table("filmgrn1\nE 0 100 1 1 0\n").is_err());
+    // Truncated in the middle of the parameters
+    let truncated = &SAMPLE_TABLE[..SAMPLE_TABLE.find("\tcCr").unwrap()];
+    assert!(parse_film_grain_table(truncated).is_err());
+    // Out-of-range scaling shift
+    assert!(parse_film_grain_table(&SAMPLE_TABLE.replace("p 3 7 0 11", "p 3 7 0 12")).is_err());
+  }
+}
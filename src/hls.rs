@@ -7,9 +7,22 @@
 // Media Patent License 1.0 was not distributed with this source code in the
 // PATENTS file, you can obtain it at www.aomedia.org/license/patent.
 
-use crate::isobmff::ISOBMFFWriter;
+use byteorder::{LittleEndian, WriteBytesExt};
+
+use crate::isobmff::{ISOBMFFBox, ISOBMFFWriter};
+use crate::level::Tier;
 use crate::util::write_leb128;
 
+// Wrap a sequence header in an OBU. This is separate from pack_obus(), as AVIF sequences
+// need an extra copy of this OBU in the track header
+pub fn pack_sequence_header_obu(sequence_header: &[u8]) -> Box<[u8]> {
+  let mut obu = Vec::new();
+  obu.push(0b0000_1010); // Sequence header OBU
+  write_leb128(&mut obu, sequence_header.len()); // Payload size
+  obu.extend_from_slice(sequence_header); // Payload
+  return obu.into_boxed_slice();
+}
+
 pub fn pack_obus(sequence_header: &[u8], frame_header: &[u8], tile_data: &[u8], include_temporal_delimiter: bool) -> Box<[u8]> {
   let mut av1_data = Vec::new();
 
@@ -32,32 +45,170 @@ pub fn pack_obus(sequence_header: &[u8], frame_header: &[u8], tile_data: &[u8],
     av1_data.push(0u8); // with a zero-byte payload
   }
 
-  av1_data.push(0b0000_1010); // Sequence header OBU
-  write_leb128(&mut av1_data, sequence_header.len()); // Payload size
-  av1_data.extend_from_slice(&sequence_header); // Payload
+  av1_data.extend_from_slice(&pack_sequence_header_obu(sequence_header));
 
   av1_data.push(0b0011_0010); // Frame OBU: combined frame header + tile data
   write_leb128(&mut av1_data, frame_header.len() + tile_data.len());
-  av1_data.extend_from_slice(&frame_header);
-  av1_data.extend_from_slice(&tile_data);
+  av1_data.extend_from_slice(frame_header);
+  av1_data.extend_from_slice(tile_data);
 
   return av1_data.into_boxed_slice();
 }
 
-pub fn pack_avif(av1_data: &[u8], crop_width: usize, crop_height: usize,
+// Write one OBU in the Annex B format: The OBU header has no size field, and is instead
+// preceded by the total length of the OBU
+fn write_annexb_obu(frame_unit: &mut Vec<u8>, obu_header: u8, payload: &[&[u8]]) {
+  let payload_size: usize = payload.iter().map(|part| part.len()).sum();
+  write_leb128(frame_unit, 1 + payload_size); // obu_length
+  frame_unit.push(obu_header);
+  for part in payload {
+    frame_unit.extend_from_slice(part);
+  }
+}
+
+// Pack the same OBUs as pack_obus(), but using the length-delimited format from Annex B
+// of the AV1 spec. This nests each OBU inside a frame unit, inside a temporal unit, each
+// of which is preceded by its size.
+// The temporal delimiter is mandatory here, as Annex B streams are only used standalone
+pub fn pack_annexb(sequence_header: &[u8], frame_header: &[u8], tile_data: &[u8]) -> Box<[u8]> {
+  let mut frame_unit = Vec::new();
+  write_annexb_obu(&mut frame_unit, 0b0001_0000, &[]); // Temporal delimiter
+  write_annexb_obu(&mut frame_unit, 0b0000_1000, &[sequence_header]); // Sequence header
+  write_annexb_obu(&mut frame_unit, 0b0011_0000, &[frame_header, tile_data]); // Frame
+
+  // We only have one frame unit, so the temporal unit just consists of
+  // its size followed by its data
+  let mut temporal_unit = Vec::new();
+  write_leb128(&mut temporal_unit, frame_unit.len()); // frame_unit_size
+  temporal_unit.extend_from_slice(&frame_unit);
+
+  let mut av1_data = Vec::new();
+  write_leb128(&mut av1_data, temporal_unit.len()); // temporal_unit_size
+  av1_data.extend_from_slice(&temporal_unit);
+
+  return av1_data.into_boxed_slice();
+}
+
+// Wrap a list of temporal units, as generated by pack_obus(), in an IVF container.
+// IVF is a minimal format consisting of a 32-byte file header, followed by each frame
+// prefixed with a 12-byte frame header. All values are little-endian
+pub fn pack_ivf(frames: &[Box<[u8]>], crop_width: usize, crop_height: usize, frame_rate: (u32, u32)) -> Box<[u8]> {
+  let mut ivf = Vec::new();
+
+  // File header
+  ivf.extend_from_slice(b"DKIF");
+  ivf.write_u16::<LittleEndian>(0).unwrap();  // Version
+  ivf.write_u16::<LittleEndian>(32).unwrap(); // Header size
+  ivf.extend_from_slice(b"AV01");
+  ivf.write_u16::<LittleEndian>(crop_width as u16).unwrap();
+  ivf.write_u16::<LittleEndian>(crop_height as u16).unwrap();
+  // Timebase: timestamps are in units of (frame_rate.1 / frame_rate.0) seconds,
+  // so that each frame lasts exactly one unit
+  ivf.write_u32::<LittleEndian>(frame_rate.0).unwrap();
+  ivf.write_u32::<LittleEndian>(frame_rate.1).unwrap();
+  ivf.write_u32::<LittleEndian>(frames.len() as u32).unwrap();
+  ivf.write_u32::<LittleEndian>(0).unwrap(); // Unused
+
+  for (i, frame) in frames.iter().enumerate() {
+    // Frame header
+    ivf.write_u32::<LittleEndian>(frame.len() as u32).unwrap();
+    ivf.write_u64::<LittleEndian>(i as u64).unwrap(); // Timestamp
+    ivf.extend_from_slice(frame);
+  }
+
+  return ivf.into_boxed_slice();
+}
+
+// AV1 codec configuration box, which is attached to both the image item and
+// the sequence track. For sequences, this also carries a copy of the sequence header OBU
+fn write_av1C(parent: &mut ISOBMFFBox, seq_level_idx: u8, seq_tier: Tier, monochrome: bool, config_obus: &[u8]) {
+  let mut av1C = parent.open_box(b"av1C");
+  av1C.write_u8(0x81);          // Custom version field: 1 bit marker that must be 1 + 7-bit version = 1
+  av1C.write_u8(seq_level_idx); // Profile 0 (top 3 bits), level
+  // Tier, 8bpp, monochrome flag, 4:2:0 subsampling, chroma sample position unknown
+  av1C.write_u8(((seq_tier as u8) << 7) | ((monochrome as u8) << 4) | 0b00001100);
+  av1C.write_u8(0x00);          // No presentation delay info
+  av1C.write_bytes(config_obus);
+  drop(av1C);
+}
+
+fn write_colr(parent: &mut ISOBMFFBox, color_primaries: u16, transfer_function: u16, matrix_coefficients: u16) {
+  let mut colr = parent.open_box(b"colr");
+  colr.write_bytes(b"nclx"); // Required subtype
+  colr.write_u16(color_primaries);
+  colr.write_u16(transfer_function);
+  colr.write_u16(matrix_coefficients);
+  colr.write_u8(0);  // TV colour range (change to 0x80 for full-range)
+  drop(colr);
+}
+
+// Identity transformation matrix, used in the movie and track headers
+fn write_unity_matrix(parent: &mut ISOBMFFBox) {
+  for value in [0x00010000, 0, 0, 0, 0x00010000, 0, 0, 0, 0x40000000] {
+    parent.write_u32(value);
+  }
+}
+
+// Extra information needed to write an animated AVIF file
+pub struct AvifSequence<'a> {
+  // Frame rate, as a fraction (numerator, denominator)
+  pub frame_rate: (u32, u32),
+  // Sequence header OBU, including its OBU header and size field
+  pub sequence_header_obu: &'a [u8],
+  // Whether each frame is a key frame. Other frames are inter frames, which
+  // are predicted from the frame before them
+  pub key_frames: &'a [bool],
+}
+
+// Alpha channel for a still image, which is stored as a separate monochrome AV1 item
+pub struct AvifAlpha<'a> {
+  // Temporal unit containing the alpha plane, as generated by pack_obus()
+  pub data: &'a [u8],
+  // Level and tier of the alpha plane's own sequence header
+  pub seq_level_idx: u8,
+  pub seq_tier: Tier,
+  // Whether the colour channels have already been multiplied by the alpha values
+  pub premultiplied: bool,
+}
+
+// Pack one or more frames into an AVIF file. Each frame should be a temporal unit,
+// as generated by pack_obus().
+//
+// For a still image, there should be exactly one frame, and `sequence` should be None.
+// Otherwise, we generate an animated AVIF file: the frames are stored as a track,
+// and the first frame doubles as the primary image item, for decoders which don't
+// support animation
+//
+// Still images can also have an alpha channel, which is stored as a second, auxiliary
+// image item linked to the first one
+#[allow(clippy::too_many_arguments)]
+pub fn pack_avif(frames: &[Box<[u8]>], crop_width: usize, crop_height: usize,
+                 seq_level_idx: u8, seq_tier: Tier,
+                 sequence: Option<AvifSequence>,
+                 alpha: Option<AvifAlpha>,
                  color_primaries: u16,
                  transfer_function: u16,
                  matrix_coefficients: u16) -> Box<[u8]> {
+  assert!(sequence.is_some() || frames.len() == 1);
+  assert!(sequence.is_none() || alpha.is_none());
   let mut avif = ISOBMFFWriter::new();
 
+  let num_items = if alpha.is_some() { 2 } else { 1 };
   let content_pos_marker;
-  let content_size = av1_data.len();
+  let mut alpha_pos_marker = None;
+  let content_size = frames[0].len();
 
   // "File type" box
   let mut ftyp = avif.open_box(b"ftyp");
-  ftyp.write_bytes(b"avif"); // Main file type
-  ftyp.write_u32(0);         // AVIF version
-  ftyp.write_bytes(b"avifmif1miafMA1B"); // "compatible brands"
+  if sequence.is_some() {
+    ftyp.write_bytes(b"avis"); // Main file type
+    ftyp.write_u32(0);         // AVIF version
+    ftyp.write_bytes(b"avifavismsf1iso8mif1miafMA1B"); // "compatible brands"
+  } else {
+    ftyp.write_bytes(b"avif"); // Main file type
+    ftyp.write_u32(0);         // AVIF version
+    ftyp.write_bytes(b"avifmif1miafMA1B"); // "compatible brands"
+  }
   drop(ftyp);
 
   // Metadata box - contains the rest of the file header
@@ -84,7 +235,7 @@ pub fn pack_avif(av1_data: &[u8], crop_width: usize, crop_height: usize,
     let mut iloc = meta.open_box_with_version(b"iloc", 0, 0);
     iloc.write_u8(0x44); // 4 bytes each for offset and length
     iloc.write_u8(0);    // No base offset; 4 reserved bits
-    iloc.write_u16(1);   // One item
+    iloc.write_u16(num_items);
 
     iloc.write_u16(1); // Item ID 1:
     iloc.write_u16(0); // "Data reference index" = 0
@@ -92,11 +243,19 @@ pub fn pack_avif(av1_data: &[u8], crop_width: usize, crop_height: usize,
     // Allocate space for the content position, but we'll need to come back and fill it in later
     content_pos_marker = iloc.mark_u32();
     iloc.write_u32(content_size as u32); // Content length
+
+    if let Some(alpha) = &alpha {
+      iloc.write_u16(2); // Item ID 2:
+      iloc.write_u16(0); // "Data reference index" = 0
+      iloc.write_u16(1); // One extent
+      alpha_pos_marker = Some(iloc.mark_u32());
+      iloc.write_u32(alpha.data.len() as u32);
+    }
     drop(iloc);
 
     // "Item info" box
     let mut iinf = meta.open_box_with_version(b"iinf", 0, 0);
-    iinf.write_u16(1); // One item
+    iinf.write_u16(num_items);
     // "infe" box per item
     {
       let mut infe = iinf.open_box_with_version(b"infe", 2, 0);
@@ -106,8 +265,39 @@ pub fn pack_avif(av1_data: &[u8], crop_width: usize, crop_height: usize,
       infe.write_bytes(b"Color\0"); // and it's the main colour data, not, say, alpha data
       drop(infe);
     }
+    if alpha.is_some() {
+      let mut infe = iinf.open_box_with_version(b"infe", 2, 0);
+      infe.write_u16(2);            // Item index 2
+      infe.write_u16(0);            // "Protection" = 0
+      infe.write_bytes(b"av01");
+      infe.write_bytes(b"Alpha\0"); // ...and this time it *is* alpha data
+      drop(infe);
+    }
     drop(iinf);
 
+    // "Item reference" box, which marks the alpha item as an auxiliary image for the
+    // colour item. If the colour item has been premultiplied by the alpha values, that
+    // is signalled by a reference in the other direction
+    if let Some(alpha) = &alpha {
+      let mut iref = meta.open_box_with_version(b"iref", 0, 0);
+      {
+        let mut auxl = iref.open_box(b"auxl");
+        auxl.write_u16(2); // From item 2...
+        auxl.write_u16(1); // ...with one reference...
+        auxl.write_u16(1); // ...to item 1
+        drop(auxl);
+
+        if alpha.premultiplied {
+          let mut prem = iref.open_box(b"prem");
+          prem.write_u16(1);
+          prem.write_u16(1);
+          prem.write_u16(2);
+          drop(prem);
+        }
+      }
+      drop(iref);
+    }
+
     // "Image properties" box
     let mut iprp = meta.open_box(b"iprp");
     {
@@ -129,28 +319,32 @@ pub fn pack_avif(av1_data: &[u8], crop_width: usize, crop_height: usize,
         drop(pixi);
 
         // AV1-specific info box
-        #[allow(non_snake_case)]
-        let mut av1C = ipco.open_box(b"av1C");
-        av1C.write_u8(0x81);       // Custom version field: 1 bit marker that must be 1 + 7-bit version = 1
-        av1C.write_u8(0x1F);       // Profile 0, level 31 (== unconstrained)
-        av1C.write_u8(0b00001100); // Main tier, 8bpp, not monochrome, 4:2:0 subsampling, chroma sample position unknown
-        av1C.write_u8(0x00);       // No presentation delay info
-        drop(av1C);
+        write_av1C(&mut ipco, seq_level_idx, seq_tier, false, &[]);
 
         // Colour info box
-        let mut colr = ipco.open_box(b"colr");
-        colr.write_bytes(b"nclx"); // Required subtype
-        colr.write_u16(color_primaries);
-        colr.write_u16(transfer_function);
-        colr.write_u16(matrix_coefficients);
-        colr.write_u8(0);  // TV colour range (change to 0x80 for full-range)
-        drop(colr);
+        write_colr(&mut ipco, color_primaries, transfer_function, matrix_coefficients);
+
+        if let Some(alpha) = &alpha {
+          // The alpha item shares the image size, but has its own pixel info and
+          // AV1 configuration, as it only has one channel
+          let mut pixi = ipco.open_box_with_version(b"pixi", 0, 0);
+          pixi.write_u8(1); // 1 channel...
+          pixi.write_u8(8); // ...which is 8 bits per pixel
+          drop(pixi);
+
+          write_av1C(&mut ipco, alpha.seq_level_idx, alpha.seq_tier, true, &[]);
+
+          // "Auxiliary type" box, which says what kind of auxiliary image this is
+          let mut auxC = ipco.open_box_with_version(b"auxC", 0, 0);
+          auxC.write_bytes(b"urn:mpeg:mpegB:cicp:systems:auxiliary:alpha\0");
+          drop(auxC);
+        }
       }
       drop(ipco);
 
       // "Image property mapping association" box
       let mut ipma = iprp.open_box_with_version(b"ipma", 0, 0);
-      ipma.write_u32(1); // One item
+      ipma.write_u32(num_items as u32);
 
       ipma.write_u16(1); // Item ID 1:
       ipma.write_u8(4); // Four associations
@@ -161,19 +355,229 @@ pub fn pack_avif(av1_data: &[u8], crop_width: usize, crop_height: usize,
       ipma.write_u8(2);
       ipma.write_u8(0x83);
       ipma.write_u8(4);
+
+      if alpha.is_some() {
+        ipma.write_u16(2); // Item ID 2:
+        ipma.write_u8(4);  // Four associations
+        ipma.write_u8(1);
+        ipma.write_u8(5);
+        ipma.write_u8(0x86);
+        ipma.write_u8(7);
+      }
       drop(ipma);
     }
     drop(iprp);
   }
   drop(meta);
 
-  // Finally, the 'mdat' box contains the image data itself
+  // For animations, the 'moov' box describes the track containing all of the frames
+  let mut chunk_pos_marker = None;
+  if let Some(sequence) = &sequence {
+    let (timescale, frame_duration) = sequence.frame_rate;
+    let duration = frames.len() as u32 * frame_duration;
+
+    let mut moov = avif.open_box(b"moov");
+    {
+      // "Movie header" box
+      let mut mvhd = moov.open_box_with_version(b"mvhd", 0, 0);
+      mvhd.write_u32(0); // Creation time
+      mvhd.write_u32(0); // Modification time
+      mvhd.write_u32(timescale);
+      mvhd.write_u32(duration);
+      mvhd.write_u32(0x00010000); // Playback rate = 1.0
+      mvhd.write_u16(0x0100);     // Volume = 1.0
+      mvhd.write_u16(0);          // Reserved
+      mvhd.write_u32(0);          // Reserved
+      mvhd.write_u32(0);          // Reserved
+      write_unity_matrix(&mut mvhd);
+      for _ in 0..6 {
+        mvhd.write_u32(0);        // Pre-defined
+      }
+      mvhd.write_u32(2);          // Next track ID
+      drop(mvhd);
+
+      let mut trak = moov.open_box(b"trak");
+      {
+        // "Track header" box. Flags = track enabled + track used in the presentation
+        let mut tkhd = trak.open_box_with_version(b"tkhd", 0, 3);
+        tkhd.write_u32(0); // Creation time
+        tkhd.write_u32(0); // Modification time
+        tkhd.write_u32(1); // Track ID
+        tkhd.write_u32(0); // Reserved
+        tkhd.write_u32(duration);
+        tkhd.write_u32(0); // Reserved
+        tkhd.write_u32(0); // Reserved
+        tkhd.write_u16(0); // Layer
+        tkhd.write_u16(0); // Alternate group
+        tkhd.write_u16(0); // Volume
+        tkhd.write_u16(0); // Reserved
+        write_unity_matrix(&mut tkhd);
+        tkhd.write_u32((crop_width as u32) << 16);  // Width, in 16.16 fixed point
+        tkhd.write_u32((crop_height as u32) << 16); // Height, in 16.16 fixed point
+        drop(tkhd);
+
+        let mut mdia = trak.open_box(b"mdia");
+        {
+          // "Media header" box
+          let mut mdhd = mdia.open_box_with_version(b"mdhd", 0, 0);
+          mdhd.write_u32(0); // Creation time
+          mdhd.write_u32(0); // Modification time
+          mdhd.write_u32(timescale);
+          mdhd.write_u32(duration);
+          mdhd.write_u16(0x55C4); // Language = "und" (undetermined), packed as 3x 5-bit values
+          mdhd.write_u16(0);      // Pre-defined
+          drop(mdhd);
+
+          // Handler box, similar to the one in the 'meta' box
+          let mut hdlr = mdia.open_box_with_version(b"hdlr", 0, 0);
+          hdlr.write_u32(0); // Must be zero
+          hdlr.write_bytes(b"pict");
+          hdlr.write_u32(0); // Must be zero
+          hdlr.write_u32(0); // Must be zero
+          hdlr.write_u32(0); // Must be zero
+          hdlr.write_bytes(b"tinyavif\0");
+          drop(hdlr);
+
+          let mut minf = mdia.open_box(b"minf");
+          {
+            // "Video media header" box. Flags must be 1
+            let mut vmhd = minf.open_box_with_version(b"vmhd", 0, 1);
+            vmhd.write_u16(0); // Graphics mode = copy
+            vmhd.write_u16(0); // Opcolor (3x)
+            vmhd.write_u16(0);
+            vmhd.write_u16(0);
+            drop(vmhd);
+
+            // "Data information" box, which says that the frames are in this file
+            let mut dinf = minf.open_box(b"dinf");
+            {
+              let mut dref = dinf.open_box_with_version(b"dref", 0, 0);
+              dref.write_u32(1); // One entry
+              // Flags = 1 means the data is in the same file
+              drop(dref.open_box_with_version(b"url ", 0, 1));
+              drop(dref);
+            }
+            drop(dinf);
+
+            // "Sample table" box, which says where each frame is and how long it lasts
+            let mut stbl = minf.open_box(b"stbl");
+            {
+              // "Sample description" box
+              let mut stsd = stbl.open_box_with_version(b"stsd", 0, 0);
+              stsd.write_u32(1); // One entry
+              {
+                let mut av01 = stsd.open_box(b"av01");
+                for _ in 0..6 {
+                  av01.write_u8(0); // Reserved
+                }
+                av01.write_u16(1); // Data reference index
+                av01.write_u16(0); // Pre-defined
+                av01.write_u16(0); // Reserved
+                av01.write_u32(0); // Pre-defined (3x)
+                av01.write_u32(0);
+                av01.write_u32(0);
+                av01.write_u16(crop_width as u16);
+                av01.write_u16(crop_height as u16);
+                av01.write_u32(0x00480000); // Horizontal resolution = 72 dpi
+                av01.write_u32(0x00480000); // Vertical resolution = 72 dpi
+                av01.write_u32(0);          // Reserved
+                av01.write_u16(1);          // One frame per sample
+                // Compressor name: a length byte followed by the name, padded to 32 bytes
+                let mut compressor_name = [0u8; 32];
+                compressor_name[0] = 8;
+                compressor_name[1..9].copy_from_slice(b"tinyavif");
+                av01.write_bytes(&compressor_name);
+                av01.write_u16(0x0018);     // Depth = 24 bits, no alpha
+                av01.write_u16(0xFFFF);     // Pre-defined = -1
+
+                write_av1C(&mut av01, seq_level_idx, seq_tier, false, sequence.sequence_header_obu);
+                write_colr(&mut av01, color_primaries, transfer_function, matrix_coefficients);
+
+                // "Coding constraints" box. Inter frames use a single reference,
+                // which is intra if the previous frame was a key frame
+                let key_frames = sequence.key_frames;
+                let max_ref_per_pic = if key_frames.iter().all(|&is_key| is_key) { 0 } else { 1 };
+                let all_ref_pics_intra = (1..key_frames.len()).all(|i| key_frames[i] || key_frames[i - 1]);
+                let mut ccst = av01.open_box_with_version(b"ccst", 0, 0);
+                ccst.write_u32((all_ref_pics_intra as u32) << 31 |
+                               1 << 30 | // Intra prediction used
+                               max_ref_per_pic << 26);
+                drop(ccst);
+                drop(av01);
+              }
+              drop(stsd);
+
+              // "Decoding time to sample" box: every frame has the same duration
+              let mut stts = stbl.open_box_with_version(b"stts", 0, 0);
+              stts.write_u32(1); // One entry
+              stts.write_u32(frames.len() as u32);
+              stts.write_u32(frame_duration);
+              drop(stts);
+
+              // "Sample to chunk" box: all of the frames are stored in a single chunk
+              let mut stsc = stbl.open_box_with_version(b"stsc", 0, 0);
+              stsc.write_u32(1); // One entry
+              stsc.write_u32(1); // First chunk
+              stsc.write_u32(frames.len() as u32); // Samples per chunk
+              stsc.write_u32(1); // Sample description index
+              drop(stsc);
+
+              // "Sample size" box
+              let mut stsz = stbl.open_box_with_version(b"stsz", 0, 0);
+              stsz.write_u32(0); // Sizes vary, so are listed individually
+              stsz.write_u32(frames.len() as u32);
+              for frame in frames {
+                stsz.write_u32(frame.len() as u32);
+              }
+              drop(stsz);
+
+              // "Chunk offset" box. As with 'iloc', we fill in the offset once we know it
+              let mut stco = stbl.open_box_with_version(b"stco", 0, 0);
+              stco.write_u32(1); // One chunk
+              chunk_pos_marker = Some(stco.mark_u32());
+              drop(stco);
+
+              // "Sync sample" box: lists the key frames, which can be decoded on their own
+              let key_frame_indices: Vec<usize> = (0..frames.len()).filter(|&i| sequence.key_frames[i]).collect();
+              let mut stss = stbl.open_box_with_version(b"stss", 0, 0);
+              stss.write_u32(key_frame_indices.len() as u32);
+              for i in key_frame_indices {
+                stss.write_u32(i as u32 + 1);
+              }
+              drop(stss);
+            }
+            drop(stbl);
+          }
+          drop(minf);
+        }
+        drop(mdia);
+      }
+      drop(trak);
+    }
+    drop(moov);
+  }
+
+  // Finally, the 'mdat' box contains the image data itself.
+  // The image item and the first frame of the track share the same data
   let mut mdat = avif.open_box(b"mdat");
   let content_pos = mdat.get_file_pos() as u32;
-  mdat.write_bytes(av1_data);
+  for frame in frames {
+    mdat.write_bytes(frame);
+  }
+  // The alpha data, if any, follows the colour data
+  let alpha_pos = mdat.get_file_pos() as u32;
+  if let Some(alpha) = &alpha {
+    mdat.write_bytes(alpha.data);
+  }
   drop(mdat);
 
   avif.write_u32_at_marker(content_pos_marker, content_pos);
+  if let Some(marker) = chunk_pos_marker {
+    avif.write_u32_at_marker(marker, content_pos);
+  }
+  if let Some(marker) = alpha_pos_marker {
+    avif.write_u32_at_marker(marker, alpha_pos);
+  }
 
   return avif.finalize();
 }
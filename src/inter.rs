@@ -0,0 +1,374 @@
+// Copyright (c) 2024-2025, The tinyavif contributors. All rights reserved
+//
+// This source code is subject to the terms of the BSD 2 Clause License and
+// the Alliance for Open Media Patent License 1.0. If the BSD 2 Clause License
+// was not distributed with this source code in the LICENSE file, you can
+// obtain it at www.aomedia.org/license/software. If the Alliance for Open
+// Media Patent License 1.0 was not distributed with this source code in the
+// PATENTS file, you can obtain it at www.aomedia.org/license/patent.
+
+// Inter prediction
+//
+// In an image sequence, most of each frame can usually be predicted well by copying
+// pixels from the previous frame, shifted by a motion vector (MV). We code each frame
+// after the first as an inter frame with a single reference, LAST_FRAME, which is
+// always the reconstruction of the previous frame.
+//
+// Motion vectors are stored in units of 1/8 luma pixel, as (row, col), like in the spec.
+// We never enable high-precision MVs, so in practice they are always multiples of 2, ie.
+// quarter-pixel. Fractional positions are interpolated using the "regular" 8-tap filter,
+// or a 4-tap version of it for blocks which are only 4 pixels wide or high, which in our
+// case means the chroma blocks.
+//
+// To find motion vectors, we first run a hierarchical full-pixel search over the whole
+// frame, at 1/4, 1/2, and then full resolution, with each level refining the result of
+// the level above it. This gives a starting MV for each 8x8 block, which the encoder then
+// refines to quarter-pixel precision, taking the cost of coding the MV into account.
+
+use crate::array2d::Array2D;
+use crate::cdf::*;
+use crate::entropycode::SymbolWriter;
+use crate::frame::Plane;
+use crate::util::*;
+
+// Motion vector coding parameters
+pub const MV_JOINTS: usize = 4;
+pub const MV_CLASSES: usize = 11;
+pub const MV_OFFSET_BITS: usize = MV_CLASSES - 1;
+pub const CLASS0_SIZE: u32 = 2;
+
+// Largest difference between an MV and its prediction which can be coded, in 1/8 pixels
+const MAX_MV_DIFF: i32 = (CLASS0_SIZE as i32) << (MV_OFFSET_BITS + 3);
+
+// Distance that predicted MVs are allowed to point outside the frame, in pixels
+pub const MV_BORDER: i32 = 16;
+
+// Size of the blocks which we use for inter prediction
+const BLOCK_SIZE: usize = 8;
+
+// Interpolation filters, indexed by the fractional position in units of 1/16 pixel.
+// These are the EIGHTTAP ("regular") filter and its 4-tap variant, which the decoder
+// uses in place of the 8-tap filter along any dimension where the block is 4 pixels or less
+const Subpel_Filters_Regular: [[i32; 8]; 16] = [
+  [0, 0, 0, 128, 0, 0, 0, 0], [0, 2, -6, 126, 8, -2, 0, 0],
+  [0, 2, -10, 122, 18, -4, 0, 0], [0, 2, -12, 116, 28, -8, 2, 0],
+  [0, 2, -14, 110, 38, -10, 2, 0], [0, 2, -14, 102, 48, -12, 2, 0],
+  [0, 2, -16, 94, 58, -12, 2, 0], [0, 2, -14, 84, 66, -12, 2, 0],
+  [0, 2, -14, 76, 76, -14, 2, 0], [0, 2, -12, 66, 84, -14, 2, 0],
+  [0, 2, -12, 58, 94, -16, 2, 0], [0, 2, -12, 48, 102, -14, 2, 0],
+  [0, 2, -10, 38, 110, -14, 2, 0], [0, 2, -8, 28, 116, -12, 2, 0],
+  [0, 0, -4, 18, 122, -10, 2, 0], [0, 0, -2, 8, 126, -6, 2, 0]
+];
+
+const Subpel_Filters_Regular_4: [[i32; 8]; 16] = [
+  [0, 0, 0, 128, 0, 0, 0, 0], [0, 0, -4, 126, 8, -2, 0, 0],
+  [0, 0, -8, 122, 18, -4, 0, 0], [0, 0, -10, 116, 28, -6, 0, 0],
+  [0, 0, -12, 110, 38, -8, 0, 0], [0, 0, -12, 102, 48, -10, 0, 0],
+  [0, 0, -14, 94, 58, -10, 0, 0], [0, 0, -12, 84, 66, -10, 0, 0],
+  [0, 0, -12, 76, 76, -12, 0, 0], [0, 0, -10, 66, 84, -12, 0, 0],
+  [0, 0, -10, 58, 94, -14, 0, 0], [0, 0, -10, 48, 102, -12, 0, 0],
+  [0, 0, -8, 38, 110, -12, 0, 0], [0, 0, -6, 28, 116, -10, 0, 0],
+  [0, 0, -4, 18, 122, -8, 0, 0], [0, 0, -2, 8, 126, -4, 0, 0]
+];
+
+// Rounding applied after the horizontal and vertical filters respectively,
+// for 8-bit video without compound prediction
+const INTER_ROUND0: u32 = 3;
+const INTER_ROUND1: u32 = 11;
+
+// Number of levels in the motion search hierarchy, including the full-resolution level
+const ME_LEVELS: usize = 3;
+
+// Full-pixel search range, in each direction, at the coarsest level and at each later level.
+// The coarse range is in pixels of the coarsest level, so covers 4x as many full-resolution pixels
+const ME_COARSE_RANGE: i32 = 8;
+const ME_REFINE_RANGE: i32 = 1;
+
+// Predict a block of one plane from the reference frame, using the given MV.
+// (y0, x0) is the top-left corner of the block in that plane's pixels, and
+// `subsampling` is 1 for chroma and 0 for luma.
+//
+// As in the decoder, any reads from outside the reference frame are clamped to
+// its nearest edge pixel. Note that this uses the crop size, not the padded size
+pub fn inter_predict_block(reference: &Plane, mv: [i16; 2], y0: usize, x0: usize, h: usize, w: usize,
+                           subsampling: usize) -> Array2D<u8> {
+  let ref_pixels = reference.pixels();
+  let last_y = reference.crop_height() as i32 - 1;
+  let last_x = reference.crop_width() as i32 - 1;
+  let filter_y = if h <= 4 { &Subpel_Filters_Regular_4 } else { &Subpel_Filters_Regular };
+  let filter_x = if w <= 4 { &Subpel_Filters_Regular_4 } else { &Subpel_Filters_Regular };
+
+  // Position of the top-left predicted pixel, in units of 1/16 pixel of this plane
+  let pos_y = ((y0 as i32) << 4) + ((2 * mv[0] as i32) >> subsampling);
+  let pos_x = ((x0 as i32) << 4) + ((2 * mv[1] as i32) >> subsampling);
+  let (int_y, frac_y) = (pos_y >> 4, (pos_y & 15) as usize);
+  let (int_x, frac_x) = (pos_x >> 4, (pos_x & 15) as usize);
+
+  // Filter horizontally first, over the extra rows needed by the vertical filter.
+  // The intermediate values are kept at higher precision
+  let intermediate = Array2D::new_with(h + 7, w, |i, j| {
+    let y = clamp(int_y + i as i32 - 3, 0, last_y) as usize;
+    let mut sum = 0;
+    for t in 0..8 {
+      let x = clamp(int_x + j as i32 + t as i32 - 3, 0, last_x) as usize;
+      sum += filter_x[frac_x][t] * ref_pixels[y][x] as i32;
+    }
+    round2(sum, INTER_ROUND0)
+  });
+
+  return Array2D::new_with(h, w, |i, j| {
+    let mut sum = 0;
+    for t in 0..8 {
+      sum += filter_y[frac_y][t] * intermediate[i + t][j];
+    }
+    clamp(round2(sum, INTER_ROUND1), 0, 255) as u8
+  });
+}
+
+// Generate the inter prediction for one plane of a block, writing it into `pixels`
+#[allow(clippy::too_many_arguments)]
+pub fn inter_predict(reference: &Plane, pixels: &mut Array2D<u8>, mv: [i16; 2], y0: usize, x0: usize,
+                     h: usize, w: usize, subsampling: usize) {
+  let pred = inter_predict_block(reference, mv, y0, x0, h, w, subsampling);
+  pixels.paste_region(y0, x0, &pred);
+}
+
+// Check whether the difference between an MV and its prediction is small enough to be coded
+pub fn can_code_mv(mv: [i16; 2], ref_mv: [i16; 2]) -> bool {
+  (0..2).all(|comp| abs(mv[comp] as i32 - ref_mv[comp] as i32) <= MAX_MV_DIFF)
+}
+
+// Clamp a predicted MV for an 8x8 block so that it doesn't point too far outside the frame.
+// This is the same as clamp_ref_dv(), but in units of 1/8 pixel
+pub fn clamp_ref_mv(mv: [i16; 2], mi_row: usize, mi_col: usize, mi_rows: usize, mi_cols: usize) -> [i16; 2] {
+  let border = (MV_BORDER + BLOCK_SIZE as i32) * 8;
+  let clamp_comp = |value: i16, mi_pos: usize, mi_size: usize| {
+    let to_start_edge = -((mi_pos * 4 * 8) as i32);
+    let to_end_edge = ((mi_size - mi_pos) * 4 * 8) as i32 - (BLOCK_SIZE * 8) as i32;
+    clamp(value as i32, to_start_edge - border, to_end_edge + border) as i16
+  };
+  [clamp_comp(mv[0], mi_row, mi_rows), clamp_comp(mv[1], mi_col, mi_cols)]
+}
+
+// Code an MV, relative to the predicted MV. Both are in units of 1/8 pixel
+pub fn write_mv<W: SymbolWriter>(w: &mut W, mv: [i16; 2], ref_mv: [i16; 2]) {
+  let diff = [mv[0] as i32 - ref_mv[0] as i32, mv[1] as i32 - ref_mv[1] as i32];
+
+  // The "joint" symbol says which of the two components are nonzero:
+  // 0 = neither, 1 = column only, 2 = row only, 3 = both
+  let joint = ((diff[0] != 0) as usize) << 1 | ((diff[1] != 0) as usize);
+  w.write_symbol(joint, &mv_joint_cdf);
+
+  for comp in 0..2 {
+    if diff[comp] != 0 {
+      write_mv_component(w, diff[comp]);
+    }
+  }
+}
+
+// Code one nonzero component of an MV difference
+fn write_mv_component<W: SymbolWriter>(w: &mut W, value: i32) {
+  // Without high-precision MVs, the 1/8 pixel bit is implicitly 1 in the coded
+  // value of (|value| - 1), so only multiples of 2 can be represented
+  assert!(value != 0 && value % 2 == 0 && abs(value) <= MAX_MV_DIFF);
+  w.write_bool(value < 0, mv_sign_cdf[0]);
+
+  // (|value| - 1) is split into a whole-pixel part, which is coded in the same way as
+  // for DVs, followed by a quarter-pixel part
+  let offset = unsigned_abs(value) - 1;
+  let int_offset = offset >> 3;
+  let fr = ((offset >> 1) & 3) as usize;
+  if int_offset < CLASS0_SIZE {
+    w.write_symbol(0, &mv_class_cdf);
+    w.write_bit(int_offset as usize, mv_class0_bit_cdf[0]);
+    w.write_symbol(fr, &mv_class0_fr_cdf[int_offset as usize]);
+  } else {
+    let class = floor_log2(int_offset);
+    w.write_symbol(class as usize, &mv_class_cdf);
+    let bits = int_offset - (1 << class);
+    for i in 0 .. class as usize {
+      w.write_bit(((bits >> i) & 1) as usize, mv_bits_cdf[i][0]);
+    }
+    w.write_symbol(fr, &mv_fr_cdf);
+  }
+}
+
+// One level of the image pyramid used for motion search, holding just the luma plane
+struct PyramidLevel {
+  pixels: Array2D<u8>,
+  height: usize,
+  width: usize,
+}
+
+impl PyramidLevel {
+  fn new(plane: &Plane) -> Self {
+    let (height, width) = (plane.crop_height(), plane.crop_width());
+    Self {
+      pixels: plane.pixels().copy_region(0, 0, height, width),
+      height: height,
+      width: width,
+    }
+  }
+
+  // Halve the resolution by averaging each 2x2 group of pixels
+  fn downscale(&self) -> Self {
+    let (height, width) = (self.height.div_ceil(2), self.width.div_ceil(2));
+    let pixels = Array2D::new_with(height, width, |i, j| {
+      let (y, x) = (2 * i, 2 * j);
+      let (y1, x1) = (min(y + 1, self.height - 1), min(x + 1, self.width - 1));
+      let sum = self.pixels[y][x] as u32 + self.pixels[y][x1] as u32 +
+                self.pixels[y1][x] as u32 + self.pixels[y1][x1] as u32;
+      round2(sum, 2) as u8
+    });
+    Self {
+      pixels: pixels,
+      height: height,
+      width: width,
+    }
+  }
+
+  fn pyramid(plane: &Plane) -> Vec<Self> {
+    let mut levels = vec![Self::new(plane)];
+    for _ in 1..ME_LEVELS {
+      let next = levels.last().unwrap().downscale();
+      levels.push(next);
+    }
+    return levels;
+  }
+}
+
+// Sum of absolute differences between an 8x8 block of the source, clipped to the
+// image, and the reference at a whole-pixel offset of (dy, dx)
+fn block_sad(source: &PyramidLevel, reference: &PyramidLevel, y0: usize, x0: usize, dy: i32, dx: i32) -> u32 {
+  let h = min(BLOCK_SIZE, source.height - y0);
+  let w = min(BLOCK_SIZE, source.width - x0);
+  let last_y = reference.height as i32 - 1;
+  let last_x = reference.width as i32 - 1;
+
+  let mut sad = 0;
+  for i in 0..h {
+    let ref_y = clamp((y0 + i) as i32 + dy, 0, last_y) as usize;
+    for j in 0..w {
+      let ref_x = clamp((x0 + j) as i32 + dx, 0, last_x) as usize;
+      sad += (source.pixels[y0 + i][x0 + j] as i32 - reference.pixels[ref_y][ref_x] as i32).unsigned_abs();
+    }
+  }
+  return sad;
+}
+
+// Find a whole-pixel motion vector for every 8x8 luma block of the source,
+// using a hierarchical search. Returns an array of MVs in units of 1/8 pixel,
+// with one entry per 8x8 block of the padded image
+pub fn estimate_motion(source: &Plane, reference: &Plane) -> Array2D<[i16; 2]> {
+  let source_levels = PyramidLevel::pyramid(source);
+  let reference_levels = PyramidLevel::pyramid(reference);
+
+  // MVs in whole pixels of the current level, per 8x8 block of that level
+  let mut field: Option<Array2D<[i32; 2]>> = None;
+  for level in (0..ME_LEVELS).rev() {
+    let src = &source_levels[level];
+    let rf = &reference_levels[level];
+    let rows = src.height.div_ceil(BLOCK_SIZE);
+    let cols = src.width.div_ceil(BLOCK_SIZE);
+
+    let parent = field.take();
+    field = Some(Array2D::new_with(rows, cols, |row, col| {
+      // Each 8x8 block covers a quarter of a block at the level above. Start from double
+      // that block's MV, but also consider zero motion, so that static areas next to
+      // moving objects aren't dragged along with them
+      let (centers, range) = match &parent {
+        None => (vec![[0, 0]], ME_COARSE_RANGE),
+        Some(parent) => {
+          let mv = parent[min(row / 2, parent.rows() - 1)][min(col / 2, parent.cols() - 1)];
+          (vec![[2 * mv[0], 2 * mv[1]], [0, 0]], ME_REFINE_RANGE)
+        },
+      };
+
+      let (y0, x0) = (row * BLOCK_SIZE, col * BLOCK_SIZE);
+      let mut best_mv = centers[0];
+      let mut best_sad = u32::MAX;
+      for center in centers {
+        for dy in -range ..= range {
+          for dx in -range ..= range {
+            let mv = [center[0] + dy, center[1] + dx];
+            let sad = block_sad(src, rf, y0, x0, mv[0], mv[1]);
+            if sad < best_sad {
+              best_sad = sad;
+              best_mv = mv;
+            }
+          }
+        }
+      }
+      best_mv
+    }));
+  }
+
+  let field = field.unwrap();
+  return Array2D::new_with(source.height() / BLOCK_SIZE, source.width() / BLOCK_SIZE, |row, col| {
+    let mv = field[row][col];
+    [(mv[0] * 8) as i16, (mv[1] * 8) as i16]
+  });
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::entropycode::{EntropyReader, EntropyWriter};
+
+  // Read an MV difference back, following read_mv() in the spec, with
+  // allow_high_precision_mv = 0
+  fn read_mv_diff(r: &mut EntropyReader) -> [i32; 2] {
+    let joint = r.read_symbol(&mv_joint_cdf);
+    let mut diff = [0; 2];
+    if joint & 2 != 0 {
+      diff[0] = read_mv_component(r);
+    }
+    if joint & 1 != 0 {
+      diff[1] = read_mv_component(r);
+    }
+    return diff;
+  }
+
+  fn read_mv_component(r: &mut EntropyReader) -> i32 {
+    let sign = r.read_bool(mv_sign_cdf[0]);
+    let class = r.read_symbol(&mv_class_cdf);
+    let mag = if class == 0 {
+      let class0_bit = r.read_bit(mv_class0_bit_cdf[0]);
+      let fr = r.read_symbol(&mv_class0_fr_cdf[class0_bit]);
+      ((class0_bit << 3) | (fr << 1) | 1) + 1
+    } else {
+      let mut d = 0;
+      for i in 0..class {
+        d |= r.read_bit(mv_bits_cdf[i][0]) << i;
+      }
+      let fr = r.read_symbol(&mv_fr_cdf);
+      ((CLASS0_SIZE as usize) << (class + 2)) + ((d << 3) | (fr << 1) | 1) + 1
+    };
+    return if sign { -(mag as i32) } else { mag as i32 };
+  }
+
+  #[test]
+  fn mv_round_trip() {
+    // Every codable difference along each axis, plus a selection of diagonal ones
+    let ref_mv: [i16; 2] = [-6, 10];
+    let mut diffs = Vec::new();
+    for value in (-MAX_MV_DIFF ..= MAX_MV_DIFF).step_by(2) {
+      diffs.push([value, 0]);
+      diffs.push([0, value]);
+      diffs.push([value, -value]);
+    }
+
+    let mut w = EntropyWriter::new();
+    for diff in &diffs {
+      let mv = [ref_mv[0] + diff[0] as i16, ref_mv[1] + diff[1] as i16];
+      assert!(can_code_mv(mv, ref_mv));
+      write_mv(&mut w, mv, ref_mv);
+    }
+    let data = w.finalize();
+
+    let mut r = EntropyReader::new(&data);
+    for diff in &diffs {
+      assert_eq!(read_mv_diff(&mut r), *diff);
+    }
+  }
+}
@@ -0,0 +1,348 @@
+// Copyright (c) 2024-2025, The tinyavif contributors. All rights reserved
+//
+// This source code is subject to the terms of the BSD 2 Clause License and
+// the Alliance for Open Media Patent License 1.0. If the BSD 2 Clause License
+// was not distributed with this source code in the LICENSE file, you can
+// obtain it at www.aomedia.org/license/software. If the Alliance for Open
+// Media Patent License 1.0 was not distributed with this source code in the
+// PATENTS file, you can obtain it at www.aomedia.org/license/patent.
+
+// Intra block copy (IntraBC)
+//
+// Screen content often contains exact repeats: the same glyph in many places,
+// or the same button or icon drawn several times. Intra block copy lets a block
+// be predicted by copying an earlier part of the current frame, like a motion
+// vector which points into the frame being decoded. The offset is called a
+// displacement vector (DV), and is always a whole number of luma pixels. For
+// chroma, the DV is halved, and half-pixel positions use bilinear filtering.
+//
+// To keep hardware decoders simple, a DV may only point at superblocks which
+// were decoded a while ago: at least 256 pixels behind in raster order, and
+// following a wavefront pattern between superblock rows. Enabling IntraBC also
+// disables all of the loop filters for the frame, as the copies have to be made
+// from the unfiltered reconstruction.
+//
+// To find candidate DVs, we hash every 8x8 block of the source image, and look
+// for earlier blocks with identical contents.
+
+use std::collections::HashMap;
+
+use crate::array2d::Array2D;
+use crate::cdf::*;
+use crate::entropycode::SymbolWriter;
+use crate::frame::Plane;
+use crate::inter::{CLASS0_SIZE, MV_BORDER, MV_OFFSET_BITS};
+use crate::util::*;
+
+// Minimum distance, in pixels, between the current block and the source of the copy
+pub const INTRABC_DELAY_PIXELS: i32 = 256;
+const INTRABC_DELAY_SB64: i32 = INTRABC_DELAY_PIXELS / 64;
+
+// Largest DV component, or difference between a DV and its prediction,
+// which can be represented in the bitstream, in pixels
+const MAX_DV: i32 = 2047;
+const MAX_DV_DIFF: i32 = (CLASS0_SIZE as i32) << MV_OFFSET_BITS;
+
+// Weights used when building the list of candidate DV predictions. Each neighbouring
+// block adds REF_MV_WEIGHT to its DV, and the nearest neighbours get an extra REF_CAT_LEVEL
+pub const REF_MV_WEIGHT: u32 = 4;
+pub const REF_CAT_LEVEL: u32 = 640;
+
+// Maximum number of hash matches to try for each block
+pub const INTRABC_MAX_CANDIDATES: usize = 4;
+
+// Maximum number of hash table entries to look at for each block. On flat or repetitive
+// content, a single hash can cover most of the image, so we have to give up at some point
+const INTRABC_MAX_ENTRIES_SCANNED: usize = 1024;
+
+// Size of the blocks which are hashed, which is the only block size we use
+const HASH_BLOCK_SIZE: usize = 8;
+
+// Table of the positions of every 8x8 block in the source image, indexed by a hash
+// of their contents. Blocks of a single flat colour are left out, as DC_PRED
+// handles those well, and they would otherwise swamp the table.
+pub struct BlockHashTable {
+  table: HashMap<u64, Vec<(u32, u32)>>,
+}
+
+impl BlockHashTable {
+  pub fn new(luma: &Plane) -> Self {
+    let pixels = luma.pixels();
+    let mut table: HashMap<u64, Vec<(u32, u32)>> = HashMap::new();
+
+    // Only hash blocks which lie entirely within the crop region. The decoder clamps
+    // any reads outside of that, so the padding pixels can't be copied
+    if luma.crop_height() >= HASH_BLOCK_SIZE && luma.crop_width() >= HASH_BLOCK_SIZE {
+      for y in 0 ..= luma.crop_height() - HASH_BLOCK_SIZE {
+        for x in 0 ..= luma.crop_width() - HASH_BLOCK_SIZE {
+          if let Some(hash) = block_hash(pixels, y, x) {
+            table.entry(hash).or_default().push((y as u32, x as u32));
+          }
+        }
+      }
+    }
+
+    Self {
+      table: table,
+    }
+  }
+
+  // Find up to INTRABC_MAX_CANDIDATES blocks whose contents exactly match the block at (y0, x0),
+  // and for which `is_usable(y, x)` returns true. `is_usable` should be much cheaper than
+  // comparing pixels, as it's checked first.
+  //
+  // The table lists positions in raster order, so we search backwards from (y0, x0).
+  // This looks at the nearest earlier blocks first, which are the most likely to be
+  // reachable by a valid DV, and the cheapest to code
+  pub fn find_matches<F: Fn(usize, usize) -> bool>(&self, pixels: &Array2D<u8>, y0: usize, x0: usize,
+                                                    is_usable: F) -> Vec<(usize, usize)> {
+    let Some(hash) = block_hash(pixels, y0, x0) else {
+      return Vec::new();
+    };
+    let Some(positions) = self.table.get(&hash) else {
+      return Vec::new();
+    };
+
+    // Check for hash collisions
+    let same_block = |y: usize, x: usize| {
+      (0..HASH_BLOCK_SIZE).all(|i| pixels[y + i][x .. x + HASH_BLOCK_SIZE] == pixels[y0 + i][x0 .. x0 + HASH_BLOCK_SIZE])
+    };
+
+    let end = positions.partition_point(|&(y, x)| (y as usize, x as usize) < (y0, x0));
+    let mut matches = Vec::new();
+    for &(y, x) in positions[..end].iter().rev().take(INTRABC_MAX_ENTRIES_SCANNED) {
+      let (y, x) = (y as usize, x as usize);
+      if is_usable(y, x) && same_block(y, x) {
+        matches.push((y, x));
+        if matches.len() == INTRABC_MAX_CANDIDATES {
+          break;
+        }
+      }
+    }
+    return matches;
+  }
+}
+
+// Hash an 8x8 block, or return None if the block is a single flat colour
+fn block_hash(pixels: &Array2D<u8>, y0: usize, x0: usize) -> Option<u64> {
+  // Each row of 8 pixels fits exactly into a u64
+  let row_value = |y: usize| u64::from_le_bytes(pixels[y][x0 .. x0 + HASH_BLOCK_SIZE].try_into().unwrap());
+
+  let first_row = row_value(y0);
+  let mut flat = first_row == (first_row & 0xff) * 0x0101_0101_0101_0101;
+  let mut hash = 0u64;
+  for y in y0 .. y0 + HASH_BLOCK_SIZE {
+    let row = row_value(y);
+    flat &= row == first_row;
+    hash = (hash.rotate_left(29) ^ row).wrapping_mul(0x9e37_79b9_7f4a_7c15);
+  }
+
+  if flat { None } else { Some(hash) }
+}
+
+// Check whether an 8x8 block at (mi_row, mi_col) is allowed to use a given DV,
+// in units of luma pixels. This corresponds to is_mv_valid() in the spec,
+// specialized to a single tile. `sb_size` is the superblock size in luma pixels.
+//
+// On top of the spec's requirements, we require the source block to lie within the
+// cropped image (of size `crop_height` x `crop_width`), because the decoder clamps
+// reads from outside that region rather than using the reconstructed padding pixels.
+pub fn is_dv_valid(dv: [i16; 2], mi_row: usize, mi_col: usize, mi_cols: usize,
+                   crop_height: usize, crop_width: usize, sb_size: usize) -> bool {
+  let (dv_row, dv_col) = (dv[0] as i32, dv[1] as i32);
+  if abs(dv_row) > MAX_DV || abs(dv_col) > MAX_DV {
+    return false;
+  }
+
+  let src_top = (mi_row * 4) as i32 + dv_row;
+  let src_left = (mi_col * 4) as i32 + dv_col;
+  let src_bottom = src_top + HASH_BLOCK_SIZE as i32;
+  let src_right = src_left + HASH_BLOCK_SIZE as i32;
+  if src_top < 0 || src_left < 0 || src_bottom > crop_height as i32 || src_right > crop_width as i32 {
+    return false;
+  }
+
+  // The source must be at least INTRABC_DELAY_SB64 64x64 units behind the current one
+  // in raster order, where rows are counted in superblocks...
+  let active_sb_row = (mi_row * 4 / sb_size) as i32;
+  let active_sb64_col = (mi_col * 4 / 64) as i32;
+  let src_sb_row = (src_bottom - 1) / sb_size as i32;
+  let src_sb64_col = (src_right - 1) / 64;
+  let total_sb64_per_row = ((mi_cols - 1) / 16 + 1) as i32;
+  let active_sb64 = active_sb_row * total_sb64_per_row + active_sb64_col;
+  let src_sb64 = src_sb_row * total_sb64_per_row + src_sb64_col;
+  if src_sb64 >= active_sb64 - INTRABC_DELAY_SB64 {
+    return false;
+  }
+
+  // ...and must also be within a wavefront, so that a decoder can process
+  // multiple superblock rows in parallel
+  let gradient = 1 + INTRABC_DELAY_SB64 + (sb_size == 128) as i32;
+  let wf_offset = gradient * (active_sb_row - src_sb_row);
+  if src_sb_row > active_sb_row || src_sb64_col >= active_sb64_col - INTRABC_DELAY_SB64 + wf_offset {
+    return false;
+  }
+
+  return true;
+}
+
+// Check whether the difference between a DV and its prediction is small enough to be coded
+pub fn can_code_dv(dv: [i16; 2], ref_dv: [i16; 2]) -> bool {
+  (0..2).all(|comp| abs(dv[comp] as i32 - ref_dv[comp] as i32) <= MAX_DV_DIFF)
+}
+
+// Clamp a predicted DV so that it doesn't point too far outside the frame
+// This corresponds to clamp_mv_row() and clamp_mv_col() in the spec
+pub fn clamp_ref_dv(dv: [i16; 2], mi_row: usize, mi_col: usize, mi_rows: usize, mi_cols: usize) -> [i16; 2] {
+  let border = MV_BORDER + HASH_BLOCK_SIZE as i32;
+  let clamp_comp = |value: i16, mi_pos: usize, mi_size: usize| {
+    let to_start_edge = -((mi_pos * 4) as i32);
+    let to_end_edge = ((mi_size - mi_pos) * 4) as i32 - HASH_BLOCK_SIZE as i32;
+    clamp(value as i32, to_start_edge - border, to_end_edge + border) as i16
+  };
+  [clamp_comp(dv[0], mi_row, mi_rows), clamp_comp(dv[1], mi_col, mi_cols)]
+}
+
+// DV prediction to use when no nearby blocks use IntraBC: point one superblock up,
+// or, in the first superblock row, as far left as the delay constraint requires
+pub fn default_ref_dv(mi_row: usize, sb_size: usize) -> [i16; 2] {
+  if mi_row * 4 < sb_size {
+    [0, -(sb_size as i32 + INTRABC_DELAY_PIXELS) as i16]
+  } else {
+    [-(sb_size as i16), 0]
+  }
+}
+
+// Generate the prediction for one plane of a block, by copying from the
+// already-reconstructed pixels at the given DV. `subsampling` is 1 for chroma
+// and 0 for luma.
+pub fn intrabc_predict(pixels: &mut Array2D<u8>, dv: [i16; 2], y0: usize, x0: usize, h: usize, w: usize,
+                       subsampling: usize) {
+  // The source position, in units of (1 >> subsampling) pixels. For chroma, this
+  // may land on a half-pixel position, in which case we average the two (or four)
+  // surrounding pixels. This matches the bilinear filter used by the decoder
+  let frac_mask = (1 << subsampling) - 1;
+  let pred = Array2D::new_with(h, w, |i, j| {
+    let pos_y = ((((y0 + i) << subsampling) as i32) + dv[0] as i32) as usize;
+    let pos_x = ((((x0 + j) << subsampling) as i32) + dv[1] as i32) as usize;
+    let (src_y, frac_y) = (pos_y >> subsampling, pos_y & frac_mask);
+    let (src_x, frac_x) = (pos_x >> subsampling, pos_x & frac_mask);
+
+    let mut sum = 0u32;
+    for y in src_y ..= src_y + frac_y {
+      for x in src_x ..= src_x + frac_x {
+        sum += pixels[y][x] as u32;
+      }
+    }
+    round2(sum, (frac_y + frac_x) as u32) as u8
+  });
+  pixels.paste_region(y0, x0, &pred);
+}
+
+// Code a DV, relative to the predicted DV
+// Both are in units of luma pixels, and stored as (row, col)
+pub fn write_dv<W: SymbolWriter>(w: &mut W, dv: [i16; 2], ref_dv: [i16; 2]) {
+  let diff = [dv[0] as i32 - ref_dv[0] as i32, dv[1] as i32 - ref_dv[1] as i32];
+
+  // The "joint" symbol says which of the two components are nonzero:
+  // 0 = neither, 1 = column only, 2 = row only, 3 = both
+  let joint = ((diff[0] != 0) as usize) << 1 | ((diff[1] != 0) as usize);
+  w.write_symbol(joint, &mv_joint_cdf);
+
+  for comp in 0..2 {
+    if diff[comp] != 0 {
+      write_dv_component(w, diff[comp]);
+    }
+  }
+}
+
+// Code one nonzero component of a DV difference
+// As IntraBC always uses whole-pixel DVs, the fractional parts which
+// would be coded for a motion vector are skipped
+fn write_dv_component<W: SymbolWriter>(w: &mut W, value: i32) {
+  assert!(value != 0 && abs(value) <= MAX_DV_DIFF);
+  w.write_bool(value < 0, mv_sign_cdf[0]);
+
+  // The magnitude is split into a class plus an offset within that class:
+  // Class 0 => |value| = 1-2
+  // Class 1 => |value| = 3-4
+  // Class 2 => |value| = 5-8
+  // ...
+  let offset = unsigned_abs(value) - 1;
+  if offset < CLASS0_SIZE {
+    w.write_symbol(0, &mv_class_cdf);
+    w.write_bool(offset == 1, mv_class0_bit_cdf[0]);
+  } else {
+    let class = floor_log2(offset);
+    w.write_symbol(class as usize, &mv_class_cdf);
+    // The remaining bits are coded from low to high, each with its own CDF
+    let bits = offset - (1 << class);
+    for i in 0 .. class as usize {
+      w.write_bit(((bits >> i) & 1) as usize, mv_bits_cdf[i][0]);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::entropycode::{EntropyReader, EntropyWriter};
+
+  // Read a DV difference back, following read_mv() in the spec for IntraBC blocks.
+  // The fractional parts are implicitly 3 and 1 (ie. 7/8 pixel), which combine with
+  // the +1 in the magnitude to give a whole number of pixels
+  fn read_dv_diff(r: &mut EntropyReader) -> [i32; 2] {
+    let joint = r.read_symbol(&mv_joint_cdf);
+    let mut diff = [0; 2];
+    if joint & 2 != 0 {
+      diff[0] = read_dv_component(r);
+    }
+    if joint & 1 != 0 {
+      diff[1] = read_dv_component(r);
+    }
+    return diff;
+  }
+
+  fn read_dv_component(r: &mut EntropyReader) -> i32 {
+    let sign = r.read_bool(mv_sign_cdf[0]);
+    let class = r.read_symbol(&mv_class_cdf);
+    let mag = if class == 0 {
+      let class0_bit = r.read_bit(mv_class0_bit_cdf[0]);
+      ((class0_bit << 3) | (3 << 1) | 1) + 1
+    } else {
+      let mut d = 0;
+      for i in 0..class {
+        d |= r.read_bit(mv_bits_cdf[i][0]) << i;
+      }
+      ((CLASS0_SIZE as usize) << (class + 2)) + ((d << 3) | (3 << 1) | 1) + 1
+    };
+    assert!(mag % 8 == 0);
+    let mag = (mag / 8) as i32;
+    return if sign { -mag } else { mag };
+  }
+
+  #[test]
+  fn dv_round_trip() {
+    // Every codable difference along each axis, plus a selection of diagonal ones
+    let ref_dv: [i16; 2] = [-64, 0];
+    let mut diffs = Vec::new();
+    for value in -MAX_DV_DIFF ..= MAX_DV_DIFF {
+      diffs.push([value, 0]);
+      diffs.push([0, value]);
+      diffs.push([value, -value]);
+    }
+
+    let mut w = EntropyWriter::new();
+    for diff in &diffs {
+      let dv = [ref_dv[0] + diff[0] as i16, ref_dv[1] + diff[1] as i16];
+      assert!(can_code_dv(dv, ref_dv));
+      write_dv(&mut w, dv, ref_dv);
+    }
+    let data = w.finalize();
+
+    let mut r = EntropyReader::new(&data);
+    for diff in &diffs {
+      assert_eq!(read_dv_diff(&mut r), *diff);
+    }
+  }
+}
@@ -0,0 +1,316 @@
+// Copyright (c) 2024-2025, The tinyavif contributors. All rights reserved
+//
+// This source code is subject to the terms of the BSD 2 Clause License and
+// the Alliance for Open Media Patent License 1.0. If the BSD 2 Clause License
+// was not distributed with this source code in the LICENSE file, you can
+// obtain it at www.aomedia.org/license/software. If the Alliance for Open
+// Media Patent License 1.0 was not distributed with this source code in the
+// PATENTS file, you can obtain it at www.aomedia.org/license/patent.
+
+// Levels and tiers
+//
+// Each AV1 sequence header declares a level, which promises that the stream stays within
+// certain limits (picture size, tile count, compressed frame size, ...), so that decoders
+// can tell up front whether they are able to play it. Level X.Y is coded as
+// seq_level_idx = (X - 2) * 4 + Y, and the special value 31 means that no limits apply.
+//
+// Levels 4.0 and up also come in two tiers. The High tier allows a lower compression ratio,
+// ie. larger frames, but otherwise has the same limits as the Main tier.
+//
+// The limits here are from Annex A of the AV1 spec. Many of them are rates (samples per
+// second, headers per second, ...), which only apply to image sequences.
+
+use std::fmt;
+
+// seq_level_idx value which means "no level-based constraints"
+pub const SEQ_LEVEL_MAX: u8 = 31;
+
+// Uncompressed frame size is (width * height * PIC_SIZE_PROFILE_FACTOR) / 8 bytes.
+// This is the value for profile 0, which is all we support
+const PIC_SIZE_PROFILE_FACTOR: usize = 15;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Tier {
+  Main = 0,
+  High = 1,
+}
+
+struct LevelLimits {
+  seq_level_idx: u8,
+  max_pic_size: usize,
+  max_h_size: usize,
+  max_v_size: usize,
+  // Luma samples per second
+  max_display_rate: u64,
+  max_decode_rate: u64,
+  // Frame headers per second
+  max_header_rate: u64,
+  // Maximum bitrate for each tier, in megabits per second
+  main_mbps: f64,
+  high_mbps: Option<f64>,
+  max_tiles: usize,
+  max_tile_cols: usize,
+  // Minimum compression ratio for each tier. The High tier is only allowed if high_mbps is present
+  main_cr: f64,
+  high_cr: f64,
+}
+
+// The levels defined by the spec, in increasing order. Other seq_level_idx values
+// (eg. 2.2 and 2.3) are reserved
+const LEVELS: [LevelLimits; 14] = [
+  LevelLimits { seq_level_idx: 0, max_pic_size: 147456, max_h_size: 2048, max_v_size: 1152,
+                max_display_rate: 4423680, max_decode_rate: 5529600, max_header_rate: 150,
+                main_mbps: 1.5, high_mbps: None, max_tiles: 8, max_tile_cols: 4, main_cr: 2.0, high_cr: 2.0 },
+  LevelLimits { seq_level_idx: 1, max_pic_size: 278784, max_h_size: 2816, max_v_size: 1584,
+                max_display_rate: 8363520, max_decode_rate: 10454400, max_header_rate: 150,
+                main_mbps: 3.0, high_mbps: None, max_tiles: 8, max_tile_cols: 4, main_cr: 2.0, high_cr: 2.0 },
+  LevelLimits { seq_level_idx: 4, max_pic_size: 665856, max_h_size: 4352, max_v_size: 2448,
+                max_display_rate: 19975680, max_decode_rate: 24969600, max_header_rate: 150,
+                main_mbps: 6.0, high_mbps: None, max_tiles: 16, max_tile_cols: 6, main_cr: 2.0, high_cr: 2.0 },
+  LevelLimits { seq_level_idx: 5, max_pic_size: 1065024, max_h_size: 5504, max_v_size: 3096,
+                max_display_rate: 31950720, max_decode_rate: 39938400, max_header_rate: 150,
+                main_mbps: 10.0, high_mbps: None, max_tiles: 16, max_tile_cols: 6, main_cr: 2.0, high_cr: 2.0 },
+  LevelLimits { seq_level_idx: 8, max_pic_size: 2359296, max_h_size: 6144, max_v_size: 3456,
+                max_display_rate: 70778880, max_decode_rate: 77856768, max_header_rate: 300,
+                main_mbps: 12.0, high_mbps: Some(30.0), max_tiles: 32, max_tile_cols: 8, main_cr: 4.0, high_cr: 4.0 },
+  LevelLimits { seq_level_idx: 9, max_pic_size: 2359296, max_h_size: 6144, max_v_size: 3456,
+                max_display_rate: 141557760, max_decode_rate: 155713536, max_header_rate: 300,
+                main_mbps: 20.0, high_mbps: Some(50.0), max_tiles: 32, max_tile_cols: 8, main_cr: 4.0, high_cr: 4.0 },
+  LevelLimits { seq_level_idx: 12, max_pic_size: 8912896, max_h_size: 8192, max_v_size: 4352,
+                max_display_rate: 267386880, max_decode_rate: 273715200, max_header_rate: 300,
+                main_mbps: 30.0, high_mbps: Some(100.0), max_tiles: 64, max_tile_cols: 8, main_cr: 6.0, high_cr: 4.0 },
+  LevelLimits { seq_level_idx: 13, max_pic_size: 8912896, max_h_size: 8192, max_v_size: 4352,
+                max_display_rate: 534773760, max_decode_rate: 547430400, max_header_rate: 300,
+                main_mbps: 40.0, high_mbps: Some(160.0), max_tiles: 64, max_tile_cols: 8, main_cr: 8.0, high_cr: 4.0 },
+  LevelLimits { seq_level_idx: 14, max_pic_size: 8912896, max_h_size: 8192, max_v_size: 4352,
+                max_display_rate: 1069547520, max_decode_rate: 1094860800, max_header_rate: 300,
+                main_mbps: 60.0, high_mbps: Some(240.0), max_tiles: 64, max_tile_cols: 8, main_cr: 8.0, high_cr: 4.0 },
+  LevelLimits { seq_level_idx: 15, max_pic_size: 8912896, max_h_size: 8192, max_v_size: 4352,
+                max_display_rate: 1069547520, max_decode_rate: 1176502272, max_header_rate: 300,
+                main_mbps: 60.0, high_mbps: Some(240.0), max_tiles: 64, max_tile_cols: 8, main_cr: 8.0, high_cr: 4.0 },
+  LevelLimits { seq_level_idx: 16, max_pic_size: 35651584, max_h_size: 16384, max_v_size: 8704,
+                max_display_rate: 1069547520, max_decode_rate: 1176502272, max_header_rate: 300,
+                main_mbps: 60.0, high_mbps: Some(240.0), max_tiles: 128, max_tile_cols: 16, main_cr: 8.0, high_cr: 4.0 },
+  LevelLimits { seq_level_idx: 17, max_pic_size: 35651584, max_h_size: 16384, max_v_size: 8704,
+                max_display_rate: 2139095040, max_decode_rate: 2189721600, max_header_rate: 300,
+                main_mbps: 100.0, high_mbps: Some(480.0), max_tiles: 128, max_tile_cols: 16, main_cr: 8.0, high_cr: 4.0 },
+  LevelLimits { seq_level_idx: 18, max_pic_size: 35651584, max_h_size: 16384, max_v_size: 8704,
+                max_display_rate: 4278190080, max_decode_rate: 4379443200, max_header_rate: 300,
+                main_mbps: 160.0, high_mbps: Some(800.0), max_tiles: 128, max_tile_cols: 16, main_cr: 8.0, high_cr: 4.0 },
+  LevelLimits { seq_level_idx: 19, max_pic_size: 35651584, max_h_size: 16384, max_v_size: 8704,
+                max_display_rate: 4278190080, max_decode_rate: 4706009088, max_header_rate: 300,
+                main_mbps: 160.0, high_mbps: Some(800.0), max_tiles: 128, max_tile_cols: 16, main_cr: 8.0, high_cr: 4.0 },
+];
+
+// The properties of an encoded image which are constrained by the level
+pub struct LevelStats {
+  // Upscaled width and height of the frame
+  pub width: usize,
+  pub height: usize,
+  pub num_tiles: usize,
+  pub tile_cols: usize,
+  // Size of the largest frame's header and tile data, in bytes
+  pub compressed_size: usize,
+  // Frames per second and average bits per second, or None for a still image
+  pub frame_rate: Option<f64>,
+  pub bitrate: Option<f64>,
+}
+
+// Display a seq_level_idx in the usual "X.Y" form
+pub struct LevelName(pub u8);
+
+impl fmt::Display for LevelName {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    if self.0 == SEQ_LEVEL_MAX {
+      write!(f, "unconstrained")
+    } else {
+      write!(f, "{}.{}", 2 + (self.0 >> 2), self.0 & 3)
+    }
+  }
+}
+
+// Parse a level given as "X.Y", or "unconstrained" for seq_level_idx 31
+pub fn parse_level(name: &str) -> Result<u8, String> {
+  if name == "unconstrained" {
+    return Ok(SEQ_LEVEL_MAX);
+  }
+  let invalid = || format!("Invalid level \"{}\": must be \"auto\", \"unconstrained\", or a level such as \"5.1\"", name);
+  let (major, minor) = name.split_once('.').ok_or_else(invalid)?;
+  let major: u8 = major.parse().map_err(|_| invalid())?;
+  let minor: u8 = minor.parse().map_err(|_| invalid())?;
+  if major < 2 || minor > 3 {
+    return Err(invalid());
+  }
+  let seq_level_idx = (major - 2) * 4 + minor;
+  if !LEVELS.iter().any(|level| level.seq_level_idx == seq_level_idx) {
+    return Err(format!("Level {} is not defined by the AV1 spec", name));
+  }
+  return Ok(seq_level_idx);
+}
+
+// Check whether the given image conforms to the given level and tier.
+// On failure, the error message explains which limit was exceeded
+pub fn check_level(seq_level_idx: u8, tier: Tier, stats: &LevelStats) -> Result<(), String> {
+  if seq_level_idx == SEQ_LEVEL_MAX {
+    return Ok(());
+  }
+  let name = LevelName(seq_level_idx);
+  let level = LEVELS.iter().find(|level| level.seq_level_idx == seq_level_idx).unwrap();
+
+  let (max_mbps, min_cr) = match tier {
+    Tier::Main => (level.main_mbps, level.main_cr),
+    Tier::High => {
+      let high_mbps = level.high_mbps.ok_or_else(|| format!("Level {} does not have a High tier", name))?;
+      (high_mbps, level.high_cr)
+    },
+  };
+
+  if stats.width > level.max_h_size || stats.height > level.max_v_size {
+    return Err(format!("Image size {}x{} exceeds the maximum of {}x{} for level {}",
+                       stats.width, stats.height, level.max_h_size, level.max_v_size, name));
+  }
+  if stats.width * stats.height > level.max_pic_size {
+    return Err(format!("Image area {} exceeds the maximum of {} pixels for level {}",
+                       stats.width * stats.height, level.max_pic_size, name));
+  }
+  if stats.num_tiles > level.max_tiles || stats.tile_cols > level.max_tile_cols {
+    return Err(format!("{} tiles ({} columns) exceeds the maximum of {} tiles ({} columns) for level {}",
+                       stats.num_tiles, stats.tile_cols, level.max_tiles, level.max_tile_cols, name));
+  }
+
+  if let Some(frame_rate) = stats.frame_rate {
+    // Every frame is shown exactly once, so the display and decode rates are the same
+    let sample_rate = (stats.width * stats.height) as f64 * frame_rate;
+    if sample_rate > level.max_display_rate as f64 || sample_rate > level.max_decode_rate as f64 {
+      return Err(format!("{:.0} luma samples per second exceeds the maximum of {} for level {}",
+                         sample_rate, level.max_display_rate.min(level.max_decode_rate), name));
+    }
+    if frame_rate > level.max_header_rate as f64 {
+      return Err(format!("{} frames per second exceeds the maximum of {} for level {}",
+                         frame_rate, level.max_header_rate, name));
+    }
+  }
+  if let Some(bitrate) = stats.bitrate {
+    if bitrate > max_mbps * 1_000_000.0 {
+      return Err(format!("Bitrate {:.3} Mbps exceeds the maximum of {} Mbps for level {} ({} tier)",
+                         bitrate / 1_000_000.0, max_mbps, name,
+                         if tier == Tier::Main { "Main" } else { "High" }));
+    }
+  }
+
+  // Each compressed frame must be at least min_cr times smaller than the uncompressed frame.
+  // The spec scales min_cr down when the display rate is below the level's maximum, but that
+  // isn't meaningful for a still image, so we always use the strictest form of the limit
+  let uncompressed_size = (stats.width * stats.height * PIC_SIZE_PROFILE_FACTOR) >> 3;
+  let max_compressed_size = (uncompressed_size as f64 / min_cr.max(1.0)) as usize;
+  if stats.compressed_size > max_compressed_size {
+    return Err(format!("Compressed size {} bytes exceeds the maximum of {} bytes for level {} ({} tier)",
+                       stats.compressed_size, max_compressed_size, name,
+                       if tier == Tier::Main { "Main" } else { "High" }));
+  }
+
+  return Ok(());
+}
+
+// Pick the lowest level, and then the lowest tier within that level, which the image conforms to.
+// If none of the defined levels fit, fall back to seq_level_idx 31
+pub fn choose_level(stats: &LevelStats, allow_high_tier: bool) -> (u8, Tier) {
+  for level in LEVELS.iter() {
+    if check_level(level.seq_level_idx, Tier::Main, stats).is_ok() {
+      return (level.seq_level_idx, Tier::Main);
+    }
+    if allow_high_tier && level.high_mbps.is_some() && check_level(level.seq_level_idx, Tier::High, stats).is_ok() {
+      return (level.seq_level_idx, Tier::High);
+    }
+  }
+  return (SEQ_LEVEL_MAX, Tier::Main);
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn still_image(width: usize, height: usize, compressed_size: usize) -> LevelStats {
+    LevelStats {
+      width: width,
+      height: height,
+      num_tiles: 1,
+      tile_cols: 1,
+      compressed_size: compressed_size,
+      frame_rate: None,
+      bitrate: None,
+    }
+  }
+
+  // Frames are kept small enough to meet the compression ratio limits of every level
+  fn sequence(width: usize, height: usize, frame_rate: f64, bitrate: f64) -> LevelStats {
+    LevelStats {
+      frame_rate: Some(frame_rate),
+      bitrate: Some(bitrate),
+      ..still_image(width, height, 100)
+    }
+  }
+
+  #[test]
+  fn picture_size_limits() {
+    // Level 2.0 allows 147456 pixels, with a maximum width of 2048
+    assert_eq!(choose_level(&still_image(2048, 72, 1000), false), (0, Tier::Main));
+    assert_eq!(choose_level(&still_image(2048, 73, 1000), false), (1, Tier::Main));
+    assert_eq!(choose_level(&still_image(2049, 8, 1000), false), (1, Tier::Main));
+    // Level 2.1 allows 278784 = 528 * 528 pixels
+    assert_eq!(choose_level(&still_image(528, 528, 1000), false), (1, Tier::Main));
+    assert_eq!(choose_level(&still_image(529, 528, 1000), false), (4, Tier::Main));
+    // Beyond level 6.3, nothing fits
+    assert_eq!(choose_level(&still_image(16385, 16, 1000), false), (SEQ_LEVEL_MAX, Tier::Main));
+    assert!(check_level(SEQ_LEVEL_MAX, Tier::Main, &still_image(16385, 16, 1000)).is_ok());
+
+    // Too many tiles for level 2.0
+    let stats = LevelStats { num_tiles: 9, tile_cols: 3, ..still_image(64, 64, 1000) };
+    assert!(check_level(0, Tier::Main, &stats).is_err());
+    assert_eq!(choose_level(&stats, false), (4, Tier::Main));
+  }
+
+  #[test]
+  fn compression_ratio_limit() {
+    // The uncompressed size of a 2048x72 image is 147456 * 15 / 8 = 276480 bytes,
+    // and level 2.0 requires a compression ratio of at least 2.
+    // Higher levels don't relax this, so a larger frame can't use any level
+    assert!(check_level(0, Tier::Main, &still_image(2048, 72, 138240)).is_ok());
+    assert!(check_level(0, Tier::Main, &still_image(2048, 72, 138241)).is_err());
+    assert_eq!(choose_level(&still_image(2048, 72, 138241), true), (SEQ_LEVEL_MAX, Tier::Main));
+  }
+
+  #[test]
+  fn rate_limits() {
+    // Level 2.0 allows 4423680 luma samples per second, which is 2048x72 at 30 fps
+    assert_eq!(choose_level(&sequence(2048, 72, 30.0, 1e6), false), (0, Tier::Main));
+    assert_eq!(choose_level(&sequence(2048, 72, 30.1, 1e6), false), (1, Tier::Main));
+    // Levels below 4.0 allow 150 frame headers per second
+    assert_eq!(choose_level(&sequence(16, 16, 150.0, 1e6), false), (0, Tier::Main));
+    assert_eq!(choose_level(&sequence(16, 16, 151.0, 1e6), false), (8, Tier::Main));
+
+    // Level 4.0 allows 12 Mbps in the Main tier, and 30 Mbps in the High tier.
+    // Level 4.1 allows 20 Mbps in the Main tier, and 50 Mbps in the High tier.
+    // Level 5.1 is the first to allow more than 30 Mbps in the Main tier
+    assert_eq!(choose_level(&sequence(640, 360, 30.0, 12e6), true), (8, Tier::Main));
+    assert_eq!(choose_level(&sequence(640, 360, 30.0, 12.5e6), true), (8, Tier::High));
+    assert_eq!(choose_level(&sequence(640, 360, 30.0, 12.5e6), false), (9, Tier::Main));
+    assert_eq!(choose_level(&sequence(640, 360, 30.0, 31e6), true), (9, Tier::High));
+    assert_eq!(choose_level(&sequence(640, 360, 30.0, 31e6), false), (13, Tier::Main));
+
+    // Levels below 4.0 have no High tier
+    assert!(check_level(5, Tier::High, &sequence(16, 16, 1.0, 1e3)).is_err());
+  }
+
+  #[test]
+  fn level_names() {
+    assert_eq!(parse_level("2.0"), Ok(0));
+    assert_eq!(parse_level("5.1"), Ok(13));
+    assert_eq!(parse_level("unconstrained"), Ok(SEQ_LEVEL_MAX));
+    assert!(parse_level("2.2").is_err());
+    assert!(parse_level("7.0").is_err());
+    assert!(parse_level("5").is_err());
+    assert_eq!(LevelName(13).to_string(), "5.1");
+    assert_eq!(LevelName(SEQ_LEVEL_MAX).to_string(), "unconstrained");
+  }
+}
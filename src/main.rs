@@ -17,6 +17,13 @@
 #![allow(non_snake_case)]
 #![allow(non_upper_case_globals)]
 
+// Similarly, functions use explicit returns, struct fields are initialized as `name: name`,
+// and loops index arrays by position, to stay close to how the AV1 spec is written
+#![allow(clippy::needless_return)]
+#![allow(clippy::redundant_field_names)]
+#![allow(clippy::needless_range_loop)]
+
+mod aq;
 mod array2d;
 mod av1_encoder;
 mod bitcode;
@@ -24,10 +31,22 @@ mod cdf;
 mod consts;
 mod entropycode;
 mod enums;
+mod film_grain;
 mod frame;
+mod grain_table;
 mod hls;
+mod inter;
+mod intrabc;
 mod isobmff;
+mod level;
+mod palette;
+mod rate;
+mod rdo;
 mod recon;
+mod roi;
+mod segmentation;
+mod sequence_header;
+mod superres;
 mod txfm;
 mod util;
 mod y4m;
@@ -37,8 +56,21 @@ use std::fs::File;
 use std::path::PathBuf;
 use std::process::exit;
 
-use crate::av1_encoder::AV1Encoder;
+use crate::aq::*;
+use crate::av1_encoder::*;
+use crate::film_grain::{estimate_film_grain, GRAIN_SEED_INCREMENT};
+use crate::enums::FrameType;
+use crate::frame::Frame;
+use crate::grain_table::{grain_table_timestamp, read_film_grain_table};
 use crate::hls::*;
+use crate::level::*;
+use crate::palette::is_screen_content;
+use crate::array2d::Array2D;
+use crate::recon::{default_qm_level, QuantizerMode};
+use crate::roi::read_roi_map;
+use crate::segmentation::Segmentation;
+use crate::sequence_header::{SequenceHeaderConfig, TimingInfo};
+use crate::superres::*;
 use crate::y4m::Y4MReader;
 
 use clap::Parser;
@@ -48,9 +80,12 @@ use clap::Parser;
 struct CommandlineArgs {
   /// Input file, must end in .y4m
   input: PathBuf,
-  /// Output file, must end in .obu or .avif [default: <input>.avif]
+  /// Output file, must end in .obu, .ivf or .avif unless --format is given [default: <input>.avif]
   #[arg(short, long)]
   output: Option<PathBuf>,
+  /// Output format. `auto` picks the format from the output file extension
+  #[arg(long, default_value = "auto", value_parser = ["auto", "obu", "annexb", "ivf", "avif"])]
+  format: String,
   /// Quantizer to use. Valid range is 1-255, inclusive
   #[arg(short, long, default_value_t = 35)]
   qindex: u8,
@@ -63,6 +98,95 @@ struct CommandlineArgs {
   /// Matrix coefficients
   #[arg(long, default_value_t = 2)]
   matrix_coefficients: u16,
+  /// Multiplier for the rate-distortion lambda (for tuning experiments)
+  #[arg(long, default_value_t = 1.0)]
+  lambda_scale: f64,
+  /// Disable rate-distortion optimized quantization
+  #[arg(long)]
+  no_trellis: bool,
+  /// Rounding mode for the initial quantization step
+  #[arg(long, default_value = "nearest", value_parser = ["nearest", "deadzone", "adaptive"])]
+  quantizer: String,
+  /// Enable quantizer matrices, with levels picked based on the qindex
+  #[arg(long)]
+  qm: bool,
+  /// Quantizer matrix level for luma (0-15). Enables quantizer matrices
+  #[arg(long)]
+  qm_y: Option<u8>,
+  /// Quantizer matrix level for chroma (0-15). Enables quantizer matrices
+  #[arg(long)]
+  qm_uv: Option<u8>,
+  /// Quantizer delta for luma DC coefficients (-64 to 63)
+  #[arg(long, default_value_t = 0, allow_negative_numbers = true)]
+  delta_q_y_dc: i8,
+  /// Quantizer delta for U DC coefficients (-64 to 63)
+  #[arg(long, default_value_t = 0, allow_negative_numbers = true)]
+  delta_q_u_dc: i8,
+  /// Quantizer delta for U AC coefficients (-64 to 63)
+  #[arg(long, default_value_t = 0, allow_negative_numbers = true)]
+  delta_q_u_ac: i8,
+  /// Quantizer delta for V DC coefficients (-64 to 63)
+  #[arg(long, default_value_t = 0, allow_negative_numbers = true)]
+  delta_q_v_dc: i8,
+  /// Quantizer delta for V AC coefficients (-64 to 63)
+  #[arg(long, default_value_t = 0, allow_negative_numbers = true)]
+  delta_q_v_ac: i8,
+  /// Automatically pick chroma quantizer deltas, lowering chroma q for saturated images
+  #[arg(long, conflicts_with_all = ["delta_q_u_dc", "delta_q_u_ac", "delta_q_v_dc", "delta_q_v_ac"])]
+  auto_chroma_q: bool,
+  /// Adaptive quantization mode
+  #[arg(long, default_value = "none", value_parser = ["none", "variance", "segment"])]
+  aq_mode: String,
+  /// Strength of adaptive quantization
+  #[arg(long, default_value_t = 1.0)]
+  aq_strength: f64,
+  /// Region-of-interest map: a grayscale .y4m or .pgm image, or a list of rectangles
+  #[arg(long)]
+  roi: Option<PathBuf>,
+  /// Whether to enable screen content tools (palette mode and intra block copy)
+  #[arg(long, default_value = "auto", value_parser = ["auto", "on", "off"])]
+  screen_content: String,
+  /// Disable intra block copy, even if screen content tools are enabled
+  #[arg(long)]
+  no_intrabc: bool,
+  /// Superblock size
+  #[arg(long, default_value = "auto", value_parser = ["auto", "64", "128"])]
+  superblock_size: String,
+  /// Horizontal super-resolution: code the image at 8/N of its width and upscale it
+  /// afterwards, for N = 9..16. `auto` picks N by rate-distortion cost at high qindex values
+  #[arg(long, default_value = "off",
+        value_parser = ["off", "auto", "9", "10", "11", "12", "13", "14", "15", "16"])]
+  superres: String,
+  /// Film grain synthesis: `auto` denoises the image before encoding, and signals
+  /// parameters for the decoder to add back similar-looking grain
+  #[arg(long, default_value = "off", value_parser = ["off", "auto"])]
+  film_grain: String,
+  /// Film grain table file, in aomenc's format, giving explicit film grain parameters
+  #[arg(long)]
+  film_grain_table: Option<PathBuf>,
+  /// AV1 level to signal, such as `5.1`. `auto` picks the lowest level the image conforms to,
+  /// and `unconstrained` signals that no level limits apply
+  #[arg(long, default_value = "auto")]
+  level: String,
+  /// Sequence header form: `reduced` is the shortest, but only describes a single still
+  /// image. `full` signals everything explicitly, as some tools require in .obu files.
+  /// `auto` uses `reduced` for still images and `full` for sequences
+  #[arg(long, default_value = "auto", value_parser = ["auto", "reduced", "full"])]
+  sequence_header: String,
+  /// For image sequences, code every Nth frame as a key frame, and the rest as inter frames
+  /// predicted from the frame before them. 0 means only the first frame is a key frame
+  #[arg(long, default_value_t = 0)]
+  keyframe_interval: usize,
+  /// Alpha channel: a .y4m file whose luma plane gives the opacity of each pixel.
+  /// Only supported for still images in .avif files
+  #[arg(long)]
+  alpha: Option<PathBuf>,
+  /// Quantizer to use for the alpha channel [default: the same as --qindex]
+  #[arg(long, requires = "alpha")]
+  alpha_qindex: Option<u8>,
+  /// Signal that the input's colour channels have already been multiplied by the alpha channel
+  #[arg(long, requires = "alpha")]
+  premultiplied_alpha: bool,
 }
 
 fn main() {
@@ -88,25 +212,30 @@ fn main() {
     input_path.with_extension("avif")
   });
 
-  let output_ext = match output_path.extension() {
-    None => {
-      println!("Error: Output file must end in .obu or .avif");
-      exit(2);
-    },
-    Some(ext_osstr) => {
-      let ext = ext_osstr.to_str().unwrap();
-      if ext != "obu" && ext != "avif" {
-        println!("Error: Output file must end in .obu or .avif");
-        exit(2);
+  let output_format = match args.format.as_str() {
+    "auto" => {
+      let ext = output_path.extension().and_then(|ext_osstr| ext_osstr.to_str());
+      match ext {
+        Some("obu") => "obu",
+        Some("ivf") => "ivf",
+        Some("avif") => "avif",
+        _ => {
+          println!("Error: Output file must end in .obu, .ivf or .avif, or the format must be set with --format");
+          exit(2);
+        }
       }
-      ext
-    }
+    },
+    format => format,
   };
 
   let base_qindex = args.qindex;
 
   let mut y4m = Y4MReader::new(File::open(input_path).unwrap()).unwrap();
   let source = y4m.read_frame().unwrap();
+  // Read one frame ahead, so that we know whether we're encoding a still image or a sequence
+  let mut next_source = y4m.read_next_frame().unwrap();
+  let is_sequence = next_source.is_some();
+  let frame_rate = y4m.frame_rate();
 
   // Check that the image will fit in one tile
   let crop_width = source.y().crop_width();
@@ -120,24 +249,423 @@ fn main() {
     exit(2);
   }
 
-  // Generate AV1 data
-  let encoder = AV1Encoder::new(crop_width, crop_height);
-  let sequence_header = encoder.generate_sequence_header();
-  let frame_header = encoder.generate_frame_header(base_qindex, false);
-  let tile_data = encoder.encode_image(&source, base_qindex);
+  // The alpha channel is coded as a separate monochrome image, which must be the same
+  // size as the main image
+  let alpha_source = args.alpha.map(|alpha_path| {
+    if is_sequence || output_format != "avif" {
+      println!("Error: Alpha channels are only supported for still images in .avif files");
+      exit(2);
+    }
+    let alpha = match File::open(&alpha_path)
+                      .and_then(Y4MReader::new)
+                      .and_then(|mut y4m| y4m.read_frame()) {
+      Ok(alpha) => alpha,
+      Err(err) => {
+        println!("Error: Could not read alpha image {}: {}", alpha_path.display(), err);
+        exit(2);
+      }
+    };
+    if alpha.y().crop_width() != crop_width || alpha.y().crop_height() != crop_height {
+      println!("Error: alpha image size {}x{} doesn't match the main image size {}x{}",
+               alpha.y().crop_width(), alpha.y().crop_height(), crop_width, crop_height);
+      exit(2);
+    }
+    alpha
+  });
+
+  if args.lambda_scale.is_nan() || args.lambda_scale <= 0.0 {
+    println!("Error: Lambda scale must be positive");
+    exit(2);
+  }
+
+  let forced_level = match args.level.as_str() {
+    "auto" => None,
+    name => match parse_level(name) {
+      Ok(seq_level_idx) => Some(seq_level_idx),
+      Err(msg) => {
+        println!("Error: {}", msg);
+        exit(2);
+      }
+    }
+  };
+
+  // The reduced sequence header can only describe a single still image
+  let reduced_still_picture_header = match args.sequence_header.as_str() {
+    "auto" => !is_sequence,
+    "reduced" => {
+      if is_sequence {
+        println!("Error: A reduced sequence header can't be used, as the input has more than one frame");
+        exit(2);
+      }
+      true
+    },
+    "full" => false,
+    _ => unreachable!(),
+  };
+
+  if args.film_grain_table.is_some() && args.film_grain != "off" {
+    println!("Error: --film-grain-table can't be combined with --film-grain");
+    exit(2);
+  }
+
+  // With a film grain table, each frame uses the entry covering its timestamp
+  let film_grain_table = match &args.film_grain_table {
+    Some(table_path) => match read_film_grain_table(table_path) {
+      Ok(table) => Some(table),
+      Err(msg) => {
+        println!("Error: {}", msg);
+        exit(2);
+      }
+    },
+    None => None,
+  };
+
+  // When estimating film grain, everything from here on works from the denoised image
+  let (source, film_grain) = match args.film_grain.as_str() {
+    "off" => (source, film_grain_table.as_ref().and_then(|table| table.lookup(0)).cloned()),
+    "auto" => match estimate_film_grain(&source) {
+      Some((denoised, params)) => (Box::new(denoised), Some(params)),
+      None => (source, None),
+    },
+    _ => unreachable!(),
+  };
+
+  let quantizer_mode = match args.quantizer.as_str() {
+    "nearest" => QuantizerMode::Nearest,
+    "deadzone" => QuantizerMode::DeadZone,
+    "adaptive" => QuantizerMode::Adaptive,
+    _ => unreachable!(),
+  };
+
+  let delta_q = if args.auto_chroma_q {
+    DeltaQ {
+      y_dc: args.delta_q_y_dc,
+      ..DeltaQ::auto_chroma(&source)
+    }
+  } else {
+    DeltaQ {
+      y_dc: args.delta_q_y_dc,
+      u_dc: args.delta_q_u_dc,
+      u_ac: args.delta_q_u_ac,
+      v_dc: args.delta_q_v_dc,
+      v_ac: args.delta_q_v_ac,
+    }
+  };
+  for value in [delta_q.y_dc, delta_q.u_dc, delta_q.u_ac, delta_q.v_dc, delta_q.v_ac] {
+    if !(-64..=63).contains(&value) {
+      println!("Error: Quantizer deltas must be in the range -64 to 63");
+      exit(2);
+    }
+  }
+
+  // Quantizer matrices are enabled by --qm, or if either level is given. Any level which
+  // isn't given is picked based on the qindex of the relevant plane, as libaom does
+  let qm_levels = if args.qm || args.qm_y.is_some() || args.qm_uv.is_some() {
+    if args.qm_y.unwrap_or(0) > 15 || args.qm_uv.unwrap_or(0) > 15 {
+      println!("Error: Quantizer matrix levels must be in the range 0-15");
+      exit(2);
+    }
+    let qm_y = args.qm_y.unwrap_or_else(|| default_qm_level(base_qindex as i32));
+    let qm_u = args.qm_uv.unwrap_or_else(|| default_qm_level(base_qindex as i32 + delta_q.u_ac as i32));
+    let qm_v = args.qm_uv.unwrap_or_else(|| default_qm_level(base_qindex as i32 + delta_q.v_ac as i32));
+    Some([qm_y, qm_u, qm_v])
+  } else {
+    None
+  };
+
+  let aq_mode = match args.aq_mode.as_str() {
+    "none" => AQMode::None,
+    "variance" => AQMode::Variance,
+    "segment" => AQMode::Segment,
+    _ => unreachable!(),
+  };
+
+  if args.aq_strength.is_nan() || args.aq_strength < 0.0 {
+    println!("Error: AQ strength must be non-negative");
+    exit(2);
+  }
+
+  let roi_offsets = args.roi.map(|roi_path| {
+    match read_roi_map(&roi_path, crop_width, crop_height) {
+      Ok(offsets) => offsets,
+      Err(msg) => {
+        println!("Error: {}", msg);
+        exit(2);
+      }
+    }
+  });
+
+  // Per-block AQ and region-of-interest maps are both signalled using segmentation.
+  // If both are used, the ROI offsets are applied on top of the AQ decisions
+  // The AQ decisions depend on the image content, so this is redone for each frame of a sequence
+  let frame_segmentation = |source: &Frame| if aq_mode == AQMode::Segment || roi_offsets.is_some() {
+    let mut block_qindex = match aq_mode {
+      AQMode::Segment => block_aq(source, base_qindex, args.aq_strength),
+      _ => Array2D::new_with(padded_height / 8, padded_width / 8, |_, _| base_qindex),
+    };
+    if let Some(offsets) = &roi_offsets {
+      for row in 0 .. block_qindex.rows() {
+        for col in 0 .. block_qindex.cols() {
+          // Qindex 0 is reserved for lossless mode, which isn't supported in combination with segmentation
+          let qindex = block_qindex[row][col] as i32 + offsets[row][col] as i32;
+          block_qindex[row][col] = qindex.clamp(1, 255) as u8;
+        }
+      }
+    }
+    Some(Segmentation::from_block_qindex(&block_qindex, base_qindex))
+  } else {
+    None
+  };
+
+  let screen_content_tools = match args.screen_content.as_str() {
+    "auto" => is_screen_content(&source),
+    "on" => true,
+    "off" => false,
+    _ => unreachable!(),
+  };
+
+  let sb_size = match args.superblock_size.as_str() {
+    "auto" => choose_sb_size(crop_width, crop_height),
+    "64" => 64,
+    "128" => 128,
+    _ => unreachable!(),
+  };
+
+  let mut config = EncoderConfig {
+    lambda_scale: args.lambda_scale,
+    trellis: !args.no_trellis,
+    quantizer_mode: quantizer_mode,
+    qm_levels: qm_levels,
+    delta_q: delta_q,
+    aq_mode: aq_mode,
+    aq_strength: args.aq_strength,
+    segmentation: frame_segmentation(&source),
+    screen_content_tools: screen_content_tools,
+    intrabc: !args.no_intrabc,
+    sb_size: sb_size,
+    superres_denom: SUPERRES_NUM,
+    // For sequences, grain has to be enabled in the sequence header if any frame uses it
+    film_grain_params_present: film_grain.is_some() ||
+                               (is_sequence && film_grain_table.as_ref().is_some_and(|table| table.has_grain())),
+    film_grain: film_grain,
+    frame_type: FrameType::KEY_FRAME,
+    frame_index: 0,
+    monochrome: false,
+  };
+
+  config.superres_denom = match args.superres.as_str() {
+    "off" => SUPERRES_NUM,
+    "auto" => choose_superres_denom(&source, base_qindex, &config),
+    denom => {
+      let denom = denom.parse::<u8>().unwrap();
+      if superres_downscaled_width(crop_width, denom) < SUPERRES_MIN_WIDTH {
+        println!("Error: image width {} is too small to use superres denominator {}", crop_width, denom);
+        exit(2);
+      }
+      denom
+    }
+  };
+
+  let mut seq = SequenceHeaderConfig::still_image(reduced_still_picture_header, SEQ_LEVEL_MAX, Tier::Main);
+  if is_sequence {
+    seq.still_picture = false;
+    seq.timing_info = Some(TimingInfo {
+      num_units_in_display_tick: frame_rate.1,
+      time_scale: frame_rate.0,
+      num_ticks_per_picture: Some(1),
+    });
+  }
+
+  // Generate AV1 data. Each frame uses the settings chosen for the first frame, except
+  // for the ones which depend on each frame's content.
+  // The first frame is a key frame, and later frames are inter frames predicted from the
+  // decoded version of the previous frame, except every `keyframe_interval` frames.
+  // With superres, the reference frames are stored at the upscaled width, so predicting
+  // from them needs scaled motion compensation. This encoder doesn't implement that,
+  // so it codes every frame as a key frame when superres is enabled
+  let all_key_frames = config.superres_denom != SUPERRES_NUM || args.keyframe_interval == 1;
+  let mut coded_frames = Vec::new();
+  let mut key_frames = Vec::new();
+  let mut frame_source = source;
+  let mut frame_config = config.clone();
+  let mut reference: Option<Frame> = None;
+  loop {
+    let is_key_frame = frame_config.frame_type == FrameType::KEY_FRAME;
+    let encoder = AV1Encoder::new(crop_width, crop_height, frame_config);
+    let frame_header = encoder.generate_frame_header(&seq, base_qindex, false);
+    let frame_reference = if is_key_frame { None } else { reference.as_ref() };
+    let (tile_data, recon) = encoder.encode_image_with_recon(&frame_source, frame_reference, base_qindex);
+    coded_frames.push((frame_header, tile_data));
+    key_frames.push(is_key_frame);
+    reference = Some(recon);
+
+    let Some(next) = next_source.take() else {
+      break;
+    };
+    next_source = y4m.read_next_frame().unwrap();
+
+    let frame_index = coded_frames.len();
+    frame_config = config.clone();
+    frame_config.frame_index = frame_index;
+    if !all_key_frames && (args.keyframe_interval == 0 || frame_index % args.keyframe_interval != 0) {
+      frame_config.frame_type = FrameType::INTER_FRAME;
+    }
+
+    // With --film-grain auto, grain is only enabled in the sequence header if the first
+    // frame had grain. If a later frame seems not to, we encode it as-is, and reuse the
+    // first frame's parameters
+    (frame_source, frame_config.film_grain) = match (args.film_grain.as_str(), &config.film_grain) {
+      ("off", _) => {
+        let timestamp = grain_table_timestamp(frame_index, frame_rate);
+        (next, film_grain_table.as_ref().and_then(|table| table.lookup(timestamp)).cloned())
+      },
+      ("auto", Some(_)) => match estimate_film_grain(&next) {
+        Some((denoised, params)) => (Box::new(denoised), Some(params)),
+        None => (next, config.film_grain.clone()),
+      },
+      _ => (next, config.film_grain.clone()),
+    };
+    if let Some(params) = &mut frame_config.film_grain {
+      // Vary the grain pattern between frames, so that it doesn't look static
+      params.grain_seed = params.grain_seed.wrapping_add((frame_index as u16).wrapping_mul(GRAIN_SEED_INCREMENT));
+    }
+    frame_config.segmentation = frame_segmentation(&frame_source);
+  }
+  // Pick or validate the level, now that we know how large the compressed frames are.
+  // We always code a single tile
+  let frame_sizes: Vec<usize> = coded_frames.iter().map(|(frame_header, tile_data)| frame_header.len() + tile_data.len()).collect();
+  let (frames_per_second, bitrate) = if is_sequence {
+    let frames_per_second = frame_rate.0 as f64 / frame_rate.1 as f64;
+    let total_bits = 8 * frame_sizes.iter().sum::<usize>();
+    (Some(frames_per_second), Some(total_bits as f64 * frames_per_second / frame_sizes.len() as f64))
+  } else {
+    (None, None)
+  };
+  let level_stats = LevelStats {
+    width: crop_width,
+    height: crop_height,
+    num_tiles: 1,
+    tile_cols: 1,
+    compressed_size: *frame_sizes.iter().max().unwrap(),
+    frame_rate: frames_per_second,
+    bitrate: bitrate,
+  };
+  let select_level = |level_stats: &LevelStats, seq: &SequenceHeaderConfig| match forced_level {
+    None => {
+      let (seq_level_idx, seq_tier) = choose_level(level_stats, seq.allow_high_tier());
+      if seq_level_idx == SEQ_LEVEL_MAX {
+        println!("Warning: the encoded image is too large for any AV1 level, signalling level {} instead",
+                 LevelName(SEQ_LEVEL_MAX));
+      }
+      (seq_level_idx, seq_tier)
+    },
+    Some(seq_level_idx) => {
+      // Use the High tier if the image only fits in that, and it can be signalled
+      match check_level(seq_level_idx, Tier::Main, level_stats) {
+        Ok(()) => (seq_level_idx, Tier::Main),
+        Err(_) if seq.allow_high_tier() && check_level(seq_level_idx, Tier::High, level_stats).is_ok() => {
+          (seq_level_idx, Tier::High)
+        },
+        Err(msg) => {
+          println!("Error: {}", msg);
+          exit(2);
+        }
+      }
+    }
+  };
+  let (seq_level_idx, seq_tier) = select_level(&level_stats, &seq);
+  seq.set_level(seq_level_idx, seq_tier);
+
+  // The sequence header only depends on settings which are the same for every frame
+  let encoder = AV1Encoder::new(crop_width, crop_height, config.clone());
+  let sequence_header = encoder.generate_sequence_header(&seq);
+
+  // The alpha channel is a separate monochrome still image, with its own sequence header and level.
+  // It uses the same settings as the main image, except for the ones which depend on the
+  // image content, or which only make sense for colour images
+  let alpha = alpha_source.map(|alpha_source| {
+    let alpha_qindex = args.alpha_qindex.unwrap_or(base_qindex);
+    let alpha_config = EncoderConfig {
+      lambda_scale: config.lambda_scale,
+      trellis: config.trellis,
+      quantizer_mode: config.quantizer_mode,
+      screen_content_tools: match args.screen_content.as_str() {
+        "auto" => is_screen_content(&alpha_source),
+        _ => screen_content_tools,
+      },
+      intrabc: config.intrabc,
+      sb_size: sb_size,
+      monochrome: true,
+      ..Default::default()
+    };
+    let mut alpha_seq = SequenceHeaderConfig::still_image(reduced_still_picture_header, SEQ_LEVEL_MAX, Tier::Main);
+    let encoder = AV1Encoder::new(crop_width, crop_height, alpha_config);
+    let frame_header = encoder.generate_frame_header(&alpha_seq, alpha_qindex, false);
+    let (tile_data, _) = encoder.encode_image_with_recon(&alpha_source, None, alpha_qindex);
 
-  // Pack into higher-level structure and write out
-  let av1_data = pack_obus(&sequence_header, &frame_header, &tile_data, true);
+    let alpha_level_stats = LevelStats {
+      compressed_size: frame_header.len() + tile_data.len(),
+      ..level_stats
+    };
+    let (seq_level_idx, seq_tier) = select_level(&alpha_level_stats, &alpha_seq);
+    alpha_seq.set_level(seq_level_idx, seq_tier);
+    let sequence_header = encoder.generate_sequence_header(&alpha_seq);
+    (pack_obus(&sequence_header, &frame_header, &tile_data, true), seq_level_idx, seq_tier)
+  });
 
-  match output_ext {
+  // Pack into higher-level structure and write out.
+  // Each frame goes in its own temporal unit, with a copy of the sequence header
+  let pack_frames = |include_temporal_delimiter: bool| -> Vec<Box<[u8]>> {
+    coded_frames.iter().map(|(frame_header, tile_data)| {
+      pack_obus(&sequence_header, frame_header, tile_data, include_temporal_delimiter)
+    }).collect()
+  };
+
+  match output_format {
     "obu" => {
       // Write OBU data directly, with no further wrapping
       let mut obu_file = File::create(output_path).unwrap();
-      obu_file.write_all(&av1_data).unwrap();
+      for temporal_unit in pack_frames(true) {
+        obu_file.write_all(&temporal_unit).unwrap();
+      }
+    },
+    "annexb" => {
+      // Write OBU data in the length-delimited Annex B format
+      let mut annexb_file = File::create(output_path).unwrap();
+      for (frame_header, tile_data) in &coded_frames {
+        let temporal_unit = pack_annexb(&sequence_header, frame_header, tile_data);
+        annexb_file.write_all(&temporal_unit).unwrap();
+      }
+    },
+    "ivf" => {
+      // Wrap OBU data in an IVF container
+      let ivf_data = pack_ivf(&pack_frames(true), crop_width, crop_height, frame_rate);
+      let mut ivf_file = File::create(output_path).unwrap();
+      ivf_file.write_all(&ivf_data).unwrap();
     },
     "avif" => {
-      // Wrap OBU data in an AVIF container
-      let avif_data = pack_avif(&av1_data, crop_width, crop_height,
+      // Wrap OBU data in an AVIF container. Image sequences don't use temporal delimiters,
+      // as the container marks where each frame starts
+      let sequence_header_obu = pack_sequence_header_obu(&sequence_header);
+      let sequence = if is_sequence {
+        Some(AvifSequence {
+          frame_rate: frame_rate,
+          sequence_header_obu: &sequence_header_obu,
+          key_frames: &key_frames,
+        })
+      } else {
+        None
+      };
+      let alpha = alpha.as_ref().map(|(data, seq_level_idx, seq_tier)| AvifAlpha {
+        data: data,
+        seq_level_idx: *seq_level_idx,
+        seq_tier: *seq_tier,
+        premultiplied: args.premultiplied_alpha,
+      });
+      let avif_data = pack_avif(&pack_frames(!is_sequence), crop_width, crop_height,
+                                seq_level_idx, seq_tier,
+                                sequence,
+                                alpha,
                                 args.color_primaries,
                                 args.transfer_function,
                                 args.matrix_coefficients);
@@ -0,0 +1,500 @@
+// Copyright (c) 2024-2025, The tinyavif contributors. All rights reserved
+//
+// This source code is subject to the terms of the BSD 2 Clause License and
+// the Alliance for Open Media Patent License 1.0. If the BSD 2 Clause License
+// was not distributed with this source code in the LICENSE file, you can
+// obtain it at www.aomedia.org/license/software. If the Alliance for Open
+// Media Patent License 1.0 was not distributed with this source code in the
+// PATENTS file, you can obtain it at www.aomedia.org/license/patent.
+
+// Palette mode
+//
+// Screen content (text, UI elements, diagrams) tends to contain blocks made up
+// of just a few distinct colours with sharp edges between them. These are very
+// expensive to code with the normal intra predictors plus a DCT residual.
+//
+// Palette mode instead gives each block a list of 2-8 colours, and codes a map
+// saying which colour each pixel uses. Luma has its own palette, while U and V
+// share a single colour map, so each chroma palette entry is a (U, V) pair.
+// The palette colours can be copied from the palettes of the above and left
+// blocks (the "palette cache"), and the colour map is coded in diagonal
+// wavefront order, with each index predicted from its already-coded neighbours.
+//
+// Palette mode is only available when screen content tools are enabled in
+// the frame header, which we do based on a simple analysis of the source image.
+
+use crate::array2d::Array2D;
+use crate::entropycode::SymbolWriter;
+use crate::frame::Frame;
+use crate::util::*;
+
+pub const PALETTE_MIN_SIZE: usize = 2;
+pub const PALETTE_MAX_SIZE: usize = 8;
+pub const PALETTE_SIZES: usize = PALETTE_MAX_SIZE - PALETTE_MIN_SIZE + 1;
+
+pub const PALETTE_COLOR_CONTEXTS: usize = 5;
+
+// Number of already-coded neighbours used to build the colour context
+const PALETTE_NUM_NEIGHBORS: usize = 3;
+const Palette_Color_Hash_Multipliers: [usize; PALETTE_NUM_NEIGHBORS] = [1, 2, 2];
+// Map from colour context hash to context index. Entries marked usize::MAX can't occur
+const Palette_Color_Context: [usize; 9] = [usize::MAX, usize::MAX, 0, usize::MAX, usize::MAX, 4, 3, 2, 1];
+
+// Screen content detection parameters
+// We look at 16x16 luma blocks, and count how many have only a handful of distinct colours.
+// If enough of the image is made up of such blocks, we enable screen content tools
+const SCREEN_CONTENT_BLOCK_SIZE: usize = 16;
+const SCREEN_CONTENT_MAX_COLORS: usize = 4;
+// Enable screen content tools if more than 1/10 of the image is made of low-colour blocks
+const SCREEN_CONTENT_AREA_DIVISOR: usize = 10;
+
+// A palette for one plane of one block
+// For chroma, the U and V palettes always have the same size
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Palette {
+  pub size: usize,
+  pub colors: [u8; PALETTE_MAX_SIZE],
+}
+
+impl Palette {
+  fn new(colors: &[u8]) -> Self {
+    assert!(PALETTE_MIN_SIZE <= colors.len() && colors.len() <= PALETTE_MAX_SIZE);
+    let mut palette = Self {
+      size: colors.len(),
+      colors: [0; PALETTE_MAX_SIZE],
+    };
+    palette.colors[..colors.len()].copy_from_slice(colors);
+    return palette;
+  }
+
+  pub fn colors(&self) -> &[u8] {
+    &self.colors[..self.size]
+  }
+}
+
+// Decide whether an image looks like screen content, in which case it's worth
+// enabling screen content tools
+pub fn is_screen_content(source: &Frame) -> bool {
+  let luma = source.y();
+  let pixels = luma.pixels();
+  let width = luma.crop_width();
+  let height = luma.crop_height();
+
+  let mut low_color_blocks = 0;
+  for y0 in (0 .. height - height % SCREEN_CONTENT_BLOCK_SIZE).step_by(SCREEN_CONTENT_BLOCK_SIZE) {
+    for x0 in (0 .. width - width % SCREEN_CONTENT_BLOCK_SIZE).step_by(SCREEN_CONTENT_BLOCK_SIZE) {
+      let mut used = [false; 256];
+      let mut num_colors = 0;
+      for y in y0 .. y0 + SCREEN_CONTENT_BLOCK_SIZE {
+        for x in x0 .. x0 + SCREEN_CONTENT_BLOCK_SIZE {
+          let value = pixels[y][x] as usize;
+          if !used[value] {
+            used[value] = true;
+            num_colors += 1;
+          }
+        }
+      }
+      // Completely flat blocks are common in natural images too, so don't count those
+      if num_colors > 1 && num_colors <= SCREEN_CONTENT_MAX_COLORS {
+        low_color_blocks += 1;
+      }
+    }
+  }
+
+  let block_area = SCREEN_CONTENT_BLOCK_SIZE * SCREEN_CONTENT_BLOCK_SIZE;
+  return low_color_blocks * block_area * SCREEN_CONTENT_AREA_DIVISOR > width * height;
+}
+
+// Pick candidate luma palettes for a block
+// This returns one palette for each size from the number of distinct colours
+// in the block (capped at PALETTE_MAX_SIZE) down to PALETTE_MIN_SIZE. Each
+// palette is sorted in ascending order, as required by the bitstream.
+pub fn luma_palette_candidates(pixels: &Array2D<u8>, y0: usize, x0: usize, h: usize, w: usize) -> Vec<Palette> {
+  let mut samples = Vec::with_capacity(h * w);
+  for i in 0..h {
+    for j in 0..w {
+      samples.push([pixels[y0 + i][x0 + j]]);
+    }
+  }
+
+  return palette_candidates(&samples).iter().map(|colors| {
+    let luma: Vec<u8> = colors.iter().map(|color| color[0]).collect();
+    Palette::new(&luma)
+  }).collect();
+}
+
+// Pick candidate chroma palettes for a block, as (U, V) palette pairs
+// These are sorted in ascending order of U, which is what the bitstream requires
+pub fn chroma_palette_candidates(u: &Array2D<u8>, v: &Array2D<u8>,
+                                 y0: usize, x0: usize, h: usize, w: usize) -> Vec<[Palette; 2]> {
+  let mut samples = Vec::with_capacity(h * w);
+  for i in 0..h {
+    for j in 0..w {
+      samples.push([u[y0 + i][x0 + j], v[y0 + i][x0 + j]]);
+    }
+  }
+
+  return palette_candidates(&samples).iter().map(|colors| {
+    let u: Vec<u8> = colors.iter().map(|color| color[0]).collect();
+    let v: Vec<u8> = colors.iter().map(|color| color[1]).collect();
+    [Palette::new(&u), Palette::new(&v)]
+  }).collect();
+}
+
+// Shared logic for picking candidate palettes. Each sample is a colour with N components.
+// If there are few enough distinct colours, the first candidate uses them exactly;
+// otherwise the colours are clustered using k-means.
+fn palette_candidates<const N: usize>(samples: &[[u8; N]]) -> Vec<Vec<[u8; N]>> {
+  let mut distinct = samples.to_vec();
+  distinct.sort();
+  distinct.dedup();
+  if distinct.len() < PALETTE_MIN_SIZE {
+    return Vec::new();
+  }
+
+  let max_size = min(distinct.len(), PALETTE_MAX_SIZE);
+  let mut candidates: Vec<Vec<[u8; N]>> = Vec::new();
+  for size in (PALETTE_MIN_SIZE ..= max_size).rev() {
+    let colors = if size == distinct.len() {
+      distinct.clone()
+    } else {
+      kmeans(samples, &distinct, size)
+    };
+    // k-means can merge clusters, leaving us with a palette we've already tried
+    if colors.len() >= PALETTE_MIN_SIZE && !candidates.contains(&colors) {
+      candidates.push(colors);
+    }
+  }
+
+  return candidates;
+}
+
+// Cluster the given samples into (at most) k colours
+// `distinct` must be the sorted list of distinct sample values, which is used
+// to pick the initial cluster centres. The result is sorted and deduplicated.
+fn kmeans<const N: usize>(samples: &[[u8; N]], distinct: &[[u8; N]], k: usize) -> Vec<[u8; N]> {
+  const MAX_ITERATIONS: usize = 10;
+
+  // Start with centres spread evenly through the distinct colours
+  let mut centers: Vec<[i32; N]> = (0..k).map(|i| {
+    let color = distinct[i * (distinct.len() - 1) / (k - 1)];
+    color.map(|c| c as i32)
+  }).collect();
+
+  let mut assignment = vec![0; samples.len()];
+  for _ in 0..MAX_ITERATIONS {
+    for (i, sample) in samples.iter().enumerate() {
+      assignment[i] = nearest_color(&centers, sample);
+    }
+
+    let mut sums = vec![[0i32; N]; k];
+    let mut counts = vec![0i32; k];
+    for (i, sample) in samples.iter().enumerate() {
+      for c in 0..N {
+        sums[assignment[i]][c] += sample[c] as i32;
+      }
+      counts[assignment[i]] += 1;
+    }
+
+    let mut changed = false;
+    for cluster in 0..k {
+      // Leave empty clusters where they are; they'll be dropped at the end
+      if counts[cluster] == 0 {
+        continue;
+      }
+      let center = sums[cluster].map(|sum| (sum + counts[cluster] / 2) / counts[cluster]);
+      if center != centers[cluster] {
+        centers[cluster] = center;
+        changed = true;
+      }
+    }
+    if !changed {
+      break;
+    }
+  }
+
+  let mut used = vec![false; k];
+  for sample in samples {
+    used[nearest_color(&centers, sample)] = true;
+  }
+  let mut colors: Vec<[u8; N]> = (0..k).filter(|&cluster| used[cluster])
+    .map(|cluster| centers[cluster].map(|c| c as u8))
+    .collect();
+  colors.sort();
+  colors.dedup();
+  return colors;
+}
+
+// Find the index of the closest colour to `sample`, by squared error
+fn nearest_color<const N: usize, T: Copy + Into<i32>>(colors: &[[T; N]], sample: &[u8; N]) -> usize {
+  let mut best_index = 0;
+  let mut best_dist = i32::MAX;
+  for (index, color) in colors.iter().enumerate() {
+    let mut dist = 0;
+    for c in 0..N {
+      let diff = color[c].into() - sample[c] as i32;
+      dist += diff * diff;
+    }
+    if dist < best_dist {
+      best_dist = dist;
+      best_index = index;
+    }
+  }
+  return best_index;
+}
+
+// Build the colour index map for a block, by mapping each pixel to the closest palette entry.
+// `planes` lists the source planes covered by the palette, along with their palette:
+// luma uses a single plane, while chroma uses U and V together
+pub fn build_color_map(planes: &[(&Array2D<u8>, &Palette)], y0: usize, x0: usize, h: usize, w: usize) -> Array2D<u8> {
+  let size = planes[0].1.size;
+  Array2D::new_with(h, w, |i, j| {
+    let mut best_index = 0;
+    let mut best_dist = i32::MAX;
+    for index in 0..size {
+      let mut dist = 0;
+      for (pixels, palette) in planes {
+        let diff = palette.colors[index] as i32 - pixels[y0 + i][x0 + j] as i32;
+        dist += diff * diff;
+      }
+      if dist < best_dist {
+        best_dist = dist;
+        best_index = index;
+      }
+    }
+    best_index as u8
+  })
+}
+
+// Fill in the prediction for a block from its palette and colour map
+pub fn palette_predict(pixels: &mut Array2D<u8>, palette: &Palette, color_map: &Array2D<u8>, y0: usize, x0: usize) {
+  for i in 0..color_map.rows() {
+    for j in 0..color_map.cols() {
+      pixels[y0 + i][x0 + j] = palette.colors[color_map[i][j] as usize];
+    }
+  }
+}
+
+// Merge the palettes of the above and left blocks into a sorted list of
+// distinct colours, which the current block can copy from cheaply.
+// The arguments are the relevant palettes, or empty slices if the neighbours
+// are unavailable or don't use palette mode.
+// This corresponds to get_palette_cache() in the spec
+pub fn get_palette_cache(above: &[u8], left: &[u8]) -> Vec<u8> {
+  let mut cache: Vec<u8> = Vec::with_capacity(above.len() + left.len());
+  let add = |cache: &mut Vec<u8>, color: u8| {
+    if cache.last() != Some(&color) {
+      cache.push(color);
+    }
+  };
+
+  let mut above_idx = 0;
+  let mut left_idx = 0;
+  while above_idx < above.len() && left_idx < left.len() {
+    let above_color = above[above_idx];
+    let left_color = left[left_idx];
+    if left_color < above_color {
+      add(&mut cache, left_color);
+      left_idx += 1;
+    } else {
+      add(&mut cache, above_color);
+      above_idx += 1;
+      if left_color == above_color {
+        left_idx += 1;
+      }
+    }
+  }
+  for &color in &above[above_idx..] {
+    add(&mut cache, color);
+  }
+  for &color in &left[left_idx..] {
+    add(&mut cache, color);
+  }
+
+  return cache;
+}
+
+// Code a non-symmetric unsigned value in the range [0, n)
+// This is like a literal with ceil(log2(n)) bits, except that some of the values
+// only need floor(log2(n)) bits. This corresponds to ns(n) in the spec.
+fn write_ns<W: SymbolWriter>(w: &mut W, value: u32, n: u32) {
+  assert!(value < n);
+  let nbits = floor_log2(n) + 1;
+  let m = (1 << nbits) - n;
+  if value < m {
+    w.write_literal(value, nbits - 1);
+  } else {
+    w.write_literal((value + m) >> 1, nbits - 1);
+    w.write_literal((value + m) & 1, 1);
+  }
+}
+
+// Signal which palette entries are copied from the palette cache
+// Returns the remaining colours, which need to be coded explicitly. Both
+// `colors` and the result are sorted in ascending order.
+fn write_cache_usage<W: SymbolWriter>(w: &mut W, colors: &[u8], cache: &[u8]) -> Vec<u8> {
+  let mut remaining = colors.to_vec();
+  let mut num_used = 0;
+  for &cached_color in cache {
+    if num_used == colors.len() {
+      break;
+    }
+    let position = remaining.iter().position(|&color| color == cached_color);
+    w.write_literal(position.is_some() as u32, 1); // use_palette_color_cache
+    if let Some(position) = position {
+      remaining.remove(position);
+      num_used += 1;
+    }
+  }
+  return remaining;
+}
+
+// Code a sorted list of colours as the first colour plus a list of deltas,
+// each of which is at least `min_delta`
+fn write_color_deltas<W: SymbolWriter>(w: &mut W, colors: &[u8], min_delta: u32) {
+  if colors.is_empty() {
+    return;
+  }
+  w.write_literal(colors[0] as u32, 8);
+  if colors.len() == 1 {
+    return;
+  }
+
+  // The number of bits per delta starts off at a value signalled here,
+  // and then shrinks as the remaining range of possible values shrinks
+  let min_bits = 8 - 3;
+  let max_delta = colors.windows(2).map(|pair| (pair[1] - pair[0]) as u32).max().unwrap();
+  let mut bits = max(ceil_log2(max_delta - min_delta + 1), min_bits);
+  w.write_literal(bits - min_bits, 2);
+
+  for i in 1 .. colors.len() {
+    let delta = (colors[i] - colors[i - 1]) as u32;
+    assert!(delta >= min_delta);
+    w.write_literal(delta - min_delta, bits);
+    let range = 256 - colors[i] as u32 - min_delta;
+    bits = min(bits, if range < 2 { 0 } else { ceil_log2(range) });
+  }
+}
+
+// Write the colours of a luma palette
+pub fn write_palette_colors_y<W: SymbolWriter>(w: &mut W, palette: &Palette, cache: &[u8]) {
+  let remaining = write_cache_usage(w, palette.colors(), cache);
+  // Luma colours are all distinct, so each delta is at least 1
+  write_color_deltas(w, &remaining, 1);
+}
+
+// Write the colours of a chroma palette
+pub fn write_palette_colors_uv<W: SymbolWriter>(w: &mut W, palette_u: &Palette, palette_v: &Palette, cache: &[u8]) {
+  // U colours work the same as luma, except that repeated colours are allowed
+  let remaining = write_cache_usage(w, palette_u.colors(), cache);
+  write_color_deltas(w, &remaining, 0);
+
+  // V colours are not sorted, so are coded either as raw values or as deltas modulo 256,
+  // whichever is cheaper
+  let colors_v = palette_v.colors();
+  let min_bits = 8 - 4;
+  let mut max_delta = 0;
+  let mut zero_count = 0;
+  for i in 1 .. colors_v.len() {
+    let diff = (colors_v[i] as i32 - colors_v[i - 1] as i32).unsigned_abs();
+    let delta = min(diff, 256 - diff);
+    max_delta = max(max_delta, delta);
+    if delta == 0 {
+      zero_count += 1;
+    }
+  }
+  let bits = max(ceil_log2(max_delta + 1), min_bits);
+  let delta_rate = 2 + 8 + (bits + 1) * (colors_v.len() as u32 - 1) - zero_count;
+  let raw_rate = 8 * colors_v.len() as u32;
+
+  // The number of extra bits can only be 0-3, so very large deltas need to be coded raw
+  if delta_rate < raw_rate && bits - min_bits <= 3 {
+    w.write_literal(1, 1); // delta_encode_palette_colors_v
+    w.write_literal(bits - min_bits, 2);
+    w.write_literal(colors_v[0] as u32, 8);
+    for i in 1 .. colors_v.len() {
+      let diff = colors_v[i] as i32 - colors_v[i - 1] as i32;
+      // Pick whichever direction is shorter, wrapping around if needed
+      let (delta, negative) = if diff.unsigned_abs() <= 256 - diff.unsigned_abs() {
+        (diff.unsigned_abs(), diff < 0)
+      } else {
+        (256 - diff.unsigned_abs(), diff > 0)
+      };
+      w.write_literal(delta, bits);
+      if delta != 0 {
+        w.write_literal(negative as u32, 1);
+      }
+    }
+  } else {
+    w.write_literal(0, 1); // delta_encode_palette_colors_v
+    for &color in colors_v {
+      w.write_literal(color as u32, 8);
+    }
+  }
+}
+
+// Work out the context for coding the colour index at (row, col), based on the
+// left, above-left, and above indices. Also returns the order in which the
+// palette entries are coded, with the most likely ones first.
+// This corresponds to get_palette_color_context() in the spec
+fn get_palette_color_context(color_map: &Array2D<u8>, row: usize, col: usize, n: usize) -> (usize, [u8; PALETTE_MAX_SIZE]) {
+  let mut scores = [0; PALETTE_MAX_SIZE];
+  let mut color_order: [u8; PALETTE_MAX_SIZE] = std::array::from_fn(|i| i as u8);
+
+  if col > 0 {
+    scores[color_map[row][col - 1] as usize] += 2;
+  }
+  if row > 0 && col > 0 {
+    scores[color_map[row - 1][col - 1] as usize] += 1;
+  }
+  if row > 0 {
+    scores[color_map[row - 1][col] as usize] += 2;
+  }
+
+  // Move the highest-scoring entries to the front, keeping ties in their original order
+  for i in 0..PALETTE_NUM_NEIGHBORS {
+    let mut max_score = scores[i];
+    let mut max_idx = i;
+    for j in i + 1 .. n {
+      if scores[j] > max_score {
+        max_score = scores[j];
+        max_idx = j;
+      }
+    }
+    if max_idx != i {
+      let max_color_order = color_order[max_idx];
+      for k in (i + 1 ..= max_idx).rev() {
+        scores[k] = scores[k - 1];
+        color_order[k] = color_order[k - 1];
+      }
+      scores[i] = max_score;
+      color_order[i] = max_color_order;
+    }
+  }
+
+  let mut hash = 0;
+  for i in 0..PALETTE_NUM_NEIGHBORS {
+    hash += scores[i] * Palette_Color_Hash_Multipliers[i];
+  }
+  let ctx = Palette_Color_Context[hash];
+  assert!(ctx != usize::MAX);
+  return (ctx, color_order);
+}
+
+// Write a colour index map, in diagonal wavefront order
+// `cdfs` holds the CDFs for the relevant palette size, indexed by colour context
+pub fn write_color_map<W: SymbolWriter>(w: &mut W, color_map: &Array2D<u8>, n: usize,
+                                        cdfs: &[&[u16]; PALETTE_COLOR_CONTEXTS]) {
+  let h = color_map.rows();
+  let wd = color_map.cols();
+
+  write_ns(w, color_map[0][0] as u32, n as u32);
+  for i in 1 .. h + wd - 1 {
+    for j in (i.saturating_sub(h - 1) ..= min(i, wd - 1)).rev() {
+      let (row, col) = (i - j, j);
+      let (ctx, color_order) = get_palette_color_context(color_map, row, col, n);
+      let symbol = color_order[..n].iter().position(|&index| index == color_map[row][col]).unwrap();
+      w.write_symbol(symbol, cdfs[ctx]);
+    }
+  }
+}
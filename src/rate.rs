@@ -0,0 +1,79 @@
+// Copyright (c) 2024-2025, The tinyavif contributors. All rights reserved
+//
+// This source code is subject to the terms of the BSD 2 Clause License and
+// the Alliance for Open Media Patent License 1.0. If the BSD 2 Clause License
+// was not distributed with this source code in the LICENSE file, you can
+// obtain it at www.aomedia.org/license/software. If the Alliance for Open
+// Media Patent License 1.0 was not distributed with this source code in the
+// PATENTS file, you can obtain it at www.aomedia.org/license/patent.
+
+// Rate estimation
+// This allows us to calculate how many bits a set of symbols would take to code,
+// without actually generating any output. That in turn allows the encoder to try
+// out multiple options and pick the best one.
+
+use crate::consts::*;
+use crate::entropycode::SymbolWriter;
+use crate::util::*;
+
+// All rates are measured in units of 1/(1 << RATE_SHIFT) bits
+pub const RATE_SHIFT: u32 = 9;
+
+// Calculate the cost of a symbol with probability p / 32768
+pub fn prob_cost(p: u16) -> u32 {
+  // A probability of 0 can't actually occur, as the entropy coder guarantees a
+  // small minimum probability for each symbol. So treat it as the smallest
+  // nonzero probability instead.
+  let p = max(p as u32, 1);
+
+  // Split p into an exponent and an 8-bit mantissa in the range [128, 256),
+  // so that p / 32768 = (mantissa / 256) * 2^(e - 14).
+  // The cost is then -log2(mantissa / 256) + (14 - e), where the first term
+  // comes from the lookup table.
+  let e = floor_log2(p);
+  let mantissa = if e >= 7 { p >> (e - 7) } else { p << (7 - e) };
+
+  // Note: When p == 32768 (e == 15), the table lookup gives exactly one bit,
+  // which cancels out with the exponent term to give a total cost of 0
+  (prob_cost_table[(mantissa - 128) as usize] as u32 + (14 << RATE_SHIFT)) - (e << RATE_SHIFT)
+}
+
+// Calculate the cost of coding `symbol` using the given CDF
+pub fn symbol_cost(symbol: usize, cdf: &[u16]) -> u32 {
+  prob_cost(get_prob(symbol, cdf))
+}
+
+// Convert a rate to a (fractional) number of bits
+pub fn rate_to_bits(rate: u32) -> f64 {
+  (rate as f64) / ((1 << RATE_SHIFT) as f64)
+}
+
+// Drop-in replacement for EntropyWriter, which accumulates the estimated cost
+// of each symbol rather than writing anything.
+//
+// The cost of each symbol is taken from its probability in the relevant CDF, which
+// ignores the small rounding effects of the real entropy coder. Higher-level
+// helpers like write_literal() and write_golomb() are shared with EntropyWriter
+// via the SymbolWriter trait, so they are costed exactly as they will be coded.
+pub struct RateEstimator {
+  rate: u32
+}
+
+impl RateEstimator {
+  pub fn new() -> Self {
+    Self {
+      rate: 0
+    }
+  }
+
+  // Total cost of all symbols written so far
+  pub fn rate(&self) -> u32 {
+    self.rate
+  }
+}
+
+impl SymbolWriter for RateEstimator {
+  fn write_symbol(&mut self, symbol: usize, cdf: &[u16]) {
+    self.rate += symbol_cost(symbol, cdf);
+  }
+}
@@ -0,0 +1,121 @@
+// Copyright (c) 2024-2025, The tinyavif contributors. All rights reserved
+//
+// This source code is subject to the terms of the BSD 2 Clause License and
+// the Alliance for Open Media Patent License 1.0. If the BSD 2 Clause License
+// was not distributed with this source code in the LICENSE file, you can
+// obtain it at www.aomedia.org/license/software. If the Alliance for Open
+// Media Patent License 1.0 was not distributed with this source code in the
+// PATENTS file, you can obtain it at www.aomedia.org/license/patent.
+
+// Rate-distortion optimization helpers
+//
+// Coding decisions are made by minimizing the cost J = D + lambda * R, where
+// D is the distortion (sum of squared errors, in pixel units) and R is the
+// rate in bits. Lambda controls the tradeoff between the two, and is derived
+// from the quantizer.
+
+use crate::array2d::Array2D;
+use crate::consts::*;
+use crate::rate::*;
+use crate::util::*;
+
+// Calculate lambda for a given qindex
+//
+// For a uniform quantizer with step size Q, high-rate theory gives D ~= Q^2 / 12,
+// with each extra bit reducing D by a factor of 4. Differentiating gives
+// lambda = -dD/dR = (ln(2) / 6) * Q^2.
+//
+// The quantizers in qindex_to_ac_q are scaled up by 8 compared to pixel units,
+// to match the scaling of our forward transforms, so we need to undo that first.
+//
+// `lambda_scale` is a fudge factor for tuning; 1.0 gives the theoretical value.
+pub fn qindex_to_lambda(qindex: u8, lambda_scale: f64) -> f64 {
+  let q = (qindex_to_ac_q[qindex as usize] as f64) / 8.0;
+  lambda_scale * q * q * std::f64::consts::LN_2 / 6.0
+}
+
+// Sum of squared differences between two regions
+pub fn sse(a: &Array2D<u8>, b: &Array2D<u8>, y0: usize, x0: usize, h: usize, w: usize) -> u64 {
+  let mut sum = 0u64;
+  for i in 0..h {
+    for j in 0..w {
+      let diff = (a[y0 + i][x0 + j] as i32) - (b[y0 + i][x0 + j] as i32);
+      sum += (diff * diff) as u64;
+    }
+  }
+  return sum;
+}
+
+// Sum of absolute transformed differences between two regions
+// This uses a 4x4 Hadamard transform on each 4x4 sub-block, and approximates
+// the cost of coding the residual better than SSE does when we haven't
+// actually transformed and quantized it yet.
+//
+// The region size must be a multiple of 4 in each direction
+pub fn satd(a: &Array2D<u8>, b: &Array2D<u8>, y0: usize, x0: usize, h: usize, w: usize) -> u64 {
+  assert!(h.is_multiple_of(4) && w.is_multiple_of(4));
+
+  let mut sum = 0u64;
+  for by in (0..h).step_by(4) {
+    for bx in (0..w).step_by(4) {
+      let mut d = [[0i32; 4]; 4];
+      for i in 0..4 {
+        for j in 0..4 {
+          d[i][j] = (a[y0 + by + i][x0 + bx + j] as i32) - (b[y0 + by + i][x0 + bx + j] as i32);
+        }
+      }
+
+      // Horizontal then vertical 4-point Hadamard transforms
+      for i in 0..4 {
+        let [d0, d1, d2, d3] = d[i];
+        let (s01, t01, s23, t23) = (d0 + d1, d0 - d1, d2 + d3, d2 - d3);
+        d[i] = [s01 + s23, t01 + t23, s01 - s23, t01 - t23];
+      }
+      for j in 0..4 {
+        let (d0, d1, d2, d3) = (d[0][j], d[1][j], d[2][j], d[3][j]);
+        let (s01, t01, s23, t23) = (d0 + d1, d0 - d1, d2 + d3, d2 - d3);
+        sum += (abs(s01 + s23) + abs(t01 + t23) + abs(s01 - s23) + abs(t01 - t23)) as u64;
+      }
+    }
+  }
+
+  // Normalize so that the result is on a similar scale to the sum of absolute differences
+  return sum.div_ceil(2);
+}
+
+// Distortion caused by quantizing a transform coefficient to the given level,
+// measured in the same units as pixel-domain SSE.
+// Our transforms scale coefficients up by a factor of 8 compared to an orthonormal
+// transform, so squared errors in the coefficient domain are 64x larger than the
+// corresponding errors in the pixel domain.
+pub fn coeff_dist(coeff: i32, level: i32, q: i32) -> f64 {
+  let err = (coeff as i64) - (level as i64) * (q as i64);
+  ((err * err) as f64) / 64.0
+}
+
+// Rate and distortion of a particular coding choice, along with the combined cost
+#[derive(Clone, Copy, Debug)]
+pub struct RDCost {
+  pub rate: u32, // In units of 1/(1 << RATE_SHIFT) bits
+  pub dist: u64,
+  pub cost: f64,
+}
+
+impl RDCost {
+  pub fn new(rate: u32, dist: u64, lambda: f64) -> Self {
+    Self {
+      rate: rate,
+      dist: dist,
+      cost: rd_cost(rate, dist, lambda),
+    }
+  }
+
+  pub fn is_better_than(&self, other: &RDCost) -> bool {
+    self.cost < other.cost
+  }
+}
+
+// Combine rate and distortion into a single cost value
+pub fn rd_cost(rate: u32, dist: u64, lambda: f64) -> f64 {
+  (dist as f64) + lambda * rate_to_bits(rate)
+}
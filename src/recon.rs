@@ -9,12 +9,17 @@
 
 // Reconstruction functions
 
+use std::ops::{Index, IndexMut};
+
 use crate::array2d::Array2D;
 use crate::consts::*;
+use crate::enums::*;
 use crate::txfm::*;
 use crate::util::*;
 
-// Predictions - only DC_PRED for now
+// Predictions
+// Chroma always uses DC_PRED, while luma can also use the directional and filter intra modes.
+// Palette and intra block copy predictions are handled in palette.rs and intrabc.rs
 pub fn dc_predict(pixels: &mut Array2D<u8>, y0: usize, x0: usize, h: usize, w: usize) {
   // For now, as we only ever use one tile, we can infer the haveLeft and haveAbove flags as:
   let haveLeft = x0 > 0;
@@ -46,6 +51,364 @@ pub fn dc_predict(pixels: &mut Array2D<u8>, y0: usize, x0: usize, h: usize, w: u
   pixels.fill_region(y0, x0, h, w, &pred);
 }
 
+// Which of the neighbouring pixels are available for intra prediction. Along with the
+// row above and the column to the left of the block, the above row may extend further
+// right, and the left column further down, if the blocks there have already been coded
+#[derive(Clone, Copy)]
+pub struct EdgeAvailability {
+  pub above: bool,
+  pub left: bool,
+  pub above_right: bool,
+  pub below_left: bool,
+}
+
+// Position of a 4x4 unit in the order in which the blocks of its superblock are coded,
+// where the superblock is `sb_size4` x `sb_size4` units.
+// As we only use PARTITION_NONE and PARTITION_SPLIT, this is a recursive Z order.
+// Note that this doesn't hold for the transform blocks within a block, as those are
+// coded in raster order; see tx_has_top_right() and tx_has_bottom_left()
+fn zorder_index(mi_row: usize, mi_col: usize, sb_size4: usize) -> usize {
+  let row = mi_row & (sb_size4 - 1);
+  let col = mi_col & (sb_size4 - 1);
+  let mut index = 0;
+  for bit in 0..floor_log2(sb_size4) {
+    index |= ((row >> bit) & 1) << (2 * bit + 1);
+    index |= ((col >> bit) & 1) << (2 * bit);
+  }
+  return index;
+}
+
+// Check whether the unit above and to the right of a square block of `size4` x `size4`
+// mode info units has already been coded.
+// The caller must check that the neighbouring unit is inside the frame
+pub fn has_top_right(mi_row: usize, mi_col: usize, size4: usize, sb_size4: usize) -> bool {
+  let sb_mask = sb_size4 - 1;
+  if mi_row & sb_mask == 0 {
+    // The neighbour is in the previous superblock row
+    true
+  } else if (mi_col & sb_mask) + size4 >= sb_size4 {
+    // The neighbour is in the next superblock, which hasn't been coded yet
+    false
+  } else {
+    zorder_index(mi_row - 1, mi_col + size4, sb_size4) < zorder_index(mi_row, mi_col, sb_size4)
+  }
+}
+
+// Check whether the unit below and to the left of a square block of `size4` x `size4`
+// mode info units has already been coded.
+// The caller must check that the neighbouring unit is inside the frame
+pub fn has_bottom_left(mi_row: usize, mi_col: usize, size4: usize, sb_size4: usize) -> bool {
+  let sb_mask = sb_size4 - 1;
+  if (mi_row & sb_mask) + size4 >= sb_size4 {
+    // The neighbour is in the next superblock row
+    false
+  } else if mi_col & sb_mask == 0 {
+    // The neighbour is in the previous superblock
+    true
+  } else {
+    zorder_index(mi_row + size4, mi_col - 1, sb_size4) < zorder_index(mi_row, mi_col, sb_size4)
+  }
+}
+
+// Check whether the unit above and to the right of a transform block has already been coded.
+// The transform block is `tx_size4` units square, at offset (row_off, col_off) units within
+// the `bsize4` x `bsize4` block at (mi_row, mi_col).
+// The caller must check that the neighbouring unit is inside the frame
+pub fn tx_has_top_right(mi_row: usize, mi_col: usize, bsize4: usize, row_off: usize, col_off: usize,
+                        tx_size4: usize, sb_size4: usize) -> bool {
+  if col_off + tx_size4 < bsize4 {
+    // The neighbour is either in the block above, or in the previous row of transform blocks
+    true
+  } else if row_off > 0 {
+    // The neighbour is in the block to the right, which hasn't been coded yet
+    false
+  } else {
+    has_top_right(mi_row, mi_col, bsize4, sb_size4)
+  }
+}
+
+// Check whether the unit below and to the left of a transform block has already been coded.
+// The arguments are the same as for tx_has_top_right()
+pub fn tx_has_bottom_left(mi_row: usize, mi_col: usize, bsize4: usize, row_off: usize, col_off: usize,
+                          tx_size4: usize, sb_size4: usize) -> bool {
+  if col_off > 0 {
+    // The neighbour is in a later row of transform blocks, or in the block below
+    false
+  } else if row_off + tx_size4 < bsize4 {
+    // The neighbour is in the block to the left
+    true
+  } else {
+    has_bottom_left(mi_row, mi_col, bsize4, sb_size4)
+  }
+}
+
+// Maximum length of an edge array. Edges are w + h pixels long, which is at most 128,
+// and upsampling is only used when this is at most 16. Two more entries are needed
+// for the top-left pixel and the extra pixel added by upsampling
+const MAX_EDGE_LEN: usize = 2 + 128;
+
+// The row of pixels above a block, or the column of pixels to its left, used for intra
+// prediction. The spec indexes these from -1, which is the top-left pixel, or from -2
+// once upsampled, so everything is stored offset by 2
+#[derive(Clone, Copy)]
+struct Edge([u8; MAX_EDGE_LEN]);
+
+impl Index<isize> for Edge {
+  type Output = u8;
+
+  fn index(&self, index: isize) -> &u8 {
+    &self.0[(index + 2) as usize]
+  }
+}
+
+impl IndexMut<isize> for Edge {
+  fn index_mut(&mut self, index: isize) -> &mut u8 {
+    &mut self.0[(index + 2) as usize]
+  }
+}
+
+// Gather the above and left edges for a block. Unavailable pixels are filled in from
+// whichever neighbours are available, or with fixed values if there are none
+fn build_edges(pixels: &Array2D<u8>, y0: usize, x0: usize, h: usize, w: usize,
+               avail: EdgeAvailability) -> (Edge, Edge) {
+  let max_x = pixels.cols() - 1;
+  let max_y = pixels.rows() - 1;
+  let mut above = Edge([0; MAX_EDGE_LEN]);
+  let mut left = Edge([0; MAX_EDGE_LEN]);
+
+  let above_limit = if avail.above_right { 2 * w } else { w };
+  let left_limit = if avail.below_left { 2 * h } else { h };
+  for i in 0 .. w + h {
+    above[i as isize] = match (avail.above, avail.left) {
+      (true, _) => pixels[y0 - 1][min(min(max_x, x0 + above_limit - 1), x0 + i)],
+      (false, true) => pixels[y0][x0 - 1],
+      (false, false) => 127,
+    };
+    left[i as isize] = match (avail.above, avail.left) {
+      (_, true) => pixels[min(min(max_y, y0 + left_limit - 1), y0 + i)][x0 - 1],
+      (true, false) => pixels[y0 - 1][x0],
+      (false, false) => 129,
+    };
+  }
+
+  let top_left = match (avail.above, avail.left) {
+    (true, true) => pixels[y0 - 1][x0 - 1],
+    (true, false) => pixels[y0 - 1][x0],
+    (false, true) => pixels[y0][x0 - 1],
+    (false, false) => 128,
+  };
+  above[-1] = top_left;
+  left[-1] = top_left;
+
+  return (above, left);
+}
+
+// Recursive filter intra prediction
+// The block is split into 4x2 patches, which are predicted in raster order. Each patch
+// is a linear combination of the 7 pixels adjacent to its top-left corner, which may
+// themselves have been predicted as part of an earlier patch
+pub fn filter_intra_predict(pixels: &mut Array2D<u8>, y0: usize, x0: usize, h: usize, w: usize,
+                            avail: EdgeAvailability, mode: FilterIntraMode) {
+  let (above, left) = build_edges(pixels, y0, x0, h, w, avail);
+  let taps = &Intra_Filter_Taps[mode as usize];
+
+  let mut pred: Array2D<u8> = Array2D::zeroed(h, w);
+  for i2 in 0 .. h / 2 {
+    for j4 in 0 .. w / 4 {
+      let (row, col) = (2 * i2, 4 * j4);
+
+      // The top-left pixel, then the 4 pixels above, then the 2 pixels to the left
+      let mut p = [0i32; 7];
+      for i in 0..5 {
+        p[i] = if row == 0 {
+          above[(col + i) as isize - 1]
+        } else if col == 0 && i == 0 {
+          left[row as isize - 1]
+        } else {
+          pred[row - 1][col + i - 1]
+        } as i32;
+      }
+      for i in 5..7 {
+        p[i] = if col == 0 {
+          left[(row + i - 5) as isize]
+        } else {
+          pred[row + i - 5][col - 1]
+        } as i32;
+      }
+
+      for k in 0..8 {
+        let sum: i32 = (0..7).map(|i| taps[k][i] as i32 * p[i]).sum();
+        pred[row + k / 4][col + k % 4] = clamp(round2_signed(sum, 4), 0, 255) as u8;
+      }
+    }
+  }
+
+  pixels.paste_region(y0, x0, &pred);
+}
+
+// Step size along the edge, per pixel moved away from the edge, for a given prediction
+// angle. The values are in units of 1/64 pixel
+fn dr_intra_derivative(angle: i32) -> i32 {
+  match angle {
+    3 => 1023, 6 => 547, 9 => 372, 14 => 273, 17 => 215, 20 => 178, 23 => 151,
+    26 => 132, 29 => 116, 32 => 102, 36 => 90, 39 => 80, 42 => 71, 45 => 64,
+    48 => 57, 51 => 51, 54 => 45, 58 => 40, 61 => 35, 64 => 31, 67 => 27,
+    70 => 23, 73 => 19, 76 => 15, 81 => 11, 84 => 7, 87 => 3,
+    _ => panic!("Invalid prediction angle {}", angle),
+  }
+}
+
+// Strength of the intra edge filter, given the block size and the difference between
+// the prediction angle and the direction of the edge.
+// This only covers the filter type used when neither neighbour uses a SMOOTH mode,
+// as this encoder never selects those
+fn edge_filter_strength(w: usize, h: usize, delta: i32) -> usize {
+  let d = abs(delta);
+  match w + h {
+    0 ..= 8 => if d >= 56 { 1 } else { 0 },
+    9 ..= 16 => if d >= 40 { 1 } else { 0 },
+    17 ..= 24 => if d >= 32 { 3 } else if d >= 16 { 2 } else if d >= 8 { 1 } else { 0 },
+    25 ..= 32 => if d >= 32 { 3 } else if d >= 4 { 2 } else if d >= 1 { 1 } else { 0 },
+    _ => if d >= 1 { 3 } else { 0 },
+  }
+}
+
+// Whether to double the resolution of an edge before predicting from it
+fn use_edge_upsample(w: usize, h: usize, delta: i32) -> bool {
+  let d = abs(delta);
+  d > 0 && d < 40 && w + h <= 16
+}
+
+// Smooth the first `size` entries of an edge, starting from the top-left pixel
+fn filter_edge(edge: &mut Edge, size: usize, strength: usize) {
+  if strength == 0 {
+    return;
+  }
+
+  let kernel = &Intra_Edge_Kernel[strength - 1];
+  let orig = *edge;
+  for i in 1..size {
+    let mut sum = 0;
+    for j in 0..5 {
+      let k = clamp(i as isize + j as isize - 2, 0, size as isize - 1);
+      sum += kernel[j] * orig[k - 1] as u32;
+    }
+    edge[i as isize - 1] = round2(sum, 4) as u8;
+  }
+}
+
+// Double the resolution of the first `num_px` entries of an edge, by interpolating
+// a new pixel between each pair of existing ones. This also fills in index -2
+fn upsample_edge(edge: &mut Edge, num_px: usize) {
+  // Copy of the edge, from index -1 to num_px - 1, with one extra pixel duplicated at each end
+  let mut dup = vec![0i32; num_px + 3];
+  dup[0] = edge[-1] as i32;
+  for i in -1 .. num_px as isize {
+    dup[(i + 2) as usize] = edge[i] as i32;
+  }
+  dup[num_px + 2] = edge[num_px as isize - 1] as i32;
+
+  edge[-2] = dup[0] as u8;
+  for i in 0..num_px {
+    let sum = -dup[i] + 9 * dup[i + 1] + 9 * dup[i + 2] - dup[i + 3];
+    edge[2 * i as isize - 1] = clamp(round2(sum, 4), 0, 255) as u8;
+    edge[2 * i as isize] = dup[i + 2] as u8;
+  }
+}
+
+// Directional prediction, where each pixel is interpolated from the above and/or left edges
+// along the given angle, in degrees. When `enable_edge_filter` is set, the edges are first
+// smoothed and possibly upsampled, depending on the block size and angle
+#[allow(clippy::too_many_arguments)]
+pub fn directional_predict(pixels: &mut Array2D<u8>, y0: usize, x0: usize, h: usize, w: usize,
+                           avail: EdgeAvailability, angle: i32, enable_edge_filter: bool) {
+  let (mut above, mut left) = build_edges(pixels, y0, x0, h, w, avail);
+  let max_x = pixels.cols() - 1;
+  let max_y = pixels.rows() - 1;
+
+  let mut upsample_above = 0;
+  let mut upsample_left = 0;
+  if enable_edge_filter {
+    if angle != 90 && angle != 180 {
+      if angle > 90 && angle < 180 && w + h >= 24 {
+        let top_left = round2(left[0] as u32 * 5 + above[-1] as u32 * 6 + above[0] as u32 * 5, 4) as u8;
+        above[-1] = top_left;
+        left[-1] = top_left;
+      }
+      if avail.above {
+        let strength = edge_filter_strength(w, h, angle - 90);
+        let num_px = min(w, max_x - x0 + 1) + if angle < 90 { h } else { 0 } + 1;
+        filter_edge(&mut above, num_px, strength);
+      }
+      if avail.left {
+        let strength = edge_filter_strength(w, h, angle - 180);
+        let num_px = min(h, max_y - y0 + 1) + if angle > 180 { w } else { 0 } + 1;
+        filter_edge(&mut left, num_px, strength);
+      }
+    }
+
+    if use_edge_upsample(w, h, angle - 90) {
+      upsample_edge(&mut above, w + if angle < 90 { h } else { 0 });
+      upsample_above = 1;
+    }
+    if use_edge_upsample(w, h, angle - 180) {
+      upsample_edge(&mut left, h + if angle > 180 { w } else { 0 });
+      upsample_left = 1;
+    }
+  }
+
+  // Interpolate between two adjacent edge pixels, with `shift` in units of 1/32 pixel
+  let interpolate = |edge: &Edge, base: isize, shift: isize| {
+    round2(edge[base] as isize * (32 - shift) + edge[base + 1] as isize * shift, 5) as u8
+  };
+
+  let pred = Array2D::new_with(h, w, |i, j| {
+    let (i, j) = (i as isize, j as isize);
+    if angle < 90 {
+      // Predict from the above row only, which extends past the right edge of the block
+      let dx = dr_intra_derivative(angle) as isize;
+      let idx = (i + 1) * dx;
+      let base = (idx >> (6 - upsample_above)) + (j << upsample_above);
+      let shift = ((idx << upsample_above) >> 1) & 31;
+      let max_base_x = ((w + h - 1) << upsample_above) as isize;
+      if base < max_base_x {
+        interpolate(&above, base, shift)
+      } else {
+        above[max_base_x]
+      }
+    } else if angle == 90 {
+      above[j]
+    } else if angle < 180 {
+      // Predict from the above row if the projected position lies along it,
+      // otherwise from the left column
+      let dx = dr_intra_derivative(180 - angle) as isize;
+      let dy = dr_intra_derivative(angle - 90) as isize;
+      let idx = (j << 6) - (i + 1) * dx;
+      let base = idx >> (6 - upsample_above);
+      if base >= -(1 << upsample_above) {
+        let shift = ((idx << upsample_above) >> 1) & 31;
+        interpolate(&above, base, shift)
+      } else {
+        let idx = (i << 6) - (j + 1) * dy;
+        let base = idx >> (6 - upsample_left);
+        let shift = ((idx << upsample_left) >> 1) & 31;
+        interpolate(&left, base, shift)
+      }
+    } else if angle == 180 {
+      left[i]
+    } else {
+      // Predict from the left column only, which extends past the bottom edge of the block
+      let dy = dr_intra_derivative(270 - angle) as isize;
+      let idx = (j + 1) * dy;
+      let base = (idx >> (6 - upsample_left)) + (i << upsample_left);
+      let shift = ((idx << upsample_left) >> 1) & 31;
+      interpolate(&left, base, shift)
+    }
+  });
+
+  pixels.paste_region(y0, x0, &pred);
+}
+
 // Transform pipeline:
 // 2d forward transform -> quantize -> dequantize -> 2d inverse transform
 // The logic here implements the "big picture" stuff, for individual transforms
@@ -65,32 +428,244 @@ pub fn compute_residual(source: &Array2D<u8>, pred: &Array2D<u8>,
   return residual;
 }
 
+// Quantizer matrices
+//
+// AV1 allows the quantizer step size to be scaled per coefficient, so that
+// high frequencies (which are less visible) can be quantized more coarsely.
+// The weights are taken from one of 16 predefined sets, selected by the qm_y,
+// qm_u, and qm_v fields in the frame header. Level 15 is defined to be flat,
+// and is also what is used when quantizer matrices are disabled.
+pub const NUM_QM_LEVELS: u8 = 16;
+pub const AOM_QM_BITS: u32 = 5;
+
+// Index of a square transform size in tables which have one entry per supported transform size.
+// This is 0 for 4x4, 1 for 8x8, 2 for 16x16, and 3 for 32x32
+pub fn txs_ctx(txsize: usize) -> usize {
+  (floor_log2(txsize) - 2) as usize
+}
+
+// Weight for coefficient (i, j) of a txsize x txsize transform block, in units of 1/32
+fn qm_weight(qm_level: u8, plane_type: usize, txsize: usize, i: usize, j: usize) -> i32 {
+  if qm_level == NUM_QM_LEVELS - 1 {
+    return 1 << AOM_QM_BITS;
+  }
+  Quantizer_Matrix[qm_level as usize][plane_type][Qm_Offset[txs_ctx(txsize)] + i * txsize + j] as i32
+}
+
+// Default quantizer matrix level for a given qindex, as used by libaom.
+// This spreads qindex values evenly over levels DEFAULT_QM_FIRST to DEFAULT_QM_LAST,
+// so lower qindex values get steeper weights
+pub const DEFAULT_QM_FIRST: u8 = 5;
+pub const DEFAULT_QM_LAST: u8 = 9;
+
+pub fn default_qm_level(qindex: i32) -> u8 {
+  let qindex = clamp(qindex, 0, 255);
+  DEFAULT_QM_FIRST + (qindex * (DEFAULT_QM_LAST + 1 - DEFAULT_QM_FIRST) as i32 / 256) as u8
+}
+
+// Quantizer settings for one plane
+// This bundles together everything needed to determine the step size for
+// each coefficient, so that quantization, dequantization, and RDO all agree
+#[derive(Clone, Copy)]
+pub struct Quantizer {
+  dc_q: i32,
+  ac_q: i32,
+  qm_level: u8,
+  plane_type: usize,
+}
+
+impl Quantizer {
+  // The DC and AC qindices are `qindex` plus the relevant frame-level deltas,
+  // clamped to the valid range as per the dc_q() and ac_q() functions in the spec
+  pub fn new(qindex: u8, dc_delta: i8, ac_delta: i8, qm_level: u8, plane: usize) -> Self {
+    assert!(qm_level < NUM_QM_LEVELS);
+    let dc_qindex = clamp(qindex as i32 + dc_delta as i32, 0, 255);
+    let ac_qindex = clamp(qindex as i32 + ac_delta as i32, 0, 255);
+    Self {
+      dc_q: qindex_to_dc_q[dc_qindex as usize],
+      ac_q: qindex_to_ac_q[ac_qindex as usize],
+      qm_level: qm_level,
+      plane_type: if plane > 0 { 1 } else { 0 },
+    }
+  }
+
+  // Step size for coefficient (i, j) of a txsize x txsize transform block
+  pub fn step(&self, i: usize, j: usize, txsize: usize) -> i32 {
+    let q = if i == 0 && j == 0 { self.dc_q } else { self.ac_q };
+    round2(q * qm_weight(self.qm_level, self.plane_type, txsize, i, j), AOM_QM_BITS)
+  }
+}
+
+// Our forward transforms scale coefficients up by a factor of 8 compared to an
+// orthonormal transform, except for 32x32, where the scale factor is only 4 so that
+// the coefficients fit in the available range. To compensate, the decoder halves the
+// dequantized values for 32x32 transforms (dqDenom in the spec).
+// We mirror this by doubling 32x32 coefficients before comparing them against the
+// quantizer step, which means that all sizes can share the same quantization and RDO logic
+pub fn dq_shift(txsize: usize) -> u32 {
+  if txsize == 32 { 1 } else { 0 }
+}
+
+// Quantizer rounding
+//
+// When quantizing a coefficient, we can choose where to put the threshold between
+// each pair of adjacent levels. Rounding to nearest minimizes the distortion, but
+// rounding values slightly above half toward zero as well (a "dead zone") saves
+// more in rate than it costs in distortion, especially for high-frequency
+// coefficients, which are mostly small.
+//
+// These offsets are in units of 1/128 of the quantizer step size: the offset is
+// added before truncating, so values are rounded up once their fractional part
+// exceeds (128 - offset)/128. So 64 means round to nearest (with halves toward zero),
+// and smaller values mean a larger dead zone.
+#[derive(Clone, Copy, Debug)]
+pub struct RoundingOffsets {
+  pub dc: i32,
+  pub low_ac: i32,
+  pub high_ac: i32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QuantizerMode {
+  // Round to nearest
+  Nearest,
+  // Fixed dead-zone rounding offsets, chosen based on frequency
+  // and on whether the block is smooth or textured
+  DeadZone,
+  // Like DeadZone, but with offsets which adapt to the statistics
+  // of the coefficients coded so far
+  Adaptive,
+}
+
+// Frequency bands, which can use different rounding offsets
+const BAND_DC: usize = 0;
+const BAND_LOW_AC: usize = 1;
+const BAND_HIGH_AC: usize = 2;
+
+fn get_band(i: usize, j: usize, txsize: usize) -> usize {
+  if i == 0 && j == 0 {
+    BAND_DC
+  } else if i + j < txsize / 2 {
+    BAND_LOW_AC
+  } else {
+    BAND_HIGH_AC
+  }
+}
+
+pub const NEAREST_ROUNDING: RoundingOffsets = RoundingOffsets { dc: 64, low_ac: 64, high_ac: 64 };
+
+// Fixed dead-zone offsets
+// Smooth blocks use offsets closer to rounding-to-nearest, as there the few nonzero
+// coefficients are important for avoiding banding
+pub const DEADZONE_ROUNDING_TEXTURED: RoundingOffsets = RoundingOffsets { dc: 56, low_ac: 48, high_ac: 40 };
+pub const DEADZONE_ROUNDING_SMOOTH: RoundingOffsets = RoundingOffsets { dc: 64, low_ac: 56, high_ac: 48 };
+
+// Statistics used to pick rounding offsets in QuantizerMode::Adaptive
+//
+// For each frequency band, and separately for smooth and textured blocks, we
+// keep a running average of the size of the coefficients relative to the
+// quantizer step. Bands where most coefficients are small relative to the
+// quantizer are sparse, and benefit from a larger dead zone; bands with
+// large coefficients are coded more efficiently with rounding closer to nearest.
+#[derive(Clone)]
+pub struct AdaptiveRounding {
+  // Indexed as [smooth][band]
+  mean_level: [[f64; 3]; 2],
+}
+
+impl AdaptiveRounding {
+  // Weight given to each new block when updating the running averages
+  const UPDATE_WEIGHT: f64 = 1.0 / 32.0;
+
+  pub fn new() -> Self {
+    Self {
+      // Start out at a level which maps to offsets similar to the fixed dead-zone values
+      mean_level: [[1.0, 0.5, 0.25]; 2],
+    }
+  }
+
+  pub fn offsets(&self, smooth: bool) -> RoundingOffsets {
+    let stats = &self.mean_level[smooth as usize];
+    // Map mean level 0 => offset 36 (large dead zone), mean level >= 1.5 => offset 60
+    let offset = |band: usize| 36 + (24.0 * stats[band].min(1.5) / 1.5).round() as i32;
+    RoundingOffsets {
+      dc: offset(BAND_DC),
+      low_ac: offset(BAND_LOW_AC),
+      high_ac: offset(BAND_HIGH_AC),
+    }
+  }
+
+  // Update statistics using the (unquantized) coefficients of a newly coded block
+  pub fn update(&mut self, smooth: bool, coeffs: &Array2D<i32>, quantizer: &Quantizer) {
+    let txsize = coeffs.rows();
+    let mut sum = [0.0f64; 3];
+    let mut count = [0usize; 3];
+    for i in 0..txsize {
+      for j in 0..txsize {
+        let band = get_band(i, j, txsize);
+        let q = quantizer.step(i, j, txsize);
+        sum[band] += (abs(coeffs[i][j] << dq_shift(txsize)) as f64) / (q as f64);
+        count[band] += 1;
+      }
+    }
+
+    let stats = &mut self.mean_level[smooth as usize];
+    for band in 0..3 {
+      let mean = sum[band] / (count[band] as f64);
+      stats[band] += Self::UPDATE_WEIGHT * (mean - stats[band]);
+    }
+  }
+}
+
+// Classify a source block as smooth or textured, for the purposes of picking
+// rounding offsets. We use the variance of the block, measured per pixel.
+pub fn is_smooth_block(source: &Array2D<u8>, y0: usize, x0: usize, h: usize, w: usize) -> bool {
+  let mut sum = 0u64;
+  let mut sum_sq = 0u64;
+  for i in 0..h {
+    for j in 0..w {
+      let px = source[y0 + i][x0 + j] as u64;
+      sum += px;
+      sum_sq += px * px;
+    }
+  }
+  let n = (h * w) as u64;
+  let variance = (sum_sq - sum * sum / n) / n;
+  return variance < 16;
+}
+
 // Quantize the coefficients in a given transform block
-pub fn quantize(residual: &mut Array2D<i32>, qindex: u8) {
-  let dc_q = qindex_to_dc_q[qindex as usize];
-  let ac_q = qindex_to_ac_q[qindex as usize];
+pub fn quantize(residual: &mut Array2D<i32>, quantizer: &Quantizer, rounding: &RoundingOffsets) {
+  let txsize = residual.rows();
+  let shift = dq_shift(txsize);
 
   residual.map(|i, j, coeff| {
-    let q = if i == 0 && j == 0 { dc_q } else { ac_q };
-    // Divide coeff by q, with rounding to nearest, halves toward 0
-    // A smaller bias can even be used, essentially rounding values slightly
-    // above half toward zero as well, to improve the average rate-distortion tradeoff -
-    // see for example QuantizationContext in rav1e.
-    // But here we take the simplest option.
-    let abs = abs(coeff);
+    let q = quantizer.step(i, j, txsize);
+    let offset = match get_band(i, j, txsize) {
+      BAND_DC => rounding.dc,
+      BAND_LOW_AC => rounding.low_ac,
+      _ => rounding.high_ac,
+    };
+    // Divide coeff by q, rounding up if the remainder is more than (128 - offset)/128 of q
+    // With offset = 64, this rounds to nearest, with halves toward 0.
+    // See RoundingOffsets for the rationale behind other values, and also
+    // optimize_coeffs() in av1_encoder.rs for a more thorough approach.
+    let bias = (q * offset - 1) >> 7;
+    let abs = abs(coeff) << shift;
     let sign = signum(coeff);
-    sign * ((abs + (q-1)/2) / q)
+    sign * ((abs + bias) / q)
   });
 }
 
-pub fn dequantize(residual: &mut Array2D<i32>, qindex: u8) {
-  let dc_q = qindex_to_dc_q[qindex as usize];
-  let ac_q = qindex_to_ac_q[qindex as usize];
+pub fn dequantize(residual: &mut Array2D<i32>, quantizer: &Quantizer) {
+  let txsize = residual.rows();
+  let shift = dq_shift(txsize);
 
   residual.map(|i, j, coeff| {
-    let q = if i == 0 && j == 0 { dc_q } else { ac_q };
-    // Simply scale the quantized coefficient by the appropriate Q
-    coeff * q
+    let q = quantizer.step(i, j, txsize);
+    // Scale the quantized coefficient by the appropriate Q. The spec applies the
+    // dq_shift() to the magnitude, so that negative values round toward zero
+    signum(coeff) * ((abs(coeff) * q) >> shift)
   });
 }
 
@@ -0,0 +1,180 @@
+// Copyright (c) 2024-2025, The tinyavif contributors. All rights reserved
+//
+// This source code is subject to the terms of the BSD 2 Clause License and
+// the Alliance for Open Media Patent License 1.0. If the BSD 2 Clause License
+// was not distributed with this source code in the LICENSE file, you can
+// obtain it at www.aomedia.org/license/software. If the Alliance for Open
+// Media Patent License 1.0 was not distributed with this source code in the
+// PATENTS file, you can obtain it at www.aomedia.org/license/patent.
+
+// Region-of-interest maps
+//
+// These let the user mark parts of the image which should be coded at higher
+// or lower quality than the rest, eg. faces or text. Each map is converted into
+// a qindex offset for each 8x8 block, which is then applied using segmentation.
+//
+// Three formats are supported:
+//
+// * A grayscale image, either as a Y4M file or a binary PGM file, the same size
+//   as the input image. 128 means no change, and each step of 2 above or below
+//   that lowers or raises the qindex by 1. So white (255) gives an offset of -63,
+//   and black (0) gives an offset of +64.
+//
+// * A text file with one rectangle per line, in the form "x y width height offset",
+//   where the position and size are in pixels and the offset is added to the qindex.
+//   Lines which are blank or start with '#' are ignored. Later rectangles take
+//   priority over earlier ones, and pixels outside every rectangle have offset 0.
+
+use std::fs::File;
+use std::path::Path;
+
+use crate::array2d::Array2D;
+use crate::util::*;
+use crate::y4m::*;
+
+// Map value which corresponds to no change in qindex
+const ROI_NEUTRAL_VALUE: i32 = 128;
+
+// Load a region-of-interest map, and convert it into a qindex offset for each 8x8 block
+// `width` and `height` are the size of the image being encoded
+pub fn read_roi_map(path: &Path, width: usize, height: usize) -> Result<Array2D<i16>, String> {
+  let ext = path.extension().and_then(|ext| ext.to_str()).unwrap_or("");
+  let pixel_offsets = match ext {
+    "y4m" => gray_to_offsets(&read_y4m_gray(path)?, width, height)?,
+    "pgm" => gray_to_offsets(&read_pgm(path)?, width, height)?,
+    _ => read_rectangles(path, width, height)?,
+  };
+
+  // Average the offsets over each 8x8 block, clamped to the image area
+  let block_rows = height.div_ceil(8);
+  let block_cols = width.div_ceil(8);
+  let block_offsets = Array2D::new_with(block_rows, block_cols, |row, col| {
+    let y1 = min(row * 8 + 8, height);
+    let x1 = min(col * 8 + 8, width);
+    let mut sum = 0i32;
+    for y in row * 8 .. y1 {
+      for x in col * 8 .. x1 {
+        sum += pixel_offsets[y][x] as i32;
+      }
+    }
+    let count = ((y1 - row * 8) * (x1 - col * 8)) as i32;
+    // Round to nearest, with ties away from zero
+    let rounded = (2 * sum.abs() + count) / (2 * count);
+    (rounded * sum.signum()) as i16
+  });
+
+  return Ok(block_offsets);
+}
+
+// Convert a grayscale map into per-pixel qindex offsets
+fn gray_to_offsets(gray: &Array2D<u8>, width: usize, height: usize) -> Result<Array2D<i16>, String> {
+  if gray.rows() != height || gray.cols() != width {
+    return Err(format!("ROI map size {}x{} does not match image size {}x{}",
+                       gray.cols(), gray.rows(), width, height));
+  }
+
+  Ok(Array2D::new_with(height, width, |y, x| {
+    // Divide by 2, rounding towards the neutral value
+    let diff = ROI_NEUTRAL_VALUE - gray[y][x] as i32;
+    (diff / 2) as i16
+  }))
+}
+
+// Read the luma plane of the first frame of a Y4M file
+fn read_y4m_gray(path: &Path) -> Result<Array2D<u8>, String> {
+  let file = File::open(path).map_err(|err| format!("Could not open ROI map: {}", err))?;
+  let mut y4m = Y4MReader::new(file).map_err(|err| format!("Could not read ROI map: {}", err))?;
+  let frame = y4m.read_frame().map_err(|err| format!("Could not read ROI map: {}", err))?;
+
+  let luma = frame.y();
+  Ok(luma.pixels().copy_region(0, 0, luma.crop_height(), luma.crop_width()))
+}
+
+// Read a binary ("P5") PGM file with 8 bits per pixel
+fn read_pgm(path: &Path) -> Result<Array2D<u8>, String> {
+  let data = std::fs::read(path).map_err(|err| format!("Could not read ROI map: {}", err))?;
+
+  // The header consists of the magic number, width, height, and maximum value,
+  // separated by whitespace. Comments run from '#' to the end of the line
+  let mut pos = 0;
+  let mut header = Vec::new();
+  while header.len() < 4 {
+    while pos < data.len() && (data[pos].is_ascii_whitespace() || data[pos] == b'#') {
+      if data[pos] == b'#' {
+        while pos < data.len() && data[pos] != b'\n' {
+          pos += 1;
+        }
+      } else {
+        pos += 1;
+      }
+    }
+    let start = pos;
+    while pos < data.len() && !data[pos].is_ascii_whitespace() {
+      pos += 1;
+    }
+    if start == pos {
+      return Err("Truncated PGM header in ROI map".to_string());
+    }
+    header.push(String::from_utf8_lossy(&data[start..pos]).into_owned());
+  }
+  // Exactly one whitespace character separates the header from the pixel data
+  pos += 1;
+
+  if header[0] != "P5" {
+    return Err("ROI map must be a binary (P5) PGM file".to_string());
+  }
+  let parse = |field: &str| {
+    field.parse::<usize>().map_err(|_| format!("Invalid value {} in PGM header of ROI map", field))
+  };
+  let width = parse(&header[1])?;
+  let height = parse(&header[2])?;
+  let maxval = parse(&header[3])?;
+  if maxval != 255 {
+    return Err("ROI map must use 8 bits per pixel (maximum value 255)".to_string());
+  }
+  if data.len() < pos + width * height {
+    return Err("Truncated pixel data in ROI map".to_string());
+  }
+
+  Ok(Array2D::new_with(height, width, |y, x| data[pos + y * width + x]))
+}
+
+// Read a list of rectangles, and convert into per-pixel qindex offsets
+fn read_rectangles(path: &Path, width: usize, height: usize) -> Result<Array2D<i16>, String> {
+  let text = std::fs::read_to_string(path).map_err(|err| format!("Could not read ROI map: {}", err))?;
+  let mut offsets = Array2D::zeroed(height, width);
+
+  for (line_idx, line) in text.lines().enumerate() {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') {
+      continue;
+    }
+
+    let bad_line = || format!("Invalid rectangle on line {} of ROI map: expected \"x y width height offset\"",
+                              line_idx + 1);
+    let fields: Vec<&str> = line.split_whitespace().collect();
+    if fields.len() != 5 {
+      return Err(bad_line());
+    }
+    let mut rect = [0usize; 4];
+    for i in 0..4 {
+      rect[i] = fields[i].parse().map_err(|_| bad_line())?;
+    }
+    let offset: i16 = fields[4].parse().map_err(|_| bad_line())?;
+    if !(-255..=255).contains(&offset) {
+      return Err(format!("Offset {} on line {} of ROI map is out of range", offset, line_idx + 1));
+    }
+
+    // Rectangles may extend past the edge of the image, in which case they are clipped
+    let [x0, y0, w, h] = rect;
+    let x1 = min(x0.saturating_add(w), width);
+    let y1 = min(y0.saturating_add(h), height);
+    for y in y0 .. y1 {
+      for x in x0 .. x1 {
+        offsets[y][x] = offset;
+      }
+    }
+  }
+
+  return Ok(offsets);
+}
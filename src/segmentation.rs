@@ -0,0 +1,234 @@
+// Copyright (c) 2024-2025, The tinyavif contributors. All rights reserved
+//
+// This source code is subject to the terms of the BSD 2 Clause License and
+// the Alliance for Open Media Patent License 1.0. If the BSD 2 Clause License
+// was not distributed with this source code in the LICENSE file, you can
+// obtain it at www.aomedia.org/license/software. If the Alliance for Open
+// Media Patent License 1.0 was not distributed with this source code in the
+// PATENTS file, you can obtain it at www.aomedia.org/license/patent.
+
+// Segmentation
+//
+// Each block can be assigned to one of up to 8 segments, and each segment can
+// override certain parameters for all of the blocks in it. For example, the
+// ALT_Q feature adds an offset to the qindex, which allows different regions
+// of the image to be coded at different qualities.
+//
+// The segment ID of each block is coded relative to a prediction made from
+// the above, left, and above-left blocks, so maps where nearby blocks share
+// the same segment are cheap to code.
+
+use crate::array2d::Array2D;
+use crate::bitcode::BitWriter;
+use crate::util::*;
+
+pub const MAX_SEGMENTS: usize = 8;
+
+// Segment feature indices
+pub const SEG_LVL_ALT_Q: usize = 0;
+pub const SEG_LVL_ALT_LF_Y_V: usize = 1;
+pub const SEG_LVL_MAX: usize = 8;
+
+// Number of bits, signedness, and maximum absolute value of each feature's data
+const Segmentation_Feature_Bits: [usize; SEG_LVL_MAX] = [8, 6, 6, 6, 6, 3, 0, 0];
+const Segmentation_Feature_Signed: [bool; SEG_LVL_MAX] = [true, true, true, true, true, false, false, false];
+const Segmentation_Feature_Max: [i32; SEG_LVL_MAX] = [255, 63, 63, 63, 63, 7, 0, 0];
+
+// Parameter overrides for a single segment
+// Each feature is None if disabled, or the value to use if enabled
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub struct SegmentFeatures {
+  // Offset added to the qindex
+  pub alt_q: Option<i16>,
+
+  // Offsets added to the loop filter levels, in the order
+  // (luma vertical edges, luma horizontal edges, U, V)
+  pub alt_lf: [Option<i8>; 4],
+}
+
+impl SegmentFeatures {
+  // Gather the feature values into the order used by the spec
+  fn feature_data(&self) -> [Option<i32>; SEG_LVL_MAX] {
+    let mut data = [None; SEG_LVL_MAX];
+    data[SEG_LVL_ALT_Q] = self.alt_q.map(|value| value as i32);
+    for i in 0..4 {
+      data[SEG_LVL_ALT_LF_Y_V + i] = self.alt_lf[i].map(|value| value as i32);
+    }
+    return data;
+  }
+
+  fn is_active(&self) -> bool {
+    self.feature_data().iter().any(|feature| feature.is_some())
+  }
+}
+
+// Segmentation settings for one frame
+#[derive(Clone, Debug)]
+pub struct Segmentation {
+  pub features: [SegmentFeatures; MAX_SEGMENTS],
+
+  // Segment ID for each 4x4 luma unit
+  // All of the units within a block must have the same segment ID
+  pub map: Array2D<u8>,
+}
+
+impl Segmentation {
+  // Build a segmentation which applies the given qindex to each 8x8 block,
+  // using the ALT_Q feature
+  //
+  // If there are more than MAX_SEGMENTS distinct values, the range of values is
+  // split into MAX_SEGMENTS evenly spaced levels and each block is rounded to the
+  // closest one. Segment IDs are given out in order of qindex, so that similar
+  // segments have nearby IDs and are cheaper to code relative to each other.
+  pub fn from_block_qindex(block_qindex: &Array2D<u8>, base_qindex: u8) -> Self {
+    let block_rows = block_qindex.rows();
+    let block_cols = block_qindex.cols();
+
+    let mut used = [false; 256];
+    for row in 0..block_rows {
+      for col in 0..block_cols {
+        used[block_qindex[row][col] as usize] = true;
+      }
+    }
+    let mut levels: Vec<u8> = (0..=255).filter(|&qindex| used[qindex as usize]).collect();
+    if levels.len() > MAX_SEGMENTS {
+      let lo = levels[0] as usize;
+      let hi = levels[levels.len() - 1] as usize;
+      levels = (0..MAX_SEGMENTS)
+        .map(|k| (lo + round2((hi - lo) * 2 * k / (MAX_SEGMENTS - 1), 1)) as u8)
+        .collect();
+    }
+
+    // Assign each block to its closest level
+    let block_level = Array2D::new_with(block_rows, block_cols, |row, col| {
+      let qindex = block_qindex[row][col];
+      (0..levels.len()).min_by_key(|&level| levels[level].abs_diff(qindex)).unwrap()
+    });
+
+    // Only give out segment IDs to levels which are actually used
+    let mut level_used = vec![false; levels.len()];
+    for row in 0..block_rows {
+      for col in 0..block_cols {
+        level_used[block_level[row][col]] = true;
+      }
+    }
+    let mut features = [SegmentFeatures::default(); MAX_SEGMENTS];
+    let mut level_segment = vec![0u8; levels.len()];
+    let mut num_segments = 0;
+    for level in 0..levels.len() {
+      if level_used[level] {
+        features[num_segments].alt_q = Some(levels[level] as i16 - base_qindex as i16);
+        level_segment[level] = num_segments as u8;
+        num_segments += 1;
+      }
+    }
+
+    // Expand to a map with one entry per 4x4 luma unit
+    let map = Array2D::new_with(block_rows * 2, block_cols * 2, |mi_row, mi_col| {
+      level_segment[block_level[mi_row / 2][mi_col / 2]]
+    });
+
+    Self {
+      features: features,
+      map: map,
+    }
+  }
+
+  // Highest segment ID with any features enabled. Segment IDs above this can't be coded
+  pub fn last_active_seg_id(&self) -> usize {
+    (0..MAX_SEGMENTS).rev()
+      .find(|&segment_id| self.features[segment_id].is_active())
+      .unwrap_or(0)
+  }
+
+  // Apply the ALT_Q feature for a given segment, if enabled
+  // This corresponds to get_qidx() in the spec
+  pub fn get_qindex(&self, segment_id: u8, qindex: u8) -> u8 {
+    match self.features[segment_id as usize].alt_q {
+      Some(alt_q) => clamp(qindex as i32 + alt_q as i32, 0, 255) as u8,
+      None => qindex,
+    }
+  }
+
+  // Write segmentation_params() for a frame with segmentation enabled
+  // As we only generate intra frames, the map and data are always explicitly
+  // coded, so segmentation_update_map and segmentation_update_data are implied
+  pub fn write_params(&self, w: &mut BitWriter) {
+    w.write_bit(1); // Segmentation enabled
+
+    for segment_id in 0..MAX_SEGMENTS {
+      let data = self.features[segment_id].feature_data();
+      for feature in 0..SEG_LVL_MAX {
+        match data[feature] {
+          None => {
+            w.write_bit(0); // Feature disabled
+          },
+          Some(value) => {
+            w.write_bit(1); // Feature enabled
+            let bits = Segmentation_Feature_Bits[feature];
+            let limit = Segmentation_Feature_Max[feature];
+            if Segmentation_Feature_Signed[feature] {
+              assert!(-limit <= value && value <= limit);
+              w.write_signed_bits(value as i64, 1 + bits);
+            } else {
+              assert!(0 <= value && value <= limit);
+              w.write_bits(value as u64, bits);
+            }
+          }
+        }
+      }
+    }
+  }
+}
+
+// Predict the segment ID of a block from its neighbours, and select
+// the context to use when coding the difference. The arguments are the
+// segment IDs of the above-left, above, and left blocks, if available.
+// Returns (prediction, context)
+pub fn predict_segment_id(prev_ul: Option<u8>, prev_u: Option<u8>, prev_l: Option<u8>) -> (u8, usize) {
+  let pred = match (prev_u, prev_l) {
+    (None, None) => 0,
+    (None, Some(l)) => l,
+    (Some(u), None) => u,
+    (Some(u), Some(l)) => if prev_ul == Some(u) { u } else { l },
+  };
+
+  let ctx = match prev_ul {
+    None => 0,
+    Some(ul) if Some(ul) == prev_u && Some(ul) == prev_l => 2,
+    Some(ul) if Some(ul) == prev_u || Some(ul) == prev_l || prev_u == prev_l => 1,
+    _ => 0,
+  };
+
+  return (pred, ctx);
+}
+
+// Map a segment ID to the symbol used to code it, given the predicted ID.
+// This puts values close to the prediction first, alternating above and below it,
+// so that the most likely values get the smallest symbols.
+// This is the inverse of neg_deinterleave() in the spec
+pub fn neg_interleave(x: usize, pred: usize, max: usize) -> usize {
+  assert!(x < max);
+  let diff = x as isize - pred as isize;
+  let interleaved = |diff: isize| {
+    if diff > 0 { (2 * diff - 1) as usize } else { (-2 * diff) as usize }
+  };
+
+  if pred == 0 {
+    return x;
+  }
+  if pred >= max - 1 {
+    return max - 1 - x;
+  }
+  if 2 * pred < max {
+    if diff.unsigned_abs() <= pred {
+      return interleaved(diff);
+    }
+    return x;
+  } else {
+    if diff.unsigned_abs() < max - pred {
+      return interleaved(diff);
+    }
+    return max - 1 - x;
+  }
+}
@@ -0,0 +1,294 @@
+// Copyright (c) 2024-2025, The tinyavif contributors. All rights reserved
+//
+// This source code is subject to the terms of the BSD 2 Clause License and
+// the Alliance for Open Media Patent License 1.0. If the BSD 2 Clause License
+// was not distributed with this source code in the LICENSE file, you can
+// obtain it at www.aomedia.org/license/software. If the Alliance for Open
+// Media Patent License 1.0 was not distributed with this source code in the
+// PATENTS file, you can obtain it at www.aomedia.org/license/patent.
+
+// Sequence header settings
+//
+// AV1 has two forms of sequence header. The reduced_still_picture_header form is a few
+// bytes shorter, but can only describe a single still keyframe: it has no timing or
+// decoder model info, only one operating point (with an implied Main tier), and
+// implicitly disables all of the inter prediction tools.
+//
+// The full form signals all of these explicitly, and is needed for anything other than
+// a single still image. Some tools also refuse to accept reduced headers in .obu files.
+//
+// The parts of the sequence header which depend on the image itself (frame size,
+// superblock size, colour config, ...) are handled by AV1Encoder::generate_sequence_header.
+// The settings here are the ones which describe the stream as a whole.
+
+use crate::bitcode::BitWriter;
+use crate::level::*;
+
+pub struct TimingInfo {
+  pub num_units_in_display_tick: u32,
+  pub time_scale: u32,
+  // Number of ticks each picture is displayed for, if this is the same for all pictures
+  pub num_ticks_per_picture: Option<u32>,
+}
+
+// Parameters for the decoder model, which lets the encoder promise that the stream
+// can be decoded with a certain amount of buffering. The lengths are in bits, 1-32
+pub struct DecoderModelInfo {
+  pub buffer_delay_length: usize,
+  pub num_units_in_decoding_tick: u32,
+  pub buffer_removal_time_length: usize,
+  pub frame_presentation_time_length: usize,
+}
+
+// Per-operating-point decoder model parameters
+pub struct OperatingParameters {
+  pub decoder_buffer_delay: u32,
+  pub encoder_buffer_delay: u32,
+  pub low_delay_mode: bool,
+}
+
+pub struct OperatingPoint {
+  // Bitmask of which temporal layers (bits 0-7) and spatial layers (bits 8-11) are decoded
+  // for this operating point. 0 means that all layers are decoded
+  pub idc: u16,
+  pub seq_level_idx: u8,
+  pub seq_tier: Tier,
+  // Only used if the sequence has decoder model info
+  pub operating_parameters: Option<OperatingParameters>,
+  // Number of decoded frames to buffer before display starts, 1-10
+  pub initial_display_delay: Option<u8>,
+}
+
+// Frame ID numbers let decoders detect lost reference frames.
+// The lengths are in bits, with delta_frame_id_length in 2-17 and
+// additional_frame_id_length in 1-8
+pub struct FrameIdNumbers {
+  pub delta_frame_id_length: usize,
+  pub additional_frame_id_length: usize,
+}
+
+impl FrameIdNumbers {
+  // Number of bits used for each frame ID
+  pub fn frame_id_length(&self) -> usize {
+    self.delta_frame_id_length + self.additional_frame_id_length
+  }
+}
+
+pub struct SequenceHeaderConfig {
+  // Use the reduced_still_picture_header form. If set, only the level of the first
+  // operating point is signalled, and everything else must be left at its default
+  pub reduced_still_picture_header: bool,
+  pub still_picture: bool,
+
+  pub timing_info: Option<TimingInfo>,
+  // Requires timing info to be present
+  pub decoder_model_info: Option<DecoderModelInfo>,
+  pub operating_points: Vec<OperatingPoint>,
+
+  pub frame_id_numbers: Option<FrameIdNumbers>,
+
+  // Inter prediction tools
+  pub enable_interintra_compound: bool,
+  pub enable_masked_compound: bool,
+  pub enable_warped_motion: bool,
+  pub enable_dual_filter: bool,
+  // Number of bits used for order hints, 1-8, or None to disable order hints.
+  // The two tools after this require order hints
+  pub order_hint_bits: Option<usize>,
+  pub enable_jnt_comp: bool,
+  pub enable_ref_frame_mvs: bool,
+}
+
+impl SequenceHeaderConfig {
+  // Settings for a single still image, with one operating point at the given level
+  pub fn still_image(reduced_still_picture_header: bool, seq_level_idx: u8, seq_tier: Tier) -> Self {
+    Self {
+      reduced_still_picture_header: reduced_still_picture_header,
+      still_picture: true,
+      timing_info: None,
+      decoder_model_info: None,
+      operating_points: vec![OperatingPoint {
+        idc: 0,
+        seq_level_idx: seq_level_idx,
+        seq_tier: seq_tier,
+        operating_parameters: None,
+        initial_display_delay: None,
+      }],
+      frame_id_numbers: None,
+      enable_interintra_compound: false,
+      enable_masked_compound: false,
+      enable_warped_motion: false,
+      enable_dual_filter: false,
+      order_hint_bits: None,
+      enable_jnt_comp: false,
+      enable_ref_frame_mvs: false,
+    }
+  }
+
+  // Set the level and tier of every operating point. We only produce single-layer
+  // streams, so every operating point decodes the same frames
+  pub fn set_level(&mut self, seq_level_idx: u8, seq_tier: Tier) {
+    for op in self.operating_points.iter_mut() {
+      op.seq_level_idx = seq_level_idx;
+      op.seq_tier = seq_tier;
+    }
+  }
+
+  // The tier is only coded for levels 4.0 and up, and only in the full sequence header
+  pub fn allow_high_tier(&self) -> bool {
+    !self.reduced_still_picture_header
+  }
+
+  pub fn validate(&self) -> Result<(), String> {
+    let num_ops = self.operating_points.len();
+    if !(1..=32).contains(&num_ops) {
+      return Err(format!("Must have 1-32 operating points, not {}", num_ops));
+    }
+
+    if self.reduced_still_picture_header {
+      let op = &self.operating_points[0];
+      if !self.still_picture || self.timing_info.is_some() || self.decoder_model_info.is_some() ||
+         num_ops != 1 || op.idc != 0 || op.seq_tier != Tier::Main || op.initial_display_delay.is_some() ||
+         self.frame_id_numbers.is_some() || self.enable_interintra_compound || self.enable_masked_compound ||
+         self.enable_warped_motion || self.enable_dual_filter || self.order_hint_bits.is_some() {
+        return Err("These sequence header settings require a full sequence header".to_string());
+      }
+      return Ok(());
+    }
+
+    if let Some(timing_info) = &self.timing_info {
+      if timing_info.num_units_in_display_tick == 0 || timing_info.time_scale == 0 {
+        return Err("Timing info must have nonzero tick length and time scale".to_string());
+      }
+      if timing_info.num_ticks_per_picture == Some(0) {
+        return Err("Timing info must have at least one tick per picture".to_string());
+      }
+    }
+
+    if let Some(info) = &self.decoder_model_info {
+      if self.timing_info.is_none() {
+        return Err("Decoder model info requires timing info".to_string());
+      }
+      if info.num_units_in_decoding_tick == 0 {
+        return Err("Decoder model info must have a nonzero decoding tick length".to_string());
+      }
+      for length in [info.buffer_delay_length, info.buffer_removal_time_length, info.frame_presentation_time_length] {
+        if !(1..=32).contains(&length) {
+          return Err("Decoder model field lengths must be 1-32 bits".to_string());
+        }
+      }
+    }
+
+    for op in &self.operating_points {
+      if op.idc >= (1 << 12) {
+        return Err(format!("Operating point idc {:#x} is too large (must be 12 bits)", op.idc));
+      }
+      if op.seq_tier == Tier::High && op.seq_level_idx <= 7 {
+        return Err(format!("Level {} does not have a High tier", LevelName(op.seq_level_idx)));
+      }
+      if let Some(params) = &op.operating_parameters {
+        let info = self.decoder_model_info.as_ref()
+          .ok_or("Operating parameters require decoder model info")?;
+        let limit = 1u64 << info.buffer_delay_length;
+        if params.decoder_buffer_delay as u64 >= limit || params.encoder_buffer_delay as u64 >= limit {
+          return Err(format!("Buffer delays must fit in {} bits", info.buffer_delay_length));
+        }
+      }
+      if let Some(delay) = op.initial_display_delay {
+        if !(1..=10).contains(&delay) {
+          return Err(format!("Initial display delay must be 1-10 frames, not {}", delay));
+        }
+      }
+    }
+
+    if let Some(frame_ids) = &self.frame_id_numbers {
+      if frame_ids.delta_frame_id_length < 2 || frame_ids.delta_frame_id_length > 17 ||
+         frame_ids.additional_frame_id_length < 1 || frame_ids.additional_frame_id_length > 8 ||
+         frame_ids.frame_id_length() > 16 {
+        return Err("Invalid frame ID lengths".to_string());
+      }
+    }
+
+    match self.order_hint_bits {
+      Some(bits) if !(1..=8).contains(&bits) => {
+        return Err(format!("Order hints must use 1-8 bits, not {}", bits));
+      },
+      None if self.enable_jnt_comp || self.enable_ref_frame_mvs => {
+        return Err("Distance-weighted compound and reference frame MVs require order hints".to_string());
+      },
+      _ => {}
+    }
+
+    return Ok(());
+  }
+
+  // Write everything from the timing info to the end of the operating points,
+  // which is only present in the full sequence header
+  pub fn write_operating_points(&self, w: &mut BitWriter) {
+    w.write_bool(self.timing_info.is_some());
+    if let Some(timing_info) = &self.timing_info {
+      w.write_bits(timing_info.num_units_in_display_tick as u64, 32);
+      w.write_bits(timing_info.time_scale as u64, 32);
+      w.write_bool(timing_info.num_ticks_per_picture.is_some()); // equal_picture_interval
+      if let Some(num_ticks) = timing_info.num_ticks_per_picture {
+        w.write_uvlc(num_ticks - 1);
+      }
+
+      w.write_bool(self.decoder_model_info.is_some());
+      if let Some(info) = &self.decoder_model_info {
+        w.write_bits((info.buffer_delay_length - 1) as u64, 5);
+        w.write_bits(info.num_units_in_decoding_tick as u64, 32);
+        w.write_bits((info.buffer_removal_time_length - 1) as u64, 5);
+        w.write_bits((info.frame_presentation_time_length - 1) as u64, 5);
+      }
+    }
+
+    let initial_display_delay_present = self.operating_points.iter().any(|op| op.initial_display_delay.is_some());
+    w.write_bool(initial_display_delay_present);
+    w.write_bits((self.operating_points.len() - 1) as u64, 5);
+    for op in &self.operating_points {
+      w.write_bits(op.idc as u64, 12);
+      w.write_bits(op.seq_level_idx as u64, 5);
+      if op.seq_level_idx > 7 {
+        w.write_bits(op.seq_tier as u64, 1);
+      }
+      if let Some(info) = &self.decoder_model_info {
+        w.write_bool(op.operating_parameters.is_some()); // decoder_model_present_for_this_op
+        if let Some(params) = &op.operating_parameters {
+          w.write_bits(params.decoder_buffer_delay as u64, info.buffer_delay_length);
+          w.write_bits(params.encoder_buffer_delay as u64, info.buffer_delay_length);
+          w.write_bool(params.low_delay_mode);
+        }
+      }
+      if initial_display_delay_present {
+        w.write_bool(op.initial_display_delay.is_some());
+        if let Some(delay) = op.initial_display_delay {
+          w.write_bits((delay - 1) as u64, 4);
+        }
+      }
+    }
+  }
+
+  // Write the inter prediction tool flags and order hint settings,
+  // which is only present in the full sequence header
+  pub fn write_inter_tools(&self, w: &mut BitWriter) {
+    w.write_bool(self.enable_interintra_compound);
+    w.write_bool(self.enable_masked_compound);
+    w.write_bool(self.enable_warped_motion);
+    w.write_bool(self.enable_dual_filter);
+    w.write_bool(self.order_hint_bits.is_some());
+    if self.order_hint_bits.is_some() {
+      w.write_bool(self.enable_jnt_comp);
+      w.write_bool(self.enable_ref_frame_mvs);
+    }
+
+    // Let each frame choose whether to use screen content tools, and whether to force
+    // integer motion vectors, matching what the reduced header implies
+    w.write_bit(1); // seq_choose_screen_content_tools
+    w.write_bit(1); // seq_choose_integer_mv
+
+    if let Some(bits) = self.order_hint_bits {
+      w.write_bits((bits - 1) as u64, 3);
+    }
+  }
+}
@@ -0,0 +1,173 @@
+// Copyright (c) 2024-2025, The tinyavif contributors. All rights reserved
+//
+// This source code is subject to the terms of the BSD 2 Clause License and
+// the Alliance for Open Media Patent License 1.0. If the BSD 2 Clause License
+// was not distributed with this source code in the LICENSE file, you can
+// obtain it at www.aomedia.org/license/software. If the Alliance for Open
+// Media Patent License 1.0 was not distributed with this source code in the
+// PATENTS file, you can obtain it at www.aomedia.org/license/patent.
+
+// Horizontal super-resolution (superres)
+//
+// At very low bitrates, it can be better to code a frame at a reduced width and
+// scale it back up afterwards, rather than spreading the few available bits over
+// every pixel. AV1 supports this for the horizontal direction only: the frame is
+// coded at a width of (8 / denom) times the output width, for denom = 9..16, and the
+// decoder then upscales each row using a fixed 8-tap filter.
+//
+// The downscaling is up to the encoder, but the upscaling is normative, so we
+// have to replicate it exactly in order to know what the decoder will output.
+
+use crate::consts::*;
+use crate::frame::Frame;
+use crate::util::*;
+
+// The denominator is coded as (denom - SUPERRES_DENOM_MIN) in SUPERRES_DENOM_BITS bits,
+// and a value of SUPERRES_NUM means no scaling
+pub const SUPERRES_NUM: u8 = 8;
+pub const SUPERRES_DENOM_MIN: u8 = 9;
+pub const SUPERRES_DENOM_BITS: usize = 3;
+
+// The downscaled frame must be at least this wide
+pub const SUPERRES_MIN_WIDTH: usize = 16;
+
+// Positions in the upscaling process are tracked in units of 1/(1 << SUPERRES_SCALE_BITS) pixel,
+// of which the top 6 fractional bits select the filter
+const SUPERRES_FILTER_TAPS: i32 = 8;
+const SUPERRES_SCALE_BITS: u32 = 14;
+const SUPERRES_SCALE_MASK: i32 = (1 << SUPERRES_SCALE_BITS) - 1;
+const SUPERRES_EXTRA_BITS: u32 = 8;
+const FILTER_BITS: u32 = 7;
+
+// Width at which a frame of the given width is coded, using the given denominator
+pub fn superres_downscaled_width(upscaled_width: usize, denom: u8) -> usize {
+  let denom = denom as usize;
+  (upscaled_width * SUPERRES_NUM as usize + denom / 2) / denom
+}
+
+// Scale each plane of a frame down horizontally to the given luma width, for encoding.
+// This isn't specified by AV1, so we use a simple triangle filter, widened in proportion
+// to the scale factor to avoid aliasing
+pub fn downscale_frame(source: &Frame, width: usize) -> Frame {
+  let mut scaled = Frame::new(source.y().crop_height(), width);
+
+  for plane in 0..3 {
+    let src = source.plane(plane);
+    let src_width = src.crop_width();
+    let dst_width = scaled.plane(plane).crop_width();
+    let scale = src_width as f64 / dst_width as f64;
+
+    for x in 0..dst_width {
+      // Centre of this output pixel, in input pixel coordinates
+      let center = (x as f64 + 0.5) * scale - 0.5;
+      let first = (center - scale).floor() as isize;
+      let last = (center + scale).ceil() as isize;
+      let taps: Vec<(usize, f64)> = (first..=last).map(|i| {
+        let weight = (1.0 - (i as f64 - center).abs() / scale).max(0.0);
+        (clamp(i, 0, src_width as isize - 1) as usize, weight)
+      }).collect();
+      let total_weight: f64 = taps.iter().map(|&(_, weight)| weight).sum();
+
+      for y in 0..src.crop_height() {
+        let value: f64 = taps.iter().map(|&(i, weight)| src.pixels()[y][i] as f64 * weight).sum();
+        scaled.plane_mut(plane).pixels_mut()[y][x] = (value / total_weight).round().clamp(0.0, 255.0) as u8;
+      }
+    }
+    scaled.plane_mut(plane).fill_padding();
+  }
+
+  return scaled;
+}
+
+// Scale each plane of a decoded frame up horizontally to the given luma width,
+// exactly as the decoder does. This corresponds to the upscaling process in the spec
+pub fn upscale_frame(frame: &Frame, upscaled_width: usize) -> Frame {
+  let mut upscaled = Frame::new(frame.y().crop_height(), upscaled_width);
+
+  for plane in 0..3 {
+    let src = frame.plane(plane);
+    let downscaled_plane_width = src.crop_width() as i32;
+    let upscaled_plane_width = upscaled.plane(plane).crop_width() as i32;
+
+    // Step between output pixels, and position of the first output pixel,
+    // in units of 1/(1 << SUPERRES_SCALE_BITS) input pixels
+    let step = ((downscaled_plane_width << SUPERRES_SCALE_BITS) + upscaled_plane_width / 2) / upscaled_plane_width;
+    let err = upscaled_plane_width * step - (downscaled_plane_width << SUPERRES_SCALE_BITS);
+    let initial_subpel_x = ((-((upscaled_plane_width - downscaled_plane_width) << (SUPERRES_SCALE_BITS - 1))
+                             + upscaled_plane_width / 2) / upscaled_plane_width
+                            + (1 << (SUPERRES_EXTRA_BITS - 1)) - err / 2) & SUPERRES_SCALE_MASK;
+
+    for y in 0..src.crop_height() {
+      let row = &src.pixels()[y];
+      for x in 0..upscaled_plane_width {
+        let src_x = initial_subpel_x + x * step;
+        let filter = &Upscale_Filter[((src_x & SUPERRES_SCALE_MASK) >> SUPERRES_EXTRA_BITS) as usize];
+        let mut sum = 0;
+        for k in 0..SUPERRES_FILTER_TAPS {
+          // Reads past the left edge, or past the end of the last 4x4 block on the right,
+          // are clamped to the edge. Note that this means pixels between the crop width and
+          // the padded width are used, so they need to match what the decoder has there
+          let sample_x = (src_x >> SUPERRES_SCALE_BITS) + k - SUPERRES_FILTER_TAPS / 2;
+          sum += row[clamp(sample_x, 0, src.width() as i32 - 1) as usize] as i32 * filter[k as usize];
+        }
+        upscaled.plane_mut(plane).pixels_mut()[y][x as usize] = clamp(round2(sum, FILTER_BITS), 0, 255) as u8;
+      }
+    }
+    upscaled.plane_mut(plane).fill_padding();
+  }
+
+  return upscaled;
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Build a frame where every row of each plane is the given list of pixels
+  fn frame_from_rows(height: usize, luma_row: &[u8], chroma_row: &[u8]) -> Frame {
+    let mut frame = Frame::new(height, luma_row.len());
+    for plane in 0..3 {
+      let row = if plane == 0 { luma_row } else { chroma_row };
+      let pixels = frame.plane_mut(plane).pixels_mut();
+      for y in 0..pixels.rows() {
+        pixels[y][..row.len()].copy_from_slice(row);
+      }
+      frame.plane_mut(plane).fill_padding();
+    }
+    return frame;
+  }
+
+  #[test]
+  fn upscale_known_rows() {
+    // Expected outputs were computed independently from the spec's upscaling process.
+    // A ramp should be preserved, apart from the edges, where the input is clamped
+    let luma_ramp: Vec<u8> = (0..16).map(|x| x * 16).collect();
+    let chroma_ramp: Vec<u8> = (0..8).map(|x| x * 32).collect();
+    let upscaled = upscale_frame(&frame_from_rows(8, &luma_ramp, &chroma_ramp), 32);
+    assert_eq!(upscaled.y().crop_width(), 32);
+    assert_eq!(upscaled.u().crop_width(), 16);
+
+    let expected_luma: [u8; 32] = [
+      0, 3, 11, 20, 28, 36, 44, 52, 60, 68, 76, 84, 92, 100, 108, 116,
+      124, 132, 140, 148, 156, 164, 172, 180, 188, 196, 204, 212, 220, 229, 237, 241
+    ];
+    let expected_chroma: [u8; 16] = [0, 6, 22, 41, 57, 72, 88, 104, 120, 136, 152, 168, 183, 202, 219, 227];
+    for y in 0..8 {
+      assert_eq!(upscaled.y().pixels()[y][..32], expected_luma);
+    }
+    for plane in 1..3 {
+      for y in 0..4 {
+        assert_eq!(upscaled.plane(plane).pixels()[y][..16], expected_chroma);
+      }
+    }
+
+    // A hard edge rings slightly on either side
+    let luma_edge: Vec<u8> = (0..16).map(|x| if x < 8 { 0 } else { 255 }).collect();
+    let upscaled = upscale_frame(&frame_from_rows(8, &luma_edge, &chroma_ramp), 32);
+    let expected_luma: [u8; 32] = [
+      0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 8, 0, 0, 58,
+      197, 255, 255, 247, 249, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255
+    ];
+    assert_eq!(upscaled.y().pixels()[0][..32], expected_luma);
+  }
+}
@@ -7,14 +7,17 @@
 // Media Patent License 1.0 was not distributed with this source code in the
 // PATENTS file, you can obtain it at www.aomedia.org/license/patent.
 
-// Forward and inverse DCT4 and DCT8 transforms
+// Forward and inverse DCT transforms, for sizes 4x4 through 32x32
 
 use crate::array2d::Array2D;
 use crate::consts::*;
 use crate::util::*;
 
+// Signature shared by the 1D transforms, so that fwd_txfm2d() and inv_txfm2d() can pick one at runtime
+type Txfm1D = dyn Fn(&mut [i32], u32, &[u32]);
+
 fn cospi_arr(cos_bit: u32) -> &'static [i32; 64] {
-  assert!(10 <= cos_bit && cos_bit <= 13);
+  assert!((10..=13).contains(&cos_bit));
   &av1_cospi_arr_data[(cos_bit - 10) as usize]
 }
 
@@ -162,6 +165,472 @@ fn fwd_dct8(arr: &mut [i32], cos_bit: u32, _stage_range: &[u32]) {
   arr.copy_from_slice(&stage5);
 }
 
+// In-place 16-point forward DCT
+fn fwd_dct16(arr: &mut [i32], cos_bit: u32, _stage_range: &[u32]) {
+  assert!(arr.len() == 16);
+
+  let cospi = cospi_arr(cos_bit);
+
+  let stage1 = [
+    arr[0] + arr[15],
+    arr[1] + arr[14],
+    arr[2] + arr[13],
+    arr[3] + arr[12],
+    arr[4] + arr[11],
+    arr[5] + arr[10],
+    arr[6] + arr[9],
+    arr[7] + arr[8],
+    -arr[8] + arr[7],
+    -arr[9] + arr[6],
+    -arr[10] + arr[5],
+    -arr[11] + arr[4],
+    -arr[12] + arr[3],
+    -arr[13] + arr[2],
+    -arr[14] + arr[1],
+    -arr[15] + arr[0],
+  ];
+
+  let stage2 = [
+    stage1[0] + stage1[7],
+    stage1[1] + stage1[6],
+    stage1[2] + stage1[5],
+    stage1[3] + stage1[4],
+    -stage1[4] + stage1[3],
+    -stage1[5] + stage1[2],
+    -stage1[6] + stage1[1],
+    -stage1[7] + stage1[0],
+    stage1[8],
+    stage1[9],
+    half_btf(-cospi[32], stage1[10], cospi[32], stage1[13], cos_bit),
+    half_btf(-cospi[32], stage1[11], cospi[32], stage1[12], cos_bit),
+    half_btf(cospi[32], stage1[12], cospi[32], stage1[11], cos_bit),
+    half_btf(cospi[32], stage1[13], cospi[32], stage1[10], cos_bit),
+    stage1[14],
+    stage1[15],
+  ];
+
+  let stage3 = [
+    stage2[0] + stage2[3],
+    stage2[1] + stage2[2],
+    -stage2[2] + stage2[1],
+    -stage2[3] + stage2[0],
+    stage2[4],
+    half_btf(-cospi[32], stage2[5], cospi[32], stage2[6], cos_bit),
+    half_btf(cospi[32], stage2[6], cospi[32], stage2[5], cos_bit),
+    stage2[7],
+    stage2[8] + stage2[11],
+    stage2[9] + stage2[10],
+    -stage2[10] + stage2[9],
+    -stage2[11] + stage2[8],
+    -stage2[12] + stage2[15],
+    -stage2[13] + stage2[14],
+    stage2[14] + stage2[13],
+    stage2[15] + stage2[12],
+  ];
+
+  let stage4 = [
+    half_btf(cospi[32], stage3[0], cospi[32], stage3[1], cos_bit),
+    half_btf(-cospi[32], stage3[1], cospi[32], stage3[0], cos_bit),
+    half_btf(cospi[48], stage3[2], cospi[16], stage3[3], cos_bit),
+    half_btf(cospi[48], stage3[3], -cospi[16], stage3[2], cos_bit),
+    stage3[4] + stage3[5],
+    -stage3[5] + stage3[4],
+    -stage3[6] + stage3[7],
+    stage3[7] + stage3[6],
+    stage3[8],
+    half_btf(-cospi[16], stage3[9], cospi[48], stage3[14], cos_bit),
+    half_btf(-cospi[48], stage3[10], -cospi[16], stage3[13], cos_bit),
+    stage3[11],
+    stage3[12],
+    half_btf(cospi[48], stage3[13], -cospi[16], stage3[10], cos_bit),
+    half_btf(cospi[16], stage3[14], cospi[48], stage3[9], cos_bit),
+    stage3[15],
+  ];
+
+  let stage5 = [
+    stage4[0],
+    stage4[1],
+    stage4[2],
+    stage4[3],
+    half_btf(cospi[56], stage4[4], cospi[8], stage4[7], cos_bit),
+    half_btf(cospi[24], stage4[5], cospi[40], stage4[6], cos_bit),
+    half_btf(cospi[24], stage4[6], -cospi[40], stage4[5], cos_bit),
+    half_btf(cospi[56], stage4[7], -cospi[8], stage4[4], cos_bit),
+    stage4[8] + stage4[9],
+    -stage4[9] + stage4[8],
+    -stage4[10] + stage4[11],
+    stage4[11] + stage4[10],
+    stage4[12] + stage4[13],
+    -stage4[13] + stage4[12],
+    -stage4[14] + stage4[15],
+    stage4[15] + stage4[14],
+  ];
+
+  let stage6 = [
+    stage5[0],
+    stage5[1],
+    stage5[2],
+    stage5[3],
+    stage5[4],
+    stage5[5],
+    stage5[6],
+    stage5[7],
+    half_btf(cospi[60], stage5[8], cospi[4], stage5[15], cos_bit),
+    half_btf(cospi[28], stage5[9], cospi[36], stage5[14], cos_bit),
+    half_btf(cospi[44], stage5[10], cospi[20], stage5[13], cos_bit),
+    half_btf(cospi[12], stage5[11], cospi[52], stage5[12], cos_bit),
+    half_btf(cospi[12], stage5[12], -cospi[52], stage5[11], cos_bit),
+    half_btf(cospi[44], stage5[13], -cospi[20], stage5[10], cos_bit),
+    half_btf(cospi[28], stage5[14], -cospi[36], stage5[9], cos_bit),
+    half_btf(cospi[60], stage5[15], -cospi[4], stage5[8], cos_bit),
+  ];
+
+  let stage7 = [
+    stage6[0],
+    stage6[8],
+    stage6[4],
+    stage6[12],
+    stage6[2],
+    stage6[10],
+    stage6[6],
+    stage6[14],
+    stage6[1],
+    stage6[9],
+    stage6[5],
+    stage6[13],
+    stage6[3],
+    stage6[11],
+    stage6[7],
+    stage6[15],
+  ];
+
+  arr.copy_from_slice(&stage7);
+}
+
+// In-place 32-point forward DCT
+fn fwd_dct32(arr: &mut [i32], cos_bit: u32, _stage_range: &[u32]) {
+  assert!(arr.len() == 32);
+
+  let cospi = cospi_arr(cos_bit);
+
+  let stage1 = [
+    arr[0] + arr[31],
+    arr[1] + arr[30],
+    arr[2] + arr[29],
+    arr[3] + arr[28],
+    arr[4] + arr[27],
+    arr[5] + arr[26],
+    arr[6] + arr[25],
+    arr[7] + arr[24],
+    arr[8] + arr[23],
+    arr[9] + arr[22],
+    arr[10] + arr[21],
+    arr[11] + arr[20],
+    arr[12] + arr[19],
+    arr[13] + arr[18],
+    arr[14] + arr[17],
+    arr[15] + arr[16],
+    -arr[16] + arr[15],
+    -arr[17] + arr[14],
+    -arr[18] + arr[13],
+    -arr[19] + arr[12],
+    -arr[20] + arr[11],
+    -arr[21] + arr[10],
+    -arr[22] + arr[9],
+    -arr[23] + arr[8],
+    -arr[24] + arr[7],
+    -arr[25] + arr[6],
+    -arr[26] + arr[5],
+    -arr[27] + arr[4],
+    -arr[28] + arr[3],
+    -arr[29] + arr[2],
+    -arr[30] + arr[1],
+    -arr[31] + arr[0],
+  ];
+
+  let stage2 = [
+    stage1[0] + stage1[15],
+    stage1[1] + stage1[14],
+    stage1[2] + stage1[13],
+    stage1[3] + stage1[12],
+    stage1[4] + stage1[11],
+    stage1[5] + stage1[10],
+    stage1[6] + stage1[9],
+    stage1[7] + stage1[8],
+    -stage1[8] + stage1[7],
+    -stage1[9] + stage1[6],
+    -stage1[10] + stage1[5],
+    -stage1[11] + stage1[4],
+    -stage1[12] + stage1[3],
+    -stage1[13] + stage1[2],
+    -stage1[14] + stage1[1],
+    -stage1[15] + stage1[0],
+    stage1[16],
+    stage1[17],
+    stage1[18],
+    stage1[19],
+    half_btf(-cospi[32], stage1[20], cospi[32], stage1[27], cos_bit),
+    half_btf(-cospi[32], stage1[21], cospi[32], stage1[26], cos_bit),
+    half_btf(-cospi[32], stage1[22], cospi[32], stage1[25], cos_bit),
+    half_btf(-cospi[32], stage1[23], cospi[32], stage1[24], cos_bit),
+    half_btf(cospi[32], stage1[24], cospi[32], stage1[23], cos_bit),
+    half_btf(cospi[32], stage1[25], cospi[32], stage1[22], cos_bit),
+    half_btf(cospi[32], stage1[26], cospi[32], stage1[21], cos_bit),
+    half_btf(cospi[32], stage1[27], cospi[32], stage1[20], cos_bit),
+    stage1[28],
+    stage1[29],
+    stage1[30],
+    stage1[31],
+  ];
+
+  let stage3 = [
+    stage2[0] + stage2[7],
+    stage2[1] + stage2[6],
+    stage2[2] + stage2[5],
+    stage2[3] + stage2[4],
+    -stage2[4] + stage2[3],
+    -stage2[5] + stage2[2],
+    -stage2[6] + stage2[1],
+    -stage2[7] + stage2[0],
+    stage2[8],
+    stage2[9],
+    half_btf(-cospi[32], stage2[10], cospi[32], stage2[13], cos_bit),
+    half_btf(-cospi[32], stage2[11], cospi[32], stage2[12], cos_bit),
+    half_btf(cospi[32], stage2[12], cospi[32], stage2[11], cos_bit),
+    half_btf(cospi[32], stage2[13], cospi[32], stage2[10], cos_bit),
+    stage2[14],
+    stage2[15],
+    stage2[16] + stage2[23],
+    stage2[17] + stage2[22],
+    stage2[18] + stage2[21],
+    stage2[19] + stage2[20],
+    -stage2[20] + stage2[19],
+    -stage2[21] + stage2[18],
+    -stage2[22] + stage2[17],
+    -stage2[23] + stage2[16],
+    -stage2[24] + stage2[31],
+    -stage2[25] + stage2[30],
+    -stage2[26] + stage2[29],
+    -stage2[27] + stage2[28],
+    stage2[28] + stage2[27],
+    stage2[29] + stage2[26],
+    stage2[30] + stage2[25],
+    stage2[31] + stage2[24],
+  ];
+
+  let stage4 = [
+    stage3[0] + stage3[3],
+    stage3[1] + stage3[2],
+    -stage3[2] + stage3[1],
+    -stage3[3] + stage3[0],
+    stage3[4],
+    half_btf(-cospi[32], stage3[5], cospi[32], stage3[6], cos_bit),
+    half_btf(cospi[32], stage3[6], cospi[32], stage3[5], cos_bit),
+    stage3[7],
+    stage3[8] + stage3[11],
+    stage3[9] + stage3[10],
+    -stage3[10] + stage3[9],
+    -stage3[11] + stage3[8],
+    -stage3[12] + stage3[15],
+    -stage3[13] + stage3[14],
+    stage3[14] + stage3[13],
+    stage3[15] + stage3[12],
+    stage3[16],
+    stage3[17],
+    half_btf(-cospi[16], stage3[18], cospi[48], stage3[29], cos_bit),
+    half_btf(-cospi[16], stage3[19], cospi[48], stage3[28], cos_bit),
+    half_btf(-cospi[48], stage3[20], -cospi[16], stage3[27], cos_bit),
+    half_btf(-cospi[48], stage3[21], -cospi[16], stage3[26], cos_bit),
+    stage3[22],
+    stage3[23],
+    stage3[24],
+    stage3[25],
+    half_btf(cospi[48], stage3[26], -cospi[16], stage3[21], cos_bit),
+    half_btf(cospi[48], stage3[27], -cospi[16], stage3[20], cos_bit),
+    half_btf(cospi[16], stage3[28], cospi[48], stage3[19], cos_bit),
+    half_btf(cospi[16], stage3[29], cospi[48], stage3[18], cos_bit),
+    stage3[30],
+    stage3[31],
+  ];
+
+  let stage5 = [
+    half_btf(cospi[32], stage4[0], cospi[32], stage4[1], cos_bit),
+    half_btf(-cospi[32], stage4[1], cospi[32], stage4[0], cos_bit),
+    half_btf(cospi[48], stage4[2], cospi[16], stage4[3], cos_bit),
+    half_btf(cospi[48], stage4[3], -cospi[16], stage4[2], cos_bit),
+    stage4[4] + stage4[5],
+    -stage4[5] + stage4[4],
+    -stage4[6] + stage4[7],
+    stage4[7] + stage4[6],
+    stage4[8],
+    half_btf(-cospi[16], stage4[9], cospi[48], stage4[14], cos_bit),
+    half_btf(-cospi[48], stage4[10], -cospi[16], stage4[13], cos_bit),
+    stage4[11],
+    stage4[12],
+    half_btf(cospi[48], stage4[13], -cospi[16], stage4[10], cos_bit),
+    half_btf(cospi[16], stage4[14], cospi[48], stage4[9], cos_bit),
+    stage4[15],
+    stage4[16] + stage4[19],
+    stage4[17] + stage4[18],
+    -stage4[18] + stage4[17],
+    -stage4[19] + stage4[16],
+    -stage4[20] + stage4[23],
+    -stage4[21] + stage4[22],
+    stage4[22] + stage4[21],
+    stage4[23] + stage4[20],
+    stage4[24] + stage4[27],
+    stage4[25] + stage4[26],
+    -stage4[26] + stage4[25],
+    -stage4[27] + stage4[24],
+    -stage4[28] + stage4[31],
+    -stage4[29] + stage4[30],
+    stage4[30] + stage4[29],
+    stage4[31] + stage4[28],
+  ];
+
+  let stage6 = [
+    stage5[0],
+    stage5[1],
+    stage5[2],
+    stage5[3],
+    half_btf(cospi[56], stage5[4], cospi[8], stage5[7], cos_bit),
+    half_btf(cospi[24], stage5[5], cospi[40], stage5[6], cos_bit),
+    half_btf(cospi[24], stage5[6], -cospi[40], stage5[5], cos_bit),
+    half_btf(cospi[56], stage5[7], -cospi[8], stage5[4], cos_bit),
+    stage5[8] + stage5[9],
+    -stage5[9] + stage5[8],
+    -stage5[10] + stage5[11],
+    stage5[11] + stage5[10],
+    stage5[12] + stage5[13],
+    -stage5[13] + stage5[12],
+    -stage5[14] + stage5[15],
+    stage5[15] + stage5[14],
+    stage5[16],
+    half_btf(-cospi[8], stage5[17], cospi[56], stage5[30], cos_bit),
+    half_btf(-cospi[56], stage5[18], -cospi[8], stage5[29], cos_bit),
+    stage5[19],
+    stage5[20],
+    half_btf(-cospi[40], stage5[21], cospi[24], stage5[26], cos_bit),
+    half_btf(-cospi[24], stage5[22], -cospi[40], stage5[25], cos_bit),
+    stage5[23],
+    stage5[24],
+    half_btf(cospi[24], stage5[25], -cospi[40], stage5[22], cos_bit),
+    half_btf(cospi[40], stage5[26], cospi[24], stage5[21], cos_bit),
+    stage5[27],
+    stage5[28],
+    half_btf(cospi[56], stage5[29], -cospi[8], stage5[18], cos_bit),
+    half_btf(cospi[8], stage5[30], cospi[56], stage5[17], cos_bit),
+    stage5[31],
+  ];
+
+  let stage7 = [
+    stage6[0],
+    stage6[1],
+    stage6[2],
+    stage6[3],
+    stage6[4],
+    stage6[5],
+    stage6[6],
+    stage6[7],
+    half_btf(cospi[60], stage6[8], cospi[4], stage6[15], cos_bit),
+    half_btf(cospi[28], stage6[9], cospi[36], stage6[14], cos_bit),
+    half_btf(cospi[44], stage6[10], cospi[20], stage6[13], cos_bit),
+    half_btf(cospi[12], stage6[11], cospi[52], stage6[12], cos_bit),
+    half_btf(cospi[12], stage6[12], -cospi[52], stage6[11], cos_bit),
+    half_btf(cospi[44], stage6[13], -cospi[20], stage6[10], cos_bit),
+    half_btf(cospi[28], stage6[14], -cospi[36], stage6[9], cos_bit),
+    half_btf(cospi[60], stage6[15], -cospi[4], stage6[8], cos_bit),
+    stage6[16] + stage6[17],
+    -stage6[17] + stage6[16],
+    -stage6[18] + stage6[19],
+    stage6[19] + stage6[18],
+    stage6[20] + stage6[21],
+    -stage6[21] + stage6[20],
+    -stage6[22] + stage6[23],
+    stage6[23] + stage6[22],
+    stage6[24] + stage6[25],
+    -stage6[25] + stage6[24],
+    -stage6[26] + stage6[27],
+    stage6[27] + stage6[26],
+    stage6[28] + stage6[29],
+    -stage6[29] + stage6[28],
+    -stage6[30] + stage6[31],
+    stage6[31] + stage6[30],
+  ];
+
+  let stage8 = [
+    stage7[0],
+    stage7[1],
+    stage7[2],
+    stage7[3],
+    stage7[4],
+    stage7[5],
+    stage7[6],
+    stage7[7],
+    stage7[8],
+    stage7[9],
+    stage7[10],
+    stage7[11],
+    stage7[12],
+    stage7[13],
+    stage7[14],
+    stage7[15],
+    half_btf(cospi[62], stage7[16], cospi[2], stage7[31], cos_bit),
+    half_btf(cospi[30], stage7[17], cospi[34], stage7[30], cos_bit),
+    half_btf(cospi[46], stage7[18], cospi[18], stage7[29], cos_bit),
+    half_btf(cospi[14], stage7[19], cospi[50], stage7[28], cos_bit),
+    half_btf(cospi[54], stage7[20], cospi[10], stage7[27], cos_bit),
+    half_btf(cospi[22], stage7[21], cospi[42], stage7[26], cos_bit),
+    half_btf(cospi[38], stage7[22], cospi[26], stage7[25], cos_bit),
+    half_btf(cospi[6], stage7[23], cospi[58], stage7[24], cos_bit),
+    half_btf(cospi[6], stage7[24], -cospi[58], stage7[23], cos_bit),
+    half_btf(cospi[38], stage7[25], -cospi[26], stage7[22], cos_bit),
+    half_btf(cospi[22], stage7[26], -cospi[42], stage7[21], cos_bit),
+    half_btf(cospi[54], stage7[27], -cospi[10], stage7[20], cos_bit),
+    half_btf(cospi[14], stage7[28], -cospi[50], stage7[19], cos_bit),
+    half_btf(cospi[46], stage7[29], -cospi[18], stage7[18], cos_bit),
+    half_btf(cospi[30], stage7[30], -cospi[34], stage7[17], cos_bit),
+    half_btf(cospi[62], stage7[31], -cospi[2], stage7[16], cos_bit),
+  ];
+
+  let stage9 = [
+    stage8[0],
+    stage8[16],
+    stage8[8],
+    stage8[24],
+    stage8[4],
+    stage8[20],
+    stage8[12],
+    stage8[28],
+    stage8[2],
+    stage8[18],
+    stage8[10],
+    stage8[26],
+    stage8[6],
+    stage8[22],
+    stage8[14],
+    stage8[30],
+    stage8[1],
+    stage8[17],
+    stage8[9],
+    stage8[25],
+    stage8[5],
+    stage8[21],
+    stage8[13],
+    stage8[29],
+    stage8[3],
+    stage8[19],
+    stage8[11],
+    stage8[27],
+    stage8[7],
+    stage8[23],
+    stage8[15],
+    stage8[31],
+  ];
+
+  arr.copy_from_slice(&stage9);
+}
+
 // In-place 4-point inverse DCT
 fn inv_dct4(arr: &mut [i32], cos_bit: u32, stage_range: &[u32]) {
   assert!(arr.len() == 4);
@@ -258,6 +727,472 @@ fn inv_dct8(arr: &mut [i32], cos_bit: u32, stage_range: &[u32]) {
   arr.copy_from_slice(&stage5);
 }
 
+// In-place 16-point inverse DCT
+fn inv_dct16(arr: &mut [i32], cos_bit: u32, stage_range: &[u32]) {
+  assert!(arr.len() == 16);
+
+  let cospi = cospi_arr(cos_bit);
+
+  let stage1 = [
+    arr[0],
+    arr[8],
+    arr[4],
+    arr[12],
+    arr[2],
+    arr[10],
+    arr[6],
+    arr[14],
+    arr[1],
+    arr[9],
+    arr[5],
+    arr[13],
+    arr[3],
+    arr[11],
+    arr[7],
+    arr[15],
+  ];
+
+  let stage2 = [
+    stage1[0],
+    stage1[1],
+    stage1[2],
+    stage1[3],
+    stage1[4],
+    stage1[5],
+    stage1[6],
+    stage1[7],
+    half_btf(cospi[60], stage1[8], -cospi[4], stage1[15], cos_bit),
+    half_btf(cospi[28], stage1[9], -cospi[36], stage1[14], cos_bit),
+    half_btf(cospi[44], stage1[10], -cospi[20], stage1[13], cos_bit),
+    half_btf(cospi[12], stage1[11], -cospi[52], stage1[12], cos_bit),
+    half_btf(cospi[52], stage1[11], cospi[12], stage1[12], cos_bit),
+    half_btf(cospi[20], stage1[10], cospi[44], stage1[13], cos_bit),
+    half_btf(cospi[36], stage1[9], cospi[28], stage1[14], cos_bit),
+    half_btf(cospi[4], stage1[8], cospi[60], stage1[15], cos_bit),
+  ];
+
+  let stage3 = [
+    stage2[0],
+    stage2[1],
+    stage2[2],
+    stage2[3],
+    half_btf(cospi[56], stage2[4], -cospi[8], stage2[7], cos_bit),
+    half_btf(cospi[24], stage2[5], -cospi[40], stage2[6], cos_bit),
+    half_btf(cospi[40], stage2[5], cospi[24], stage2[6], cos_bit),
+    half_btf(cospi[8], stage2[4], cospi[56], stage2[7], cos_bit),
+    clamp_value(stage2[8] + stage2[9], stage_range[3]),
+    clamp_value(stage2[8] - stage2[9], stage_range[3]),
+    clamp_value(-stage2[10] + stage2[11], stage_range[3]),
+    clamp_value(stage2[10] + stage2[11], stage_range[3]),
+    clamp_value(stage2[12] + stage2[13], stage_range[3]),
+    clamp_value(stage2[12] - stage2[13], stage_range[3]),
+    clamp_value(-stage2[14] + stage2[15], stage_range[3]),
+    clamp_value(stage2[14] + stage2[15], stage_range[3]),
+  ];
+
+  let stage4 = [
+    half_btf(cospi[32], stage3[0], cospi[32], stage3[1], cos_bit),
+    half_btf(cospi[32], stage3[0], -cospi[32], stage3[1], cos_bit),
+    half_btf(cospi[48], stage3[2], -cospi[16], stage3[3], cos_bit),
+    half_btf(cospi[16], stage3[2], cospi[48], stage3[3], cos_bit),
+    clamp_value(stage3[4] + stage3[5], stage_range[4]),
+    clamp_value(stage3[4] - stage3[5], stage_range[4]),
+    clamp_value(-stage3[6] + stage3[7], stage_range[4]),
+    clamp_value(stage3[6] + stage3[7], stage_range[4]),
+    stage3[8],
+    half_btf(-cospi[16], stage3[9], cospi[48], stage3[14], cos_bit),
+    half_btf(-cospi[48], stage3[10], -cospi[16], stage3[13], cos_bit),
+    stage3[11],
+    stage3[12],
+    half_btf(-cospi[16], stage3[10], cospi[48], stage3[13], cos_bit),
+    half_btf(cospi[48], stage3[9], cospi[16], stage3[14], cos_bit),
+    stage3[15],
+  ];
+
+  let stage5 = [
+    clamp_value(stage4[0] + stage4[3], stage_range[5]),
+    clamp_value(stage4[1] + stage4[2], stage_range[5]),
+    clamp_value(stage4[1] - stage4[2], stage_range[5]),
+    clamp_value(stage4[0] - stage4[3], stage_range[5]),
+    stage4[4],
+    half_btf(-cospi[32], stage4[5], cospi[32], stage4[6], cos_bit),
+    half_btf(cospi[32], stage4[5], cospi[32], stage4[6], cos_bit),
+    stage4[7],
+    clamp_value(stage4[8] + stage4[11], stage_range[5]),
+    clamp_value(stage4[9] + stage4[10], stage_range[5]),
+    clamp_value(stage4[9] - stage4[10], stage_range[5]),
+    clamp_value(stage4[8] - stage4[11], stage_range[5]),
+    clamp_value(-stage4[12] + stage4[15], stage_range[5]),
+    clamp_value(-stage4[13] + stage4[14], stage_range[5]),
+    clamp_value(stage4[13] + stage4[14], stage_range[5]),
+    clamp_value(stage4[12] + stage4[15], stage_range[5]),
+  ];
+
+  let stage6 = [
+    clamp_value(stage5[0] + stage5[7], stage_range[6]),
+    clamp_value(stage5[1] + stage5[6], stage_range[6]),
+    clamp_value(stage5[2] + stage5[5], stage_range[6]),
+    clamp_value(stage5[3] + stage5[4], stage_range[6]),
+    clamp_value(stage5[3] - stage5[4], stage_range[6]),
+    clamp_value(stage5[2] - stage5[5], stage_range[6]),
+    clamp_value(stage5[1] - stage5[6], stage_range[6]),
+    clamp_value(stage5[0] - stage5[7], stage_range[6]),
+    stage5[8],
+    stage5[9],
+    half_btf(-cospi[32], stage5[10], cospi[32], stage5[13], cos_bit),
+    half_btf(-cospi[32], stage5[11], cospi[32], stage5[12], cos_bit),
+    half_btf(cospi[32], stage5[11], cospi[32], stage5[12], cos_bit),
+    half_btf(cospi[32], stage5[10], cospi[32], stage5[13], cos_bit),
+    stage5[14],
+    stage5[15],
+  ];
+
+  let stage7 = [
+    clamp_value(stage6[0] + stage6[15], stage_range[7]),
+    clamp_value(stage6[1] + stage6[14], stage_range[7]),
+    clamp_value(stage6[2] + stage6[13], stage_range[7]),
+    clamp_value(stage6[3] + stage6[12], stage_range[7]),
+    clamp_value(stage6[4] + stage6[11], stage_range[7]),
+    clamp_value(stage6[5] + stage6[10], stage_range[7]),
+    clamp_value(stage6[6] + stage6[9], stage_range[7]),
+    clamp_value(stage6[7] + stage6[8], stage_range[7]),
+    clamp_value(stage6[7] - stage6[8], stage_range[7]),
+    clamp_value(stage6[6] - stage6[9], stage_range[7]),
+    clamp_value(stage6[5] - stage6[10], stage_range[7]),
+    clamp_value(stage6[4] - stage6[11], stage_range[7]),
+    clamp_value(stage6[3] - stage6[12], stage_range[7]),
+    clamp_value(stage6[2] - stage6[13], stage_range[7]),
+    clamp_value(stage6[1] - stage6[14], stage_range[7]),
+    clamp_value(stage6[0] - stage6[15], stage_range[7]),
+  ];
+
+  arr.copy_from_slice(&stage7);
+}
+
+// In-place 32-point inverse DCT
+fn inv_dct32(arr: &mut [i32], cos_bit: u32, stage_range: &[u32]) {
+  assert!(arr.len() == 32);
+
+  let cospi = cospi_arr(cos_bit);
+
+  let stage1 = [
+    arr[0],
+    arr[16],
+    arr[8],
+    arr[24],
+    arr[4],
+    arr[20],
+    arr[12],
+    arr[28],
+    arr[2],
+    arr[18],
+    arr[10],
+    arr[26],
+    arr[6],
+    arr[22],
+    arr[14],
+    arr[30],
+    arr[1],
+    arr[17],
+    arr[9],
+    arr[25],
+    arr[5],
+    arr[21],
+    arr[13],
+    arr[29],
+    arr[3],
+    arr[19],
+    arr[11],
+    arr[27],
+    arr[7],
+    arr[23],
+    arr[15],
+    arr[31],
+  ];
+
+  let stage2 = [
+    stage1[0],
+    stage1[1],
+    stage1[2],
+    stage1[3],
+    stage1[4],
+    stage1[5],
+    stage1[6],
+    stage1[7],
+    stage1[8],
+    stage1[9],
+    stage1[10],
+    stage1[11],
+    stage1[12],
+    stage1[13],
+    stage1[14],
+    stage1[15],
+    half_btf(cospi[62], stage1[16], -cospi[2], stage1[31], cos_bit),
+    half_btf(cospi[30], stage1[17], -cospi[34], stage1[30], cos_bit),
+    half_btf(cospi[46], stage1[18], -cospi[18], stage1[29], cos_bit),
+    half_btf(cospi[14], stage1[19], -cospi[50], stage1[28], cos_bit),
+    half_btf(cospi[54], stage1[20], -cospi[10], stage1[27], cos_bit),
+    half_btf(cospi[22], stage1[21], -cospi[42], stage1[26], cos_bit),
+    half_btf(cospi[38], stage1[22], -cospi[26], stage1[25], cos_bit),
+    half_btf(cospi[6], stage1[23], -cospi[58], stage1[24], cos_bit),
+    half_btf(cospi[58], stage1[23], cospi[6], stage1[24], cos_bit),
+    half_btf(cospi[26], stage1[22], cospi[38], stage1[25], cos_bit),
+    half_btf(cospi[42], stage1[21], cospi[22], stage1[26], cos_bit),
+    half_btf(cospi[10], stage1[20], cospi[54], stage1[27], cos_bit),
+    half_btf(cospi[50], stage1[19], cospi[14], stage1[28], cos_bit),
+    half_btf(cospi[18], stage1[18], cospi[46], stage1[29], cos_bit),
+    half_btf(cospi[34], stage1[17], cospi[30], stage1[30], cos_bit),
+    half_btf(cospi[2], stage1[16], cospi[62], stage1[31], cos_bit),
+  ];
+
+  let stage3 = [
+    stage2[0],
+    stage2[1],
+    stage2[2],
+    stage2[3],
+    stage2[4],
+    stage2[5],
+    stage2[6],
+    stage2[7],
+    half_btf(cospi[60], stage2[8], -cospi[4], stage2[15], cos_bit),
+    half_btf(cospi[28], stage2[9], -cospi[36], stage2[14], cos_bit),
+    half_btf(cospi[44], stage2[10], -cospi[20], stage2[13], cos_bit),
+    half_btf(cospi[12], stage2[11], -cospi[52], stage2[12], cos_bit),
+    half_btf(cospi[52], stage2[11], cospi[12], stage2[12], cos_bit),
+    half_btf(cospi[20], stage2[10], cospi[44], stage2[13], cos_bit),
+    half_btf(cospi[36], stage2[9], cospi[28], stage2[14], cos_bit),
+    half_btf(cospi[4], stage2[8], cospi[60], stage2[15], cos_bit),
+    clamp_value(stage2[16] + stage2[17], stage_range[3]),
+    clamp_value(stage2[16] - stage2[17], stage_range[3]),
+    clamp_value(-stage2[18] + stage2[19], stage_range[3]),
+    clamp_value(stage2[18] + stage2[19], stage_range[3]),
+    clamp_value(stage2[20] + stage2[21], stage_range[3]),
+    clamp_value(stage2[20] - stage2[21], stage_range[3]),
+    clamp_value(-stage2[22] + stage2[23], stage_range[3]),
+    clamp_value(stage2[22] + stage2[23], stage_range[3]),
+    clamp_value(stage2[24] + stage2[25], stage_range[3]),
+    clamp_value(stage2[24] - stage2[25], stage_range[3]),
+    clamp_value(-stage2[26] + stage2[27], stage_range[3]),
+    clamp_value(stage2[26] + stage2[27], stage_range[3]),
+    clamp_value(stage2[28] + stage2[29], stage_range[3]),
+    clamp_value(stage2[28] - stage2[29], stage_range[3]),
+    clamp_value(-stage2[30] + stage2[31], stage_range[3]),
+    clamp_value(stage2[30] + stage2[31], stage_range[3]),
+  ];
+
+  let stage4 = [
+    stage3[0],
+    stage3[1],
+    stage3[2],
+    stage3[3],
+    half_btf(cospi[56], stage3[4], -cospi[8], stage3[7], cos_bit),
+    half_btf(cospi[24], stage3[5], -cospi[40], stage3[6], cos_bit),
+    half_btf(cospi[40], stage3[5], cospi[24], stage3[6], cos_bit),
+    half_btf(cospi[8], stage3[4], cospi[56], stage3[7], cos_bit),
+    clamp_value(stage3[8] + stage3[9], stage_range[4]),
+    clamp_value(stage3[8] - stage3[9], stage_range[4]),
+    clamp_value(-stage3[10] + stage3[11], stage_range[4]),
+    clamp_value(stage3[10] + stage3[11], stage_range[4]),
+    clamp_value(stage3[12] + stage3[13], stage_range[4]),
+    clamp_value(stage3[12] - stage3[13], stage_range[4]),
+    clamp_value(-stage3[14] + stage3[15], stage_range[4]),
+    clamp_value(stage3[14] + stage3[15], stage_range[4]),
+    stage3[16],
+    half_btf(-cospi[8], stage3[17], cospi[56], stage3[30], cos_bit),
+    half_btf(-cospi[56], stage3[18], -cospi[8], stage3[29], cos_bit),
+    stage3[19],
+    stage3[20],
+    half_btf(-cospi[40], stage3[21], cospi[24], stage3[26], cos_bit),
+    half_btf(-cospi[24], stage3[22], -cospi[40], stage3[25], cos_bit),
+    stage3[23],
+    stage3[24],
+    half_btf(-cospi[40], stage3[22], cospi[24], stage3[25], cos_bit),
+    half_btf(cospi[24], stage3[21], cospi[40], stage3[26], cos_bit),
+    stage3[27],
+    stage3[28],
+    half_btf(-cospi[8], stage3[18], cospi[56], stage3[29], cos_bit),
+    half_btf(cospi[56], stage3[17], cospi[8], stage3[30], cos_bit),
+    stage3[31],
+  ];
+
+  let stage5 = [
+    half_btf(cospi[32], stage4[0], cospi[32], stage4[1], cos_bit),
+    half_btf(cospi[32], stage4[0], -cospi[32], stage4[1], cos_bit),
+    half_btf(cospi[48], stage4[2], -cospi[16], stage4[3], cos_bit),
+    half_btf(cospi[16], stage4[2], cospi[48], stage4[3], cos_bit),
+    clamp_value(stage4[4] + stage4[5], stage_range[5]),
+    clamp_value(stage4[4] - stage4[5], stage_range[5]),
+    clamp_value(-stage4[6] + stage4[7], stage_range[5]),
+    clamp_value(stage4[6] + stage4[7], stage_range[5]),
+    stage4[8],
+    half_btf(-cospi[16], stage4[9], cospi[48], stage4[14], cos_bit),
+    half_btf(-cospi[48], stage4[10], -cospi[16], stage4[13], cos_bit),
+    stage4[11],
+    stage4[12],
+    half_btf(-cospi[16], stage4[10], cospi[48], stage4[13], cos_bit),
+    half_btf(cospi[48], stage4[9], cospi[16], stage4[14], cos_bit),
+    stage4[15],
+    clamp_value(stage4[16] + stage4[19], stage_range[5]),
+    clamp_value(stage4[17] + stage4[18], stage_range[5]),
+    clamp_value(stage4[17] - stage4[18], stage_range[5]),
+    clamp_value(stage4[16] - stage4[19], stage_range[5]),
+    clamp_value(-stage4[20] + stage4[23], stage_range[5]),
+    clamp_value(-stage4[21] + stage4[22], stage_range[5]),
+    clamp_value(stage4[21] + stage4[22], stage_range[5]),
+    clamp_value(stage4[20] + stage4[23], stage_range[5]),
+    clamp_value(stage4[24] + stage4[27], stage_range[5]),
+    clamp_value(stage4[25] + stage4[26], stage_range[5]),
+    clamp_value(stage4[25] - stage4[26], stage_range[5]),
+    clamp_value(stage4[24] - stage4[27], stage_range[5]),
+    clamp_value(-stage4[28] + stage4[31], stage_range[5]),
+    clamp_value(-stage4[29] + stage4[30], stage_range[5]),
+    clamp_value(stage4[29] + stage4[30], stage_range[5]),
+    clamp_value(stage4[28] + stage4[31], stage_range[5]),
+  ];
+
+  let stage6 = [
+    clamp_value(stage5[0] + stage5[3], stage_range[6]),
+    clamp_value(stage5[1] + stage5[2], stage_range[6]),
+    clamp_value(stage5[1] - stage5[2], stage_range[6]),
+    clamp_value(stage5[0] - stage5[3], stage_range[6]),
+    stage5[4],
+    half_btf(-cospi[32], stage5[5], cospi[32], stage5[6], cos_bit),
+    half_btf(cospi[32], stage5[5], cospi[32], stage5[6], cos_bit),
+    stage5[7],
+    clamp_value(stage5[8] + stage5[11], stage_range[6]),
+    clamp_value(stage5[9] + stage5[10], stage_range[6]),
+    clamp_value(stage5[9] - stage5[10], stage_range[6]),
+    clamp_value(stage5[8] - stage5[11], stage_range[6]),
+    clamp_value(-stage5[12] + stage5[15], stage_range[6]),
+    clamp_value(-stage5[13] + stage5[14], stage_range[6]),
+    clamp_value(stage5[13] + stage5[14], stage_range[6]),
+    clamp_value(stage5[12] + stage5[15], stage_range[6]),
+    stage5[16],
+    stage5[17],
+    half_btf(-cospi[16], stage5[18], cospi[48], stage5[29], cos_bit),
+    half_btf(-cospi[16], stage5[19], cospi[48], stage5[28], cos_bit),
+    half_btf(-cospi[48], stage5[20], -cospi[16], stage5[27], cos_bit),
+    half_btf(-cospi[48], stage5[21], -cospi[16], stage5[26], cos_bit),
+    stage5[22],
+    stage5[23],
+    stage5[24],
+    stage5[25],
+    half_btf(-cospi[16], stage5[21], cospi[48], stage5[26], cos_bit),
+    half_btf(-cospi[16], stage5[20], cospi[48], stage5[27], cos_bit),
+    half_btf(cospi[48], stage5[19], cospi[16], stage5[28], cos_bit),
+    half_btf(cospi[48], stage5[18], cospi[16], stage5[29], cos_bit),
+    stage5[30],
+    stage5[31],
+  ];
+
+  let stage7 = [
+    clamp_value(stage6[0] + stage6[7], stage_range[7]),
+    clamp_value(stage6[1] + stage6[6], stage_range[7]),
+    clamp_value(stage6[2] + stage6[5], stage_range[7]),
+    clamp_value(stage6[3] + stage6[4], stage_range[7]),
+    clamp_value(stage6[3] - stage6[4], stage_range[7]),
+    clamp_value(stage6[2] - stage6[5], stage_range[7]),
+    clamp_value(stage6[1] - stage6[6], stage_range[7]),
+    clamp_value(stage6[0] - stage6[7], stage_range[7]),
+    stage6[8],
+    stage6[9],
+    half_btf(-cospi[32], stage6[10], cospi[32], stage6[13], cos_bit),
+    half_btf(-cospi[32], stage6[11], cospi[32], stage6[12], cos_bit),
+    half_btf(cospi[32], stage6[11], cospi[32], stage6[12], cos_bit),
+    half_btf(cospi[32], stage6[10], cospi[32], stage6[13], cos_bit),
+    stage6[14],
+    stage6[15],
+    clamp_value(stage6[16] + stage6[23], stage_range[7]),
+    clamp_value(stage6[17] + stage6[22], stage_range[7]),
+    clamp_value(stage6[18] + stage6[21], stage_range[7]),
+    clamp_value(stage6[19] + stage6[20], stage_range[7]),
+    clamp_value(stage6[19] - stage6[20], stage_range[7]),
+    clamp_value(stage6[18] - stage6[21], stage_range[7]),
+    clamp_value(stage6[17] - stage6[22], stage_range[7]),
+    clamp_value(stage6[16] - stage6[23], stage_range[7]),
+    clamp_value(-stage6[24] + stage6[31], stage_range[7]),
+    clamp_value(-stage6[25] + stage6[30], stage_range[7]),
+    clamp_value(-stage6[26] + stage6[29], stage_range[7]),
+    clamp_value(-stage6[27] + stage6[28], stage_range[7]),
+    clamp_value(stage6[27] + stage6[28], stage_range[7]),
+    clamp_value(stage6[26] + stage6[29], stage_range[7]),
+    clamp_value(stage6[25] + stage6[30], stage_range[7]),
+    clamp_value(stage6[24] + stage6[31], stage_range[7]),
+  ];
+
+  let stage8 = [
+    clamp_value(stage7[0] + stage7[15], stage_range[8]),
+    clamp_value(stage7[1] + stage7[14], stage_range[8]),
+    clamp_value(stage7[2] + stage7[13], stage_range[8]),
+    clamp_value(stage7[3] + stage7[12], stage_range[8]),
+    clamp_value(stage7[4] + stage7[11], stage_range[8]),
+    clamp_value(stage7[5] + stage7[10], stage_range[8]),
+    clamp_value(stage7[6] + stage7[9], stage_range[8]),
+    clamp_value(stage7[7] + stage7[8], stage_range[8]),
+    clamp_value(stage7[7] - stage7[8], stage_range[8]),
+    clamp_value(stage7[6] - stage7[9], stage_range[8]),
+    clamp_value(stage7[5] - stage7[10], stage_range[8]),
+    clamp_value(stage7[4] - stage7[11], stage_range[8]),
+    clamp_value(stage7[3] - stage7[12], stage_range[8]),
+    clamp_value(stage7[2] - stage7[13], stage_range[8]),
+    clamp_value(stage7[1] - stage7[14], stage_range[8]),
+    clamp_value(stage7[0] - stage7[15], stage_range[8]),
+    stage7[16],
+    stage7[17],
+    stage7[18],
+    stage7[19],
+    half_btf(-cospi[32], stage7[20], cospi[32], stage7[27], cos_bit),
+    half_btf(-cospi[32], stage7[21], cospi[32], stage7[26], cos_bit),
+    half_btf(-cospi[32], stage7[22], cospi[32], stage7[25], cos_bit),
+    half_btf(-cospi[32], stage7[23], cospi[32], stage7[24], cos_bit),
+    half_btf(cospi[32], stage7[23], cospi[32], stage7[24], cos_bit),
+    half_btf(cospi[32], stage7[22], cospi[32], stage7[25], cos_bit),
+    half_btf(cospi[32], stage7[21], cospi[32], stage7[26], cos_bit),
+    half_btf(cospi[32], stage7[20], cospi[32], stage7[27], cos_bit),
+    stage7[28],
+    stage7[29],
+    stage7[30],
+    stage7[31],
+  ];
+
+  let stage9 = [
+    clamp_value(stage8[0] + stage8[31], stage_range[9]),
+    clamp_value(stage8[1] + stage8[30], stage_range[9]),
+    clamp_value(stage8[2] + stage8[29], stage_range[9]),
+    clamp_value(stage8[3] + stage8[28], stage_range[9]),
+    clamp_value(stage8[4] + stage8[27], stage_range[9]),
+    clamp_value(stage8[5] + stage8[26], stage_range[9]),
+    clamp_value(stage8[6] + stage8[25], stage_range[9]),
+    clamp_value(stage8[7] + stage8[24], stage_range[9]),
+    clamp_value(stage8[8] + stage8[23], stage_range[9]),
+    clamp_value(stage8[9] + stage8[22], stage_range[9]),
+    clamp_value(stage8[10] + stage8[21], stage_range[9]),
+    clamp_value(stage8[11] + stage8[20], stage_range[9]),
+    clamp_value(stage8[12] + stage8[19], stage_range[9]),
+    clamp_value(stage8[13] + stage8[18], stage_range[9]),
+    clamp_value(stage8[14] + stage8[17], stage_range[9]),
+    clamp_value(stage8[15] + stage8[16], stage_range[9]),
+    clamp_value(stage8[15] - stage8[16], stage_range[9]),
+    clamp_value(stage8[14] - stage8[17], stage_range[9]),
+    clamp_value(stage8[13] - stage8[18], stage_range[9]),
+    clamp_value(stage8[12] - stage8[19], stage_range[9]),
+    clamp_value(stage8[11] - stage8[20], stage_range[9]),
+    clamp_value(stage8[10] - stage8[21], stage_range[9]),
+    clamp_value(stage8[9] - stage8[22], stage_range[9]),
+    clamp_value(stage8[8] - stage8[23], stage_range[9]),
+    clamp_value(stage8[7] - stage8[24], stage_range[9]),
+    clamp_value(stage8[6] - stage8[25], stage_range[9]),
+    clamp_value(stage8[5] - stage8[26], stage_range[9]),
+    clamp_value(stage8[4] - stage8[27], stage_range[9]),
+    clamp_value(stage8[3] - stage8[28], stage_range[9]),
+    clamp_value(stage8[2] - stage8[29], stage_range[9]),
+    clamp_value(stage8[1] - stage8[30], stage_range[9]),
+    clamp_value(stage8[0] - stage8[31], stage_range[9]),
+  ];
+
+  arr.copy_from_slice(&stage9);
+}
+
 // Perform a 2D forward transform composed of two 1D transforms
 // R = row transform (applied first)
 // C = col transform (applied second)
@@ -265,21 +1200,36 @@ pub fn fwd_txfm2d(residual: &mut Array2D<i32>, txh: usize, txw: usize) {
   assert!(residual.rows() == txh);
   assert!(residual.cols() == txw);
 
+  // The larger transforms use fewer bits of precision for the cosine constants,
+  // to leave headroom for their larger intermediate values
   let txsz_idx;
-  let fwd_txfm: &dyn Fn(&mut [i32], u32, &[u32]);
-  if txh == 8 && txw == 8 {
+  let fwd_txfm: &Txfm1D;
+  let cos_bit_col;
+  let cos_bit_row;
+  if txh == 32 && txw == 32 {
+    txsz_idx = 3;
+    fwd_txfm = &fwd_dct32;
+    cos_bit_col = 12;
+    cos_bit_row = 12;
+  } else if txh == 16 && txw == 16 {
+    txsz_idx = 2;
+    fwd_txfm = &fwd_dct16;
+    cos_bit_col = 13;
+    cos_bit_row = 12;
+  } else if txh == 8 && txw == 8 {
     txsz_idx = 1;
     fwd_txfm = &fwd_dct8;
+    cos_bit_col = 13;
+    cos_bit_row = 13;
   } else if txh == 4 && txw == 4 {
     txsz_idx = 0;
     fwd_txfm = &fwd_dct4;
+    cos_bit_col = 13;
+    cos_bit_row = 13;
   } else {
     todo!();
   }
 
-  let cos_bit_col = 13; // For both 4x4 and 8x8 forward transforms, less for some other sizes
-  let cos_bit_row = 13; // For both 4x4 and 8x8 forward transforms, less for some other sizes
-
   let bd = 8;
   let stages = av1_txfm_stages[txsz_idx];
   let shift = &av1_txfm_fwd_shift[txsz_idx];
@@ -321,8 +1271,14 @@ pub fn inv_txfm2d(residual: &mut Array2D<i32>, txh: usize, txw: usize) {
   assert!(residual.cols() == txw);
 
   let txsz_idx;
-  let inv_txfm: &dyn Fn(&mut [i32], u32, &[u32]);
-  if txh == 8 && txw == 8 {
+  let inv_txfm: &Txfm1D;
+  if txh == 32 && txw == 32 {
+    txsz_idx = 3;
+    inv_txfm = &inv_dct32;
+  } else if txh == 16 && txw == 16 {
+    txsz_idx = 2;
+    inv_txfm = &inv_dct16;
+  } else if txh == 8 && txw == 8 {
     txsz_idx = 1;
     inv_txfm = &inv_dct8;
   } else if txh == 4 && txw == 4 {
@@ -336,22 +1292,18 @@ pub fn inv_txfm2d(residual: &mut Array2D<i32>, txh: usize, txw: usize) {
   let cos_bit_row = 12; // For all inverse transform sizes
 
   let bd = 8;
-  let opt_range_row = 16;
-  let opt_range_col = 16;
   let stages = av1_txfm_stages[txsz_idx];
   let shift = &av1_txfm_inv_shift[txsz_idx];
-  // TODO: I think this is just all zeros?
-  //let stage_ranges = &av1_txfm_inv_range_mult2[txsz_idx];
 
-  let mut stage_range_row = vec![0u32; stages];
-  let mut stage_range_col = vec![0u32; stages];
-
-  for i in 0..stages {
-    stage_range_row[i] = (/*stage_ranges[i] + */ av1_txfm_inv_start_range[txsz_idx] + (bd as i32) + 1) as u32;
-  }
-  for i in 0..stages {
-    stage_range_col[i] = (/*stage_ranges[i] + */ av1_txfm_inv_start_range[txsz_idx] + shift[0] + (bd as i32) + 1) as u32;
-  }
+  // Intermediate values are clamped to 16 bits at every stage, matching libaom and
+  // other decoders. Note that we must not use the tighter ranges which the
+  // intermediate values would theoretically fit in for "natural" inputs, as high-contrast
+  // content (eg. text) can exceed those, and then our reconstruction would not match
+  // the decoder's.
+  let opt_range_row = 16;
+  let opt_range_col = 16;
+  let stage_range_row = vec![opt_range_row; stages];
+  let stage_range_col = vec![opt_range_col; stages];
 
   // Row transforms
   for i in 0..txh {
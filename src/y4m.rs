@@ -20,7 +20,11 @@ const Y4M_FRAME_MAGIC: &str = "FRAME";
 pub struct Y4MReader<R> {
   inner: R,
   width: usize,
-  height: usize
+  height: usize,
+  // Grayscale files ("Cmono") only contain a Y plane
+  monochrome: bool,
+  // Frame rate, as a fraction (numerator, denominator)
+  frame_rate: (u32, u32)
 }
 
 pub struct Y4MWriter<W> {
@@ -60,6 +64,23 @@ fn expect_whitespace<R: Read>(r: &mut R) -> Result<u8, io::Error> {
   }
 }
 
+// Read characters up to the next whitespace character
+// Returns the characters read, plus the whitespace character found
+fn read_token<R: Read>(r: &mut R) -> Result<(Vec<u8>, u8), io::Error> {
+  let mut token = Vec::new();
+  loop {
+    let byte = r.read_u8()?;
+    match byte {
+      b' ' | b'\t' | b'\n' => {
+        return Ok((token, byte));
+      },
+      _ => {
+        token.push(byte);
+      }
+    }
+  }
+}
+
 // Skip forward until we find a whitespace character
 // Returns the first whitespace character found
 fn find_whitespace<R: Read>(r: &mut R) -> Result<u8, io::Error> {
@@ -87,6 +108,9 @@ impl<R: Read> Y4MReader<R> {
 
     let mut width = 0;
     let mut height = 0;
+    let mut monochrome = false;
+    // The frame rate is a mandatory parameter, but be lenient if it's missing
+    let mut frame_rate = (30, 1);
 
     // Parse parameter line
     loop {
@@ -117,6 +141,30 @@ impl<R: Read> Y4MReader<R> {
             _ => { panic!("Unexpected byte {} in Y4M file", byte); }
           }
         },
+        b'F' => {
+          let (num, byte) = read_decimal(&mut inner)?;
+          if byte != b':' {
+            panic!("Unexpected byte {} in Y4M frame rate", byte);
+          }
+          let (den, byte) = read_decimal(&mut inner)?;
+          if num == 0 || den == 0 || num > u32::MAX as usize || den > u32::MAX as usize {
+            panic!("Invalid Y4M frame rate {}:{}", num, den);
+          }
+          frame_rate = (num as u32, den as u32);
+          match byte {
+            b'\n' => { break; },
+            b' ' | b'\t' | b'\r' => { continue; }
+            _ => { panic!("Unexpected byte {} in Y4M file", byte); }
+          }
+        },
+        b'C' => {
+          // Colour space. We only need to distinguish grayscale from 4:2:0 here
+          let (colorspace, byte) = read_token(&mut inner)?;
+          monochrome = colorspace.starts_with(b"mono");
+          if byte == b'\n' {
+            break;
+          }
+        },
         _ => {
           // Other parameters that we aren't parsing yet
           // Just skip until we find whitespace
@@ -135,17 +183,34 @@ impl<R: Read> Y4MReader<R> {
     Ok(Y4MReader {
       inner: inner,
       width: width,
-      height: height
+      height: height,
+      monochrome: monochrome,
+      frame_rate: frame_rate
     })
   }
 
+  pub fn frame_rate(&self) -> (u32, u32) {
+    self.frame_rate
+  }
+
   pub fn read_frame(&mut self) -> Result<Box<Frame>, io::Error> {
+    match self.read_next_frame()? {
+      Some(frame) => Ok(frame),
+      None => Err(io::Error::new(io::ErrorKind::UnexpectedEof, "No more frames in Y4M file")),
+    }
+  }
+
+  // Read the next frame, or return None if we're at the end of the file
+  pub fn read_next_frame(&mut self) -> Result<Option<Box<Frame>>, io::Error> {
     // Read frame line
     // Technically this can have parameters, but they aren't useful to us.
     // So just check the magic number to ensure we're in the right place
     // and skip the rest of the line
     let mut frame_magic = [0u8; 5];
-    self.inner.read_exact(&mut frame_magic)?;
+    if self.inner.read(&mut frame_magic[..1])? == 0 {
+      return Ok(None);
+    }
+    self.inner.read_exact(&mut frame_magic[1..])?;
     if frame_magic != Y4M_FRAME_MAGIC.as_bytes() {
       panic!("Invalid frame header");
     }
@@ -155,17 +220,23 @@ impl<R: Read> Y4MReader<R> {
     // Read actual frame data
     let mut frame = Frame::new(self.height, self.width);
     frame.y_mut().read_from(&mut self.inner)?;
-    frame.u_mut().read_from(&mut self.inner)?;
-    frame.v_mut().read_from(&mut self.inner)?;
+    if self.monochrome {
+      // Fill in neutral chroma
+      frame.u_mut().pixels_mut().fill_with(|_, _| 128);
+      frame.v_mut().pixels_mut().fill_with(|_, _| 128);
+    } else {
+      frame.u_mut().read_from(&mut self.inner)?;
+      frame.v_mut().read_from(&mut self.inner)?;
+    }
 
-    Ok(Box::new(frame))
+    Ok(Some(Box::new(frame)))
   }
 }
 
 impl<W: Write> Y4MWriter<W> {
   pub fn new(mut inner: W, width: usize, height: usize) -> Result<Self, io::Error> {
     inner.write_all(Y4M_FILE_MAGIC.as_bytes())?;
-    write!(inner, "W{} H{}\n", width, height)?;
+    writeln!(inner, "W{} H{}", width, height)?;
 
     Ok(Y4MWriter {
       inner: inner,